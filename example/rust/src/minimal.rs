@@ -30,7 +30,10 @@ pub fn say_hello_to(name: &str) {
   // Font size is set to 48.0 and other styles use default values
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .font_size(Some(48.0.into()))
@@ -44,6 +47,7 @@ pub fn say_hello_to(name: &str) {
   // Set dimensions to 1200x630 pixels (common size for social media images)
   let root: ContainerNode<NodeKind> = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Default::default(),
     children: Some([text.into()].into()),
@@ -64,5 +68,5 @@ pub fn say_hello_to(name: &str) {
   let mut file = File::create("output.webp").unwrap();
 
   // Write the image to the file in WebP format
-  write_image(&image, &mut file, ImageOutputFormat::WebP, None).unwrap();
+  write_image(&image, &mut file, ImageOutputFormat::WebP, None, None).unwrap();
 }
@@ -7,7 +7,12 @@ function applyStyle(node: AnyNode, style?: CSSProperties) {
   }
 }
 
-function applyPreset(node: AnyNode, preset?: CSSProperties) {
+function applyPreset(node: AnyNode, preset?: CSSProperties | string) {
+  if (typeof preset === "string") {
+    node.preset = preset;
+    return;
+  }
+
   if (preset && Object.keys(preset).length > 0) {
     node.preset = preset;
   }
@@ -23,6 +28,10 @@ export function container(props: Omit<ContainerNode, "type">): ContainerNode {
     node.tw = props.tw;
   }
 
+  if (props.id) {
+    node.id = props.id;
+  }
+
   applyPreset(node, props.preset);
   applyStyle(node, props.style);
 
@@ -56,6 +65,10 @@ export function text(
     node.tw = props.tw;
   }
 
+  if (props.id) {
+    node.id = props.id;
+  }
+
   applyPreset(node, props.preset);
   applyStyle(node, style ?? props.style);
 
@@ -74,6 +87,10 @@ export function image(props: Omit<ImageNode, "type">): ImageNode {
     node.tw = props.tw;
   }
 
+  if (props.id) {
+    node.id = props.id;
+  }
+
   applyPreset(node, props.preset);
   applyStyle(node, props.style);
 
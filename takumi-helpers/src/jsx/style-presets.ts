@@ -121,6 +121,22 @@ export const defaultStylePresets: Partial<
     margin: "1em 0",
     display: "block",
   },
+  // List elements, the marker is set on the list and drawn in its left padding
+  ul: {
+    listStyleType: "disc",
+    margin: "1em 0",
+    paddingLeft: 40,
+    display: "block",
+  },
+  ol: {
+    listStyleType: "decimal",
+    margin: "1em 0",
+    paddingLeft: 40,
+    display: "block",
+  },
+  li: {
+    display: "block",
+  },
   mark: {
     backgroundColor: "yellow",
     color: "black",
@@ -16,26 +16,48 @@ export type Node = ContainerNode | TextNode | ImageNode | AnyNode;
 
 export type ContainerNode = {
   type: "container";
-  preset?: CSSProperties;
+  /** Identifier for debugging, outlined when listed in the `debugHighlightIds` render option. */
+  id?: string;
+  preset?: CSSProperties | string;
   style?: CSSProperties;
   children?: Node[];
   tw?: string;
 };
 
+export type FontSizeFit = {
+  min: number;
+  max: number;
+  mode?: "box" | "singleLine" | { lines: number };
+};
+
 export type TextNode = {
   type: "text";
+  /** Identifier for debugging, outlined when listed in the `debugHighlightIds` render option. */
+  id?: string;
   text: string;
-  preset?: CSSProperties;
+  fontSizeFit?: FontSizeFit;
+  firstLetterStyle?: CSSProperties;
+  preset?: CSSProperties | string;
   style?: CSSProperties;
   tw?: string;
 };
 
+export type ImageNodeSource = {
+  url: string;
+  type?: string;
+  /** Pixel density the image is made for, like a `2x` srcset descriptor. Defaults to `1`. */
+  density?: number;
+};
+
 export type ImageNode = {
   type: "image";
+  /** Identifier for debugging, outlined when listed in the `debugHighlightIds` render option. */
+  id?: string;
   src: string;
+  sources?: ImageNodeSource[];
   width?: number;
   height?: number;
-  preset?: CSSProperties;
+  preset?: CSSProperties | string;
   style?: CSSProperties;
   tw?: string;
 };
@@ -84,7 +84,7 @@ function extractFetchedResources(
     return options.fetchedResources;
   }
 
-  const urls = extractResourceUrls(node);
+  const urls = extractResourceUrls(node, options?.devicePixelRatio);
 
   return fetchResources(urls);
 }
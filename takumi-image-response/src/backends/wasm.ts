@@ -125,7 +125,7 @@ function createStream(component: ReactNode, options: ImageResponseOptions) {
         const node = await fromJsx(component, options.jsx);
 
         if (!options.fetchedResources) {
-          const urls = extractResourceUrls(node);
+          const urls = extractResourceUrls(node, options.devicePixelRatio);
 
           if (urls.length > 0) {
             options.fetchedResources = await fetchResources(urls);
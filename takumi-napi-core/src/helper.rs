@@ -8,11 +8,15 @@ use takumi::{
 use crate::deserialize_with_tracing;
 
 /// Collects the fetch task urls from the node.
-#[napi(ts_args_type = "node: AnyNode")]
-pub fn extract_resource_urls(node: Object) -> Result<Vec<String>> {
+///
+/// With a device pixel ratio, only the image sources picked for it are collected.
+#[napi(ts_args_type = "node: AnyNode, devicePixelRatio?: number")]
+pub fn extract_resource_urls(node: Object, device_pixel_ratio: Option<f64>) -> Result<Vec<String>> {
   let node: NodeKind = deserialize_with_tracing(node)?;
 
-  let mut collection = FetchTaskCollection::default();
+  let mut collection = device_pixel_ratio.map_or_else(FetchTaskCollection::default, |ratio| {
+    FetchTaskCollection::with_device_pixel_ratio(ratio as f32)
+  });
 
   node.collect_fetch_tasks(&mut collection);
   node.collect_style_fetch_tasks(&mut collection);
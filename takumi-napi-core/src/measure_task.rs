@@ -18,6 +18,7 @@ pub struct MeasureTask<'g> {
   pub global: &'g GlobalContext,
   pub viewport: Viewport,
   pub fetched_resources: HashMap<Arc<str>, Buffer>,
+  pub max_text_length: Option<usize>,
 }
 
 impl<'g> MeasureTask<'g> {
@@ -45,6 +46,7 @@ impl<'g> MeasureTask<'g> {
         .into_iter()
         .map(|image| Ok((Arc::from(image.src), buffer_from_object(env, image.data)?)))
         .collect::<Result<_>>()?,
+      max_text_length: options.max_text_length.map(|length| length as usize),
     })
   }
 }
@@ -74,6 +76,7 @@ impl Task for MeasureTask<'_> {
       .fetched_resources(initialized_images)
       .node(node)
       .global(self.global)
+      .max_text_length(self.max_text_length)
       .build()
       .map_err(map_error)?;
 
@@ -16,6 +16,7 @@ pub struct RenderAnimationTask<'g> {
   pub viewport: Viewport,
   pub format: AnimationOutputFormat,
   pub draw_debug_border: bool,
+  pub max_text_length: Option<usize>,
 }
 
 impl Task for RenderAnimationTask<'_> {
@@ -37,6 +38,7 @@ impl Task for RenderAnimationTask<'_> {
               .node(node)
               .global(self.context)
               .draw_debug_border(self.draw_debug_border)
+              .max_text_length(self.max_text_length)
               .build()
               .map_err(map_error)?,
           )
@@ -50,7 +52,7 @@ impl Task for RenderAnimationTask<'_> {
 
     match self.format {
       AnimationOutputFormat::webp => {
-        encode_animated_webp(&frames, &mut buffer, true, false, None)
+        encode_animated_webp(&frames, &mut buffer, None, self.context)
           .map_err(|e| napi::Error::from_reason(e.to_string()))?;
       }
       AnimationOutputFormat::apng => {
@@ -15,12 +15,18 @@ use crate::{
 
 pub struct RenderTask<'g> {
   pub draw_debug_border: bool,
+  pub debug_highlight_ids: Vec<String>,
   pub node: Option<NodeKind>,
   pub global: &'g GlobalContext,
   pub viewport: Viewport,
   pub format: OutputFormat,
   pub quality: Option<u8>,
+  pub color_profile: Option<Buffer>,
   pub fetched_resources: HashMap<Arc<str>, Buffer>,
+  pub max_width: Option<u32>,
+  pub max_height: Option<u32>,
+  pub max_area: Option<u64>,
+  pub max_text_length: Option<usize>,
 }
 
 impl<'g> RenderTask<'g> {
@@ -44,13 +50,19 @@ impl<'g> RenderTask<'g> {
       },
       format: options.format.unwrap_or(OutputFormat::png),
       quality: options.quality,
+      color_profile: options.color_profile,
       draw_debug_border: options.draw_debug_border.unwrap_or_default(),
+      debug_highlight_ids: options.debug_highlight_ids.unwrap_or_default(),
       fetched_resources: options
         .fetched_resources
         .unwrap_or_default()
         .into_iter()
         .map(|image| Ok((Arc::from(image.src), buffer_from_object(env, image.data)?)))
         .collect::<Result<_>>()?,
+      max_width: options.max_width,
+      max_height: options.max_height,
+      max_area: options.max_area.map(|area| area as u64),
+      max_text_length: options.max_text_length.map(|length| length as usize),
     })
   }
 }
@@ -82,6 +94,11 @@ impl Task for RenderTask<'_> {
         .node(node)
         .global(self.global)
         .draw_debug_border(self.draw_debug_border)
+        .debug_highlight_ids(self.debug_highlight_ids.clone())
+        .max_width(self.max_width)
+        .max_height(self.max_height)
+        .max_area(self.max_area)
+        .max_text_length(self.max_text_length)
         .build()
         .map_err(map_error)?,
     )
@@ -93,7 +110,14 @@ impl Task for RenderTask<'_> {
 
     let mut buffer = Vec::new();
 
-    write_image(&image, &mut buffer, self.format.into(), self.quality).map_err(map_error)?;
+    write_image(
+      &image,
+      &mut buffer,
+      self.format.into(),
+      self.quality,
+      self.color_profile.as_deref(),
+    )
+    .map_err(map_error)?;
 
     Ok(buffer)
   }
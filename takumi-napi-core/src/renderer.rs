@@ -98,13 +98,25 @@ pub struct RenderOptions<'env> {
   pub format: Option<OutputFormat>,
   /// The quality of JPEG format (0-100).
   pub quality: Option<u8>,
+  /// An ICC color profile embedded into the encoded image, ignored for the raw format.
+  pub color_profile: Option<Buffer>,
   /// Whether to draw debug borders.
   pub draw_debug_border: Option<bool>,
+  /// Ids of nodes to highlight with a debug outline.
+  pub debug_highlight_ids: Option<Vec<String>>,
   /// The fetched resources to use.
   pub fetched_resources: Option<Vec<ImageSource<'env>>>,
   /// The device pixel ratio.
   /// @default 1.0
   pub device_pixel_ratio: Option<f64>,
+  /// The maximum width of the rendered image. Rendering fails if the computed width exceeds it.
+  pub max_width: Option<u32>,
+  /// The maximum height of the rendered image. Rendering fails if the computed height exceeds it.
+  pub max_height: Option<u32>,
+  /// The maximum area (width × height) of the rendered image. Rendering fails if the computed area exceeds it.
+  pub max_area: Option<f64>,
+  /// The maximum number of characters in a single text node. Rendering fails if any text exceeds it.
+  pub max_text_length: Option<u32>,
 }
 
 /// Represents a single frame in an animation sequence.
@@ -128,6 +140,8 @@ pub struct RenderAnimationOptions {
   pub height: u32,
   /// The output animation format (WebP or APNG).
   pub format: Option<AnimationOutputFormat>,
+  /// The maximum number of characters in a single text node. Rendering fails if any frame has longer text.
+  pub max_text_length: Option<u32>,
 }
 
 /// Output format for animated images.
@@ -433,6 +447,12 @@ impl Renderer {
     self.global.persistent_image_store.clear();
   }
 
+  /// Unloads all fonts from the renderer.
+  #[napi]
+  pub fn clear_fonts(&mut self) {
+    self.global.font_context.clear();
+  }
+
   /// Renders a node tree into an image buffer asynchronously.
   #[napi(
     ts_args_type = "source: AnyNode, options?: RenderOptions, signal?: AbortSignal",
@@ -511,6 +531,7 @@ impl Renderer {
         viewport: (options.width, options.height).into(),
         format: options.format.unwrap_or(AnimationOutputFormat::webp),
         draw_debug_border: options.draw_debug_border.unwrap_or_default(),
+        max_text_length: options.max_text_length.map(|length| length as usize),
       },
       signal,
     ))
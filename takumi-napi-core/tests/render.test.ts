@@ -196,4 +196,5 @@ describe("render", () => {
 
 describe("clean up", () => {
   test("clearImageStore", () => renderer.clearImageStore());
+  test("clearFonts", () => renderer.clearFonts());
 });
@@ -55,7 +55,7 @@ pub async fn generate_image_handler(
 
     let mut buffer = Vec::new();
 
-    write_image(&image, &mut buffer, format, query.quality).map_err(|_| {
+    write_image(&image, &mut buffer, format, query.quality, None).map_err(|_| {
       (
         StatusCode::INTERNAL_SERVER_ERROR,
         "Failed to write image.".to_string(),
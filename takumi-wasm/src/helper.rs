@@ -18,11 +18,19 @@ pub fn map_error<E: Display>(err: E) -> js_sys::Error {
 pub type JsResult<T> = Result<T, js_sys::Error>;
 
 /// Collects the fetch task urls from the node.
+///
+/// With a device pixel ratio, only the image sources picked for it are collected.
 #[wasm_bindgen(js_name = extractResourceUrls)]
-pub fn extract_resource_urls(node: AnyNode) -> JsResult<Vec<String>> {
+pub fn extract_resource_urls(
+  node: AnyNode,
+  device_pixel_ratio: Option<f32>,
+) -> JsResult<Vec<String>> {
   let node: NodeKind = from_value(node.into()).map_err(map_error)?;
 
-  let mut collection = FetchTaskCollection::default();
+  let mut collection = device_pixel_ratio.map_or_else(
+    FetchTaskCollection::default,
+    FetchTaskCollection::with_device_pixel_ratio,
+  );
 
   node.collect_fetch_tasks(&mut collection);
   node.collect_style_fetch_tasks(&mut collection);
@@ -40,5 +48,5 @@ pub fn extract_resource_urls(node: AnyNode) -> JsResult<Vec<String>> {
 /// @deprecated Use `extractResourceUrls` instead.
 #[wasm_bindgen(js_name = collectNodeFetchTasks)]
 pub fn collect_node_fetch_tasks(node: AnyNode) -> JsResult<Vec<String>> {
-  extract_resource_urls(node)
+  extract_resource_urls(node, None)
 }
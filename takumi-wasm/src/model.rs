@@ -1,6 +1,6 @@
 //! Data models and types for the WebAssembly bindings.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
@@ -9,6 +9,8 @@ use wasm_bindgen::prelude::*;
 const TS_APPEND_CONTENT: &'static str = r#"
 export type AnyNode = { type: string; [key: string]: any };
 
+export type AnyStyle = { [key: string]: any };
+
 export type ByteBuf = Uint8Array | ArrayBuffer | Buffer;
 
 export type RenderOptions = {
@@ -29,6 +31,10 @@ export type RenderOptions = {
    * The quality of JPEG format (0-100).
    */
   quality?: number,
+  /**
+   * An ICC color profile embedded into the encoded image, ignored for the raw format.
+   */
+  colorProfile?: ByteBuf,
   /**
    * The resources fetched externally. You should collect the fetch tasks first using `extractResourceUrls` and then pass the resources here.
    */
@@ -37,11 +43,31 @@ export type RenderOptions = {
    * Whether to draw debug borders.
    */
   drawDebugBorder?: boolean,
+  /**
+   * Ids of nodes to highlight with a debug outline.
+   */
+  debugHighlightIds?: string[],
   /**
    * Defines the ratio resolution of the image to the physical pixels.
    * @default 1.0
    */
   devicePixelRatio?: number,
+  /**
+   * The maximum width of the rendered image. Rendering fails if the computed width exceeds it.
+   */
+  maxWidth?: number,
+  /**
+   * The maximum height of the rendered image. Rendering fails if the computed height exceeds it.
+   */
+  maxHeight?: number,
+  /**
+   * The maximum area (width × height) of the rendered image. Rendering fails if the computed area exceeds it.
+   */
+  maxArea?: number,
+  /**
+   * The maximum number of characters in a single text node. Rendering fails if any text exceeds it.
+   */
+  maxTextLength?: number,
 };
 
 export type RenderAnimationOptions = {
@@ -49,6 +75,11 @@ export type RenderAnimationOptions = {
   height: number,
   format?: "webp" | "apng",
   drawDebugBorder?: boolean,
+  background?: AnyNode,
+  /**
+   * The maximum number of characters in a single text node. Rendering fails if any frame has longer text.
+   */
+  maxTextLength?: number,
 };
 
 export type FontDetails = {
@@ -92,6 +123,61 @@ export type MeasuredNode = {
   runs: MeasuredTextRun[],
 };
 
+export type DebugLayoutStyle = {
+  display: string,
+  position: string,
+  boxSizing: string,
+  flexDirection: string,
+  overflowX: string,
+  overflowY: string,
+  fontSize: number,
+};
+
+export type DebugLayoutNode = {
+  nodeType: "container" | "image" | "text" | null,
+  x: number,
+  y: number,
+  width: number,
+  height: number,
+  padding: [number, number, number, number],
+  border: [number, number, number, number],
+  margin: [number, number, number, number],
+  style: DebugLayoutStyle,
+  children: DebugLayoutNode[],
+};
+
+export type LoadedFontFamily = {
+  family: string,
+  /**
+   * The distinct weights of the faces, in ascending order. Variable fonts report their default weight.
+   */
+  weights: number[],
+  styles: ("normal" | "italic" | "oblique")[],
+};
+
+export type RenderStats = {
+  /**
+   * The time spent building the tree and computing its layout, in milliseconds.
+   */
+  layoutTimeMs: number,
+  /**
+   * The time spent drawing onto the canvas, in milliseconds.
+   */
+  drawTimeMs: number,
+  /**
+   * The number of nodes in the tree.
+   */
+  nodeCount: number,
+  /**
+   * The number of distinct external images resolved.
+   */
+  resolvedImageCount: number,
+  /**
+   * The most bytes held at once by full-size canvas images, including isolated layers.
+   */
+  peakCanvasBytes: number,
+};
+
 export type AnimationFrameSource = {
   node: AnyNode,
   durationMs: number,
@@ -105,6 +191,10 @@ extern "C" {
   #[derive(Debug)]
   pub type AnyNode;
 
+  /// JavaScript object representing a node style.
+  #[wasm_bindgen(typescript_type = "AnyStyle")]
+  pub type StyleType;
+
   /// JavaScript object representing render options.
   #[wasm_bindgen(typescript_type = "RenderOptions")]
   pub type RenderOptionsType;
@@ -133,6 +223,18 @@ extern "C" {
   #[wasm_bindgen(typescript_type = "MeasuredNode")]
   pub type MeasuredNodeType;
 
+  /// JavaScript object representing a debug layout node tree.
+  #[wasm_bindgen(typescript_type = "DebugLayoutNode")]
+  pub type DebugLayoutNodeType;
+
+  /// JavaScript array listing the loaded font families.
+  #[wasm_bindgen(typescript_type = "LoadedFontFamily[]")]
+  pub type LoadedFontFamiliesType;
+
+  /// JavaScript object representing the stats of a render.
+  #[wasm_bindgen(typescript_type = "RenderStats")]
+  pub type RenderStatsType;
+
   /// JavaScript object representing an animation frame source.
   #[wasm_bindgen(typescript_type = "AnimationFrameSource")]
   pub type AnimationFrameSourceType;
@@ -150,12 +252,24 @@ pub struct RenderOptions {
   pub format: Option<OutputFormat>,
   /// The JPEG quality (0-100), if applicable.
   pub quality: Option<u8>,
+  /// An ICC color profile embedded into the encoded image.
+  pub color_profile: Option<ByteBuf>,
   /// Pre-fetched image resources to use during rendering.
   pub fetched_resources: Option<Vec<ImageSource>>,
   /// Whether to draw debug borders around layout elements.
   pub draw_debug_border: Option<bool>,
+  /// Ids of nodes to highlight with a debug outline.
+  pub debug_highlight_ids: Option<Vec<String>>,
   /// The device pixel ratio for scaling.
   pub device_pixel_ratio: Option<f32>,
+  /// The maximum width of the rendered image in pixels.
+  pub max_width: Option<u32>,
+  /// The maximum height of the rendered image in pixels.
+  pub max_height: Option<u32>,
+  /// The maximum area (width × height) of the rendered image in pixels.
+  pub max_area: Option<u64>,
+  /// The maximum number of characters in a single text node.
+  pub max_text_length: Option<usize>,
 }
 
 /// Options for rendering an animated image.
@@ -170,6 +284,11 @@ pub struct RenderAnimationOptions {
   pub format: Option<AnimationOutputFormat>,
   /// Whether to draw debug borders around layout elements.
   pub draw_debug_border: Option<bool>,
+  /// A static node rendered once and shared by every frame, with each frame's node drawn on top.
+  /// Its layout must not change between frames.
+  pub background: Option<takumi::layout::node::NodeKind>,
+  /// The maximum number of characters in a single text node, checked for the background and every frame.
+  pub max_text_length: Option<usize>,
 }
 
 /// Details for loading a custom font.
@@ -289,3 +408,60 @@ pub struct ImageCacheKey {
   pub(crate) src: Box<str>,
   pub(crate) data_hash: u64,
 }
+
+/// A loaded font family, as returned by `Renderer.listFonts`.
+#[derive(Serialize)]
+pub struct LoadedFontFamily {
+  /// The family name.
+  pub family: String,
+  /// The distinct weights of the faces in ascending order.
+  pub weights: Vec<f32>,
+  /// The distinct styles of the faces.
+  pub styles: Vec<&'static str>,
+}
+
+impl From<takumi::resources::font::LoadedFamily> for LoadedFontFamily {
+  fn from(family: takumi::resources::font::LoadedFamily) -> Self {
+    Self {
+      family: family.family,
+      weights: family.weights,
+      styles: family
+        .styles
+        .into_iter()
+        .map(|style| match style {
+          takumi::parley::FontStyle::Normal => "normal",
+          takumi::parley::FontStyle::Italic => "italic",
+          takumi::parley::FontStyle::Oblique(_) => "oblique",
+        })
+        .collect(),
+    }
+  }
+}
+
+/// The stats of a render, as returned by `Renderer.renderWithStats`.
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderStats {
+  /// The layout time in milliseconds.
+  pub layout_time_ms: f64,
+  /// The draw time in milliseconds.
+  pub draw_time_ms: f64,
+  /// The number of nodes.
+  pub node_count: usize,
+  /// The number of distinct external images resolved.
+  pub resolved_image_count: usize,
+  /// The peak canvas memory in bytes.
+  pub peak_canvas_bytes: usize,
+}
+
+impl From<takumi::rendering::RenderStats> for RenderStats {
+  fn from(stats: takumi::rendering::RenderStats) -> Self {
+    Self {
+      layout_time_ms: stats.layout_time.as_secs_f64() * 1000.0,
+      draw_time_ms: stats.draw_time.as_secs_f64() * 1000.0,
+      node_count: stats.node_count,
+      resolved_image_count: stats.resolved_image_count,
+      peak_canvas_bytes: stats.peak_canvas_bytes,
+    }
+  }
+}
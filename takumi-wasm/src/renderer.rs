@@ -4,22 +4,24 @@ use crate::{
   helper::map_error,
   model::{
     AnimationFrameSource, AnimationFrameSourceType, AnimationOutputFormat, AnyNode,
-    ConstructRendererOptions, ConstructRendererOptionsType, Font, FontType, ImageCacheKey,
-    ImageSource, ImageSourceType, MeasuredNodeType, OutputFormat, RenderAnimationOptions,
-    RenderAnimationOptionsType, RenderOptions, RenderOptionsType,
+    ConstructRendererOptions, ConstructRendererOptionsType, DebugLayoutNodeType, Font, FontType,
+    ImageCacheKey, ImageSource, ImageSourceType, LoadedFontFamiliesType, LoadedFontFamily,
+    MeasuredNodeType, OutputFormat, RenderAnimationOptions, RenderAnimationOptionsType,
+    RenderOptions, RenderOptionsType, RenderStats, RenderStatsType, StyleType,
   },
 };
 use base64::{Engine, prelude::BASE64_STANDARD};
 use js_sys::Uint8Array;
 use serde_wasm_bindgen::{from_value, to_value};
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 use takumi::{
   GlobalContext,
-  layout::{DEFAULT_DEVICE_PIXEL_RATIO, DEFAULT_FONT_SIZE, Viewport, node::NodeKind},
+  layout::{DEFAULT_DEVICE_PIXEL_RATIO, DEFAULT_FONT_SIZE, Viewport, node::NodeKind, style::Style},
   parley::{FontWeight, fontique::FontInfoOverride},
   rendering::{
     AnimationFrame, ImageOutputFormat, RenderOptionsBuilder, encode_animated_png,
-    encode_animated_webp, measure_layout, render, write_image,
+    encode_animated_webp, measure_layout, render, render_debug_layout, render_with_stats,
+    write_image,
   },
   resources::image::load_image_source_from_bytes,
 };
@@ -57,6 +59,74 @@ impl WasmBuffer {
   }
 }
 
+/// Unencoded pixels of a rendered image.
+///
+/// Pixels are straight (not premultiplied) RGBA8 in row-major order.
+#[wasm_bindgen]
+pub struct RawImage {
+  width: u32,
+  height: u32,
+  data: Box<[u8]>,
+}
+
+#[wasm_bindgen]
+impl RawImage {
+  /// Returns the image width in pixels.
+  #[wasm_bindgen(getter)]
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  /// Returns the image height in pixels.
+  #[wasm_bindgen(getter)]
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+
+  /// Returns a Uint8Array view over the pixels in WASM memory without cloning.
+  #[wasm_bindgen(js_name = asUint8Array)]
+  pub fn as_uint8_array(&self) -> Uint8Array {
+    // SAFETY: `self.data` is owned by this object, so the view remains valid
+    // for the lifetime of this `RawImage` instance.
+    unsafe { Uint8Array::view(self.data.as_ref()) }
+  }
+}
+
+/// An encoded image along with the stats of the render that produced it.
+#[wasm_bindgen]
+pub struct RenderedImage {
+  data: Box<[u8]>,
+  stats: RenderStats,
+}
+
+#[wasm_bindgen]
+impl RenderedImage {
+  /// Returns the buffer byte length.
+  #[wasm_bindgen(getter = byteLength)]
+  pub fn byte_length(&self) -> usize {
+    self.data.len()
+  }
+
+  /// Returns a Uint8Array view over WASM memory without cloning.
+  #[wasm_bindgen(js_name = asUint8Array)]
+  pub fn as_uint8_array(&self) -> Uint8Array {
+    // SAFETY: `self.data` is owned by this object, so the view remains valid
+    // for the lifetime of this `RenderedImage` instance.
+    unsafe { Uint8Array::view(self.data.as_ref()) }
+  }
+
+  /// Returns the timings and counters of the render.
+  #[wasm_bindgen(getter)]
+  pub fn stats(&self) -> Result<RenderStatsType, js_sys::Error> {
+    Ok(to_value(&self.stats).map_err(map_error)?.into())
+  }
+}
+
+/// `Instant` isn't available on `wasm32-unknown-unknown`, so renders are timed with `Date.now()`.
+fn js_clock() -> Duration {
+  Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+}
+
 /// The main renderer for Takumi image rendering engine.
 #[wasm_bindgen]
 #[derive(Default)]
@@ -170,6 +240,35 @@ impl Renderer {
     self.context.persistent_image_store.clear();
   }
 
+  /// Unloads all fonts from the renderer.
+  #[wasm_bindgen(js_name = clearFonts)]
+  pub fn clear_fonts(&mut self) {
+    self.context.font_context.clear();
+  }
+
+  /// Lists the loaded font families with the weights and styles of their faces.
+  #[wasm_bindgen(js_name = listFonts)]
+  pub fn list_fonts(&mut self) -> Result<LoadedFontFamiliesType, js_sys::Error> {
+    let families: Vec<LoadedFontFamily> = self
+      .context
+      .font_context
+      .loaded_families()
+      .into_iter()
+      .map(Into::into)
+      .collect();
+
+    Ok(to_value(&families).map_err(map_error)?.into())
+  }
+
+  /// Registers a named style preset that nodes can reference with `preset: "name"`.
+  #[wasm_bindgen(js_name = registerPreset)]
+  pub fn register_preset(&mut self, name: String, style: StyleType) -> Result<(), js_sys::Error> {
+    let style: Style = from_value(style.into()).map_err(map_error)?;
+    self.context.register_preset(name, style);
+
+    Ok(())
+  }
+
   /// Renders a node tree into an image buffer.
   #[wasm_bindgen]
   pub fn render(
@@ -188,22 +287,73 @@ impl Renderer {
       .map(WasmBuffer::from_vec)
   }
 
-  fn render_internal(&self, node: NodeKind, options: RenderOptions) -> Result<Vec<u8>, JsValue> {
+  /// Renders a node tree into an image buffer, along with timings and counters of the render.
+  #[wasm_bindgen(js_name = renderWithStats)]
+  pub fn render_with_stats(
+    &self,
+    node: AnyNode,
+    options: Option<RenderOptionsType>,
+  ) -> Result<RenderedImage, JsValue> {
+    let node: NodeKind = from_value(node.into()).map_err(map_error)?;
+    let options: RenderOptions = options
+      .map(|options| from_value(options.into()).map_err(map_error))
+      .transpose()?
+      .unwrap_or_default();
+
+    let (data, stats) = self.render_with_stats_internal(node, options)?;
+
+    Ok(RenderedImage {
+      data: data.into_boxed_slice(),
+      stats: stats.into(),
+    })
+  }
+
+  /// Renders a node tree into unencoded RGBA pixels along with the image dimensions.
+  ///
+  /// `format` and `quality` options are ignored.
+  #[wasm_bindgen(js_name = renderRaw)]
+  pub fn render_raw(
+    &self,
+    node: AnyNode,
+    options: Option<RenderOptionsType>,
+  ) -> Result<RawImage, JsValue> {
+    let node: NodeKind = from_value(node.into()).map_err(map_error)?;
+    let options: RenderOptions = options
+      .map(|options| from_value(options.into()).map_err(map_error))
+      .transpose()?
+      .unwrap_or_default();
+
+    let render_options = self.create_render_options(node, &options)?;
+    let image = render(render_options).map_err(map_error)?;
+
+    Ok(RawImage {
+      width: image.width(),
+      height: image.height(),
+      data: image.into_raw().into_boxed_slice(),
+    })
+  }
+
+  fn create_render_options(
+    &self,
+    node: NodeKind,
+    options: &RenderOptions,
+  ) -> Result<takumi::rendering::RenderOptions<'_, NodeKind>, JsValue> {
     let fetched_resources = options
       .fetched_resources
+      .as_ref()
       .map(|resources| -> Result<_, JsValue> {
         resources
-          .into_iter()
+          .iter()
           .map(|source| {
             let image = load_image_source_from_bytes(&source.data).map_err(map_error)?;
-            Ok((source.src, image))
+            Ok((source.src.clone(), image))
           })
           .collect::<Result<_, JsValue>>()
       })
       .transpose()?
       .unwrap_or_default();
 
-    let render_options = RenderOptionsBuilder::default()
+    RenderOptionsBuilder::default()
       .viewport(Viewport {
         width: options.width,
         height: options.height,
@@ -213,25 +363,52 @@ impl Renderer {
           .unwrap_or(DEFAULT_DEVICE_PIXEL_RATIO),
       })
       .draw_debug_border(options.draw_debug_border.unwrap_or_default())
+      .debug_highlight_ids(options.debug_highlight_ids.clone().unwrap_or_default())
       .fetched_resources(fetched_resources)
+      .max_width(options.max_width)
+      .max_height(options.max_height)
+      .max_area(options.max_area)
+      .max_text_length(options.max_text_length)
+      .clock(js_clock)
       .node(node)
       .global(&self.context)
       .build()
-      .map_err(|e| JsValue::from_str(&format!("Failed to build render options: {e}")))?;
+      .map_err(|e| JsValue::from_str(&format!("Failed to build render options: {e}")))
+  }
 
-    let image = render(render_options).map_err(map_error)?;
+  fn render_internal(&self, node: NodeKind, options: RenderOptions) -> Result<Vec<u8>, JsValue> {
+    self
+      .render_with_stats_internal(node, options)
+      .map(|(buffer, _)| buffer)
+  }
+
+  fn render_with_stats_internal(
+    &self,
+    node: NodeKind,
+    options: RenderOptions,
+  ) -> Result<(Vec<u8>, takumi::rendering::RenderStats), JsValue> {
+    let render_options = self.create_render_options(node, &options)?;
+
+    let (image, stats) = render_with_stats(render_options).map_err(map_error)?;
 
     let format = options.format.unwrap_or(OutputFormat::Png);
 
     if format == OutputFormat::Raw {
-      return Ok(image.into_raw());
+      return Ok((image.into_raw(), stats));
     }
 
     let mut buffer = Vec::new();
 
-    write_image(&image, &mut buffer, format.into(), options.quality).map_err(map_error)?;
+    write_image(
+      &image,
+      &mut buffer,
+      format.into(),
+      options.quality,
+      options.color_profile.as_deref().map(Vec::as_slice),
+    )
+    .map_err(map_error)?;
 
-    Ok(buffer)
+    Ok((buffer, stats))
   }
 
   /// Measures a node tree and returns layout information.
@@ -247,37 +424,28 @@ impl Renderer {
       .transpose()?
       .unwrap_or_default();
 
-    let fetched_resources = options
-      .fetched_resources
-      .map(|resources| -> Result<_, JsValue> {
-        resources
-          .into_iter()
-          .map(|source| {
-            let image = load_image_source_from_bytes(&source.data).map_err(map_error)?;
-            Ok((source.src, image))
-          })
-          .collect::<Result<_, JsValue>>()
-      })
+    let render_options = self.create_render_options(node, &options)?;
+
+    let layout = measure_layout(render_options).map_err(map_error)?;
+
+    Ok(to_value(&layout).map_err(map_error)?.into())
+  }
+
+  /// Computes the layout of a node tree and returns it with each node's box and a subset of its computed style.
+  #[wasm_bindgen(js_name = debugLayout)]
+  pub fn debug_layout(
+    &self,
+    node: AnyNode,
+    options: Option<RenderOptionsType>,
+  ) -> Result<DebugLayoutNodeType, JsValue> {
+    let node: NodeKind = from_value(node.into()).map_err(map_error)?;
+    let options: RenderOptions = options
+      .map(|options| from_value(options.into()).map_err(map_error))
       .transpose()?
       .unwrap_or_default();
 
-    let render_options = RenderOptionsBuilder::default()
-      .viewport(Viewport {
-        width: options.width,
-        height: options.height,
-        font_size: DEFAULT_FONT_SIZE,
-        device_pixel_ratio: options
-          .device_pixel_ratio
-          .unwrap_or(DEFAULT_DEVICE_PIXEL_RATIO),
-      })
-      .draw_debug_border(options.draw_debug_border.unwrap_or_default())
-      .fetched_resources(fetched_resources)
-      .node(node)
-      .global(&self.context)
-      .build()
-      .map_err(|e| JsValue::from_str(&format!("Failed to build render options: {e}")))?;
-
-    let layout = measure_layout(render_options).map_err(map_error)?;
+    let render_options = self.create_render_options(node, &options)?;
+    let layout = render_debug_layout(render_options).map_err(map_error)?;
 
     Ok(to_value(&layout).map_err(map_error)?.into())
   }
@@ -324,6 +492,22 @@ impl Renderer {
     let frames: Vec<AnimationFrameSource> = from_value(frames.into()).map_err(map_error)?;
     let options: RenderAnimationOptions = from_value(options.into()).map_err(map_error)?;
 
+    let background = options
+      .background
+      .map(|node| {
+        let render_options = RenderOptionsBuilder::default()
+          .viewport((options.width, options.height).into())
+          .node(node)
+          .global(&self.context)
+          .draw_debug_border(options.draw_debug_border.unwrap_or_default())
+          .max_text_length(options.max_text_length)
+          .build()
+          .map_err(|e| JsValue::from_str(&format!("Failed to build render options: {e}")))?;
+
+        render(render_options).map_err(map_error)
+      })
+      .transpose()?;
+
     let rendered_frames: Vec<AnimationFrame> = frames
       .into_iter()
       .map(|frame| -> Result<AnimationFrame, JsValue> {
@@ -332,6 +516,8 @@ impl Renderer {
           .node(frame.node)
           .global(&self.context)
           .draw_debug_border(options.draw_debug_border.unwrap_or_default())
+          .background(background.as_ref())
+          .max_text_length(options.max_text_length)
           .build()
           .map_err(|e| JsValue::from_str(&format!("Failed to build render options: {e}")))?;
 
@@ -344,7 +530,7 @@ impl Renderer {
 
     match options.format.unwrap_or(AnimationOutputFormat::WebP) {
       AnimationOutputFormat::WebP => {
-        encode_animated_webp(&rendered_frames, &mut buffer, true, false, None)
+        encode_animated_webp(&rendered_frames, &mut buffer, None, &self.context)
           .map_err(map_error)?;
       }
       AnimationOutputFormat::APng => {
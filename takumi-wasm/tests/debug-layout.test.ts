@@ -0,0 +1,40 @@
+import { describe, expect, it } from "bun:test";
+import { Renderer } from "../bundlers/node";
+
+describe("Renderer.debugLayout", () => {
+  const renderer = new Renderer();
+
+  it("should dump the computed layout tree", () => {
+    const node = {
+      type: "container",
+      style: {
+        width: 200,
+        height: 100,
+        padding: 10,
+      },
+      children: [
+        {
+          type: "container",
+          style: {
+            width: 50,
+            height: 50,
+            margin: 5,
+          },
+        },
+      ],
+    };
+
+    const result = renderer.debugLayout(node);
+
+    expect(result.nodeType).toBe("container");
+    expect(result.width).toBe(200);
+    expect(result.height).toBe(100);
+    expect(result.padding).toEqual([10, 10, 10, 10]);
+
+    const [child] = result.children;
+
+    expect(child?.x).toBe(15);
+    expect(child?.y).toBe(15);
+    expect(child?.margin).toEqual([5, 5, 5, 5]);
+  });
+});
@@ -343,3 +343,23 @@ test("report deserialize error for textDecorationLine with invalid string value"
     "invalid value: string \"invalid\", expected a value of 'underline', 'line-through' or 'overline'; also accepts 'none', 'initial' or 'inherit'.",
   );
 });
+
+test("report deserialize error for gridTemplateAreas with non-rectangular area", () => {
+  expect(() =>
+    renderer.render(
+      {
+        type: "container",
+        children: [],
+        style: {
+          gridTemplateAreas: '"a a" "a b"',
+        },
+      },
+      {
+        width: 100,
+        height: 100,
+      },
+    ),
+  ).toThrowError(
+    "invalid value: string \"\\\"a a\\\" \\\"a b\\\"\", grid-template-areas area 'a' must form a single rectangle.",
+  );
+});
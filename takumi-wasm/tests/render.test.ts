@@ -3,7 +3,7 @@ import { readFile } from "node:fs/promises";
 import { join } from "node:path";
 import { container, image, text } from "@takumi-rs/helpers";
 import { Glob } from "bun";
-import { Renderer, WasmBuffer } from "../bundlers/node";
+import { RawImage, Renderer, WasmBuffer } from "../bundlers/node";
 
 const fontsGlob = new Glob("**/*.{woff2,ttf}");
 
@@ -55,6 +55,15 @@ describe("setup", () => {
     for (const font of fonts) renderer.loadFont(font);
   });
 
+  test("listFonts", () => {
+    const archivo = renderer
+      .listFonts()
+      .find((family) => family.family === "Archivo");
+
+    expect(archivo?.weights.length).toBeGreaterThan(0);
+    expect(archivo?.styles).toEqual(["normal"]);
+  });
+
   test("putPersistentImage", () => {
     renderer.putPersistentImage({
       src: localImagePath,
@@ -143,6 +152,18 @@ describe("render", () => {
     expect(result.asUint8Array()).toBeInstanceOf(Uint8Array);
   });
 
+  test("renderRaw returns unencoded pixels with dimensions", () => {
+    using result = renderer.renderRaw(node, {
+      width: 1200,
+      height: 630,
+    });
+
+    expect(result).toBeInstanceOf(RawImage);
+    expect(result.width).toBe(1200);
+    expect(result.height).toBe(630);
+    expect(result.asUint8Array().byteLength).toBe(1200 * 630 * 4);
+  });
+
   test("with fetched resources", () => {
     using result = renderer.render(node, {
       width: 1200,
@@ -0,0 +1,109 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use takumi::{
+  GlobalContext,
+  layout::{
+    Viewport,
+    node::{ContainerNode, NodeKind},
+  },
+  rendering::{RenderOptionsBuilder, render},
+};
+
+const FRAMES: u32 = 60;
+
+fn container(tw: String, children: Option<Box<[NodeKind]>>) -> NodeKind {
+  NodeKind::Container(ContainerNode {
+    children,
+    preset: None,
+    id: None,
+    style: None,
+    tw: Some(tw.parse().unwrap()),
+  })
+}
+
+fn background() -> NodeKind {
+  container(
+    "w-full h-full bg-linear-to-r from-sky-500 to-indigo-700".to_string(),
+    Some(
+      [container(
+        "absolute top-[64px] left-[64px] w-[384px] h-[256px] rounded-3xl bg-white shadow-2xl blur-md"
+          .to_string(),
+        None,
+      )]
+      .into(),
+    ),
+  )
+}
+
+fn progress_bar(frame: u32) -> NodeKind {
+  container(
+    format!(
+      "absolute bottom-[32px] left-[32px] h-[16px] w-[{}px] rounded-full bg-white",
+      frame * 7
+    ),
+    None,
+  )
+}
+
+fn bench_animation(c: &mut Criterion) {
+  let global = GlobalContext::default();
+  let viewport = Viewport::new(Some(512), Some(512));
+
+  let mut group = c.benchmark_group("animation");
+
+  group.bench_function("full_60_frames", |b| {
+    b.iter(|| {
+      for frame in 0..FRAMES {
+        let node = container(
+          "w-full h-full".to_string(),
+          Some([background(), progress_bar(frame)].into()),
+        );
+
+        let options = RenderOptionsBuilder::default()
+          .viewport(viewport)
+          .node(node)
+          .global(&global)
+          .build()
+          .unwrap();
+
+        black_box(render(options).unwrap());
+      }
+    })
+  });
+
+  group.bench_function("incremental_60_frames", |b| {
+    b.iter(|| {
+      let background = render(
+        RenderOptionsBuilder::default()
+          .viewport(viewport)
+          .node(background())
+          .global(&global)
+          .build()
+          .unwrap(),
+      )
+      .unwrap();
+
+      for frame in 0..FRAMES {
+        let node = container(
+          "w-full h-full".to_string(),
+          Some([progress_bar(frame)].into()),
+        );
+
+        let options = RenderOptionsBuilder::default()
+          .viewport(viewport)
+          .node(node)
+          .global(&global)
+          .background(Some(&background))
+          .build()
+          .unwrap();
+
+        black_box(render(options).unwrap());
+      }
+    })
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_animation);
+criterion_main!(benches);
@@ -13,6 +13,7 @@ fn run_effect_render(global: &GlobalContext, effect_tw: &str) {
   let node = NodeKind::Container(ContainerNode {
     children: None,
     preset: None,
+    id: None,
     style: None,
     // We set a reasonable size and background so the effect is actually computed
     tw: Some(
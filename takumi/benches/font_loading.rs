@@ -0,0 +1,54 @@
+//! Compares loading a font into every worker's context with importing it from a
+//! [`PreparedFonts`](takumi::resources::font::PreparedFonts) snapshot.
+//!
+//! Run with `cargo bench --bench font_loading` and compare `load_and_store_per_context` against
+//! `import_prepared_per_context`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use takumi::resources::font::FontContext;
+
+/// How many contexts a worker pool initializes, one per worker.
+const WORKERS: usize = 8;
+
+/// A large WOFF2 font, decompressing it dominates loading.
+const FONT: &[u8] =
+  include_bytes!("../../assets/fonts/noto-sans/NotoSansTC-VariableFont_wght.woff2");
+
+fn bench_font_loading(c: &mut Criterion) {
+  let mut group = c.benchmark_group("font_loading");
+  group.sample_size(10);
+
+  group.bench_function("load_and_store_per_context", |b| {
+    b.iter(|| {
+      for _ in 0..WORKERS {
+        let mut context = FontContext::default();
+        context
+          .load_and_store(black_box(FONT).into(), None, None)
+          .unwrap();
+        black_box(context);
+      }
+    })
+  });
+
+  group.bench_function("import_prepared_per_context", |b| {
+    b.iter(|| {
+      let mut source = FontContext::default();
+      source
+        .load_and_store(black_box(FONT).into(), None, None)
+        .unwrap();
+      let prepared = source.export_prepared();
+
+      for _ in 1..WORKERS {
+        let mut context = FontContext::default();
+        context.import_prepared(&prepared);
+        black_box(context);
+      }
+    })
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_font_loading);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Renders the frames of a ticking counter with and without the glyph mask cache.
+//!
+//! Run with `cargo bench --bench glyph_cache` and compare `counter_60_frames` against
+//! `counter_60_frames_uncached`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use serde_json::{from_value, json};
+use std::hint::black_box;
+use takumi::{
+  GlobalContext,
+  layout::{Viewport, node::NodeKind},
+  rendering::{RenderOptionsBuilder, render},
+  resources::glyph_cache::GlyphCache,
+};
+
+const FRAMES: u32 = 60;
+
+const FONT: &[u8] = include_bytes!("../../assets/fonts/geist/Geist[wght].woff2");
+
+fn global(glyph_cache: GlyphCache) -> GlobalContext {
+  let mut global = GlobalContext {
+    glyph_cache,
+    ..Default::default()
+  };
+
+  global
+    .font_context
+    .load_and_store(FONT.into(), None, None)
+    .unwrap();
+
+  global
+}
+
+/// A counter card where only the digits change between frames.
+fn counter(frame: u32) -> NodeKind {
+  from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "flexDirection": "column",
+      "alignItems": "center",
+      "justifyContent": "center",
+      "backgroundColor": "white",
+      "fontFamily": "Geist",
+    },
+    "children": [
+      {
+        "type": "text",
+        "text": "Seconds until launch",
+        "style": { "fontSize": "32px" },
+      },
+      {
+        "type": "text",
+        "text": format!("00:00:{:02}", FRAMES - frame),
+        "style": { "fontSize": "96px", "fontWeight": "700" },
+      },
+    ],
+  }))
+  .unwrap()
+}
+
+fn render_frames(global: &GlobalContext, viewport: Viewport) {
+  for frame in 0..FRAMES {
+    let options = RenderOptionsBuilder::default()
+      .viewport(viewport)
+      .node(counter(frame))
+      .global(global)
+      .build()
+      .unwrap();
+
+    black_box(render(options).unwrap());
+  }
+}
+
+fn bench_glyph_cache(c: &mut Criterion) {
+  let viewport = Viewport::new(Some(512), Some(256));
+
+  let mut group = c.benchmark_group("glyph_cache");
+  group.sample_size(10);
+
+  let cached = global(GlyphCache::default());
+  group.bench_function("counter_60_frames", |b| {
+    b.iter(|| render_frames(&cached, viewport))
+  });
+
+  let uncached = global(GlyphCache::new(0));
+  group.bench_function("counter_60_frames_uncached", |b| {
+    b.iter(|| render_frames(&uncached, viewport))
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_glyph_cache);
+criterion_main!(benches);
@@ -21,6 +21,7 @@ fn run_gradient_render(global: &GlobalContext, background_image_str: &str) {
   let node = NodeKind::Container(ContainerNode {
     children: None,
     preset: None,
+    id: None,
     style: Some(style),
     tw: None,
   });
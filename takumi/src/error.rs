@@ -31,6 +31,39 @@ pub enum TakumiError {
   #[error("Invalid viewport: width or height cannot be 0")]
   InvalidViewport,
 
+  /// The rendered size exceeds the limits set in the render options.
+  #[error("Canvas too large: {width}x{height} exceeds the configured limits")]
+  CanvasTooLarge {
+    /// The width of the rendered image.
+    width: u32,
+    /// The height of the rendered image.
+    height: u32,
+  },
+
+  /// A text node is longer than the limit set in the render options.
+  #[error("Text too long: {length} characters exceeds the configured limit of {max_length}")]
+  TextTooLong {
+    /// The number of characters in the text.
+    length: usize,
+    /// The configured limit.
+    max_length: usize,
+  },
+
+  /// The background image passed to the renderer doesn't match the rendered size.
+  #[error(
+    "Background size mismatch: expected {expected_width}x{expected_height}, got {width}x{height}"
+  )]
+  BackgroundSizeMismatch {
+    /// The width of the rendered image.
+    expected_width: u32,
+    /// The height of the rendered image.
+    expected_height: u32,
+    /// The width of the background image.
+    width: u32,
+    /// The height of the background image.
+    height: u32,
+  },
+
   /// Error related to font processing.
   #[error("Font error: {0}")]
   FontError(#[from] FontError),
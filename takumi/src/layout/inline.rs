@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use parley::{InlineBox, PositionedLayoutItem};
+use parley::{InlineBox, PositionedLayoutItem, TextStyle, TreeBuilder};
 use taffy::{AvailableSpace, Layout, Rect, Size};
 
 use crate::{
@@ -9,13 +9,14 @@ use crate::{
     node::Node,
     style::{
       Color, FontSynthesis, SizedFontStyle, SizedTextDecorationThickness, TextDecorationLines,
-      TextDecorationSkipInk, TextOverflow, TextWrapStyle, VerticalAlign,
+      TextDecorationSkipInk, TextEmphasisStyle, TextOverflow, TextUnderlinePosition, TextWrapStyle,
+      VerticalAlign,
     },
     tree::RenderNode,
   },
   rendering::{
-    MaxHeight, RenderContext, apply_text_transform, apply_white_space_collapse, make_balanced_text,
-    make_pretty_text,
+    MaxHeight, RenderContext, apply_font_variant_emoji, apply_tab_size, apply_text_autospace,
+    collapse_and_transform_text, make_balanced_text, make_pretty_text,
   },
 };
 
@@ -63,6 +64,8 @@ pub(crate) enum InlineItem<'c, 'g, N: Node<N>> {
   Text {
     text: Cow<'c, str>,
     context: &'c RenderContext<'g>,
+    /// Accumulated `vertical-align` shift of the enclosing inline boxes, positive being downwards.
+    baseline_shift: f32,
   },
 }
 
@@ -70,13 +73,15 @@ pub(crate) fn collect_inline_items<'n, 'g, N: Node<N>>(
   root: &'n RenderNode<'g, N>,
 ) -> Vec<InlineItem<'n, 'g, N>> {
   let mut items = Vec::new();
-  collect_inline_items_impl(root, 0, &mut items);
+  collect_inline_items_impl(root, 0, root.context.sizing.font_size, 0.0, &mut items);
   items
 }
 
 fn collect_inline_items_impl<'n, 'g, N: Node<N>>(
   node: &'n RenderNode<'g, N>,
   depth: usize,
+  parent_font_size: f32,
+  parent_baseline_shift: f32,
   items: &mut Vec<InlineItem<'n, 'g, N>>,
 ) {
   if depth > 0 && node.is_inline_atomic_container() {
@@ -84,19 +89,44 @@ fn collect_inline_items_impl<'n, 'g, N: Node<N>>(
     return;
   }
 
+  // The root is the block container, so only nested inline boxes shift their text.
+  let baseline_shift = if depth > 0 {
+    parent_baseline_shift
+      + node.context.style.vertical_align.text_baseline_shift(
+        &node.context.sizing,
+        parent_font_size,
+        node.context.style.line_height,
+      )
+  } else {
+    0.0
+  };
+
   if let Some(inline_content) = node.node.as_ref().and_then(Node::inline_content) {
     match inline_content {
       InlineContentKind::Box => items.push(InlineItem::RenderNode { render_node: node }),
       InlineContentKind::Text(text) => items.push(InlineItem::Text {
         text,
         context: &node.context,
+        baseline_shift,
       }),
     }
+  } else if let Some(content) = &node.context.style.content {
+    items.push(InlineItem::Text {
+      text: content.text(),
+      context: &node.context,
+      baseline_shift,
+    });
   }
 
   if let Some(children) = &node.children {
     for child in children {
-      collect_inline_items_impl(child, depth + 1, items);
+      collect_inline_items_impl(
+        child,
+        depth + 1,
+        node.context.sizing.font_size,
+        baseline_shift,
+        items,
+      );
     }
   }
 }
@@ -115,9 +145,14 @@ pub(crate) struct InlineBrush {
   pub decoration_thickness: SizedTextDecorationThickness,
   pub decoration_line: TextDecorationLines,
   pub decoration_skip_ink: TextDecorationSkipInk,
+  pub underline_position: TextUnderlinePosition,
+  pub emphasis_style: TextEmphasisStyle,
+  pub emphasis_color: Color,
   pub stroke_color: Color,
   pub font_synthesis: FontSynthesis,
   pub vertical_align: VerticalAlign,
+  /// Vertical offset of synthesized subscript or superscript glyphs, positive being downwards.
+  pub baseline_shift: f32,
 }
 
 impl Default for InlineBrush {
@@ -128,13 +163,45 @@ impl Default for InlineBrush {
       decoration_thickness: SizedTextDecorationThickness::Value(0.0),
       decoration_line: TextDecorationLines::empty(),
       decoration_skip_ink: TextDecorationSkipInk::default(),
+      underline_position: TextUnderlinePosition::default(),
+      emphasis_style: TextEmphasisStyle::None,
+      emphasis_color: Color::black(),
       stroke_color: Color::black(),
       font_synthesis: FontSynthesis::default(),
       vertical_align: VerticalAlign::default(),
+      baseline_shift: 0.0,
     }
   }
 }
 
+/// Converts the font style into the parley text style used to build the inline layout.
+///
+/// Unlike `overflow-wrap: anywhere`, the soft wrap opportunities of `break-word` don't count
+/// towards the min-content size, so words stay whole when measuring it.
+fn text_style<'s>(style: &'s SizedFontStyle<'s>, min_content: bool) -> TextStyle<'s, InlineBrush> {
+  let mut text_style = TextStyle::from(style);
+
+  if min_content && matches!(text_style.overflow_wrap, parley::OverflowWrap::BreakWord) {
+    text_style.overflow_wrap = parley::OverflowWrap::Normal;
+  }
+
+  text_style
+}
+
+/// Returns the width measured inline content is clamped to. At min-content the lines are broken
+/// against zero width, yet the words that can't be broken still take up their full width.
+pub(crate) fn measure_width_limit(
+  available_space: Size<AvailableSpace>,
+  known_dimensions: Size<Option<f32>>,
+  max_width: f32,
+) -> f32 {
+  if known_dimensions.width.is_none() && available_space.width == AvailableSpace::MinContent {
+    f32::MAX
+  } else {
+    max_width
+  }
+}
+
 pub(crate) fn measure_inline_layout(layout: &mut InlineLayout, max_width: f32) -> Size<f32> {
   let (max_run_width, total_height) =
     layout
@@ -166,20 +233,48 @@ pub(crate) fn create_inline_layout<'c, 'g: 'c, N: Node<N> + 'c>(
 ) -> (InlineLayout, String, Vec<ProcessedInlineSpan<'c, 'g, N>>) {
   let mut spans: Vec<ProcessedInlineSpan<'c, 'g, N>> = Vec::new();
 
-  let (mut layout, text) = global.font_context.tree_builder(style.into(), |builder| {
+  let min_content = available_space.width == AvailableSpace::MinContent;
+
+  let root_style = text_style(style, min_content);
+
+  let (mut layout, text) = global.font_context.tree_builder(root_style, |builder| {
     let mut index_pos = 0;
+    let mut previous_char = None;
+    let mut column = 0;
 
     for item in items {
       match item {
-        InlineItem::Text { text, context } => {
-          let span_style = context.style.to_sized_font_style(context);
-          let transformed = apply_text_transform(&text, context.style.text_transform);
+        InlineItem::Text {
+          text,
+          context,
+          baseline_shift,
+        } => {
+          let mut span_style = context.style.to_sized_font_style(context);
+          span_style.vertical_align_shift = baseline_shift;
+          let transformed = collapse_and_transform_text(
+            &text,
+            style.parent.white_space_collapse(),
+            context.style.text_transform,
+            context.style.lang.as_ref(),
+          );
+          let transformed = apply_font_variant_emoji(transformed, context.style.font_variant_emoji);
+          let collapsed = apply_tab_size(
+            transformed,
+            context.style.tab_size.columns(&context.sizing),
+            &mut column,
+          );
           let collapsed =
-            apply_white_space_collapse(&transformed, style.parent.white_space_collapse());
+            apply_text_autospace(collapsed, context.style.text_autospace, previous_char);
+
+          if let Some(last) = collapsed.chars().next_back() {
+            previous_char = Some(last);
+          }
 
-          builder.push_style_span((&span_style).into());
-          builder.push_text(&collapsed);
-          builder.pop_style_span();
+          global.font_context.push_text_span(
+            builder,
+            &collapsed,
+            text_style(&span_style, min_content),
+          );
 
           index_pos += collapsed.len();
 
@@ -246,6 +341,7 @@ pub(crate) fn create_inline_layout<'c, 'g: 'c, N: Node<N> + 'c>(
           }));
 
           builder.push_inline_box(inline_box);
+          previous_char = None;
         }
       }
     }
@@ -258,11 +354,13 @@ pub(crate) fn create_inline_layout<'c, 'g: 'c, N: Node<N> + 'c>(
   }
 
   // Handle ellipsis when text overflows
-  if style.parent.text_overflow == TextOverflow::Ellipsis {
-    let is_overflowing = layout
-      .lines()
-      .last()
-      .is_some_and(|last_line| last_line.text_range().end < text.len());
+  if style.parent.should_ellipsize() {
+    let is_middle = style.parent.text_overflow == TextOverflow::EllipsisMiddle;
+    // A single unbreakable token stays on one line, so the middle ellipsis also checks its width.
+    let is_overflowing = layout.lines().last().is_some_and(|last_line| {
+      last_line.text_range().end < text.len()
+        || (is_middle && last_line.metrics().advance > max_width)
+    });
 
     if is_overflowing {
       make_ellipsis_layout(
@@ -299,7 +397,7 @@ pub(crate) fn create_inline_layout<'c, 'g: 'c, N: Node<N> + 'c>(
 
   layout.align(
     Some(max_width),
-    style.parent.text_align.into(),
+    style.parent.text_align.resolve(style.parent.direction),
     Default::default(),
   );
 
@@ -410,6 +508,23 @@ fn make_ellipsis_layout<'c, 'g: 'c, N: Node<N> + 'c>(
 
   let available_w = (max_width - ellipsis_w).max(0.0);
 
+  if root_style.parent.text_overflow == TextOverflow::EllipsisMiddle
+    && spans
+      .iter()
+      .all(|span| matches!(span, ProcessedInlineSpan::Text { .. }))
+  {
+    return make_middle_ellipsis_layout(
+      layout,
+      spans,
+      available_w,
+      max_width,
+      max_height,
+      ellipsis_char,
+      root_style,
+      global,
+    );
+  }
+
   let truncate_at: Option<usize> = layout.lines().last().and_then(|last_line| {
     let mut accumulated = 0.0_f32;
     let mut last_fitting_byte: Option<usize> = Some(0);
@@ -486,20 +601,141 @@ fn make_ellipsis_layout<'c, 'g: 'c, N: Node<N> + 'c>(
       for span in spans.iter() {
         match span {
           ProcessedInlineSpan::Text { text, style } => {
-            builder.push_style_span(style.into());
-            builder.push_text(text);
-            builder.pop_style_span();
+            global
+              .font_context
+              .push_text_span(builder, text, style.into());
           }
           ProcessedInlineSpan::Box(item) => {
             builder.push_inline_box(item.inline_box.clone());
           }
         }
       }
-      builder.push_style_span((&*ellipsis_style).into());
-      builder.push_text(ellipsis_char);
-      builder.pop_style_span();
+      global
+        .font_context
+        .push_text_span(builder, ellipsis_char, (&*ellipsis_style).into());
+    });
+
+  break_lines(&mut final_layout, max_width, max_height);
+  *layout = final_layout;
+}
+
+/// Keeps the start and the end of the text on one line and replaces the middle with an ellipsis.
+///
+/// The text is cut at cluster boundaries, so a single unbreakable token is elided as well.
+#[allow(clippy::too_many_arguments)]
+fn make_middle_ellipsis_layout<'c, 'g: 'c, N: Node<N> + 'c>(
+  layout: &mut InlineLayout,
+  spans: &mut [ProcessedInlineSpan<'c, 'g, N>],
+  available_w: f32,
+  max_width: f32,
+  max_height: Option<MaxHeight>,
+  ellipsis_char: &str,
+  root_style: &'c SizedFontStyle,
+  global: &GlobalContext,
+) {
+  // Lay out the whole text on a single line to measure clusters from both ends.
+  let (mut full_layout, _) = global
+    .font_context
+    .tree_builder(root_style.into(), |builder| {
+      push_text_spans(builder, spans, global)
+    });
+  full_layout.break_all_lines(None);
+
+  let mut clusters = Vec::new();
+  for line in full_layout.lines() {
+    for run in line.runs() {
+      clusters.extend(
+        run
+          .clusters()
+          .map(|cluster| (cluster.text_range(), cluster.advance())),
+      );
+    }
+  }
+  clusters.sort_by_key(|(range, _)| range.start);
+
+  // Alternate between both ends so the start and the end share the available width.
+  let (mut head, mut tail) = (0, clusters.len());
+  let mut accumulated = 0.0_f32;
+  let mut take_head = true;
+
+  while head < tail {
+    let index = if take_head { head } else { tail - 1 };
+    let cluster_w = clusters[index].1;
+    if accumulated + cluster_w > available_w {
+      break;
+    }
+    accumulated += cluster_w;
+
+    if take_head {
+      head += 1;
+    } else {
+      tail -= 1;
+    }
+    take_head = !take_head;
+  }
+
+  if head == tail {
+    return;
+  }
+
+  let text_len: usize = spans
+    .iter()
+    .map(|span| match span {
+      ProcessedInlineSpan::Text { text, .. } => text.len(),
+      ProcessedInlineSpan::Box(_) => 0,
+    })
+    .sum();
+
+  // Nothing fits when the box is narrower than the ellipsis, so no cluster is kept from the end.
+  let prefix_end = clusters[..head].last().map_or(0, |(range, _)| range.end);
+  let suffix_start = clusters
+    .get(tail)
+    .map_or(text_len, |(range, _)| range.start);
+
+  // The ellipsis joins the span where the cut starts so span offsets still match the layout text.
+  let mut offset = 0;
+  let mut ellipsis_pushed = false;
+  for span in spans.iter_mut() {
+    let ProcessedInlineSpan::Text { text, .. } = span else {
+      continue;
+    };
+    let range = offset..offset + text.len();
+    offset = range.end;
+
+    let keep_head = &text[..prefix_end.clamp(range.start, range.end) - range.start];
+    let keep_tail = &text[suffix_start.clamp(range.start, range.end) - range.start..];
+
+    let mut kept = String::with_capacity(keep_head.len() + keep_tail.len() + ellipsis_char.len());
+    kept.push_str(keep_head);
+    if !ellipsis_pushed && range.end > prefix_end {
+      kept.push_str(ellipsis_char);
+      ellipsis_pushed = true;
+    }
+    kept.push_str(keep_tail);
+
+    *text = kept;
+  }
+
+  let (mut final_layout, _) = global
+    .font_context
+    .tree_builder(root_style.into(), |builder| {
+      push_text_spans(builder, spans, global)
     });
 
   break_lines(&mut final_layout, max_width, max_height);
   *layout = final_layout;
 }
+
+fn push_text_spans<N: Node<N>>(
+  builder: &mut TreeBuilder<'_, InlineBrush>,
+  spans: &[ProcessedInlineSpan<'_, '_, N>],
+  global: &GlobalContext,
+) {
+  for span in spans {
+    if let ProcessedInlineSpan::Text { text, style } = span {
+      global
+        .font_context
+        .push_text_span(builder, text, style.into());
+    }
+  }
+}
@@ -6,11 +6,16 @@
 use std::fmt::Debug;
 
 use serde::Deserialize;
+use taffy::{AvailableSpace, Layout, Size};
 
-use crate::layout::{
-  Viewport,
-  node::Node,
-  style::{InheritedStyle, Style, tw::TailwindValues},
+use crate::{
+  Result,
+  layout::{
+    Viewport,
+    node::{Node, TextNode},
+    style::{InheritedStyle, Style, StylePreset, StylePresets, tw::TailwindValues},
+  },
+  rendering::{Canvas, RenderContext},
 };
 
 /// A container node that can hold child nodes.
@@ -19,8 +24,11 @@ use crate::layout::{
 /// properties like flexbox layout to arrange their children.
 #[derive(Debug, Deserialize, Clone)]
 pub struct ContainerNode<Nodes: Node<Nodes>> {
-  /// Default style presets from HTML element type (lowest priority)
-  pub preset: Option<Style>,
+  /// Default style presets from HTML element type or a registered preset name (lowest priority)
+  pub preset: Option<StylePreset>,
+  /// An identifier for debugging, highlighted when listed in the render options' `debug_highlight_ids`.
+  #[serde(default, alias = "data-testid")]
+  pub id: Option<String>,
   /// The styling properties for this container
   pub style: Option<Style>,
   /// The child nodes contained within this container
@@ -29,6 +37,21 @@ pub struct ContainerNode<Nodes: Node<Nodes>> {
   pub tw: Option<TailwindValues>,
 }
 
+/// Synthesizes a text node for the `content` property, which stands in for the container's children.
+fn generated_text(context: &RenderContext) -> Option<TextNode> {
+  let content = context.style.content.as_ref()?;
+
+  Some(TextNode {
+    preset: None,
+    id: None,
+    style: None,
+    text: content.text().into_owned(),
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+  })
+}
+
 impl<Nodes: Node<Nodes>> Node<Nodes> for ContainerNode<Nodes> {
   fn children_ref(&self) -> Option<&[Nodes]> {
     self.children.as_deref()
@@ -38,12 +61,17 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for ContainerNode<Nodes> {
     &mut self,
     parent_style: &InheritedStyle,
     viewport: Viewport,
+    presets: &StylePresets,
   ) -> InheritedStyle {
     // Start with empty style
     let mut style = Style::default();
 
     // 1. Apply preset first (lowest priority)
-    if let Some(preset) = self.preset.take() {
+    if let Some(preset) = self
+      .preset
+      .take()
+      .and_then(|preset| preset.resolve(presets))
+    {
       style.merge_from(preset);
     }
 
@@ -64,7 +92,46 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for ContainerNode<Nodes> {
     self.children.take()
   }
 
+  fn measure(
+    &self,
+    context: &RenderContext,
+    available_space: Size<AvailableSpace>,
+    known_dimensions: Size<Option<f32>>,
+    style: &taffy::Style,
+  ) -> Size<f32> {
+    let Some(text) = generated_text(context) else {
+      return Size::ZERO;
+    };
+
+    <TextNode as Node<Nodes>>::measure(&text, context, available_space, known_dimensions, style)
+  }
+
+  fn draw_content(
+    &self,
+    context: &RenderContext,
+    canvas: &mut Canvas,
+    layout: Layout,
+  ) -> Result<()> {
+    let Some(text) = generated_text(context) else {
+      return Ok(());
+    };
+
+    <TextNode as Node<Nodes>>::draw_content(&text, context, canvas, layout)
+  }
+
   fn get_style(&self) -> Option<&Style> {
     self.style.as_ref()
   }
+
+  fn get_style_mut(&mut self) -> Option<&mut Style> {
+    Some(self.style.get_or_insert_with(Style::default))
+  }
+
+  fn node_type(&self) -> &'static str {
+    "container"
+  }
+
+  fn id(&self) -> Option<&str> {
+    self.id.as_deref()
+  }
 }
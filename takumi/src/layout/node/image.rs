@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{iter::once, sync::Arc};
 
 use data_url::DataUrl;
 use serde::Deserialize;
@@ -11,7 +11,7 @@ use crate::{
   layout::{
     inline::InlineContentKind,
     node::Node,
-    style::{InheritedStyle, Style, tw::TailwindValues},
+    style::{InheritedStyle, Style, StylePreset, StylePresets, tw::TailwindValues},
   },
   rendering::{Canvas, RenderContext, draw_image},
   resources::{
@@ -23,12 +23,21 @@ use crate::{
 /// A node that renders image content.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ImageNode {
-  /// Default style presets from HTML element type (lowest priority)
-  pub preset: Option<Style>,
+  /// Default style presets from HTML element type or a registered preset name (lowest priority)
+  pub preset: Option<StylePreset>,
+  /// An identifier for debugging, highlighted when listed in the render options' `debug_highlight_ids`.
+  #[serde(default, alias = "data-testid")]
+  pub id: Option<String>,
   /// The styling properties for this image node
   pub style: Option<Style>,
-  /// The source URL or path to the image
+  /// The source URL or path to the image, used when none of `sources` is supported
   pub src: Arc<str>,
+  /// Alternative sources annotated with their MIME type and pixel density.
+  ///
+  /// The best supported format is preferred over `src`, then the density closest to the device pixel ratio
+  /// without going below it. `src` takes part as an untyped `1x` source.
+  #[serde(default)]
+  pub sources: Option<Box<[ImageNodeSource]>>,
   /// The width of the image
   pub width: Option<f32>,
   /// The height of the image
@@ -37,10 +46,125 @@ pub struct ImageNode {
   pub tw: Option<TailwindValues>,
 }
 
+/// An alternative source of an [`ImageNode`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImageNodeSource {
+  /// The source URL or path to the image
+  pub url: Arc<str>,
+  /// The MIME type of the image, e.g. `image/avif`. Untyped sources are treated like PNG or JPEG
+  #[serde(rename = "type")]
+  pub mime_type: Option<Arc<str>>,
+  /// The pixel density the image is made for, like a `2x` descriptor in `srcset`. Defaults to `1`
+  pub density: Option<f32>,
+}
+
+/// Preference of untyped sources, shared with `src`.
+const UNTYPED_SOURCE_PREFERENCE: u8 = 2;
+
+impl ImageNodeSource {
+  /// Lower is preferred, `None` if the format can't be decoded.
+  fn preference(&self) -> Option<u8> {
+    let Some(mime_type) = self.mime_type.as_deref() else {
+      return Some(UNTYPED_SOURCE_PREFERENCE);
+    };
+
+    match mime_type.trim().to_ascii_lowercase().as_str() {
+      #[cfg(feature = "avif")]
+      "image/avif" => Some(0),
+      "image/webp" => Some(1),
+      "image/png" | "image/jpeg" | "image/gif" => Some(UNTYPED_SOURCE_PREFERENCE),
+      #[cfg(feature = "svg")]
+      "image/svg+xml" => Some(UNTYPED_SOURCE_PREFERENCE),
+      _ => None,
+    }
+  }
+
+  fn density(&self) -> f32 {
+    self
+      .density
+      .filter(|density| density.is_finite() && *density > 0.0)
+      .unwrap_or(1.0)
+  }
+}
+
+impl ImageNode {
+  /// Returns the sources with the most preferred supported format and their densities,
+  /// keeping the first source of each density.
+  fn candidates(&self) -> Vec<(&Arc<str>, f32)> {
+    let sources = self
+      .sources
+      .as_deref()
+      .unwrap_or_default()
+      .iter()
+      .filter_map(|source| Some((source.preference()?, &source.url, source.density())))
+      .chain(once((UNTYPED_SOURCE_PREFERENCE, &self.src, 1.0)));
+
+    let Some(best_preference) = sources.clone().map(|(preference, ..)| preference).min() else {
+      return Vec::new();
+    };
+
+    let mut candidates: Vec<(&Arc<str>, f32)> = Vec::new();
+
+    for (preference, url, density) in sources {
+      if preference == best_preference && candidates.iter().all(|(_, seen)| *seen != density) {
+        candidates.push((url, density));
+      }
+    }
+
+    candidates
+  }
+
+  /// Returns the URL to load at a device pixel ratio of `1`.
+  pub fn selected_src(&self) -> &Arc<str> {
+    self.selected_src_for_device_pixel_ratio(1.0)
+  }
+
+  /// Returns the URL to load for the device pixel ratio.
+  ///
+  /// Among the sources with the most preferred supported format, the one with the smallest density
+  /// covering the device pixel ratio wins, or the densest one if none covers it.
+  pub fn selected_src_for_device_pixel_ratio(&self, device_pixel_ratio: f32) -> &Arc<str> {
+    self.selected_source(device_pixel_ratio).0
+  }
+
+  /// Returns the URL to load for the device pixel ratio along with the density it was made for.
+  pub(crate) fn selected_source(&self, device_pixel_ratio: f32) -> (&Arc<str>, f32) {
+    select_by_density(self.candidates(), device_pixel_ratio).unwrap_or((&self.src, 1.0))
+  }
+}
+
+/// Picks the candidate with the smallest density covering the device pixel ratio,
+/// or the densest one if none covers it.
+pub(crate) fn select_by_density<T>(
+  candidates: impl IntoIterator<Item = (T, f32)>,
+  device_pixel_ratio: f32,
+) -> Option<(T, f32)> {
+  candidates.into_iter().min_by(|(_, a), (_, b)| {
+    let a_below = *a < device_pixel_ratio;
+    let b_below = *b < device_pixel_ratio;
+
+    a_below.cmp(&b_below).then_with(|| {
+      (a - device_pixel_ratio)
+        .abs()
+        .total_cmp(&(b - device_pixel_ratio).abs())
+    })
+  })
+}
+
 impl<Nodes: Node<Nodes>> Node<Nodes> for ImageNode {
   fn collect_fetch_tasks(&self, collection: &mut FetchTaskCollection) {
-    if self.src.starts_with("https://") || self.src.starts_with("http://") {
-      collection.insert(self.src.clone());
+    // Without a device pixel ratio, every density of the selected format may be drawn.
+    let sources = match collection.device_pixel_ratio() {
+      Some(device_pixel_ratio) => {
+        vec![self.selected_src_for_device_pixel_ratio(device_pixel_ratio)]
+      }
+      None => self.candidates().into_iter().map(|(src, _)| src).collect(),
+    };
+
+    for src in sources {
+      if src.starts_with("https://") || src.starts_with("http://") {
+        collection.insert(src.clone());
+      }
     }
   }
 
@@ -48,12 +172,17 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for ImageNode {
     &mut self,
     parent_style: &InheritedStyle,
     viewport: Viewport,
+    presets: &StylePresets,
   ) -> InheritedStyle {
     // Start with empty style
     let mut style = Style::default();
 
     // 1. Apply preset first (lowest priority)
-    if let Some(preset) = self.preset.take() {
+    if let Some(preset) = self
+      .preset
+      .take()
+      .and_then(|preset| preset.resolve(presets))
+    {
       style.merge_from(preset);
     }
 
@@ -81,19 +210,22 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for ImageNode {
     known_dimensions: Size<Option<f32>>,
     style: &taffy::Style,
   ) -> Size<f32> {
-    let Ok(image) = resolve_image(&self.src, context) else {
+    let (src, density) = self.selected_source(context.sizing.viewport.device_pixel_ratio);
+
+    let Ok(image) = resolve_image(src, context) else {
       return Size::zero();
     };
 
+    // A source made for a higher density has more pixels for the same CSS size.
     let image_size = match &*image {
       #[cfg(feature = "svg")]
       ImageSource::Svg(svg) => Size {
-        width: svg.size().width(),
-        height: svg.size().height(),
+        width: svg.size().width() / density,
+        height: svg.size().height() / density,
       },
       ImageSource::Bitmap(bitmap) => Size {
-        width: bitmap.width() as f32,
-        height: bitmap.height() as f32,
+        width: bitmap.width() as f32 / density,
+        height: bitmap.height() as f32 / density,
       },
     };
 
@@ -123,7 +255,10 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for ImageNode {
     canvas: &mut Canvas,
     layout: Layout,
   ) -> Result<()> {
-    let Ok(image) = resolve_image(&self.src, context) else {
+    let Ok(image) = resolve_image(
+      self.selected_src_for_device_pixel_ratio(context.sizing.viewport.device_pixel_ratio),
+      context,
+    ) else {
       return Ok(());
     };
 
@@ -134,6 +269,22 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for ImageNode {
   fn get_style(&self) -> Option<&Style> {
     self.style.as_ref()
   }
+
+  fn get_style_mut(&mut self) -> Option<&mut Style> {
+    Some(self.style.get_or_insert_with(Style::default))
+  }
+
+  fn node_type(&self) -> &'static str {
+    "image"
+  }
+
+  fn is_replaced(&self) -> bool {
+    true
+  }
+
+  fn id(&self) -> Option<&str> {
+    self.id.as_deref()
+  }
 }
 
 const DATA_URI_PREFIX: &str = "data:";
@@ -159,6 +310,17 @@ pub(crate) fn resolve_image(src: &str, context: &RenderContext) -> ImageResult {
     return Err(ImageResourceError::SvgParseNotSupported);
   }
 
+  let img = resolve_external_image(src, context)?;
+
+  let mut resolved_images = context.resolved_images.borrow_mut();
+  if !resolved_images.contains(src) {
+    resolved_images.insert(src.to_string());
+  }
+
+  Ok(img)
+}
+
+fn resolve_external_image(src: &str, context: &RenderContext) -> ImageResult {
   if let Some(img) = context.fetched_resources.get(src) {
     return Ok(img.clone());
   }
@@ -167,5 +329,203 @@ pub(crate) fn resolve_image(src: &str, context: &RenderContext) -> ImageResult {
     return Ok(img.clone());
   }
 
+  if let Some(resolver) = &context.global.image_resolver {
+    // Measuring and drawing both resolve the image, so a failure is remembered for the render.
+    if context.failed_images.borrow().contains(src) {
+      return Err(ImageResourceError::Unknown);
+    }
+
+    let img = resolver.resolve(src).inspect_err(|_| {
+      context.failed_images.borrow_mut().insert(src.to_string());
+    })?;
+
+    context
+      .global
+      .persistent_image_store
+      .insert(src.to_string(), img.clone());
+
+    return Ok(img);
+  }
+
   Err(ImageResourceError::Unknown)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::layout::node::NodeKind;
+
+  fn fetch_tasks(node: serde_json::Value) -> Vec<Arc<str>> {
+    let mut collection = FetchTaskCollection::default();
+
+    if let Ok(node) = serde_json::from_value::<ImageNode>(node) {
+      <ImageNode as Node<NodeKind>>::collect_fetch_tasks(&node, &mut collection);
+    }
+
+    collection.into_inner().into_vec()
+  }
+
+  #[cfg(feature = "avif")]
+  #[test]
+  fn test_prefers_avif_source() {
+    let tasks = fetch_tasks(serde_json::json!({
+      "src": "https://example.com/image.png",
+      "sources": [
+        { "url": "https://example.com/image.png", "type": "image/png" },
+        { "url": "https://example.com/image.webp", "type": "image/webp" },
+        { "url": "https://example.com/image.avif", "type": "image/avif" },
+      ],
+    }));
+
+    assert_eq!(tasks, [Arc::<str>::from("https://example.com/image.avif")]);
+  }
+
+  #[cfg(not(feature = "avif"))]
+  #[test]
+  fn test_skips_avif_source_without_feature() {
+    let tasks = fetch_tasks(serde_json::json!({
+      "src": "https://example.com/image.png",
+      "sources": [
+        { "url": "https://example.com/image.avif", "type": "image/avif" },
+        { "url": "https://example.com/image.png", "type": "image/png" },
+        { "url": "https://example.com/image.webp", "type": "image/webp" },
+      ],
+    }));
+
+    assert_eq!(tasks, [Arc::<str>::from("https://example.com/image.webp")]);
+  }
+
+  #[test]
+  fn test_falls_back_to_src_without_supported_sources() {
+    let tasks = fetch_tasks(serde_json::json!({
+      "src": "https://example.com/image.png",
+      "sources": [{ "url": "https://example.com/image.jxl", "type": "image/jxl" }],
+    }));
+
+    assert_eq!(tasks, [Arc::<str>::from("https://example.com/image.png")]);
+  }
+
+  #[test]
+  fn test_selects_source_by_device_pixel_ratio() {
+    let Ok(node) = serde_json::from_value::<ImageNode>(serde_json::json!({
+      "src": "https://example.com/image.png",
+      "sources": [
+        { "url": "https://example.com/image@2x.png", "density": 2.0 },
+        { "url": "https://example.com/image@3x.png", "density": 3.0 },
+      ],
+    })) else {
+      unreachable!()
+    };
+
+    assert_eq!(&**node.selected_src(), "https://example.com/image.png");
+    assert_eq!(
+      &**node.selected_src_for_device_pixel_ratio(2.0),
+      "https://example.com/image@2x.png"
+    );
+    assert_eq!(
+      &**node.selected_src_for_device_pixel_ratio(1.5),
+      "https://example.com/image@2x.png"
+    );
+    assert_eq!(
+      &**node.selected_src_for_device_pixel_ratio(4.0),
+      "https://example.com/image@3x.png"
+    );
+  }
+
+  #[test]
+  fn test_fetches_only_selected_density_when_ratio_is_known() {
+    let Ok(node) = serde_json::from_value::<ImageNode>(serde_json::json!({
+      "src": "https://example.com/image.png",
+      "sources": [
+        { "url": "https://example.com/image@2x.png", "density": 2.0 },
+        { "url": "https://example.com/image@3x.png", "density": 3.0 },
+      ],
+    })) else {
+      unreachable!()
+    };
+
+    let mut collection = FetchTaskCollection::with_device_pixel_ratio(2.0);
+    <ImageNode as Node<NodeKind>>::collect_fetch_tasks(&node, &mut collection);
+
+    assert_eq!(
+      collection.into_inner().into_vec(),
+      [Arc::<str>::from("https://example.com/image@2x.png")]
+    );
+  }
+
+  #[test]
+  fn test_dense_source_keeps_css_size() {
+    let Ok(node) = serde_json::from_value::<ImageNode>(serde_json::json!({
+      "src": "image.png",
+      "sources": [{ "url": "image@2x.png", "density": 2.0 }],
+    })) else {
+      unreachable!()
+    };
+
+    let global = crate::GlobalContext::default();
+    let viewport = Viewport {
+      device_pixel_ratio: 2.0,
+      ..Viewport::new(Some(100), Some(100))
+    };
+    let fetched_resources = [(
+      Arc::from("image@2x.png"),
+      Arc::new(ImageSource::Bitmap(image::RgbaImage::new(200, 100))),
+    )]
+    .into();
+    let context = RenderContext::new(&global, viewport, fetched_resources);
+
+    // The 200x100 source at 2x is 100x50 in CSS pixels, like its 1x version.
+    assert_eq!(
+      <ImageNode as Node<NodeKind>>::measure(
+        &node,
+        &context,
+        Size::MAX_CONTENT,
+        Size::NONE,
+        &taffy::Style::default(),
+      ),
+      Size {
+        width: 100.0 * 2.0,
+        height: 50.0 * 2.0,
+      }
+    );
+  }
+
+  #[test]
+  fn test_image_resolver_resolves_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::GlobalContext;
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let global = GlobalContext {
+      image_resolver: Some(Box::new(|src: &str| {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+
+        if src == "file://logo.png" {
+          Ok(Arc::new(ImageSource::Bitmap(image::RgbaImage::new(2, 1))))
+        } else {
+          Err(ImageResourceError::Unknown)
+        }
+      })),
+      ..Default::default()
+    };
+    let context = RenderContext::new(&global, (100, 100).into(), Default::default());
+
+    for _ in 0..2 {
+      assert!(
+        resolve_image("file://logo.png", &context).is_ok_and(|image| image.size() == (2.0, 1.0))
+      );
+    }
+    for _ in 0..2 {
+      assert!(resolve_image("file://missing.png", &context).is_err());
+    }
+
+    assert_eq!(CALLS.load(Ordering::Relaxed), 2);
+    assert!(
+      global
+        .persistent_image_store
+        .contains_key("file://logo.png")
+    );
+  }
+}
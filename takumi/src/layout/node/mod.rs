@@ -18,12 +18,12 @@ use crate::{
     inline::InlineContentKind,
     style::{
       Affine, BackgroundClip, BackgroundImage, BlendMode, CssValue, InheritedStyle, Length, Sides,
-      Style,
+      Style, StylePresets,
     },
   },
   rendering::{
     BackgroundTile, BorderProperties, Canvas, RenderContext, SizedShadow,
-    collect_background_layers, rasterize_layers,
+    collect_background_layers, draw_border_image, rasterize_layers,
   },
   resources::task::FetchTaskCollection,
 };
@@ -44,9 +44,9 @@ macro_rules! impl_node_enum {
         }
       }
 
-      fn create_inherited_style(&mut self, parent: &$crate::layout::style::InheritedStyle, viewport: $crate::layout::Viewport) -> $crate::layout::style::InheritedStyle {
+      fn create_inherited_style(&mut self, parent: &$crate::layout::style::InheritedStyle, viewport: $crate::layout::Viewport, presets: &$crate::layout::style::StylePresets) -> $crate::layout::style::InheritedStyle {
         match self {
-          $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::create_inherited_style(inner, parent, viewport), )*
+          $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::create_inherited_style(inner, parent, viewport, presets), )*
         }
       }
 
@@ -104,6 +104,30 @@ macro_rules! impl_node_enum {
         }
       }
 
+      fn get_style_mut(&mut self) -> Option<&mut Style> {
+        match self {
+          $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::get_style_mut(inner), )*
+        }
+      }
+
+      fn node_type(&self) -> &'static str {
+        match self {
+          $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::node_type(inner), )*
+        }
+      }
+
+      fn is_replaced(&self) -> bool {
+        match self {
+          $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::is_replaced(inner), )*
+        }
+      }
+
+      fn id(&self) -> Option<&str> {
+        match self {
+          $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::id(inner), )*
+        }
+      }
+
       fn collect_fetch_tasks(&self, collection: &mut FetchTaskCollection) {
         match self {
           $( $name::$variant(inner) => <_ as $crate::layout::node::Node<$name>>::collect_fetch_tasks(inner, collection), )*
@@ -157,47 +181,56 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
   /// Returns a reference to this node's raw [`Style`], if any.
   fn get_style(&self) -> Option<&Style>;
 
+  /// Returns a mutable reference to this node's raw [`Style`], creating an empty one if unset.
+  ///
+  /// Returns `None` for nodes that don't carry a style.
+  fn get_style_mut(&mut self) -> Option<&mut Style> {
+    None
+  }
+
+  /// Returns the name of this node's type, used in debug output.
+  ///
+  /// Defaults to the Rust type name, so custom nodes are told apart without implementing it.
+  fn node_type(&self) -> &'static str {
+    std::any::type_name::<Self>()
+  }
+
+  /// Whether the node draws replaced content, like an image, in [`Self::draw_content`].
+  fn is_replaced(&self) -> bool {
+    false
+  }
+
+  /// Returns the debugging identifier of this node, if any.
+  fn id(&self) -> Option<&str> {
+    None
+  }
+
   /// Creates resolving tasks for style's http resources.
   fn collect_style_fetch_tasks(&self, collection: &mut FetchTaskCollection) {
     if let Some(style) = self.get_style() {
       if let CssValue::Value(Some(images)) = &style.background_image {
-        collection.insert_many(images.iter().filter_map(|image| {
-          if let BackgroundImage::Url(url) = image {
-            Some(url.clone())
-          } else {
-            None
-          }
-        }))
+        collection.insert_many(images.iter().flat_map(BackgroundImage::urls).cloned());
       };
 
       if let CssValue::Value(background) = &style.background {
-        collection.insert_many(background.iter().filter_map(|background| {
-          if let BackgroundImage::Url(url) = &background.image {
-            Some(url.clone())
-          } else {
-            None
-          }
-        }));
+        collection.insert_many(
+          background
+            .iter()
+            .flat_map(|background| background.image.urls())
+            .cloned(),
+        );
       };
 
       if let CssValue::Value(Some(images)) = &style.mask_image {
-        collection.insert_many(images.iter().filter_map(|image| {
-          if let BackgroundImage::Url(url) = image {
-            Some(url.clone())
-          } else {
-            None
-          }
-        }));
+        collection.insert_many(images.iter().flat_map(BackgroundImage::urls).cloned());
       };
 
       if let CssValue::Value(mask) = &style.mask {
-        collection.insert_many(mask.iter().filter_map(|background| {
-          if let BackgroundImage::Url(url) = &background.image {
-            Some(url.clone())
-          } else {
-            None
-          }
-        }));
+        collection.insert_many(mask.iter().flat_map(|mask| mask.image.urls()).cloned());
+      };
+
+      if let CssValue::Value(Some(image)) = &style.border_image_source {
+        collection.insert_many(image.urls().cloned());
       };
     };
 
@@ -216,10 +249,13 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
   }
 
   /// Create a [`InheritedStyle`] instance or clone the parent's.
+  ///
+  /// Named presets are resolved against `presets`.
   fn create_inherited_style(
     &mut self,
     _parent: &InheritedStyle,
     viewport: Viewport,
+    presets: &StylePresets,
   ) -> InheritedStyle;
 
   /// Retrieve content for inline layout.
@@ -305,7 +341,16 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
     layout: Layout,
   ) -> Result<()> {
     if let Some(box_shadow) = context.style.box_shadow.as_ref() {
-      let border_radius = BorderProperties::from_context(context, layout.size, layout.border);
+      // Inset shadows are painted inside the border, following the inner corner radii.
+      let mut border_radius = BorderProperties::from_context(context, layout.size, layout.border);
+      border_radius.inset_by_border_width();
+
+      let padding_box = Size {
+        width: (layout.size.width - layout.border.left - layout.border.right).max(0.0),
+        height: (layout.size.height - layout.border.top - layout.border.bottom).max(0.0),
+      };
+      let transform =
+        context.transform * Affine::translation(layout.border.left, layout.border.top);
 
       for shadow in box_shadow.iter() {
         if !shadow.inset {
@@ -318,7 +363,7 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
           context.current_color,
           layout.size,
         );
-        shadow.draw_inset(context.transform, border_radius, canvas, layout)?;
+        shadow.draw_inset(transform, border_radius, canvas, padding_box)?;
       }
     }
     Ok(())
@@ -335,7 +380,7 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
 
     match context.style.background_clip {
       BackgroundClip::BorderBox => {
-        let tiles = collect_background_layers(context, layout.size, &mut canvas.buffer_pool)?;
+        let tiles = collect_background_layers(context, layout, &mut canvas.buffer_pool)?;
 
         for tile in tiles {
           for y in &tile.ys {
@@ -354,7 +399,7 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
       BackgroundClip::PaddingBox => {
         border_radius.inset_by_border_width();
 
-        let layers = collect_background_layers(context, layout.size, &mut canvas.buffer_pool)?;
+        let layers = collect_background_layers(context, layout, &mut canvas.buffer_pool)?;
 
         if let Some(tile) = rasterize_layers(
           layers,
@@ -385,7 +430,7 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
         border_radius.inset_by_border_width();
         border_radius.expand_by(layout.padding.map(|size| -size));
 
-        let layers = collect_background_layers(context, layout.size, &mut canvas.buffer_pool)?;
+        let layers = collect_background_layers(context, layout, &mut canvas.buffer_pool)?;
 
         if let Some(tile) = rasterize_layers(
           layers,
@@ -440,9 +485,13 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
     canvas: &mut Canvas,
     layout: Layout,
   ) -> Result<()> {
+    if draw_border_image(context, canvas, layout)? {
+      return Ok(());
+    }
+
     let clip_image = if context.style.background_clip == BackgroundClip::BorderArea {
       rasterize_layers(
-        collect_background_layers(context, layout.size, &mut canvas.buffer_pool)?,
+        collect_background_layers(context, layout, &mut canvas.buffer_pool)?,
         layout.size.map(|x| x as u32),
         context,
         BorderProperties::default(),
@@ -454,7 +503,22 @@ pub trait Node<N: Node<N>>: Send + Sync + Clone {
       None
     };
 
-    BorderProperties::from_context(context, layout.size, layout.border).draw(
+    let mut border_width = layout.border;
+    let Sides([top, right, bottom, left]) = context.collapsed_borders;
+
+    // The sides shared with a sibling whose border is drawn instead.
+    for (collapsed, width) in [
+      (top, &mut border_width.top),
+      (right, &mut border_width.right),
+      (bottom, &mut border_width.bottom),
+      (left, &mut border_width.left),
+    ] {
+      if collapsed {
+        *width = 0.0;
+      }
+    }
+
+    BorderProperties::from_context(context, layout.size, border_width).draw(
       canvas,
       layout.size,
       context.transform,
@@ -1,36 +1,491 @@
-use std::iter::once;
+use std::{borrow::Cow, ops::Range};
 
 use serde::Deserialize;
 use taffy::{AvailableSpace, Layout, Size};
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-  Result,
+  Error, Result,
   layout::{
     Viewport,
     inline::{
-      InlineContentKind, InlineItem, InlineLayoutStage, create_inline_constraint,
-      create_inline_layout, measure_inline_layout,
+      InlineContentKind, InlineItem, InlineLayout, InlineLayoutStage, ProcessedInlineSpan,
+      create_inline_constraint, create_inline_layout, measure_inline_layout, measure_width_limit,
     },
     node::Node,
-    style::{InheritedStyle, Style, tw::TailwindValues},
+    style::{InheritedStyle, SizedFontStyle, Style, StylePreset, StylePresets, tw::TailwindValues},
+  },
+  rendering::{
+    Canvas, MaxHeight, RenderContext, draw_column_rules, inline_drawing::draw_inline_layout,
   },
-  rendering::{Canvas, MaxHeight, RenderContext, inline_drawing::draw_inline_layout},
 };
 
+/// Number of binary search steps used to fit the font size.
+const FONT_SIZE_FIT_ITERATIONS: u32 = 12;
+
+/// Number of binary search steps used to balance column heights.
+const COLUMN_BALANCE_ITERATIONS: u32 = 16;
+
+/// Controls how many lines the fitted text may occupy.
+#[derive(Debug, Default, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FontSizeFitMode {
+  /// The text may wrap onto as many lines as the box height allows.
+  #[default]
+  Box,
+  /// The text must fit on a single line.
+  SingleLine,
+  /// The text may wrap onto at most the given number of lines.
+  Lines(u32),
+}
+
+/// Shrinks the font size of a text node until its text fits within the box.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FontSizeFit {
+  /// The smallest font size in pixels the text may shrink to.
+  pub min: f32,
+  /// The largest font size in pixels, used when the text already fits.
+  pub max: f32,
+  /// How many lines the fitted text may occupy.
+  #[serde(default)]
+  pub mode: FontSizeFitMode,
+}
+
 /// A node that renders text content.
 ///
 /// Text nodes display text with configurable font properties,
 /// alignment, and styling options.
 #[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TextNode {
-  /// Default style presets from HTML element type (lowest priority)
-  pub preset: Option<Style>,
+  /// Default style presets from HTML element type or a registered preset name (lowest priority)
+  pub preset: Option<StylePreset>,
+  /// An identifier for debugging, highlighted when listed in the render options' `debug_highlight_ids`.
+  #[serde(default, alias = "data-testid")]
+  pub id: Option<String>,
   /// The styling properties for this text node
   pub style: Option<Style>,
   /// The text content to be rendered
   pub text: String,
   /// The tailwind properties for this text node
   pub tw: Option<TailwindValues>,
+  /// Shrinks the font size so the text fits within the node's box
+  #[serde(default)]
+  pub font_size_fit: Option<FontSizeFit>,
+  /// The style applied to the first letter of the text, like the `::first-letter` pseudo-element
+  #[serde(default)]
+  pub first_letter_style: Option<Style>,
+}
+
+/// The columns a multi-column text node flows into.
+#[derive(Debug, Clone, Copy)]
+struct TextColumns {
+  count: usize,
+  width: f32,
+  gap: f32,
+}
+
+impl TextColumns {
+  /// Returns the columns when `column-count` splits the text into more than one column.
+  fn new(context: &RenderContext, width: f32) -> Option<Self> {
+    let count = context.style.column_count.multiple()?;
+
+    // Without a definite width there is nothing to divide, so the text stays in one column.
+    if width >= f32::MAX {
+      return None;
+    }
+
+    let gap = context.style.resolved_column_gap(&context.sizing, width);
+
+    Some(Self {
+      count,
+      width: ((width - gap * (count - 1) as f32) / count as f32).max(0.0),
+      gap,
+    })
+  }
+}
+
+/// Text laid out at the column width, with its lines balanced across the columns.
+struct ColumnFlow<'c, 'g, N: Node<N>> {
+  text: String,
+  spans: Vec<ProcessedInlineSpan<'c, 'g, N>>,
+  /// The range of the processed text in each column.
+  column_ranges: Vec<Range<usize>>,
+  column_heights: Vec<f32>,
+}
+
+/// Splits consecutive lines into at most `column_count` columns, keeping the tallest one as short
+/// as possible. Columns are filled greedily, so when the lines don't divide evenly the last column
+/// ends up shorter than the others.
+pub(crate) fn balance_columns(line_heights: &[f32], column_count: usize) -> Vec<Range<usize>> {
+  let pack = |limit: f32| {
+    let mut columns = Vec::with_capacity(column_count);
+    let mut start = 0;
+    let mut height = 0.0;
+
+    for (index, line_height) in line_heights.iter().enumerate() {
+      if index > start && height + line_height > limit {
+        columns.push(start..index);
+        start = index;
+        height = 0.0;
+      }
+
+      height += line_height;
+    }
+
+    if start < line_heights.len() {
+      columns.push(start..line_heights.len());
+    }
+
+    columns
+  };
+
+  let total: f32 = line_heights.iter().sum();
+  let tallest = line_heights.iter().copied().fold(0.0, f32::max);
+  let (mut low, mut high) = (tallest.max(total / column_count as f32), total);
+
+  if pack(low).len() <= column_count {
+    return pack(low);
+  }
+
+  for _ in 0..COLUMN_BALANCE_ITERATIONS {
+    let mid = (low + high) / 2.0;
+
+    if pack(mid).len() <= column_count {
+      high = mid;
+    } else {
+      low = mid;
+    }
+  }
+
+  pack(high)
+}
+
+/// Returns the text range and height of every line in the layout.
+fn line_ranges(layout: &InlineLayout) -> (Vec<Range<usize>>, Vec<f32>) {
+  layout
+    .lines()
+    .map(|line| (line.text_range(), line.metrics().line_height))
+    .unzip()
+}
+
+/// Returns whether the character is punctuation that is kept together with the first letter.
+fn is_first_letter_punctuation(c: char) -> bool {
+  c.is_ascii_punctuation()
+    || matches!(
+      c,
+      '\u{a1}'
+        | '\u{ab}'
+        | '\u{bb}'
+        | '\u{bf}'
+        | '\u{2018}'..='\u{201f}'
+        | '\u{2039}'
+        | '\u{203a}'
+        | '\u{3008}'..='\u{3011}'
+        | '\u{ff08}'
+    )
+}
+
+/// Fails if the text has more than `max_text_length` characters, so it is never shaped.
+pub(crate) fn check_text_length(text: &str, max_text_length: usize) -> Result<()> {
+  // Every character takes at least one byte, so shorter texts don't need to be counted.
+  if text.len() <= max_text_length {
+    return Ok(());
+  }
+
+  let length = text.chars().count();
+
+  if length > max_text_length {
+    return Err(Error::TextTooLong {
+      length,
+      max_length: max_text_length,
+    });
+  }
+
+  Ok(())
+}
+
+/// Returns the byte length of the first letter, including leading whitespace and punctuation.
+///
+/// The letter is a whole extended grapheme cluster, so accented letters and emoji sequences are kept whole.
+pub(crate) fn first_letter_len(text: &str) -> usize {
+  text
+    .grapheme_indices(true)
+    .find(|(_, grapheme)| {
+      !grapheme
+        .chars()
+        .all(|c| c.is_whitespace() || is_first_letter_punctuation(c))
+    })
+    .map_or(text.len(), |(index, grapheme)| index + grapheme.len())
+}
+
+impl TextNode {
+  /// Returns the render context of the first letter, if it has its own style.
+  fn first_letter_context<'g>(&self, context: &RenderContext<'g>) -> Option<RenderContext<'g>> {
+    let style = self.first_letter_style.clone()?;
+
+    Some(context.with_child_style(style.inherit(&context.style)))
+  }
+
+  /// Splits the text into inline items, giving the first letter its own context when styled.
+  fn inline_items<'c, 'g, N: Node<N>>(
+    &'c self,
+    context: &'c RenderContext<'g>,
+    first_letter_context: Option<&'c RenderContext<'g>>,
+  ) -> impl Iterator<Item = InlineItem<'c, 'g, N>> {
+    let (first_letter, rest) = match first_letter_context {
+      Some(first_letter_context) => {
+        let (first_letter, rest) = self.text.split_at(first_letter_len(&self.text));
+
+        (
+          Some(InlineItem::Text {
+            text: first_letter.into(),
+            context: first_letter_context,
+            baseline_shift: 0.0,
+          }),
+          rest,
+        )
+      }
+      None => (None, self.text.as_str()),
+    };
+
+    first_letter
+      .into_iter()
+      .chain(
+        (!rest.is_empty() || first_letter_context.is_none()).then(|| InlineItem::Text {
+          text: rest.into(),
+          context,
+          baseline_shift: 0.0,
+        }),
+      )
+  }
+
+  /// Lays the text out at the column width and balances its lines across the columns.
+  fn column_layout<'c, 'g: 'c, N: Node<N>>(
+    &'c self,
+    context: &'c RenderContext<'g>,
+    first_letter_context: Option<&'c RenderContext<'g>>,
+    font_style: &'c SizedFontStyle,
+    columns: TextColumns,
+  ) -> ColumnFlow<'c, 'g, N> {
+    let (layout, text, spans) = create_inline_layout(
+      self.inline_items::<N>(context, first_letter_context),
+      Size {
+        width: AvailableSpace::Definite(columns.width),
+        height: AvailableSpace::MaxContent,
+      },
+      columns.width,
+      None,
+      font_style,
+      context.global,
+      InlineLayoutStage::Measure,
+    );
+
+    let (line_ranges, line_heights) = line_ranges(&layout);
+    let column_lines = balance_columns(&line_heights, columns.count);
+
+    let column_ranges = column_lines
+      .iter()
+      .map(|lines| line_ranges[lines.start].start..line_ranges[lines.end - 1].end)
+      .collect();
+    let column_heights = column_lines
+      .into_iter()
+      .map(|lines| line_heights[lines].iter().sum::<f32>())
+      .collect();
+
+    ColumnFlow {
+      text,
+      spans,
+      column_ranges,
+      column_heights,
+    }
+  }
+
+  /// Draws the text flowed into balanced columns, each laid out from its slice of the text.
+  fn draw_columns<'g, N: Node<N>>(
+    &self,
+    context: &RenderContext<'g>,
+    canvas: &mut Canvas,
+    layout: Layout,
+    first_letter_context: Option<&RenderContext<'g>>,
+    font_style: &SizedFontStyle,
+    columns: TextColumns,
+  ) -> Result<()> {
+    let ColumnFlow {
+      text,
+      spans,
+      column_ranges,
+      ..
+    } = self.column_layout::<N>(context, first_letter_context, font_style, columns);
+
+    // Pairs the processed text of each item with the context it was styled with.
+    let mut offset = 0;
+    let item_ranges = self
+      .inline_items::<N>(context, first_letter_context)
+      .zip(&spans)
+      .filter_map(|(item, span)| {
+        let (
+          InlineItem::Text {
+            context,
+            baseline_shift,
+            ..
+          },
+          ProcessedInlineSpan::Text {
+            text: span_text, ..
+          },
+        ) = (item, span)
+        else {
+          return None;
+        };
+
+        let range = offset..offset + span_text.len();
+        offset = range.end;
+
+        Some((range, context, baseline_shift))
+      })
+      .collect::<Vec<_>>();
+
+    if let Some(rule) = context.style.resolved_column_rule(
+      &context.sizing,
+      context.current_color,
+      layout.content_box_width(),
+    ) {
+      // Rules only separate columns that received lines, so trailing empty columns get none.
+      let content_left = layout.border.left + layout.padding.left;
+      let centers = (1..column_ranges.len())
+        .map(|index| {
+          content_left + index as f32 * (columns.width + columns.gap) - columns.gap / 2.0
+        })
+        .collect::<Vec<_>>();
+
+      draw_column_rules(
+        canvas,
+        &centers,
+        layout.border.top + layout.padding.top,
+        layout.content_box_height(),
+        rule,
+        context.transform,
+      );
+    }
+
+    for (index, column_range) in column_ranges.into_iter().enumerate() {
+      let items = item_ranges
+        .iter()
+        .filter_map(|(range, context, baseline_shift)| {
+          let start = range.start.max(column_range.start);
+          let end = range.end.min(column_range.end);
+
+          (start < end).then(|| InlineItem::<N>::Text {
+            text: Cow::Borrowed(&text[start..end]),
+            context,
+            baseline_shift: *baseline_shift,
+          })
+        });
+
+      let (inline_layout, _, column_spans) = create_inline_layout(
+        items,
+        Size {
+          width: AvailableSpace::Definite(columns.width),
+          height: AvailableSpace::MaxContent,
+        },
+        columns.width,
+        None,
+        font_style,
+        context.global,
+        InlineLayoutStage::Draw,
+      );
+
+      let mut column_layout = layout;
+      column_layout.padding.left += index as f32 * (columns.width + columns.gap);
+
+      draw_inline_layout(
+        context,
+        canvas,
+        column_layout,
+        inline_layout,
+        font_style,
+        &column_spans,
+      )?;
+    }
+
+    Ok(())
+  }
+
+  /// Returns the render context with the font size fitted to the given box, if requested.
+  fn fit_context<'g, N: Node<N>>(
+    &self,
+    context: &RenderContext<'g>,
+    size: Size<f32>,
+  ) -> Option<RenderContext<'g>> {
+    let fit = self.font_size_fit?;
+    let dpr = context.sizing.viewport.device_pixel_ratio;
+    let (min, max) = (fit.min * dpr, fit.max.max(fit.min) * dpr);
+
+    let mut fit_context = context.clone();
+    let mut fits = |font_size: f32| {
+      fit_context.sizing.font_size = font_size;
+      self.fits_within::<N>(&fit_context, fit.mode, size)
+    };
+
+    let font_size = if fits(max) {
+      max
+    } else if !fits(min) {
+      min
+    } else {
+      let (mut low, mut high) = (min, max);
+
+      for _ in 0..FONT_SIZE_FIT_ITERATIONS {
+        let mid = (low + high) / 2.0;
+
+        if fits(mid) {
+          low = mid;
+        } else {
+          high = mid;
+        }
+      }
+
+      low
+    };
+
+    fit_context.sizing.font_size = font_size;
+    Some(fit_context)
+  }
+
+  /// Checks whether the text laid out with the context's font size fits within the box.
+  fn fits_within<N: Node<N>>(
+    &self,
+    context: &RenderContext,
+    mode: FontSizeFitMode,
+    size: Size<f32>,
+  ) -> bool {
+    let font_style = context.style.to_sized_font_style(context);
+    let first_letter_context = self.first_letter_context(context);
+
+    let (mut layout, _, _) = create_inline_layout(
+      self.inline_items::<N>(context, first_letter_context.as_ref()),
+      Size {
+        width: AvailableSpace::Definite(size.width),
+        height: AvailableSpace::MaxContent,
+      },
+      size.width,
+      None,
+      &font_style,
+      context.global,
+      InlineLayoutStage::Measure,
+    );
+
+    let measured = measure_inline_layout(&mut layout, size.width);
+    let line_count = layout.len();
+
+    let fits_lines = match mode {
+      FontSizeFitMode::Box => true,
+      FontSizeFitMode::SingleLine => line_count <= 1,
+      FontSizeFitMode::Lines(lines) => line_count <= lines as usize,
+    };
+
+    fits_lines && measured.width <= size.width && measured.height <= size.height
+  }
 }
 
 impl<Nodes: Node<Nodes>> Node<Nodes> for TextNode {
@@ -38,12 +493,17 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for TextNode {
     &mut self,
     parent_style: &InheritedStyle,
     viewport: Viewport,
+    presets: &StylePresets,
   ) -> InheritedStyle {
     // Start with empty style
     let mut style = Style::default();
 
     // 1. Apply preset first (lowest priority)
-    if let Some(preset) = self.preset.take() {
+    if let Some(preset) = self
+      .preset
+      .take()
+      .and_then(|preset| preset.resolve(presets))
+    {
       style.merge_from(preset);
     }
 
@@ -70,8 +530,10 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for TextNode {
     canvas: &mut Canvas,
     layout: Layout,
   ) -> Result<()> {
-    let font_style = context.style.to_sized_font_style(context);
     let size = layout.content_box_size();
+    let fit_context = self.fit_context::<Nodes>(context, size);
+    let context = fit_context.as_ref().unwrap_or(context);
+    let font_style = context.style.to_sized_font_style(context);
 
     if font_style.sizing.font_size == 0.0 {
       return Ok(());
@@ -82,13 +544,21 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for TextNode {
       None => Some(MaxHeight::Absolute(size.height)),
     };
 
-    let inline_text: InlineItem<'_, '_, Nodes> = InlineItem::Text {
-      text: self.text.as_str().into(),
-      context,
-    };
+    let first_letter_context = self.first_letter_context(context);
+
+    if let Some(columns) = TextColumns::new(context, size.width) {
+      return self.draw_columns::<Nodes>(
+        context,
+        canvas,
+        layout,
+        first_letter_context.as_ref(),
+        &font_style,
+        columns,
+      );
+    }
 
     let (inline_layout, _, spans) = create_inline_layout(
-      once(inline_text),
+      self.inline_items::<Nodes>(context, first_letter_context.as_ref()),
       Size {
         width: AvailableSpace::Definite(size.width),
         height: AvailableSpace::Definite(size.height),
@@ -112,18 +582,36 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for TextNode {
     known_dimensions: Size<Option<f32>>,
     _style: &taffy::Style,
   ) -> Size<f32> {
-    let inline_content: InlineItem<'_, '_, Nodes> = InlineItem::Text {
-      text: self.text.as_str().into(),
-      context,
+    // Without a definite width, the text is fitted against the widest line it could take.
+    let fit_size = Size {
+      width: known_dimensions
+        .width
+        .or(available_space.width.into_option())
+        .unwrap_or(f32::MAX),
+      height: known_dimensions.height.unwrap_or(f32::MAX),
     };
+    let fit_context = self.fit_context::<Nodes>(context, fit_size);
+    let context = fit_context.as_ref().unwrap_or(context);
+
+    let first_letter_context = self.first_letter_context(context);
 
     let (max_width, max_height) =
       create_inline_constraint(context, available_space, known_dimensions);
 
     let font_style = context.style.to_sized_font_style(context);
 
+    if let Some(columns) = TextColumns::new(context, max_width) {
+      let flow =
+        self.column_layout::<Nodes>(context, first_letter_context.as_ref(), &font_style, columns);
+
+      return Size {
+        width: max_width,
+        height: flow.column_heights.into_iter().fold(0.0, f32::max).ceil(),
+      };
+    }
+
     let (mut layout, _, _) = create_inline_layout(
-      once(inline_content),
+      self.inline_items::<Nodes>(context, first_letter_context.as_ref()),
       available_space,
       max_width,
       max_height,
@@ -132,10 +620,86 @@ impl<Nodes: Node<Nodes>> Node<Nodes> for TextNode {
       InlineLayoutStage::Measure,
     );
 
-    measure_inline_layout(&mut layout, max_width)
+    measure_inline_layout(
+      &mut layout,
+      measure_width_limit(available_space, known_dimensions, max_width),
+    )
   }
 
   fn get_style(&self) -> Option<&Style> {
     self.style.as_ref()
   }
+
+  fn get_style_mut(&mut self) -> Option<&mut Style> {
+    Some(self.style.get_or_insert_with(Style::default))
+  }
+
+  fn node_type(&self) -> &'static str {
+    "text"
+  }
+
+  fn id(&self) -> Option<&str> {
+    self.id.as_deref()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{balance_columns, first_letter_len};
+
+  fn first_letter(text: &str) -> &str {
+    &text[..first_letter_len(text)]
+  }
+
+  #[test]
+  fn test_first_letter_is_single_character() {
+    assert_eq!(first_letter("Once upon a time"), "O");
+  }
+
+  #[test]
+  fn test_first_letter_includes_leading_punctuation() {
+    assert_eq!(first_letter("\u{201c}Hello,\u{201d} she said"), "\u{201c}H");
+    assert_eq!(first_letter("\"(Quoted)\""), "\"(Q");
+  }
+
+  #[test]
+  fn test_first_letter_keeps_combining_marks() {
+    assert_eq!(first_letter("e\u{301}te\u{301}"), "e\u{301}");
+  }
+
+  #[test]
+  fn test_first_letter_keeps_emoji_sequences() {
+    assert_eq!(
+      first_letter("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb} codes"),
+      "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}"
+    );
+    assert_eq!(
+      first_letter("\u{1f1f9}\u{1f1fc} flag"),
+      "\u{1f1f9}\u{1f1fc}"
+    );
+  }
+
+  #[test]
+  fn test_first_letter_of_punctuation_only_text() {
+    assert_eq!(first_letter("..."), "...");
+  }
+
+  #[test]
+  fn test_balance_columns_splits_evenly() {
+    assert_eq!(balance_columns(&[10.0; 9], 3), [0..3, 3..6, 6..9]);
+  }
+
+  #[test]
+  fn test_balance_columns_leaves_last_column_shorter() {
+    assert_eq!(balance_columns(&[10.0; 7], 3), [0..3, 3..6, 6..7]);
+    assert_eq!(balance_columns(&[10.0; 2], 3), [0..1, 1..2]);
+  }
+
+  #[test]
+  fn test_balance_columns_accounts_for_tall_lines() {
+    assert_eq!(
+      balance_columns(&[40.0, 10.0, 10.0, 10.0, 10.0], 2),
+      [0..1, 1..5]
+    );
+  }
 }
@@ -4,9 +4,9 @@ mod stylesheets;
 /// Handle Tailwind CSS properties.
 pub mod tw;
 
-use std::marker::PhantomData;
+use std::{borrow::Cow, marker::PhantomData};
 
-use cssparser::match_ignore_ascii_case;
+use cssparser::{ParseError, ParseErrorKind, match_ignore_ascii_case};
 pub use properties::*;
 use serde::{
   Deserialize, Deserializer,
@@ -28,6 +28,20 @@ pub enum CssValue<T, const DEFAULT_INHERIT: bool = false> {
   Value(T),
 }
 
+/// Maps a failed string parse to a serde error, keeping the parser message when it explains why the value is invalid.
+fn invalid_str_error<E: de::Error>(
+  value: &str,
+  error: ParseError<'_, Cow<'_, str>>,
+  expected: &dyn de::Expected,
+) -> E {
+  match error.kind {
+    ParseErrorKind::Custom(message) if !message.starts_with(UNEXPECTED_TOKEN_MESSAGE_PREFIX) => {
+      E::custom(format_args!("invalid value: string {value:?}, {message}"))
+    }
+    _ => E::invalid_value(de::Unexpected::Str(value), expected),
+  }
+}
+
 // Visitor for CssValue<T>
 struct CssValueVisitor<T, const DEFAULT_INHERIT: bool> {
   _marker: PhantomData<T>,
@@ -75,7 +89,7 @@ impl<'de, T: for<'i> FromCss<'i>, const DEFAULT_INHERIT: bool> Visitor<'de>
       "unset" => Ok(CssValue::Unset),
       _ => T::from_str(value)
         .map(CssValue::Value)
-        .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self)),
+        .map_err(|error| invalid_str_error(value, error, &self)),
     }
   }
 
@@ -141,8 +155,9 @@ impl<'de, T: for<'i> FromCss<'i>, const DEFAULT_INHERIT: bool> Visitor<'de>
     {
       write!(
         f,
-        "{}; also accepts 'none', 'initial' or 'inherit'.",
-        T::expect_message()
+        "{}; also accepts {}'initial' or 'inherit'.",
+        T::expect_message(),
+        if T::ACCEPTS_NONE { "'none', " } else { "" }
       )
     }
 
@@ -159,14 +174,17 @@ impl<'de, T: for<'i> FromCss<'i>, const DEFAULT_INHERIT: bool> Visitor<'de>
   where
     E: de::Error,
   {
+    if T::ACCEPTS_NONE && value.eq_ignore_ascii_case("none") {
+      return Ok(CssValue::Value(None));
+    }
+
     match_ignore_ascii_case! {value,
-      "none" => Ok(CssValue::Value(None)),
       "initial" => Ok(CssValue::Initial),
       "inherit" => Ok(CssValue::Inherit),
       "unset" => Ok(CssValue::Unset),
       _ => T::from_str(value)
         .map(|v| CssValue::Value(Some(v)))
-        .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self)),
+        .map_err(|error| invalid_str_error(value, error, &self)),
     }
   }
 
@@ -18,6 +18,8 @@ pub struct Background {
   pub repeat: BackgroundRepeat,
   /// Background clip.
   pub clip: BackgroundClip,
+  /// Background origin.
+  pub origin: BackgroundOrigin,
   /// Background blend mode.
   pub blend_mode: BlendMode,
 }
@@ -38,6 +40,7 @@ impl<'i> FromCss<'i> for Background {
     let mut size = None;
     let mut repeat = None;
     let mut clip = None;
+    let mut origin = None;
     let mut blend_mode = None;
 
     loop {
@@ -85,6 +88,14 @@ impl<'i> FromCss<'i> for Background {
         continue;
       }
 
+      // A single box sets both background-origin and background-clip, a second one sets the clip
+      if origin.is_none()
+        && let Ok(value) = input.try_parse(BackgroundOrigin::from_css)
+      {
+        origin = Some(value);
+        continue;
+      }
+
       // Try to parse background-clip
       if clip.is_none()
         && let Ok(value) = input.try_parse(BackgroundClip::from_css)
@@ -114,7 +125,10 @@ impl<'i> FromCss<'i> for Background {
       position: position.unwrap_or_default(),
       size: size.unwrap_or_default(),
       repeat: repeat.unwrap_or_default(),
-      clip: clip.unwrap_or_default(),
+      clip: clip
+        .or(origin.map(BackgroundClip::from))
+        .unwrap_or_default(),
+      origin: origin.unwrap_or_default(),
       blend_mode: blend_mode.unwrap_or_default(),
     })
   }
@@ -126,6 +140,7 @@ impl<'i> FromCss<'i> for Background {
       CssToken::Token("position"),
       CssToken::Token("repeat"),
       CssToken::Token("clip"),
+      CssToken::Token("origin"),
       CssToken::Token("blend-mode"),
     ]
   }
@@ -173,6 +188,32 @@ mod tests {
       Ok(Background {
         color: Some(ColorInput::Value(Color([255, 0, 0, 255]))),
         clip: BackgroundClip::BorderBox,
+        origin: BackgroundOrigin::BorderBox,
+        ..Default::default()
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_background_origin_and_clip() {
+    assert_eq!(
+      Background::from_str("url(image.png) content-box padding-box"),
+      Ok(Background {
+        image: BackgroundImage::Url("image.png".into()),
+        clip: BackgroundClip::PaddingBox,
+        origin: BackgroundOrigin::ContentBox,
+        ..Default::default()
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_background_text_clip_keeps_default_origin() {
+    assert_eq!(
+      Background::from_str("red text"),
+      Ok(Background {
+        color: Some(ColorInput::Value(Color([255, 0, 0, 255]))),
+        clip: BackgroundClip::Text,
         ..Default::default()
       })
     );
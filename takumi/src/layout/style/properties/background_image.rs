@@ -2,9 +2,12 @@ use std::sync::Arc;
 
 use cssparser::{Parser, Token, match_ignore_ascii_case};
 
-use crate::layout::style::{
-  ConicGradient, CssToken, FromCss, LinearGradient, MakeComputed, NoiseV1, ParseResult,
-  RadialGradient, tw::TailwindPropertyParser,
+use crate::layout::{
+  node::select_by_density,
+  style::{
+    ConicGradient, CssToken, FromCss, LinearGradient, MakeComputed, NoiseV1, ParseResult,
+    RadialGradient, tw::TailwindPropertyParser,
+  },
 };
 use crate::rendering::Sizing;
 
@@ -24,6 +27,135 @@ pub enum BackgroundImage {
   Noise(NoiseV1),
   /// Load external image resource.
   Url(Arc<str>),
+  /// CSS image-set(...), an external image picked by the device pixel ratio.
+  ImageSet(ImageSet),
+}
+
+/// A candidate image of [`ImageSet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSetOption {
+  /// The URL of the image.
+  pub url: Arc<str>,
+  /// The pixel density of the image, in image pixels per CSS pixel.
+  pub density: f32,
+}
+
+/// Represents `image-set()`, a list of images for different pixel densities, like `srcset` of `<img>`.
+///
+/// The image with the smallest density covering the device pixel ratio is drawn,
+/// or the densest one if none covers it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSet(pub Box<[ImageSetOption]>);
+
+impl ImageSet {
+  /// Returns the candidate to draw at the device pixel ratio.
+  pub fn select(&self, device_pixel_ratio: f32) -> Option<&ImageSetOption> {
+    select_by_density(
+      self.0.iter().map(|option| (option, option.density)),
+      device_pixel_ratio,
+    )
+    .map(|(option, _)| option)
+  }
+}
+
+impl<'i> FromCss<'i> for ImageSet {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let function = input.expect_function()?.clone();
+
+    if !function.eq_ignore_ascii_case("image-set")
+      && !function.eq_ignore_ascii_case("-webkit-image-set")
+    {
+      return Err(Self::unexpected_token_error(
+        location,
+        &Token::Function(function),
+      ));
+    }
+
+    input.parse_nested_block(|input| {
+      let options = input.parse_comma_separated(ImageSetOption::from_css)?;
+
+      Ok(ImageSet(options.into_boxed_slice()))
+    })
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("image-set()")]
+  }
+}
+
+impl<'i> FromCss<'i> for ImageSetOption {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let url = match input.try_parse(Parser::expect_url) {
+      Ok(url) => url,
+      Err(_) => input.expect_string()?.clone(),
+    };
+
+    let density = match input.try_parse(parse_resolution) {
+      Ok(density) => density,
+      Err(_) => 1.0,
+    };
+
+    Ok(ImageSetOption {
+      url: (&*url).into(),
+      density,
+    })
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("url()"), CssToken::Token("string")]
+  }
+}
+
+/// Parses a `<resolution>` like `2x`, `2dppx` or `192dpi` into image pixels per CSS pixel.
+fn parse_resolution<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, f32> {
+  let location = input.current_source_location();
+  let token = input.next()?;
+
+  let density = match token {
+    Token::Dimension { value, unit, .. } => match_ignore_ascii_case! { unit,
+      "x" | "dppx" => *value,
+      "dpi" => *value / 96.0,
+      "dpcm" => *value * 2.54 / 96.0,
+      _ => return Err(ImageSetOption::unexpected_token_error(location, token)),
+    },
+    _ => return Err(ImageSetOption::unexpected_token_error(location, token)),
+  };
+
+  if !density.is_finite() || density <= 0.0 {
+    return Err(location.new_custom_error(ImageSetOption::expect_message()));
+  }
+
+  Ok(density)
+}
+
+impl BackgroundImage {
+  /// Returns the URLs of the external images to fetch, every candidate of an `image-set()`.
+  pub(crate) fn urls(&self) -> impl Iterator<Item = &Arc<str>> {
+    let (url, options): (Option<&Arc<str>>, &[ImageSetOption]) = match self {
+      BackgroundImage::Url(url) => (Some(url), &[]),
+      BackgroundImage::ImageSet(set) => (None, &set.0),
+      _ => (None, &[]),
+    };
+
+    url
+      .into_iter()
+      .chain(options.iter().map(|option| &option.url))
+  }
+
+  /// Returns the URL of the external image to draw and its scale from image pixels to device pixels.
+  ///
+  /// The selected image of an `image-set()` is scaled by the device pixel ratio over its density,
+  /// and `url()` counts as a `1x` image, like `src` of an image node.
+  pub(crate) fn selected_url(&self, device_pixel_ratio: f32) -> Option<(&Arc<str>, f32)> {
+    match self {
+      BackgroundImage::Url(url) => Some((url, device_pixel_ratio)),
+      BackgroundImage::ImageSet(set) => set
+        .select(device_pixel_ratio)
+        .map(|option| (&option.url, device_pixel_ratio / option.density)),
+      _ => None,
+    }
+  }
 }
 
 impl MakeComputed for BackgroundImage {
@@ -66,6 +198,7 @@ impl<'i> FromCss<'i> for BackgroundImage {
     input.reset(&start);
 
     match_ignore_ascii_case! {&function,
+      "image-set" | "-webkit-image-set" => Ok(BackgroundImage::ImageSet(ImageSet::from_css(input)?)),
       "linear-gradient" => Ok(BackgroundImage::Linear(LinearGradient::from_css(input)?)),
       "radial-gradient" => Ok(BackgroundImage::Radial(RadialGradient::from_css(input)?)),
       "conic-gradient" => Ok(BackgroundImage::Conic(ConicGradient::from_css(input)?)),
@@ -77,6 +210,7 @@ impl<'i> FromCss<'i> for BackgroundImage {
   fn valid_tokens() -> &'static [CssToken] {
     &[
       CssToken::Token("url()"),
+      CssToken::Token("image-set()"),
       CssToken::Token("linear-gradient()"),
       CssToken::Token("radial-gradient()"),
       CssToken::Token("conic-gradient()"),
@@ -119,6 +253,73 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_image_set() {
+    assert_eq!(
+      BackgroundImage::from_str("image-set(url(a.png) 1x, \"b.png\" 2x, url(c.png) 288dpi)"),
+      Ok(BackgroundImage::ImageSet(ImageSet(
+        [
+          ImageSetOption {
+            url: "a.png".into(),
+            density: 1.0,
+          },
+          ImageSetOption {
+            url: "b.png".into(),
+            density: 2.0,
+          },
+          ImageSetOption {
+            url: "c.png".into(),
+            density: 3.0,
+          },
+        ]
+        .into()
+      )))
+    );
+    assert!(BackgroundImage::from_str("image-set(url(a.png) 0x)").is_err());
+    assert!(BackgroundImage::from_str("image-set()").is_err());
+  }
+
+  #[test]
+  fn test_image_set_selects_by_density() {
+    let Ok(BackgroundImage::ImageSet(set)) =
+      BackgroundImage::from_str("image-set(url(a.png) 1x, url(b.png) 2x)")
+    else {
+      unreachable!()
+    };
+
+    let selected = |device_pixel_ratio| {
+      set
+        .select(device_pixel_ratio)
+        .map(|option| option.url.to_string())
+    };
+
+    assert_eq!(selected(1.0), Some("a.png".to_string()));
+    assert_eq!(selected(1.5), Some("b.png".to_string()));
+    assert_eq!(selected(2.0), Some("b.png".to_string()));
+    assert_eq!(selected(3.0), Some("b.png".to_string()));
+  }
+
+  #[test]
+  fn test_url_scales_like_one_x_image_set() {
+    let (Ok(url), Ok(set)) = (
+      BackgroundImage::from_str("url(a.png)"),
+      BackgroundImage::from_str("image-set(url(a.png) 1x)"),
+    ) else {
+      unreachable!()
+    };
+
+    for device_pixel_ratio in [1.0, 2.0, 3.0] {
+      assert_eq!(
+        url.selected_url(device_pixel_ratio),
+        set.selected_url(device_pixel_ratio)
+      );
+      assert_eq!(
+        url.selected_url(device_pixel_ratio).map(|(_, scale)| scale),
+        Some(device_pixel_ratio)
+      );
+    }
+  }
+
   #[test]
   fn test_parse_tailwind_arbitrary_url() {
     assert_eq!(
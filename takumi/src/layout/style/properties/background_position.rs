@@ -39,12 +39,19 @@ pub enum PositionComponent {
   KeywordY(PositionKeywordY),
   /// An absolute length value.
   Length(Length),
+  /// A length offset from a horizontal edge, like `right 20px`.
+  OffsetX(PositionKeywordX, Length),
+  /// A length offset from a vertical edge, like `bottom 10px`.
+  OffsetY(PositionKeywordY, Length),
 }
 
 impl MakeComputed for PositionComponent {
   fn make_computed(&mut self, sizing: &Sizing) {
-    if let Self::Length(length) = self {
-      length.make_computed(sizing);
+    match self {
+      Self::Length(length) | Self::OffsetX(_, length) | Self::OffsetY(_, length) => {
+        length.make_computed(sizing);
+      }
+      Self::KeywordX(_) | Self::KeywordY(_) => {}
     }
   }
 }
@@ -55,20 +62,95 @@ impl From<Length> for PositionComponent {
   }
 }
 
+impl PositionKeywordX {
+  /// The position of the keyword as a percentage of the width.
+  fn percentage(self) -> f32 {
+    match self {
+      Self::Left => 0.0,
+      Self::Center => 50.0,
+      Self::Right => 100.0,
+    }
+  }
+}
+
+impl PositionKeywordY {
+  /// The position of the keyword as a percentage of the height.
+  fn percentage(self) -> f32 {
+    match self {
+      Self::Top => 0.0,
+      Self::Center => 50.0,
+      Self::Bottom => 100.0,
+    }
+  }
+}
+
+/// Offsets the edge at `edge` percent by `offset`, towards the center from the end edge.
+///
+/// `right 20px` becomes `calc(100% - 20px)` and `center 20px` becomes `calc(50% + 20px)`.
+/// Offsets that aren't linear, like `min()`, keep the edge position.
+fn offset_edge_length(edge: f32, offset: Length) -> Length {
+  if edge == 0.0 {
+    return offset;
+  }
+
+  let offset = if edge == 100.0 {
+    offset.negative()
+  } else {
+    offset
+  };
+
+  match offset {
+    Length::Percentage(offset) => Length::Percentage(edge + offset),
+    _ => offset
+      .plus_percentage(edge)
+      .unwrap_or(Length::Percentage(edge)),
+  }
+}
+
+/// Converts the component to a single length.
+///
+/// Edge offsets like `right 20px` are expressed with `calc()`. Prefer resolving the whole
+/// [`BackgroundPosition`], which also keeps offsets that aren't linear.
 impl From<PositionComponent> for Length {
   fn from(component: PositionComponent) -> Self {
     match component {
-      PositionComponent::KeywordX(keyword) => match keyword {
-        PositionKeywordX::Center => Self::Percentage(50.0),
-        PositionKeywordX::Left => Self::Percentage(0.0),
-        PositionKeywordX::Right => Self::Percentage(100.0),
-      },
-      PositionComponent::KeywordY(keyword) => match keyword {
-        PositionKeywordY::Center => Self::Percentage(50.0),
-        PositionKeywordY::Top => Self::Percentage(0.0),
-        PositionKeywordY::Bottom => Self::Percentage(100.0),
-      },
+      PositionComponent::KeywordX(keyword) => Self::Percentage(keyword.percentage()),
+      PositionComponent::KeywordY(keyword) => Self::Percentage(keyword.percentage()),
       PositionComponent::Length(length) => length,
+      PositionComponent::OffsetX(edge, offset) => offset_edge_length(edge.percentage(), offset),
+      PositionComponent::OffsetY(edge, offset) => offset_edge_length(edge.percentage(), offset),
+    }
+  }
+}
+
+impl PositionComponent {
+  /// Resolves the component to pixels, with keywords and percentages relative to `basis`.
+  pub(crate) fn to_px(self, sizing: &Sizing, basis: f32) -> f32 {
+    match self {
+      Self::KeywordX(PositionKeywordX::Left) | Self::KeywordY(PositionKeywordY::Top) => 0.0,
+      Self::KeywordX(PositionKeywordX::Center) | Self::KeywordY(PositionKeywordY::Center) => {
+        basis / 2.0
+      }
+      Self::KeywordX(PositionKeywordX::Right) | Self::KeywordY(PositionKeywordY::Bottom) => basis,
+      Self::Length(length) => length.to_px(sizing, basis),
+      Self::OffsetX(edge, offset) => {
+        let offset = offset.to_px(sizing, basis);
+
+        match edge {
+          PositionKeywordX::Left => offset,
+          PositionKeywordX::Center => basis / 2.0 + offset,
+          PositionKeywordX::Right => basis - offset,
+        }
+      }
+      Self::OffsetY(edge, offset) => {
+        let offset = offset.to_px(sizing, basis);
+
+        match edge {
+          PositionKeywordY::Top => offset,
+          PositionKeywordY::Center => basis / 2.0 + offset,
+          PositionKeywordY::Bottom => basis - offset,
+        }
+      }
     }
   }
 }
@@ -86,8 +168,8 @@ impl MakeComputed for BackgroundPosition {
 impl BackgroundPosition {
   pub(crate) fn to_point(self, sizing: &Sizing, border_box: Size<f32>) -> Point<f32> {
     Point {
-      x: Length::from(self.0.x).to_px(sizing, border_box.width),
-      y: Length::from(self.0.y).to_px(sizing, border_box.height),
+      x: self.0.x.to_px(sizing, border_box.width),
+      y: self.0.y.to_px(sizing, border_box.height),
     }
   }
 }
@@ -147,10 +229,25 @@ impl Default for BackgroundPosition {
 
 impl<'i> FromCss<'i> for BackgroundPosition {
   fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
     let first = PositionComponent::from_css(input)?;
     // If a second exists, parse it; otherwise, 1-value syntax means y=center
     let second = input.try_parse(PositionComponent::from_css).ok();
 
+    // The 3 and 4-value syntax pairs edge keywords with offsets, like `right 20px bottom 10px`.
+    if let Some(second) = second
+      && let Ok(third) = input.try_parse(PositionComponent::from_css)
+    {
+      let fourth = input.try_parse(PositionComponent::from_css).ok();
+
+      let Some((x, y)) = group_edge_offsets(&[Some(first), Some(second), Some(third), fourth])
+      else {
+        return Err(location.new_custom_error(Self::expect_message()));
+      };
+
+      return Ok(BackgroundPosition(SpacePair::from_pair(x, y)));
+    }
+
     let (x, y) = match (first, second) {
       (PositionComponent::KeywordY(_), None) => {
         (PositionComponent::KeywordX(PositionKeywordX::Center), first)
@@ -168,6 +265,51 @@ impl<'i> FromCss<'i> for BackgroundPosition {
   }
 }
 
+/// Groups the components of the 3 and 4-value syntax into edge offsets, returning the `(x, y)` pair.
+fn group_edge_offsets(
+  components: &[Option<PositionComponent>],
+) -> Option<(PositionComponent, PositionComponent)> {
+  let mut components = components.iter().flatten().copied().peekable();
+  let mut x = None;
+  let mut y = None;
+  let mut centers = 0;
+
+  while let Some(component) = components.next() {
+    let offset = components
+      .next_if(|next| matches!(next, PositionComponent::Length(_)))
+      .map(Length::from);
+
+    match (component, offset) {
+      (PositionComponent::KeywordX(PositionKeywordX::Center), None) => centers += 1,
+      (PositionComponent::KeywordX(PositionKeywordX::Center), Some(_)) => return None,
+      (PositionComponent::KeywordX(edge), offset) if x.is_none() => {
+        x = Some(offset.map_or(PositionComponent::KeywordX(edge), |offset| {
+          PositionComponent::OffsetX(edge, offset)
+        }));
+      }
+      (PositionComponent::KeywordY(edge), offset) if y.is_none() => {
+        y = Some(offset.map_or(PositionComponent::KeywordY(edge), |offset| {
+          PositionComponent::OffsetY(edge, offset)
+        }));
+      }
+      _ => return None,
+    }
+  }
+
+  // `center` takes whichever axis the other edge doesn't.
+  for _ in 0..centers {
+    if x.is_none() {
+      x = Some(PositionComponent::KeywordX(PositionKeywordX::Center));
+    } else if y.is_none() {
+      y = Some(PositionComponent::KeywordY(PositionKeywordY::Center));
+    } else {
+      return None;
+    }
+  }
+
+  Some((x?, y?))
+}
+
 impl<'i> FromCss<'i> for PositionComponent {
   fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
     if let Ok(v) = input.try_parse(Length::from_css) {
@@ -222,3 +364,111 @@ impl<'i> FromCss<'i> for BackgroundPositions {
     BackgroundPosition::valid_tokens()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::rc::Rc;
+
+  use super::*;
+  use crate::{
+    layout::{Viewport, style::CalcArena},
+    rendering::FontMetrics,
+  };
+
+  #[test]
+  fn test_parse_background_position_edge_offsets() {
+    assert_eq!(
+      BackgroundPosition::from_str("right 20px bottom 10px"),
+      Ok(BackgroundPosition(SpacePair::from_pair(
+        PositionComponent::OffsetX(PositionKeywordX::Right, Length::Px(20.0)),
+        PositionComponent::OffsetY(PositionKeywordY::Bottom, Length::Px(10.0)),
+      )))
+    );
+    assert_eq!(
+      BackgroundPosition::from_str("bottom 10% right 20px"),
+      Ok(BackgroundPosition(SpacePair::from_pair(
+        PositionComponent::OffsetX(PositionKeywordX::Right, Length::Px(20.0)),
+        PositionComponent::OffsetY(PositionKeywordY::Bottom, Length::Percentage(10.0)),
+      )))
+    );
+  }
+
+  #[test]
+  fn test_position_component_to_length() {
+    assert_eq!(
+      Length::from(PositionComponent::KeywordX(PositionKeywordX::Right)),
+      Length::Percentage(100.0)
+    );
+    assert_eq!(
+      Length::from(PositionComponent::OffsetY(
+        PositionKeywordY::Top,
+        Length::Px(10.0)
+      )),
+      Length::Px(10.0)
+    );
+    assert_eq!(
+      Length::from(PositionComponent::OffsetX(
+        PositionKeywordX::Right,
+        Length::Percentage(20.0)
+      )),
+      Length::Percentage(80.0)
+    );
+  }
+
+  #[test]
+  fn test_parse_background_position_three_values() {
+    assert_eq!(
+      BackgroundPosition::from_str("center top 10px"),
+      Ok(BackgroundPosition(SpacePair::from_pair(
+        PositionComponent::KeywordX(PositionKeywordX::Center),
+        PositionComponent::OffsetY(PositionKeywordY::Top, Length::Px(10.0)),
+      )))
+    );
+    assert_eq!(
+      BackgroundPosition::from_str("left 5px bottom"),
+      Ok(BackgroundPosition(SpacePair::from_pair(
+        PositionComponent::OffsetX(PositionKeywordX::Left, Length::Px(5.0)),
+        PositionComponent::KeywordY(PositionKeywordY::Bottom),
+      )))
+    );
+  }
+
+  #[test]
+  fn test_parse_background_position_invalid_edge_offsets() {
+    assert!(BackgroundPosition::from_str("left 10px 20px").is_err());
+    assert!(BackgroundPosition::from_str("left 10px right 20px").is_err());
+    assert!(BackgroundPosition::from_str("center 10px top").is_err());
+  }
+
+  #[test]
+  fn test_resolve_edge_offset() {
+    let sizing = Sizing {
+      viewport: Viewport::new(Some(100), Some(100)),
+      font_size: 16.0,
+      font_metrics: FontMetrics::default(),
+      calc_arena: Rc::new(CalcArena::default()),
+    };
+
+    assert_eq!(
+      PositionComponent::OffsetX(PositionKeywordX::Right, Length::Px(20.0)).to_px(&sizing, 100.0),
+      80.0
+    );
+    assert_eq!(
+      PositionComponent::OffsetY(PositionKeywordY::Bottom, Length::Percentage(10.0))
+        .to_px(&sizing, 200.0),
+      180.0
+    );
+
+    let right = Length::from(PositionComponent::OffsetX(
+      PositionKeywordX::Right,
+      Length::Px(20.0),
+    ));
+    assert_eq!(right.to_px(&sizing, 100.0), 80.0);
+
+    let center = Length::from(PositionComponent::OffsetY(
+      PositionKeywordY::Center,
+      Length::Px(20.0),
+    ));
+    assert_eq!(center.to_px(&sizing, 200.0), 120.0);
+  }
+}
@@ -124,6 +124,18 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_border_transparent_color() {
+    assert_eq!(
+      Border::from_str("1px solid transparent"),
+      Ok(Border {
+        width: Length::Px(1.0),
+        style: BorderStyle::Solid,
+        color: ColorInput::Value(Color::transparent()),
+      })
+    );
+  }
+
   #[test]
   fn test_parse_border_width_and_style() {
     assert_eq!(
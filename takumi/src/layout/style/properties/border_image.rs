@@ -0,0 +1,283 @@
+use cssparser::{Parser, Token};
+
+use crate::{
+  layout::style::{
+    CssToken, FromCss, Length, MakeComputed, ParseResult, Sides, declare_enum_from_css_impl,
+  },
+  rendering::Sizing,
+};
+
+/// A `border-image-slice` offset into the source image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderImageSliceValue {
+  /// An offset in pixels of the source image.
+  Number(f32),
+  /// An offset relative to the size of the source image, from 0.0 to 1.0.
+  Percentage(f32),
+}
+
+impl Default for BorderImageSliceValue {
+  fn default() -> Self {
+    Self::Percentage(1.0)
+  }
+}
+
+impl BorderImageSliceValue {
+  /// Resolves the offset in pixels of a source image with the given size along the axis.
+  pub(crate) fn resolve(self, image_size: f32) -> f32 {
+    let offset = match self {
+      Self::Number(number) => number,
+      Self::Percentage(percentage) => percentage * image_size,
+    };
+
+    offset.clamp(0.0, image_size)
+  }
+}
+
+impl<'i> FromCss<'i> for BorderImageSliceValue {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let token = input.next()?;
+
+    match token {
+      Token::Number { value, .. } if *value >= 0.0 => Ok(Self::Number(*value)),
+      Token::Percentage { unit_value, .. } if *unit_value >= 0.0 => {
+        Ok(Self::Percentage(*unit_value))
+      }
+      _ => Err(Self::unexpected_token_error(location, token)),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("number"), CssToken::Token("percentage")]
+  }
+}
+
+/// Represents the `border-image-slice` property, which divides the source image into nine regions.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderImageSlice {
+  /// The inward offsets of the top, right, bottom and left edges.
+  pub offsets: Sides<BorderImageSliceValue>,
+  /// Whether the middle region is drawn as well.
+  pub fill: bool,
+}
+
+impl MakeComputed for BorderImageSlice {}
+
+impl<'i> FromCss<'i> for BorderImageSlice {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let fill_before = input
+      .try_parse(|input| input.expect_ident_matching("fill"))
+      .is_ok();
+
+    let offsets = Sides::<BorderImageSliceValue>::from_css(input)?;
+
+    let fill_after = !fill_before
+      && input
+        .try_parse(|input| input.expect_ident_matching("fill"))
+        .is_ok();
+
+    Ok(Self {
+      offsets,
+      fill: fill_before || fill_after,
+    })
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Token("number"),
+      CssToken::Token("percentage"),
+      CssToken::Keyword("fill"),
+    ]
+  }
+}
+
+/// A `border-image-width` value for one side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderImageWidth {
+  /// A length, percentages are relative to the size of the border image area.
+  Length(Length),
+  /// A multiple of the border width of the side.
+  Number(f32),
+  /// The size of the matching slice of the source image.
+  Auto,
+}
+
+impl Default for BorderImageWidth {
+  fn default() -> Self {
+    Self::Number(1.0)
+  }
+}
+
+impl MakeComputed for BorderImageWidth {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    if let Self::Length(length) = self {
+      length.make_computed(sizing);
+    }
+  }
+}
+
+impl<'i> FromCss<'i> for BorderImageWidth {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if input
+      .try_parse(|input| input.expect_ident_matching("auto"))
+      .is_ok()
+    {
+      return Ok(Self::Auto);
+    }
+
+    if let Ok(number) = input.try_parse(Parser::expect_number) {
+      return Ok(Self::Number(number.max(0.0)));
+    }
+
+    Ok(Self::Length(Length::from_css(input)?))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("auto"),
+      CssToken::Token("number"),
+      CssToken::Token("length"),
+    ]
+  }
+}
+
+/// A `border-image-outset` value for one side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderImageOutset {
+  /// A length the border image area extends beyond the border box.
+  Length(Length),
+  /// A multiple of the border width of the side.
+  Number(f32),
+}
+
+impl Default for BorderImageOutset {
+  fn default() -> Self {
+    Self::Number(0.0)
+  }
+}
+
+impl MakeComputed for BorderImageOutset {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    if let Self::Length(length) = self {
+      length.make_computed(sizing);
+    }
+  }
+}
+
+impl<'i> FromCss<'i> for BorderImageOutset {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if let Ok(number) = input.try_parse(Parser::expect_number) {
+      return Ok(Self::Number(number.max(0.0)));
+    }
+
+    Ok(Self::Length(Length::from_css(input)?))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("number"), CssToken::Token("length")]
+  }
+}
+
+/// How the edge and middle regions of a border image fill their area along one axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BorderImageRepeatStyle {
+  /// Stretch the region to fill the area
+  #[default]
+  Stretch,
+  /// Tile the region, centered in the area
+  Repeat,
+  /// Tile the region, scaled so a whole number of tiles fits
+  Round,
+  /// Tile the region, distributing the leftover space between the tiles
+  Space,
+}
+
+declare_enum_from_css_impl!(
+  BorderImageRepeatStyle,
+  "stretch" => BorderImageRepeatStyle::Stretch,
+  "repeat" => BorderImageRepeatStyle::Repeat,
+  "round" => BorderImageRepeatStyle::Round,
+  "space" => BorderImageRepeatStyle::Space
+);
+
+/// Represents the `border-image-repeat` property, for the horizontal and vertical axes.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderImageRepeat(pub BorderImageRepeatStyle, pub BorderImageRepeatStyle);
+
+impl MakeComputed for BorderImageRepeat {}
+
+impl<'i> FromCss<'i> for BorderImageRepeat {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let x = BorderImageRepeatStyle::from_css(input)?;
+    let y = input
+      .try_parse(BorderImageRepeatStyle::from_css)
+      .unwrap_or(x);
+
+    Ok(Self(x, y))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    BorderImageRepeatStyle::valid_tokens()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_border_image_slice() {
+    assert_eq!(
+      BorderImageSlice::from_str("30 25% fill"),
+      Ok(BorderImageSlice {
+        offsets: Sides([
+          BorderImageSliceValue::Number(30.0),
+          BorderImageSliceValue::Percentage(0.25),
+          BorderImageSliceValue::Number(30.0),
+          BorderImageSliceValue::Percentage(0.25),
+        ]),
+        fill: true,
+      })
+    );
+    assert_eq!(
+      BorderImageSlice::from_str("fill 10"),
+      Ok(BorderImageSlice {
+        offsets: Sides([BorderImageSliceValue::Number(10.0); 4]),
+        fill: true,
+      })
+    );
+    assert!(BorderImageSlice::from_str("-1").is_err());
+  }
+
+  #[test]
+  fn test_parse_border_image_width() {
+    assert_eq!(
+      Sides::<BorderImageWidth>::from_str("2 auto 10px"),
+      Ok(Sides([
+        BorderImageWidth::Number(2.0),
+        BorderImageWidth::Auto,
+        BorderImageWidth::Length(Length::Px(10.0)),
+        BorderImageWidth::Auto,
+      ]))
+    );
+  }
+
+  #[test]
+  fn test_parse_border_image_repeat() {
+    assert_eq!(
+      BorderImageRepeat::from_str("round"),
+      Ok(BorderImageRepeat(
+        BorderImageRepeatStyle::Round,
+        BorderImageRepeatStyle::Round
+      ))
+    );
+    assert_eq!(
+      BorderImageRepeat::from_str("repeat space"),
+      Ok(BorderImageRepeat(
+        BorderImageRepeatStyle::Repeat,
+        BorderImageRepeatStyle::Space
+      ))
+    );
+  }
+}
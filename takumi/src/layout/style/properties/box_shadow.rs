@@ -229,6 +229,21 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_box_shadow_transparent_color() {
+    assert_eq!(
+      BoxShadow::from_str("2px 4px transparent"),
+      Ok(BoxShadow {
+        offset_x: Px(2.0),
+        offset_y: Px(4.0),
+        blur_radius: Length::zero(),
+        spread_radius: Length::zero(),
+        color: ColorInput::Value(Color::transparent()),
+        inset: false,
+      })
+    );
+  }
+
   #[test]
   fn test_parse_inset_box_shadow() {
     // Test parsing inset box-shadow
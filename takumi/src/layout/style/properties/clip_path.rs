@@ -1,11 +1,11 @@
 use cssparser::{Parser, Token, match_ignore_ascii_case};
-use taffy::{AbsoluteAxis, Point, Rect, Size};
+use taffy::{AbsoluteAxis, Layout, Point, Rect, Size};
 use zeno::{Fill, PathBuilder, PathData, Placement};
 
 use crate::{
   layout::style::{
-    Axis, BorderStyle, Color, CssToken, FromCss, ImageScalingAlgorithm, Length, MakeComputed,
-    ParseResult, Sides, SpacePair,
+    Affine, Axis, BorderStyle, Color, CssToken, CssValue, FromCss, ImageScalingAlgorithm, Length,
+    MakeComputed, ParseResult, Sides, SpacePair, declare_enum_from_css_impl,
   },
   rendering::{BorderProperties, BufferPool, MaskMemory, RenderContext, Sizing},
 };
@@ -134,6 +134,202 @@ pub struct PathShape {
   pub path: Box<str>,
 }
 
+/// Represents a command of the shape() function.
+///
+/// Coordinates of `by` commands are relative to the current point, and those of `to` commands to the
+/// reference box, with percentages resolving against its width for x and its height for y.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeCommand {
+  /// `move <by | to> <coordinate-pair>`, starts a new subpath
+  Move {
+    /// Whether the point is relative to the current point
+    relative: bool,
+    /// The new current point
+    to: PolygonCoordinate,
+  },
+  /// `line <by | to> <coordinate-pair>`
+  Line {
+    /// Whether the point is relative to the current point
+    relative: bool,
+    /// The end point of the line
+    to: PolygonCoordinate,
+  },
+  /// `hline <by | to> <length-percentage>`
+  HLine {
+    /// Whether the x coordinate is relative to the current point
+    relative: bool,
+    /// The x coordinate of the end point
+    x: Length,
+  },
+  /// `vline <by | to> <length-percentage>`
+  VLine {
+    /// Whether the y coordinate is relative to the current point
+    relative: bool,
+    /// The y coordinate of the end point
+    y: Length,
+  },
+  /// `curve <by | to> <coordinate-pair> with <coordinate-pair> [/ <coordinate-pair>]?`
+  Curve {
+    /// Whether the points are relative to the current point
+    relative: bool,
+    /// The end point of the curve
+    to: PolygonCoordinate,
+    /// The first control point
+    control: PolygonCoordinate,
+    /// The second control point, a quadratic curve is drawn without it
+    control_2: Option<PolygonCoordinate>,
+  },
+  /// `smooth <by | to> <coordinate-pair> [with <coordinate-pair>]?`, whose first control point
+  /// reflects the last control point of the previous curve
+  Smooth {
+    /// Whether the points are relative to the current point
+    relative: bool,
+    /// The end point of the curve
+    to: PolygonCoordinate,
+    /// The second control point, a quadratic curve is drawn without it
+    control: Option<PolygonCoordinate>,
+  },
+  /// `close`, returns to the start of the subpath
+  Close,
+}
+
+impl MakeComputed for ShapeCommand {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    match self {
+      ShapeCommand::Move { to, .. } | ShapeCommand::Line { to, .. } => to.make_computed(sizing),
+      ShapeCommand::HLine { x, .. } => x.make_computed(sizing),
+      ShapeCommand::VLine { y, .. } => y.make_computed(sizing),
+      ShapeCommand::Curve {
+        to,
+        control,
+        control_2,
+        ..
+      } => {
+        to.make_computed(sizing);
+        control.make_computed(sizing);
+        control_2.make_computed(sizing);
+      }
+      ShapeCommand::Smooth { to, control, .. } => {
+        to.make_computed(sizing);
+        control.make_computed(sizing);
+      }
+      ShapeCommand::Close => {}
+    }
+  }
+}
+
+/// Represents a shape() function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeFunction {
+  /// The fill rule to use
+  pub fill_rule: Option<FillRule>,
+  /// The starting point
+  pub from: PolygonCoordinate,
+  /// The commands drawing the shape
+  pub commands: Box<[ShapeCommand]>,
+}
+
+impl MakeComputed for ShapeFunction {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    self.from.make_computed(sizing);
+    self.commands.make_computed(sizing);
+  }
+}
+
+fn offset_point(relative: bool, origin: (f32, f32), point: (f32, f32)) -> (f32, f32) {
+  if relative {
+    (origin.0 + point.0, origin.1 + point.1)
+  } else {
+    point
+  }
+}
+
+impl ShapeFunction {
+  fn append_commands(&self, paths: &mut impl PathBuilder, size: Size<f32>, sizing: &Sizing) {
+    let resolve = |coordinate: PolygonCoordinate| {
+      (
+        coordinate.x.to_px(sizing, size.width),
+        coordinate.y.to_px(sizing, size.height),
+      )
+    };
+
+    let mut current = resolve(self.from);
+    let mut subpath_start = current;
+    let mut last_control = None;
+
+    paths.move_to(current);
+
+    for command in &self.commands {
+      let origin = current;
+
+      match *command {
+        ShapeCommand::Move { relative, to } => {
+          current = offset_point(relative, origin, resolve(to));
+          subpath_start = current;
+          paths.move_to(current);
+          last_control = None;
+        }
+        ShapeCommand::Line { relative, to } => {
+          current = offset_point(relative, origin, resolve(to));
+          paths.line_to(current);
+          last_control = None;
+        }
+        ShapeCommand::HLine { relative, x } => {
+          current.0 = offset_point(relative, origin, (x.to_px(sizing, size.width), 0.0)).0;
+          paths.line_to(current);
+          last_control = None;
+        }
+        ShapeCommand::VLine { relative, y } => {
+          current.1 = offset_point(relative, origin, (0.0, y.to_px(sizing, size.height))).1;
+          paths.line_to(current);
+          last_control = None;
+        }
+        ShapeCommand::Curve {
+          relative,
+          to,
+          control,
+          control_2,
+        } => {
+          current = offset_point(relative, origin, resolve(to));
+          let control = offset_point(relative, origin, resolve(control));
+
+          if let Some(control_2) = control_2 {
+            let control_2 = offset_point(relative, origin, resolve(control_2));
+            paths.curve_to(control, control_2, current);
+            last_control = Some(control_2);
+          } else {
+            paths.quad_to(control, current);
+            last_control = Some(control);
+          }
+        }
+        ShapeCommand::Smooth {
+          relative,
+          to,
+          control,
+        } => {
+          current = offset_point(relative, origin, resolve(to));
+          let reflected =
+            last_control.map_or(origin, |(x, y)| (2.0 * origin.0 - x, 2.0 * origin.1 - y));
+
+          if let Some(control) = control {
+            let control = offset_point(relative, origin, resolve(control));
+            paths.curve_to(reflected, control, current);
+            last_control = Some(control);
+          } else {
+            paths.quad_to(reflected, current);
+            last_control = Some(reflected);
+          }
+        }
+        ShapeCommand::Close => {
+          paths.close();
+          current = subpath_start;
+          last_control = None;
+        }
+      }
+    }
+  }
+}
+
 /// Represents a basic shape function for clip-path.
 #[derive(Debug, Clone, PartialEq)]
 pub enum BasicShape {
@@ -145,6 +341,183 @@ pub enum BasicShape {
   Polygon(PolygonShape),
   /// path() function
   Path(PathShape),
+  /// shape() function
+  Shape(ShapeFunction),
+}
+
+/// The reference box of a `clip-path`, which sizes and positions its basic shape.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryBox {
+  /// The margin box.
+  MarginBox,
+  /// The border box.
+  #[default]
+  BorderBox,
+  /// The padding box.
+  PaddingBox,
+  /// The content box.
+  ContentBox,
+  /// The object bounding box, which is the content box for boxes laid out with CSS.
+  FillBox,
+  /// The stroke bounding box, which is the border box for boxes laid out with CSS.
+  StrokeBox,
+  /// The nearest SVG viewport, which is the border box for boxes laid out with CSS.
+  ViewBox,
+}
+
+declare_enum_from_css_impl!(
+  GeometryBox,
+  "margin-box" => GeometryBox::MarginBox,
+  "border-box" => GeometryBox::BorderBox,
+  "padding-box" => GeometryBox::PaddingBox,
+  "content-box" => GeometryBox::ContentBox,
+  "fill-box" => GeometryBox::FillBox,
+  "stroke-box" => GeometryBox::StrokeBox,
+  "view-box" => GeometryBox::ViewBox
+);
+
+impl GeometryBox {
+  /// Returns the offset of the box from the border box origin, and its size.
+  pub(crate) fn resolve(self, layout: &Layout) -> (Point<f32>, Size<f32>) {
+    match self {
+      GeometryBox::MarginBox => (
+        Point {
+          x: -layout.margin.left,
+          y: -layout.margin.top,
+        },
+        Size {
+          width: layout.size.width + layout.margin.left + layout.margin.right,
+          height: layout.size.height + layout.margin.top + layout.margin.bottom,
+        },
+      ),
+      GeometryBox::BorderBox | GeometryBox::StrokeBox | GeometryBox::ViewBox => {
+        (Point::ZERO, layout.size)
+      }
+      GeometryBox::PaddingBox => (
+        Point {
+          x: layout.border.left,
+          y: layout.border.top,
+        },
+        Size {
+          width: layout.size.width - layout.border.left - layout.border.right,
+          height: layout.size.height - layout.border.top - layout.border.bottom,
+        },
+      ),
+      GeometryBox::ContentBox | GeometryBox::FillBox => (
+        Point {
+          x: layout.border.left + layout.padding.left,
+          y: layout.border.top + layout.padding.top,
+        },
+        layout.content_box_size(),
+      ),
+    }
+  }
+}
+
+/// Represents the `clip-path` property, a basic shape resolved against a reference box.
+///
+/// Either may come first, like `circle(50%) content-box` or `content-box circle(50%)`,
+/// and a reference box on its own like `content-box` clips to that box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipPath {
+  /// The shape to clip to, or `None` to clip to the reference box rounded by `border-radius`.
+  pub shape: Option<BasicShape>,
+  /// The box the shape is sized and positioned in, the border box by default.
+  pub reference_box: GeometryBox,
+}
+
+impl From<BasicShape> for ClipPath {
+  fn from(shape: BasicShape) -> Self {
+    Self {
+      shape: Some(shape),
+      reference_box: GeometryBox::default(),
+    }
+  }
+}
+
+impl<const DEFAULT_INHERIT: bool> From<BasicShape> for CssValue<Option<ClipPath>, DEFAULT_INHERIT> {
+  fn from(shape: BasicShape) -> Self {
+    CssValue::Value(Some(shape.into()))
+  }
+}
+
+impl<const DEFAULT_INHERIT: bool> From<Option<BasicShape>>
+  for CssValue<Option<ClipPath>, DEFAULT_INHERIT>
+{
+  fn from(shape: Option<BasicShape>) -> Self {
+    CssValue::Value(shape.map(Into::into))
+  }
+}
+
+impl MakeComputed for ClipPath {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    self.shape.make_computed(sizing);
+  }
+}
+
+impl ClipPath {
+  /// Whether the shape has no geometry, such a clip path doesn't clip anything.
+  pub(crate) fn is_empty(&self) -> bool {
+    self.shape.as_ref().is_some_and(BasicShape::is_empty)
+  }
+
+  pub(crate) fn render_mask(
+    &self,
+    context: &RenderContext,
+    layout: &Layout,
+    mask_memory: &mut MaskMemory,
+    buffer_pool: &mut BufferPool,
+  ) -> (Vec<u8>, Placement) {
+    let (offset, size) = self.reference_box.resolve(layout);
+
+    if let Some(shape) = &self.shape {
+      return shape.render_mask(context, offset, size, mask_memory, buffer_pool);
+    }
+
+    // The corners follow `border-radius`, grown or shrunk by the distance from the border edge.
+    let mut border = BorderProperties::from_context(context, layout.size, layout.border);
+    if !border.is_zero() {
+      border.expand_by(Rect {
+        left: -offset.x,
+        right: offset.x + size.width - layout.size.width,
+        top: -offset.y,
+        bottom: offset.y + size.height - layout.size.height,
+      });
+    }
+
+    let mut paths = Vec::new();
+    border.append_mask_commands(&mut paths, size, offset);
+
+    mask_memory.render(
+      &paths,
+      Some(context.transform),
+      Some(Fill::NonZero.into()),
+      buffer_pool,
+    )
+  }
+}
+
+impl<'i> FromCss<'i> for ClipPath {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let reference_box = input.try_parse(GeometryBox::from_css).ok();
+    let shape = match reference_box {
+      Some(_) => input.try_parse(BasicShape::from_css).ok(),
+      None => Some(BasicShape::from_css(input)?),
+    };
+    let reference_box = match reference_box {
+      Some(reference_box) => reference_box,
+      None => input.try_parse(GeometryBox::from_css).unwrap_or_default(),
+    };
+
+    Ok(ClipPath {
+      shape,
+      reference_box,
+    })
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    BasicShape::valid_tokens()
+  }
 }
 
 impl MakeComputed for BasicShape {
@@ -153,6 +526,7 @@ impl MakeComputed for BasicShape {
       BasicShape::Inset(shape) => shape.make_computed(sizing),
       BasicShape::Ellipse(shape) => shape.make_computed(sizing),
       BasicShape::Polygon(shape) => shape.make_computed(sizing),
+      BasicShape::Shape(shape) => shape.make_computed(sizing),
       BasicShape::Path(_) => {}
     }
   }
@@ -171,13 +545,25 @@ impl BasicShape {
     match self {
       BasicShape::Polygon(shape) => shape.fill_rule,
       BasicShape::Path(shape) => shape.fill_rule,
+      BasicShape::Shape(shape) => shape.fill_rule,
       _ => None,
     }
   }
 
+  /// Whether the shape has no geometry, such a shape doesn't clip anything.
+  pub(crate) fn is_empty(&self) -> bool {
+    match self {
+      BasicShape::Polygon(shape) => shape.coordinates.is_empty(),
+      BasicShape::Path(shape) => shape.path.trim().is_empty(),
+      _ => false,
+    }
+  }
+
+  /// Renders the shape sized to `size`, with its origin at `offset` in the border box.
   pub(crate) fn render_mask(
     &self,
     context: &RenderContext,
+    offset: Point<f32>,
     size: Size<f32>,
     mask_memory: &mut MaskMemory,
     buffer_pool: &mut BufferPool,
@@ -263,11 +649,14 @@ impl BasicShape {
       BasicShape::Path(shape) => {
         paths.extend(shape.path.as_ref().commands());
       }
+      BasicShape::Shape(shape) => {
+        shape.append_commands(&mut paths, size, &context.sizing);
+      }
     }
 
     mask_memory.render(
       &paths,
-      Some(context.transform),
+      Some(context.transform * Affine::translation(offset.x, offset.y)),
       Some(Fill::from(self.fill_rule().unwrap_or(context.style.clip_rule)).into()),
       buffer_pool,
     )
@@ -389,6 +778,14 @@ impl<'i> FromCss<'i> for BasicShape {
 
             let mut coordinates = Vec::new();
 
+            // An empty polygon() is allowed, and doesn't clip anything
+            if fill_rule.is_none() && input.is_exhausted() {
+              return Ok(BasicShape::Polygon(PolygonShape {
+                fill_rule,
+                coordinates: coordinates.into_boxed_slice(),
+              }));
+            }
+
             // Parse first coordinate pair
             coordinates.push(PolygonCoordinate::from_css(input)?);
 
@@ -415,6 +812,24 @@ impl<'i> FromCss<'i> for BasicShape {
               path,
             }))
           }),
+          "shape" => parser.parse_nested_block(|input| {
+            let fill_rule = input.try_parse(FillRule::from_css).ok();
+
+            input.expect_ident_matching("from")?;
+            let from = PolygonCoordinate::from_css(input)?;
+
+            let mut commands = Vec::new();
+
+            while input.try_parse(Parser::expect_comma).is_ok() {
+              commands.push(ShapeCommand::from_css(input)?);
+            }
+
+            Ok(BasicShape::Shape(ShapeFunction {
+              fill_rule,
+              from,
+              commands: commands.into_boxed_slice(),
+            }))
+          }),
           _ => Err(Self::unexpected_token_error(location, token)),
         }
       }
@@ -429,6 +844,76 @@ impl<'i> FromCss<'i> for BasicShape {
       CssToken::Token("ellipse()"),
       CssToken::Token("polygon()"),
       CssToken::Token("path()"),
+      CssToken::Token("shape()"),
+    ]
+  }
+}
+
+/// Parses the `by` or `to` keyword of a shape() command, returning whether it is relative.
+fn parse_command_origin<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, bool> {
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?.clone();
+
+  match_ignore_ascii_case! { &ident,
+    "by" => Ok(true),
+    "to" => Ok(false),
+    _ => Err(ShapeCommand::unexpected_token_error(location, &Token::Ident(ident))),
+  }
+}
+
+impl<'i> FromCss<'i> for ShapeCommand {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?.clone();
+
+    if ident.eq_ignore_ascii_case("close") {
+      return Ok(ShapeCommand::Close);
+    }
+
+    let relative = parse_command_origin(input)?;
+
+    match_ignore_ascii_case! { &ident,
+      "move" => Ok(ShapeCommand::Move { relative, to: PolygonCoordinate::from_css(input)? }),
+      "line" => Ok(ShapeCommand::Line { relative, to: PolygonCoordinate::from_css(input)? }),
+      "hline" => Ok(ShapeCommand::HLine { relative, x: Length::from_css(input)? }),
+      "vline" => Ok(ShapeCommand::VLine { relative, y: Length::from_css(input)? }),
+      "curve" => {
+        let to = PolygonCoordinate::from_css(input)?;
+        input.expect_ident_matching("with")?;
+        let control = PolygonCoordinate::from_css(input)?;
+        let control_2 = input
+          .try_parse(|input| {
+            input.expect_delim('/')?;
+            PolygonCoordinate::from_css(input)
+          })
+          .ok();
+
+        Ok(ShapeCommand::Curve { relative, to, control, control_2 })
+      },
+      "smooth" => {
+        let to = PolygonCoordinate::from_css(input)?;
+        let control = input
+          .try_parse(|input| {
+            input.expect_ident_matching("with")?;
+            PolygonCoordinate::from_css(input)
+          })
+          .ok();
+
+        Ok(ShapeCommand::Smooth { relative, to, control })
+      },
+      _ => Err(Self::unexpected_token_error(location, &Token::Ident(ident))),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("move"),
+      CssToken::Keyword("line"),
+      CssToken::Keyword("hline"),
+      CssToken::Keyword("vline"),
+      CssToken::Keyword("curve"),
+      CssToken::Keyword("smooth"),
+      CssToken::Keyword("close"),
     ]
   }
 }
@@ -551,6 +1036,36 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_clip_path_reference_box() {
+    let Ok(circle) = BasicShape::from_str("circle(50%)") else {
+      unreachable!()
+    };
+
+    assert_eq!(
+      ClipPath::from_str("circle(50%) content-box"),
+      Ok(ClipPath {
+        shape: Some(circle.clone()),
+        reference_box: GeometryBox::ContentBox,
+      })
+    );
+    assert_eq!(
+      ClipPath::from_str("margin-box circle(50%)"),
+      Ok(ClipPath {
+        shape: Some(circle.clone()),
+        reference_box: GeometryBox::MarginBox,
+      })
+    );
+    assert_eq!(ClipPath::from_str("circle(50%)"), Ok(circle.into()));
+    assert_eq!(
+      ClipPath::from_str("content-box"),
+      Ok(ClipPath {
+        shape: None,
+        reference_box: GeometryBox::ContentBox,
+      })
+    );
+  }
+
   #[test]
   fn test_parse_polygon_triangle() {
     assert!(matches!(
@@ -633,4 +1148,94 @@ mod tests {
       })))
     );
   }
+
+  #[test]
+  fn test_parse_empty_polygon() {
+    let shape = BasicShape::from_str("polygon()");
+
+    assert_eq!(
+      shape,
+      Ok(BasicShape::Polygon(PolygonShape {
+        fill_rule: None,
+        coordinates: Box::new([]),
+      }))
+    );
+    assert!(shape.as_ref().is_ok_and(BasicShape::is_empty));
+  }
+
+  #[test]
+  fn test_parse_shape() {
+    assert_eq!(
+      BasicShape::from_str(
+        "shape(evenodd from 0 0, line to 100% 0, hline by -20px, curve to 50% 100% with 100% 100% / 80% 50%, smooth by 10px 0, close)"
+      ),
+      Ok(BasicShape::Shape(ShapeFunction {
+        fill_rule: Some(FillRule::EvenOdd),
+        from: SpacePair::from_single(Px(0.0)),
+        commands: Box::new([
+          ShapeCommand::Line {
+            relative: false,
+            to: SpacePair::from_pair(Percentage(100.0), Px(0.0)),
+          },
+          ShapeCommand::HLine {
+            relative: true,
+            x: Px(-20.0),
+          },
+          ShapeCommand::Curve {
+            relative: false,
+            to: SpacePair::from_pair(Percentage(50.0), Percentage(100.0)),
+            control: SpacePair::from_single(Percentage(100.0)),
+            control_2: Some(SpacePair::from_pair(Percentage(80.0), Percentage(50.0))),
+          },
+          ShapeCommand::Smooth {
+            relative: true,
+            to: SpacePair::from_pair(Px(10.0), Px(0.0)),
+            control: None,
+          },
+          ShapeCommand::Close,
+        ]),
+      }))
+    );
+  }
+
+  #[test]
+  fn test_parse_shape_requires_from() {
+    assert!(BasicShape::from_str("shape(line to 10px 10px)").is_err());
+    assert!(BasicShape::from_str("shape(from 0 0, line 10px 10px)").is_err());
+  }
+
+  #[test]
+  fn test_polygon_percentages_resolve_against_their_axis() {
+    use crate::GlobalContext;
+
+    let global = GlobalContext::default();
+    let context = RenderContext::new(&global, (400, 200).into(), Default::default());
+    let mut mask_memory = MaskMemory::default();
+    let mut buffer_pool = BufferPool::default();
+
+    // A rectangle covering the right half horizontally and the bottom half vertically.
+    let shape = BasicShape::from_str("polygon(50% 50%, 100% 50%, 100% 100%, 50% 100%)");
+
+    let placement = shape.map(|shape| {
+      shape
+        .render_mask(
+          &context,
+          Point::ZERO,
+          Size {
+            width: 400.0,
+            height: 200.0,
+          },
+          &mut mask_memory,
+          &mut buffer_pool,
+        )
+        .1
+    });
+
+    assert!(placement.is_ok_and(|placement| {
+      placement.left == 200
+        && placement.top == 100
+        && (200..=201).contains(&placement.width)
+        && (100..=101).contains(&placement.height)
+    }));
+  }
 }
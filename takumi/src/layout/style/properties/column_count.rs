@@ -0,0 +1,63 @@
+use cssparser::{Parser, Token};
+
+use crate::layout::style::{CssToken, FromCss, MakeComputed, ParseResult};
+
+/// Parsed `column-count` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnCount {
+  /// The content isn't split into columns.
+  #[default]
+  Auto,
+  /// The content flows into the given number of columns.
+  Count(u32),
+}
+
+impl ColumnCount {
+  /// Returns the number of columns, if the content is split into more than one.
+  pub(crate) fn multiple(self) -> Option<usize> {
+    match self {
+      ColumnCount::Count(count) if count > 1 => Some(count as usize),
+      _ => None,
+    }
+  }
+}
+
+impl MakeComputed for ColumnCount {}
+
+impl<'i> FromCss<'i> for ColumnCount {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if input
+      .try_parse(|input| input.expect_ident_matching("auto"))
+      .is_ok()
+    {
+      return Ok(ColumnCount::Auto);
+    }
+
+    let location = input.current_source_location();
+    let token = input.next()?;
+
+    match *token {
+      Token::Number {
+        int_value: Some(count),
+        ..
+      } if count >= 1 => Ok(ColumnCount::Count(count as u32)),
+      _ => Err(Self::unexpected_token_error(location, token)),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Keyword("auto"), CssToken::Token("integer")]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_column_count() {
+    assert_eq!(ColumnCount::from_str("auto"), Ok(ColumnCount::Auto));
+    assert_eq!(ColumnCount::from_str("3"), Ok(ColumnCount::Count(3)));
+    assert!(ColumnCount::from_str("0").is_err());
+  }
+}
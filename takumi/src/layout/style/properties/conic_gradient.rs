@@ -8,8 +8,8 @@ use super::gradient_utils::{
 };
 use crate::{
   layout::style::{
-    Angle, BackgroundPosition, CssToken, FromCss, GradientStop, GradientStops, Length,
-    MakeComputed, ParseResult,
+    Angle, BackgroundPosition, CssToken, FromCss, GradientStop, GradientStops, MakeComputed,
+    ParseResult,
   },
   rendering::{RenderContext, Sizing},
 };
@@ -99,8 +99,8 @@ impl ConicGradientTile {
     context: &RenderContext,
     buffer_pool: &mut crate::rendering::BufferPool,
   ) -> Self {
-    let cx = Length::from(gradient.center.0.x).to_px(&context.sizing, width as f32);
-    let cy = Length::from(gradient.center.0.y).to_px(&context.sizing, height as f32);
+    let cx = gradient.center.0.x.to_px(&context.sizing, width as f32);
+    let cy = gradient.center.0.y.to_px(&context.sizing, height as f32);
 
     let start_rad = gradient.from_angle.to_radians();
 
@@ -0,0 +1,283 @@
+use std::borrow::Cow;
+
+use cssparser::{Parser, Token, match_ignore_ascii_case};
+
+use crate::layout::style::{CssToken, FromCss, ListStyleType, MakeComputed, ParseResult};
+
+/// A part of the [`Content`] property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentItem {
+  /// A literal string.
+  String(String),
+  /// The value of the innermost counter with the name, formatted with the list style type,
+  /// set by `counter(name)` or `counter(name, style)`.
+  Counter(String, ListStyleType),
+}
+
+/// Represents the `content` property, a string rendered as the node's sole inline content.
+///
+/// It replaces the children of the node, so small labels don't need a separate text node.
+/// Consecutive strings and counters are concatenated, like `content: "Step " counter(step)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Content(pub Box<[ContentItem]>);
+
+impl Content {
+  /// Returns the generated text. Counters are resolved to strings when the render tree is built.
+  pub fn text(&self) -> Cow<'_, str> {
+    match self.0.as_ref() {
+      [ContentItem::String(string)] => Cow::Borrowed(string),
+      items => Cow::Owned(
+        items
+          .iter()
+          .filter_map(|item| match item {
+            ContentItem::String(string) => Some(string.as_str()),
+            ContentItem::Counter(..) => None,
+          })
+          .collect(),
+      ),
+    }
+  }
+
+  /// Resolves the counters against the counters in scope, leaving only the generated text.
+  pub(crate) fn resolve(&self, counters: &Counters) -> Self {
+    let text = self
+      .0
+      .iter()
+      .map(|item| match item {
+        ContentItem::String(string) => Cow::Borrowed(string.as_str()),
+        ContentItem::Counter(name, style) => style.counter_text(counters.value(name)),
+      })
+      .collect();
+
+    Content([ContentItem::String(text)].into())
+  }
+}
+
+impl MakeComputed for Content {}
+
+impl<'i> FromCss<'i> for Content {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let mut items = vec![ContentItem::from_css(input)?];
+
+    while let Ok(item) = input.try_parse(ContentItem::from_css) {
+      items.push(item);
+    }
+
+    Ok(Content(items.into_boxed_slice()))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    ContentItem::valid_tokens()
+  }
+}
+
+impl<'i> FromCss<'i> for ContentItem {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let token = input.next()?;
+
+    match token {
+      Token::QuotedString(string) => Ok(ContentItem::String(string.to_string())),
+      Token::Function(function) if function.eq_ignore_ascii_case("counter") => input
+        .parse_nested_block(|input| {
+          let name = input.expect_ident()?.to_string();
+
+          let style = if input.try_parse(Parser::expect_comma).is_ok() {
+            ListStyleType::from_css(input)?
+          } else {
+            ListStyleType::Decimal
+          };
+
+          Ok(ContentItem::Counter(name, style))
+        }),
+      token => Err(Self::unexpected_token_error(location, token)),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("string"), CssToken::Token("counter()")]
+  }
+}
+
+/// A list of counter names with their values, used by `counter-reset` and `counter-increment`.
+///
+/// A name without a value uses `DEFAULT_VALUE`, which is 0 for resets and 1 for increments.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CounterList<const DEFAULT_VALUE: i32>(pub Box<[(String, i32)]>);
+
+/// The `counter-reset` property, creating counters for the node, its following siblings and their descendants.
+pub type CounterReset = CounterList<0>;
+
+/// The `counter-increment` property, adding to the innermost counters with the names.
+pub type CounterIncrement = CounterList<1>;
+
+impl<const DEFAULT_VALUE: i32> MakeComputed for CounterList<DEFAULT_VALUE> {}
+
+impl<'i, const DEFAULT_VALUE: i32> FromCss<'i> for CounterList<DEFAULT_VALUE> {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if input
+      .try_parse(|input| input.expect_ident_matching("none"))
+      .is_ok()
+    {
+      return Ok(CounterList(Box::default()));
+    }
+
+    let mut counters = Vec::new();
+
+    loop {
+      let location = input.current_source_location();
+      let name = input.expect_ident()?.clone();
+
+      match_ignore_ascii_case! { &name,
+        "none" | "initial" | "inherit" | "unset" | "default" => {
+          return Err(location.new_custom_error(Self::expect_message()));
+        },
+        _ => {}
+      }
+
+      let value = input
+        .try_parse(Parser::expect_integer)
+        .unwrap_or(DEFAULT_VALUE);
+
+      counters.push((name.to_string(), value));
+
+      if input.is_exhausted() {
+        break;
+      }
+    }
+
+    Ok(CounterList(counters.into_boxed_slice()))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Keyword("none"), CssToken::Token("custom-ident")]
+  }
+}
+
+/// The counters in scope while building the render tree, from outermost to innermost.
+#[derive(Debug, Default)]
+pub(crate) struct Counters(Vec<(String, i32)>);
+
+impl Counters {
+  /// The number of counters in scope, to leave the scope of a parent with [`Self::truncate`].
+  pub(crate) fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Drops the counters created after the scope of length `len` was entered.
+  pub(crate) fn truncate(&mut self, len: usize) {
+    self.0.truncate(len);
+  }
+
+  /// Applies `counter-reset` then `counter-increment` of a node.
+  pub(crate) fn apply(
+    &mut self,
+    reset: Option<&CounterReset>,
+    increment: Option<&CounterIncrement>,
+  ) {
+    for (name, value) in reset.iter().flat_map(|reset| reset.0.iter()) {
+      self.0.push((name.clone(), *value));
+    }
+
+    for (name, value) in increment.iter().flat_map(|increment| increment.0.iter()) {
+      match self.innermost_mut(name) {
+        Some(counter) => *counter = counter.saturating_add(*value),
+        // Incrementing a counter that isn't in scope creates it, like `counter-reset` would.
+        None => self.0.push((name.clone(), *value)),
+      }
+    }
+  }
+
+  /// The value of the innermost counter with the name, or 0 if there is none.
+  pub(crate) fn value(&self, name: &str) -> i32 {
+    self
+      .0
+      .iter()
+      .rev()
+      .find(|(counter, _)| counter == name)
+      .map_or(0, |(_, value)| *value)
+  }
+
+  fn innermost_mut(&mut self, name: &str) -> Option<&mut i32> {
+    self
+      .0
+      .iter_mut()
+      .rev()
+      .find(|(counter, _)| counter == name)
+      .map(|(_, value)| value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_content() {
+    assert_eq!(
+      Content::from_str("\"×\""),
+      Ok(Content([ContentItem::String("×".to_string())].into()))
+    );
+    assert_eq!(
+      Content::from_str("\"New\" \" !\"").map(|content| content.text().into_owned()),
+      Ok("New !".to_string())
+    );
+    assert!(Content::from_str("close").is_err());
+  }
+
+  #[test]
+  fn test_parse_content_counter() {
+    assert_eq!(
+      Content::from_str("\"Step \" counter(step) counter(dot, disc)"),
+      Ok(Content(
+        [
+          ContentItem::String("Step ".to_string()),
+          ContentItem::Counter("step".to_string(), ListStyleType::Decimal),
+          ContentItem::Counter("dot".to_string(), ListStyleType::Disc),
+        ]
+        .into()
+      ))
+    );
+  }
+
+  #[test]
+  fn test_parse_counter_list() {
+    assert_eq!(
+      CounterIncrement::from_str("step"),
+      Ok(CounterList([("step".to_string(), 1)].into()))
+    );
+    assert_eq!(
+      CounterReset::from_str("step section 5"),
+      Ok(CounterList(
+        [("step".to_string(), 0), ("section".to_string(), 5)].into()
+      ))
+    );
+    assert_eq!(CounterReset::from_str("none"), Ok(CounterList::default()));
+    assert!(CounterReset::from_str("step none").is_err());
+  }
+
+  #[test]
+  fn test_counters_resolve_innermost_scope() {
+    let content = Content(
+      [
+        ContentItem::Counter("step".to_string(), ListStyleType::Decimal),
+        ContentItem::String(".".to_string()),
+        ContentItem::Counter("missing".to_string(), ListStyleType::Decimal),
+      ]
+      .into(),
+    );
+    let reset = CounterList([("step".to_string(), 2)].into());
+    let increment = CounterList([("step".to_string(), 1)].into());
+
+    let mut counters = Counters::default();
+    counters.apply(Some(&reset), Some(&increment));
+    assert_eq!(content.resolve(&counters).text(), "3.0");
+
+    let outer = counters.len();
+    counters.apply(Some(&CounterList([("step".to_string(), 0)].into())), None);
+    assert_eq!(content.resolve(&counters).text(), "0.0");
+
+    counters.truncate(outer);
+    assert_eq!(content.resolve(&counters).text(), "3.0");
+  }
+}
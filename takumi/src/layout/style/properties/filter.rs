@@ -1,13 +1,15 @@
+use std::{collections::HashMap, sync::Arc};
+
 use cssparser::{Parser, Token, match_ignore_ascii_case};
-use image::{Rgba, RgbaImage, imageops::colorops::huerotate_in_place};
+use image::{Rgba, RgbaImage};
 use smallvec::SmallVec;
 use taffy::{Point, Size};
 
 use crate::{
   Result,
   layout::style::{
-    Affine, Angle, BlendMode, Color, CssToken, FromCss, Length, MakeComputed, ParseResult,
-    PercentageNumber, TextShadow, tw::TailwindPropertyParser,
+    Affine, Angle, BlendMode, Color, ColorInput, CssToken, FromCss, Length, MakeComputed,
+    ParseResult, PercentageNumber, TextShadow, tw::TailwindPropertyParser,
   },
   rendering::{
     BlurFormat, BlurType, BorderProperties, BufferPool, Canvas, RenderContext, SizedShadow, Sizing,
@@ -27,17 +29,18 @@ pub(crate) fn build_brightness_table(value: f32) -> TransferTable {
   table
 }
 
-/// Builds a LUT for the Contrast filter.
+/// Builds a LUT for the Contrast filter, scaling the distance from the middle gray.
 pub(crate) fn build_contrast_table(value: f32) -> TransferTable {
   let mut table = [0u8; 256];
   for (i, entry) in table.iter_mut().enumerate() {
-    *entry = ((i as f32 - 128.0) * value + 128.0).clamp(0.0, 255.0) as u8;
+    *entry = ((i as f32 - 127.5) * value + 127.5).clamp(0.0, 255.0) as u8;
   }
   table
 }
 
-/// Builds a LUT for the Invert filter.
+/// Builds a LUT for the Invert filter, amounts above 1 are clamped.
 pub(crate) fn build_invert_table(amount: f32) -> TransferTable {
+  let amount = amount.clamp(0.0, 1.0);
   let mut table = [0u8; 256];
   for (i, entry) in table.iter_mut().enumerate() {
     let inverted = 255 - i as u8;
@@ -46,8 +49,9 @@ pub(crate) fn build_invert_table(amount: f32) -> TransferTable {
   table
 }
 
-/// Builds a LUT for the Opacity filter (applied to alpha channel).
+/// Builds a LUT for the Opacity filter (applied to alpha channel), values above 1 are clamped.
 pub(crate) fn build_opacity_table(value: f32) -> TransferTable {
+  let value = value.clamp(0.0, 1.0);
   let mut table = [0u8; 256];
   for (i, entry) in table.iter_mut().enumerate() {
     *entry = (i as f32 * value).clamp(0.0, 255.0) as u8;
@@ -55,8 +59,98 @@ pub(crate) fn build_opacity_table(value: f32) -> TransferTable {
   table
 }
 
+/// A 3x3 matrix applied to the straight RGB channels, rows producing red, green and blue.
+pub(crate) type ColorMatrix = [[f32; 3]; 3];
+
+/// Builds the `grayscale()` matrix from the Filter Effects spec, amounts above 1 are clamped.
+pub(crate) fn build_grayscale_matrix(amount: f32) -> ColorMatrix {
+  let a = 1.0 - amount.clamp(0.0, 1.0);
+
+  [
+    [
+      0.2126 + 0.7874 * a,
+      0.7152 - 0.7152 * a,
+      0.0722 - 0.0722 * a,
+    ],
+    [
+      0.2126 - 0.2126 * a,
+      0.7152 + 0.2848 * a,
+      0.0722 - 0.0722 * a,
+    ],
+    [
+      0.2126 - 0.2126 * a,
+      0.7152 - 0.7152 * a,
+      0.0722 + 0.9278 * a,
+    ],
+  ]
+}
+
+/// Builds the `sepia()` matrix from the Filter Effects spec, amounts above 1 are clamped.
+pub(crate) fn build_sepia_matrix(amount: f32) -> ColorMatrix {
+  let a = 1.0 - amount.clamp(0.0, 1.0);
+
+  [
+    [0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a],
+    [0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a],
+    [0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a],
+  ]
+}
+
+/// Builds the `saturate()` matrix from the Filter Effects spec, values above 1 oversaturate.
+pub(crate) fn build_saturate_matrix(value: f32) -> ColorMatrix {
+  let s = value.max(0.0);
+
+  [
+    [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+    [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+    [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+  ]
+}
+
+/// Builds the `hue-rotate()` matrix from the Filter Effects spec, which keeps the luminance.
+pub(crate) fn build_hue_rotate_matrix(degrees: f32) -> ColorMatrix {
+  let (sin, cos) = degrees.to_radians().sin_cos();
+
+  [
+    [
+      0.213 + cos * 0.787 - sin * 0.213,
+      0.715 - cos * 0.715 - sin * 0.715,
+      0.072 - cos * 0.072 + sin * 0.928,
+    ],
+    [
+      0.213 - cos * 0.213 + sin * 0.143,
+      0.715 + cos * 0.285 + sin * 0.140,
+      0.072 - cos * 0.072 - sin * 0.283,
+    ],
+    [
+      0.213 - cos * 0.213 - sin * 0.787,
+      0.715 - cos * 0.715 + sin * 0.715,
+      0.072 + cos * 0.928 + sin * 0.072,
+    ],
+  ]
+}
+
+/// A filter registered on the [`GlobalContext`](crate::GlobalContext), referenced by
+/// `filter: url(#name)`.
+pub trait CustomFilter: Send + Sync {
+  /// Applies the filter in place to the rendered node, with straight (not premultiplied) alpha.
+  fn apply(&self, image: &mut RgbaImage);
+}
+
+impl<F> CustomFilter for F
+where
+  F: Fn(&mut RgbaImage) + Send + Sync,
+{
+  fn apply(&self, image: &mut RgbaImage) {
+    self(image)
+  }
+}
+
+/// Custom filters registered by name.
+pub type CustomFilters = HashMap<String, Box<dyn CustomFilter>>;
+
 /// Represents a single CSS filter operation
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Filter {
   /// Brightness multiplier (1 = unchanged). Accepts number or percentage
   Brightness(PercentageNumber),
@@ -78,6 +172,11 @@ pub enum Filter {
   Blur(Length),
   /// Drop shadow effect with offset, blur, and color (reuses TextShadow parsing)
   DropShadow(TextShadow),
+  /// Multiplies the color channels by the tint color while keeping the source alpha
+  Tint(ColorInput),
+  /// A custom filter referenced by `url(#name)`, holding the name without the `#`.
+  /// Names that are not registered are skipped.
+  Url(Arc<str>),
 }
 
 /// A list of filter operations
@@ -102,13 +201,24 @@ impl TailwindPropertyParser for Filters {
 impl Filter {
   pub(crate) fn categorize(&self) -> FilterCategory<'_> {
     match self {
-      Filter::Blur(_) | Filter::DropShadow(_) | Filter::HueRotate(_) => {
+      Filter::Blur(_) | Filter::DropShadow(_) | Filter::Tint(_) | Filter::Url(_) => {
         FilterCategory::Complex(self)
       }
       _ => FilterCategory::Pixel(self),
     }
   }
 
+  /// Returns the color matrix of filters that mix the RGB channels.
+  pub(crate) fn color_matrix(&self) -> Option<ColorMatrix> {
+    match *self {
+      Filter::Grayscale(PercentageNumber(amount)) => Some(build_grayscale_matrix(amount)),
+      Filter::Sepia(PercentageNumber(amount)) => Some(build_sepia_matrix(amount)),
+      Filter::Saturate(PercentageNumber(value)) => Some(build_saturate_matrix(value)),
+      Filter::HueRotate(angle) => Some(build_hue_rotate_matrix(*angle)),
+      _ => None,
+    }
+  }
+
   /// Returns a LUT if this filter is a simple 1D channel transfer.
   /// Returns (RGB_LUT, Alpha_LUT).
   pub(crate) fn transfer_tables(&self) -> (Option<TransferTable>, Option<TransferTable>) {
@@ -126,74 +236,40 @@ impl Filter {
 pub(crate) enum FilterCategory<'f> {
   /// Pixel filters that can potentially be batched
   Pixel(&'f Filter),
-  /// Complex filters that need special handling (blur, drop-shadow, hue-rotate)
+  /// Complex filters that need special handling (blur, drop-shadow, tint, url)
   Complex(&'f Filter),
 }
 
-/// Calculates the luma of an RGB pixel.
+/// Applies a color matrix to the RGB channels of a straight alpha pixel.
 #[inline(always)]
-fn get_luma(pixel: &[u8]) -> f32 {
-  pixel[0] as f32 * 0.2126 + pixel[1] as f32 * 0.7152 + pixel[2] as f32 * 0.0722
+fn apply_color_matrix(pixel: &mut [u8; 4], matrix: &ColorMatrix) {
+  let [r, g, b, _] = pixel.map(f32::from);
+
+  for (channel, row) in pixel.iter_mut().zip(matrix) {
+    *channel = (row[0] * r + row[1] * g + row[2] * b)
+      .round()
+      .clamp(0.0, 255.0) as u8;
+  }
 }
 
-/// Applies a single pixel filter inline - used for single filter optimization
-#[inline(always)]
-fn apply_single_pixel_filter(pixel: &mut [u8], filter: &Filter) {
-  match *filter {
-    Filter::Brightness(PercentageNumber(value)) => {
-      for channel in pixel.iter_mut().take(3) {
-        *channel = ((*channel) as f32 * value).clamp(0.0, 255.0) as u8;
-      }
-    }
-    Filter::Contrast(PercentageNumber(value)) => {
-      for channel in pixel.iter_mut().take(3) {
-        *channel = ((*channel as f32 - 128.0) * value + 128.0).clamp(0.0, 255.0) as u8;
-      }
-    }
-    Filter::Grayscale(PercentageNumber(amount)) => {
-      let lum = get_luma(pixel);
-      for channel in pixel.iter_mut().take(3) {
-        *channel = ((*channel as f32 * (1.0 - amount)) + (lum * amount)).clamp(0.0, 255.0) as u8;
-      }
-    }
-    Filter::Saturate(PercentageNumber(value)) => {
-      let lum = get_luma(pixel);
-      for channel in pixel.iter_mut().take(3) {
-        *channel = (lum * (1.0 - value) + *channel as f32 * value).clamp(0.0, 255.0) as u8;
-      }
-    }
-    Filter::Invert(PercentageNumber(amount)) => {
-      for channel in pixel.iter_mut().take(3) {
-        let inverted = u8::MAX.saturating_sub(*channel);
-        *channel =
-          ((*channel as f32 * (1.0 - amount)) + (inverted as f32 * amount)).clamp(0.0, 255.0) as u8;
-      }
-    }
-    Filter::Sepia(PercentageNumber(amount)) => {
-      // Sepia tone matrix coefficients
-      let r = pixel[0] as f32;
-      let g = pixel[1] as f32;
-      let b = pixel[2] as f32;
-
-      let sepia_r = (r * 0.393 + g * 0.769 + b * 0.189).clamp(0.0, 255.0);
-      let sepia_g = (r * 0.349 + g * 0.686 + b * 0.168).clamp(0.0, 255.0);
-      let sepia_b = (r * 0.272 + g * 0.534 + b * 0.131).clamp(0.0, 255.0);
-
-      pixel[0] = (r * (1.0 - amount) + sepia_r * amount).clamp(0.0, 255.0) as u8;
-      pixel[1] = (g * (1.0 - amount) + sepia_g * amount).clamp(0.0, 255.0) as u8;
-      pixel[2] = (b * (1.0 - amount) + sepia_b * amount).clamp(0.0, 255.0) as u8;
-    }
-    Filter::Opacity(PercentageNumber(value)) => {
-      pixel[3] = ((pixel[3]) as f32 * value).clamp(0.0, 255.0) as u8;
+/// Multiplies the color channels of every pixel by the tint color, leaving alpha untouched.
+fn apply_tint_filter(image: &mut RgbaImage, tint: Color) {
+  let [r, g, b, _] = tint.0;
+
+  for pixel in bytemuck::cast_slice_mut::<u8, [u8; 4]>(image.as_mut()) {
+    if pixel[3] == 0 {
+      continue;
     }
-    // Complex filters are not handled here
-    Filter::Blur(_) | Filter::DropShadow(_) | Filter::HueRotate(_) => {}
+
+    pixel[0] = fast_div_255(pixel[0] as u32 * r as u32);
+    pixel[1] = fast_div_255(pixel[1] as u32 * g as u32);
+    pixel[2] = fast_div_255(pixel[2] as u32 * b as u32);
   }
 }
 
 /// Filter prepared for batch execution
-enum PreparedFilter<'a> {
-  Matrix(&'a Filter),
+enum PreparedFilter {
+  Matrix(Box<ColorMatrix>),
   RgbLut(Box<TransferTable>),
   AlphaLut(Box<TransferTable>),
   BothLut(Box<TransferTable>, Box<TransferTable>),
@@ -208,11 +284,13 @@ fn apply_batched_pixel_filters(image: &mut RgbaImage, filters: &[&Filter]) {
   // Pre-calculate LUTs and categorize filters
   let prepared: SmallVec<[PreparedFilter; 4]> = filters
     .iter()
-    .map(|&f| match f.transfer_tables() {
-      (Some(rgb), Some(alpha)) => PreparedFilter::BothLut(Box::new(rgb), Box::new(alpha)),
-      (Some(rgb), None) => PreparedFilter::RgbLut(Box::new(rgb)),
-      (None, Some(alpha)) => PreparedFilter::AlphaLut(Box::new(alpha)),
-      (None, None) => PreparedFilter::Matrix(f),
+    .filter_map(|&f| match f.transfer_tables() {
+      (Some(rgb), Some(alpha)) => Some(PreparedFilter::BothLut(Box::new(rgb), Box::new(alpha))),
+      (Some(rgb), None) => Some(PreparedFilter::RgbLut(Box::new(rgb))),
+      (None, Some(alpha)) => Some(PreparedFilter::AlphaLut(Box::new(alpha))),
+      (None, None) => f
+        .color_matrix()
+        .map(|matrix| PreparedFilter::Matrix(Box::new(matrix))),
     })
     .collect();
 
@@ -223,7 +301,7 @@ fn apply_batched_pixel_filters(image: &mut RgbaImage, filters: &[&Filter]) {
 
     for p in &prepared {
       match p {
-        PreparedFilter::Matrix(f) => apply_single_pixel_filter(pixel, f),
+        PreparedFilter::Matrix(matrix) => apply_color_matrix(pixel, matrix),
         PreparedFilter::RgbLut(t) => {
           pixel[0] = t[pixel[0] as usize];
           pixel[1] = t[pixel[1] as usize];
@@ -248,6 +326,7 @@ pub(crate) fn apply_filters<'f, F: Iterator<Item = &'f Filter>>(
   sizing: &Sizing,
   current_color: Color,
   buffer_pool: &mut BufferPool,
+  custom_filters: &CustomFilters,
   filters: F,
 ) -> Result<()> {
   // Collect filters and batch consecutive pixel filters
@@ -268,9 +347,6 @@ pub(crate) fn apply_filters<'f, F: Iterator<Item = &'f Filter>>(
 
         // Apply complex filter
         match *f {
-          Filter::HueRotate(angle) => {
-            huerotate_in_place(image, *angle as i32);
-          }
           Filter::Blur(blur) => {
             apply_blur(
               BlurFormat::Rgba(image),
@@ -287,6 +363,14 @@ pub(crate) fn apply_filters<'f, F: Iterator<Item = &'f Filter>>(
             let shadow = SizedShadow::from_text_shadow(drop_shadow, sizing, current_color, size);
             apply_drop_shadow_filter(image, &shadow, buffer_pool)?;
           }
+          Filter::Tint(color) => {
+            apply_tint_filter(image, color.resolve(current_color));
+          }
+          Filter::Url(ref name) => {
+            if let Some(custom_filter) = custom_filters.get(&**name) {
+              custom_filter.apply(image);
+            }
+          }
           _ => unreachable!(),
         }
       }
@@ -382,6 +466,7 @@ pub(crate) fn apply_backdrop_filter(
     &context.sizing,
     context.current_color,
     &mut canvas.buffer_pool,
+    &context.global.custom_filters,
     drop_shadow_filtered,
   )?;
 
@@ -548,6 +633,10 @@ impl<'i> FromCss<'i> for Filters {
 
 impl<'i> FromCss<'i> for Filter {
   fn from_css(parser: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if let Ok(url) = parser.try_parse(Parser::expect_url) {
+      return Ok(Filter::Url(url.strip_prefix('#').unwrap_or(&url).into()));
+    }
+
     let location = parser.current_source_location();
     let token = parser.next()?;
 
@@ -595,6 +684,9 @@ impl<'i> FromCss<'i> for Filter {
         // drop-shadow uses the same syntax as text-shadow
         Ok(Filter::DropShadow(TextShadow::from_css(input)?))
       }),
+      "tint" => parser.parse_nested_block(|input| {
+        Ok(Filter::Tint(ColorInput::from_css(input)?))
+      }),
       _ => Err(Self::unexpected_token_error(location, token)),
     }
   }
@@ -611,6 +703,8 @@ impl<'i> FromCss<'i> for Filter {
       CssToken::Token("sepia()"),
       CssToken::Token("blur()"),
       CssToken::Token("drop-shadow()"),
+      CssToken::Token("tint()"),
+      CssToken::Token("url()"),
     ]
   }
 }
@@ -626,6 +720,7 @@ mod tests {
       Viewport,
       style::{CalcArena, Color, ColorInput, Length::Px},
     },
+    rendering::FontMetrics,
   };
 
   #[test]
@@ -677,6 +772,132 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_tint_filter() {
+    assert_eq!(
+      Filter::from_str("tint(red)"),
+      Ok(Filter::Tint(ColorInput::Value(Color([255, 0, 0, 255]))))
+    );
+  }
+
+  #[test]
+  fn test_parse_url_filter() {
+    assert_eq!(
+      Filter::from_str("url(#duotone)"),
+      Ok(Filter::Url("duotone".into()))
+    );
+    assert_eq!(
+      Filter::from_str("url(\"#duotone\")"),
+      Ok(Filter::Url("duotone".into()))
+    );
+  }
+
+  #[test]
+  fn test_apply_tint_filter_keeps_alpha() -> Result<()> {
+    let mut image = RgbaImage::new(2, 1);
+    image.put_pixel(0, 0, Rgba([255, 255, 255, 255]));
+    image.put_pixel(1, 0, Rgba([128, 128, 128, 64]));
+
+    let viewport = Viewport::new(Some(100), Some(100));
+    let sizing = Sizing {
+      viewport,
+      font_size: 16.0,
+      font_metrics: FontMetrics::default(),
+      calc_arena: Rc::new(CalcArena::default()),
+    };
+    let mut buffer_pool = BufferPool::default();
+    apply_filters(
+      &mut image,
+      &sizing,
+      Color::black(),
+      &mut buffer_pool,
+      &CustomFilters::default(),
+      [Filter::Tint(ColorInput::Value(Color([225, 29, 72, 255])))].iter(),
+    )?;
+
+    assert_eq!(image.get_pixel(0, 0).0, [225, 29, 72, 255]);
+    assert_eq!(image.get_pixel(1, 0).0[3], 64);
+
+    Ok(())
+  }
+
+  fn blur_test_sizing(device_pixel_ratio: f32) -> Sizing {
+    let mut viewport = Viewport::new(Some(100), Some(100));
+    viewport.device_pixel_ratio = device_pixel_ratio;
+
+    Sizing {
+      viewport,
+      font_size: 16.0,
+      font_metrics: FontMetrics::default(),
+      calc_arena: Rc::new(CalcArena::default()),
+    }
+  }
+
+  fn white_square_image() -> RgbaImage {
+    let mut image = RgbaImage::new(48, 48);
+    for y in 16..32 {
+      for x in 16..32 {
+        image.put_pixel(x, y, Rgba([255, 255, 255, 255]));
+      }
+    }
+    image
+  }
+
+  #[test]
+  fn test_apply_blur_filter_has_no_dark_halo() -> Result<()> {
+    let mut image = white_square_image();
+    let mut buffer_pool = BufferPool::default();
+    apply_filters(
+      &mut image,
+      &blur_test_sizing(1.0),
+      Color::black(),
+      &mut buffer_pool,
+      &CustomFilters::default(),
+      [Filter::Blur(Px(4.0))].iter(),
+    )?;
+
+    // The halo around the square fades out in alpha only, its color stays white.
+    let halo = image.get_pixel(12, 24).0;
+    assert!(halo[3] > 0 && halo[3] < 255);
+
+    for pixel in image.pixels().filter(|pixel| pixel.0[3] > 0) {
+      assert!(pixel.0[..3].iter().all(|channel| *channel >= 250));
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_blur_filter_scales_with_device_pixel_ratio() -> Result<()> {
+    let mut buffer_pool = BufferPool::default();
+
+    let mut image = white_square_image();
+    apply_filters(
+      &mut image,
+      &blur_test_sizing(1.0),
+      Color::black(),
+      &mut buffer_pool,
+      &CustomFilters::default(),
+      [Filter::Blur(Px(2.0))].iter(),
+    )?;
+    let spread_1x = image.get_pixel(8, 24).0[3];
+
+    let mut image = white_square_image();
+    apply_filters(
+      &mut image,
+      &blur_test_sizing(2.0),
+      Color::black(),
+      &mut buffer_pool,
+      &CustomFilters::default(),
+      [Filter::Blur(Px(2.0))].iter(),
+    )?;
+    let spread_2x = image.get_pixel(8, 24).0[3];
+
+    assert!(spread_2x > spread_1x);
+
+    Ok(())
+  }
+
   #[test]
   fn test_apply_filters_lut_batching() -> Result<()> {
     let mut image = RgbaImage::new(1, 1);
@@ -692,6 +913,7 @@ mod tests {
     let sizing = Sizing {
       viewport,
       font_size: 16.0,
+      font_metrics: FontMetrics::default(),
       calc_arena: Rc::new(CalcArena::default()),
     };
     let mut buffer_pool = BufferPool::default();
@@ -700,6 +922,7 @@ mod tests {
       &sizing,
       Color::black(),
       &mut buffer_pool,
+      &CustomFilters::default(),
       filters.iter(),
     )?;
 
@@ -712,4 +935,137 @@ mod tests {
 
     Ok(())
   }
+
+  fn apply_to_pixel(filters: &[Filter], pixel: [u8; 4]) -> Result<[u8; 4]> {
+    let mut image = RgbaImage::from_pixel(1, 1, Rgba(pixel));
+    let sizing = Sizing {
+      viewport: Viewport::new(Some(100), Some(100)),
+      font_size: 16.0,
+      font_metrics: FontMetrics::default(),
+      calc_arena: Rc::new(CalcArena::default()),
+    };
+
+    apply_filters(
+      &mut image,
+      &sizing,
+      Color::black(),
+      &mut BufferPool::default(),
+      &CustomFilters::default(),
+      filters.iter(),
+    )?;
+
+    Ok(image.get_pixel(0, 0).0)
+  }
+
+  #[test]
+  fn test_apply_grayscale_filter() -> Result<()> {
+    let red = [255, 0, 0, 255];
+
+    assert_eq!(
+      apply_to_pixel(&[Filter::Grayscale(PercentageNumber(1.0))], red)?,
+      [54, 54, 54, 255]
+    );
+    // Amounts above 1 are clamped.
+    assert_eq!(
+      apply_to_pixel(&[Filter::Grayscale(PercentageNumber(2.0))], red)?,
+      [54, 54, 54, 255]
+    );
+    assert_eq!(
+      apply_to_pixel(&[Filter::Grayscale(PercentageNumber(0.0))], red)?,
+      red
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_sepia_filter() -> Result<()> {
+    assert_eq!(
+      apply_to_pixel(
+        &[Filter::Sepia(PercentageNumber(1.0))],
+        [255, 255, 255, 255]
+      )?,
+      [255, 255, 239, 255]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_saturate_filter() -> Result<()> {
+    let red = [255, 0, 0, 255];
+
+    assert_eq!(
+      apply_to_pixel(&[Filter::Saturate(PercentageNumber(0.0))], red)?,
+      [54, 54, 54, 255]
+    );
+    assert_eq!(
+      apply_to_pixel(&[Filter::Saturate(PercentageNumber(1.0))], red)?,
+      red
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_hue_rotate_filter() -> Result<()> {
+    assert_eq!(
+      apply_to_pixel(&[Filter::HueRotate(Angle::new(180.0))], [255, 0, 0, 255])?,
+      [0, 109, 109, 255]
+    );
+    assert_eq!(
+      apply_to_pixel(&[Filter::HueRotate(Angle::new(360.0))], [10, 200, 30, 255])?,
+      [10, 200, 30, 255]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_invert_filter_keeps_alpha() -> Result<()> {
+    assert_eq!(
+      apply_to_pixel(&[Filter::Invert(PercentageNumber(1.0))], [255, 0, 100, 128])?,
+      [0, 255, 155, 128]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_brightness_and_contrast_filters() -> Result<()> {
+    let color = [200, 100, 50, 255];
+
+    assert_eq!(
+      apply_to_pixel(&[Filter::Brightness(PercentageNumber(0.5))], color)?,
+      [100, 50, 25, 255]
+    );
+    assert_eq!(
+      apply_to_pixel(&[Filter::Contrast(PercentageNumber(2.0))], color)?,
+      [255, 72, 0, 255]
+    );
+    assert_eq!(
+      apply_to_pixel(&[Filter::Contrast(PercentageNumber(1.0))], color)?,
+      color
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_apply_filters_left_to_right() -> Result<()> {
+    let color = [200, 200, 200, 255];
+    let brightness = Filter::Brightness(PercentageNumber(0.5));
+    let contrast = Filter::Contrast(PercentageNumber(2.0));
+
+    assert_eq!(
+      apply_to_pixel(&[brightness.clone(), contrast.clone()], color)?,
+      [72, 72, 72, 255]
+    );
+    assert_eq!(
+      apply_to_pixel(&[contrast, brightness], color)?,
+      [127, 127, 127, 255]
+    );
+
+    Ok(())
+  }
 }
@@ -0,0 +1,192 @@
+use cssparser::{Parser, match_ignore_ascii_case};
+use parley::FontFeature;
+use smallvec::SmallVec;
+
+use crate::layout::style::{
+  CssToken, FromCss, MakeComputed, ParseResult, declare_enum_from_css_impl,
+};
+
+/// Controls the kerning information stored in the font.
+///
+/// Corresponds to CSS font-kerning property.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum FontKerning {
+  /// The shaper decides, which applies kerning.
+  #[default]
+  Auto,
+  /// Kerning is applied.
+  Normal,
+  /// Kerning is disabled.
+  None,
+}
+
+declare_enum_from_css_impl!(
+  FontKerning,
+  "auto" => FontKerning::Auto,
+  "normal" => FontKerning::Normal,
+  "none" => FontKerning::None,
+);
+
+impl FontKerning {
+  /// Returns the `kern` feature setting, if kerning is forced on or off.
+  pub(crate) fn feature(self) -> Option<FontFeature> {
+    match self {
+      FontKerning::Auto => None,
+      FontKerning::Normal => font_feature("kern", true),
+      FontKerning::None => font_feature("kern", false),
+    }
+  }
+}
+
+/// Controls which ligatures and contextual forms are used.
+///
+/// Corresponds to CSS font-variant-ligatures property. Each group is `None` when left to the
+/// font's defaults, so `normal` leaves all of them unset and `none` disables all of them.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FontVariantLigatures {
+  /// Common ligatures like `fi`, the `liga` and `clig` features.
+  pub common: Option<bool>,
+  /// Discretionary ligatures, the `dlig` feature.
+  pub discretionary: Option<bool>,
+  /// Historical ligatures, the `hlig` feature.
+  pub historical: Option<bool>,
+  /// Contextual alternates, the `calt` feature.
+  pub contextual: Option<bool>,
+}
+
+impl FontVariantLigatures {
+  /// Ligatures and contextual forms are all disabled.
+  pub const NONE: Self = Self {
+    common: Some(false),
+    discretionary: Some(false),
+    historical: Some(false),
+    contextual: Some(false),
+  };
+
+  /// Returns the feature settings of the groups that are set.
+  pub(crate) fn features(self) -> SmallVec<[FontFeature; 5]> {
+    [
+      ("liga", self.common),
+      ("clig", self.common),
+      ("dlig", self.discretionary),
+      ("hlig", self.historical),
+      ("calt", self.contextual),
+    ]
+    .into_iter()
+    .filter_map(|(tag, enabled)| font_feature(tag, enabled?))
+    .collect()
+  }
+}
+
+impl MakeComputed for FontVariantLigatures {}
+
+impl<'i> FromCss<'i> for FontVariantLigatures {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if input
+      .try_parse(|input| input.expect_ident_matching("normal"))
+      .is_ok()
+    {
+      return Ok(Self::default());
+    }
+
+    if input
+      .try_parse(|input| input.expect_ident_matching("none"))
+      .is_ok()
+    {
+      return Ok(Self::NONE);
+    }
+
+    let mut ligatures = Self::default();
+
+    loop {
+      let location = input.current_source_location();
+      let ident = input.expect_ident()?.clone();
+
+      let (group, enabled) = match_ignore_ascii_case! { &ident,
+        "common-ligatures" => (&mut ligatures.common, true),
+        "no-common-ligatures" => (&mut ligatures.common, false),
+        "discretionary-ligatures" => (&mut ligatures.discretionary, true),
+        "no-discretionary-ligatures" => (&mut ligatures.discretionary, false),
+        "historical-ligatures" => (&mut ligatures.historical, true),
+        "no-historical-ligatures" => (&mut ligatures.historical, false),
+        "contextual" => (&mut ligatures.contextual, true),
+        "no-contextual" => (&mut ligatures.contextual, false),
+        _ => return Err(location.new_custom_error(Self::expect_message())),
+      };
+
+      // Each group may only be given once.
+      if group.replace(enabled).is_some() {
+        return Err(location.new_custom_error(Self::expect_message()));
+      }
+
+      if input.is_exhausted() {
+        break;
+      }
+    }
+
+    Ok(ligatures)
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("normal"),
+      CssToken::Keyword("none"),
+      CssToken::Keyword("common-ligatures"),
+      CssToken::Keyword("no-common-ligatures"),
+      CssToken::Keyword("discretionary-ligatures"),
+      CssToken::Keyword("no-discretionary-ligatures"),
+      CssToken::Keyword("historical-ligatures"),
+      CssToken::Keyword("no-historical-ligatures"),
+      CssToken::Keyword("contextual"),
+      CssToken::Keyword("no-contextual"),
+    ]
+  }
+}
+
+fn font_feature(tag: &str, enabled: bool) -> Option<FontFeature> {
+  FontFeature::parse_list(&format!("\"{tag}\" {}", u8::from(enabled))).next()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_font_variant_ligatures() {
+    assert_eq!(
+      FontVariantLigatures::from_str("normal"),
+      Ok(FontVariantLigatures::default())
+    );
+    assert_eq!(
+      FontVariantLigatures::from_str("none"),
+      Ok(FontVariantLigatures::NONE)
+    );
+    assert_eq!(
+      FontVariantLigatures::from_str("no-common-ligatures discretionary-ligatures"),
+      Ok(FontVariantLigatures {
+        common: Some(false),
+        discretionary: Some(true),
+        ..Default::default()
+      })
+    );
+    assert!(FontVariantLigatures::from_str("common-ligatures no-common-ligatures").is_err());
+    assert!(FontVariantLigatures::from_str("common-ligatures none").is_err());
+  }
+
+  #[test]
+  fn test_font_variant_ligatures_features() {
+    assert!(FontVariantLigatures::default().features().is_empty());
+    assert_eq!(FontVariantLigatures::NONE.features().len(), 5);
+    assert_eq!(
+      FontVariantLigatures {
+        discretionary: Some(true),
+        ..Default::default()
+      }
+      .features()
+      .as_slice(),
+      FontFeature::parse_list("\"dlig\" 1")
+        .collect::<Vec<_>>()
+        .as_slice()
+    );
+  }
+}
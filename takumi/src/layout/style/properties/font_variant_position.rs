@@ -0,0 +1,59 @@
+use parley::FontFeature;
+use swash::{Tag, tag_from_bytes};
+
+use crate::layout::style::declare_enum_from_css_impl;
+
+/// Font size scale applied when subscript or superscript glyphs have to be synthesized.
+pub(crate) const SYNTHESIZED_POSITION_SCALE: f32 = 0.6;
+
+/// Controls the use of alternate, smaller glyphs positioned as subscript or superscript.
+///
+/// Corresponds to CSS font-variant-position property.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum FontVariantPosition {
+  /// Glyphs are rendered on the normal baseline.
+  #[default]
+  Normal,
+  /// Glyphs are rendered as subscript.
+  Sub,
+  /// Glyphs are rendered as superscript.
+  Super,
+}
+
+declare_enum_from_css_impl!(
+  FontVariantPosition,
+  "normal" => FontVariantPosition::Normal,
+  "sub" => FontVariantPosition::Sub,
+  "super" => FontVariantPosition::Super,
+);
+
+impl FontVariantPosition {
+  /// Returns the OpenType feature tag providing the positioned glyphs, if any.
+  pub(crate) fn feature_tag(self) -> Option<Tag> {
+    match self {
+      FontVariantPosition::Normal => None,
+      FontVariantPosition::Sub => Some(tag_from_bytes(b"subs")),
+      FontVariantPosition::Super => Some(tag_from_bytes(b"sups")),
+    }
+  }
+
+  /// Returns the OpenType feature setting enabling the positioned glyphs, if any.
+  pub(crate) fn feature(self) -> Option<FontFeature> {
+    let source = match self {
+      FontVariantPosition::Normal => return None,
+      FontVariantPosition::Sub => "\"subs\"",
+      FontVariantPosition::Super => "\"sups\"",
+    };
+
+    FontFeature::parse_list(source).next()
+  }
+
+  /// Returns the baseline shift of synthesized glyphs relative to the font size, positive being downwards.
+  pub(crate) fn synthesized_baseline_shift(self) -> f32 {
+    match self {
+      FontVariantPosition::Normal => 0.0,
+      FontVariantPosition::Sub => 0.2,
+      FontVariantPosition::Super => -0.34,
+    }
+  }
+}
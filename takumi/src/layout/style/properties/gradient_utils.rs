@@ -18,6 +18,9 @@ pub(crate) fn interpolate_rgba(c1: Color, c2: Color, t: f32) -> Color {
 }
 
 /// Interpolates between two colors in RGBA space, if t is 0.0 or 1.0, returns the first or second color as f32x4.
+///
+/// Channels are interpolated premultiplied, so a `transparent` stop fades the alpha of its
+/// neighbor instead of pulling the color toward black.
 fn interpolate_rgba_impl(c1: Color, c2: Color, t: f32) -> f32x4 {
   let c1_f32 = f32x4::from([
     c1.0[0] as f32,
@@ -41,7 +44,18 @@ fn interpolate_rgba_impl(c1: Color, c2: Color, t: f32) -> f32x4 {
     return c2_f32;
   }
 
-  c1_f32 * (1.0 - t) + c2_f32 * t
+  let weight1 = c1.0[3] as f32 / 255.0 * (1.0 - t);
+  let weight2 = c2.0[3] as f32 / 255.0 * t;
+  let alpha = weight1 + weight2;
+
+  if alpha <= f32::EPSILON {
+    return f32x4::ZERO;
+  }
+
+  let mut result = ((c1_f32 * weight1 + c2_f32 * weight2) * (1.0 / alpha)).to_array();
+  result[3] = alpha * 255.0;
+
+  f32x4::from(result)
 }
 
 pub(crate) fn color_from_stops(position: f32, resolved_stops: &[ResolvedGradientStop]) -> f32x4 {
@@ -301,6 +315,23 @@ mod tests {
 
   use super::*;
 
+  #[test]
+  fn test_interpolate_rgba_toward_transparent_keeps_color() {
+    assert_eq!(
+      interpolate_rgba(Color([0, 0, 255, 255]), Color::transparent(), 0.5),
+      Color([0, 0, 255, 128])
+    );
+  }
+
+  #[test]
+  fn test_interpolate_rgba_between_translucent_colors_is_premultiplied() {
+    // The opaque red dominates the mix since the green is barely visible.
+    assert_eq!(
+      interpolate_rgba(Color([255, 0, 0, 255]), Color([0, 255, 0, 85]), 0.5),
+      Color([191, 64, 0, 170])
+    );
+  }
+
   #[test]
   fn test_resolve_stops_along_axis() {
     let stops = vec![
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 
 use cssparser::{Parser, Token};
 
@@ -48,6 +48,36 @@ impl From<GridTemplateAreas> for Vec<taffy::GridTemplateArea<String>> {
   }
 }
 
+/// Returns the first named area whose cells don't fill their bounding rectangle.
+fn first_non_rectangular_area(rows: &[Vec<String>]) -> Option<&str> {
+  // Bounds as (row_min, row_max, column_min, column_max) and the number of cells.
+  let mut areas: Vec<(&str, (usize, usize, usize, usize), usize)> = Vec::new();
+
+  for (r, row) in rows.iter().enumerate() {
+    for (c, cell) in row.iter().enumerate() {
+      if cell == "." {
+        continue;
+      }
+
+      match areas.iter_mut().find(|(name, ..)| *name == cell.as_str()) {
+        Some((_, bounds, count)) => {
+          bounds.0 = bounds.0.min(r);
+          bounds.1 = bounds.1.max(r);
+          bounds.2 = bounds.2.min(c);
+          bounds.3 = bounds.3.max(c);
+          *count += 1;
+        }
+        None => areas.push((cell.as_str(), (r, r, c, c), 1)),
+      }
+    }
+  }
+
+  areas
+    .into_iter()
+    .find(|(_, (rmin, rmax, cmin, cmax), count)| (rmax - rmin + 1) * (cmax - cmin + 1) != *count)
+    .map(|(name, ..)| name)
+}
+
 impl<'i> FromCss<'i> for GridTemplateAreas {
   fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
     let location = input.current_source_location();
@@ -85,15 +115,20 @@ impl<'i> FromCss<'i> for GridTemplateAreas {
       }
     }
 
-    // Validate consistent column counts across rows
     if let Some(width) = rows.first().map(Vec::len)
-      && rows.iter().any(|r| r.len() != width)
+      && let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width)
     {
-      // Create a parse error for inconsistent row lengths
-      return Err(Self::unexpected_token_error(
-        input.current_source_location(),
-        &Token::Ident("inconsistent-rows".into()),
-      ));
+      return Err(input.new_custom_error(Cow::Owned(format!(
+        "grid-template-areas rows must have the same number of columns, row 1 has {width} but row {} has {}.",
+        index + 1,
+        row.len()
+      ))));
+    }
+
+    if let Some(name) = first_non_rectangular_area(&rows) {
+      return Err(input.new_custom_error(Cow::Owned(format!(
+        "grid-template-areas area '{name}' must form a single rectangle."
+      ))));
     }
 
     Ok(GridTemplateAreas(rows))
@@ -103,3 +138,49 @@ impl<'i> FromCss<'i> for GridTemplateAreas {
     &[CssToken::Token("string")]
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use cssparser::ParseErrorKind;
+
+  use super::*;
+
+  fn parse_error_message(source: &str) -> Option<String> {
+    match GridTemplateAreas::from_str(source) {
+      Err(error) => match error.kind {
+        ParseErrorKind::Custom(message) => Some(message.into_owned()),
+        ParseErrorKind::Basic(_) => None,
+      },
+      Ok(_) => None,
+    }
+  }
+
+  #[test]
+  fn test_parse_grid_template_areas() {
+    assert_eq!(
+      GridTemplateAreas::from_str(r#""a a ." "b b c""#),
+      Ok(GridTemplateAreas(vec![
+        vec!["a".to_string(), "a".to_string(), ".".to_string()],
+        vec!["b".to_string(), "b".to_string(), "c".to_string()],
+      ]))
+    );
+  }
+
+  #[test]
+  fn test_parse_grid_template_areas_ragged_rows() {
+    assert_eq!(
+      parse_error_message(r#""a a" "b b c""#).as_deref(),
+      Some(
+        "grid-template-areas rows must have the same number of columns, row 1 has 2 but row 2 has 3."
+      )
+    );
+  }
+
+  #[test]
+  fn test_parse_grid_template_areas_l_shaped_area() {
+    assert_eq!(
+      parse_error_message(r#""a a" "a b""#).as_deref(),
+      Some("grid-template-areas area 'a' must form a single rectangle.")
+    );
+  }
+}
@@ -0,0 +1,73 @@
+use cssparser::{Parser, Token};
+
+use crate::layout::style::{CssToken, FromCss, MakeComputed, ParseResult};
+
+/// The language of the content as a BCP 47 tag, like the HTML `lang` attribute.
+///
+/// It picks locale-specific glyphs during shaping and locale-specific case mappings for
+/// `text-transform`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lang(String);
+
+impl Lang {
+  /// Returns the language tag.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  /// Whether the language uses the Turkic case mappings, where `i` and `ı` are distinct letters.
+  pub(crate) fn is_turkic(&self) -> bool {
+    let primary = self.0.split(['-', '_']).next().unwrap_or_default();
+
+    primary.eq_ignore_ascii_case("tr") || primary.eq_ignore_ascii_case("az")
+  }
+}
+
+impl MakeComputed for Lang {}
+
+impl<'i> FromCss<'i> for Lang {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let tag = input.expect_ident_or_string()?.clone();
+
+    let is_valid = !tag.is_empty()
+      && tag
+        .split(['-', '_'])
+        .all(|subtag| !subtag.is_empty() && subtag.chars().all(|ch| ch.is_ascii_alphanumeric()));
+
+    if !is_valid {
+      return Err(Self::unexpected_token_error(location, &Token::Ident(tag)));
+    }
+
+    Ok(Lang(tag.to_string()))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("language-tag")]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_lang() {
+    assert_eq!(Lang::from_str("tr"), Ok(Lang("tr".to_string())));
+    assert_eq!(Lang::from_str("zh-Hant"), Ok(Lang("zh-Hant".to_string())));
+    assert!(Lang::from_str("en--US").is_err());
+  }
+
+  #[test]
+  fn test_lang_is_turkic() {
+    let Ok(turkish) = Lang::from_str("tr-TR") else {
+      unreachable!()
+    };
+    let Ok(english) = Lang::from_str("en") else {
+      unreachable!()
+    };
+
+    assert!(turkish.is_turkic());
+    assert!(!english.is_turkic());
+  }
+}
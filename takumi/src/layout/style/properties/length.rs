@@ -1,11 +1,14 @@
-use std::{cell::RefCell, ops::Neg};
+use std::{
+  cell::RefCell,
+  ops::{Deref, Neg},
+};
 
 use cssparser::{Parser, Token, match_ignore_ascii_case};
 use taffy::{CompactLength, Dimension, LengthPercentage, LengthPercentageAuto};
 
 use crate::{
   layout::style::{
-    AspectRatio, CssToken, FromCss, MakeComputed, ParseResult,
+    AspectRatio, CssToken, CssValue, FromCss, MakeComputed, ParseResult,
     tw::{TW_VAR_SPACING, TailwindPropertyParser},
   },
   rendering::Sizing,
@@ -21,15 +24,15 @@ const CALC_ZERO_EPSILON: f32 = 1e-6;
 
 #[derive(Default)]
 pub(crate) struct CalcArena {
-  linear_values: RefCell<Vec<CalcLinear>>,
+  expressions: RefCell<Vec<CalcClamp<CalcLinear>>>,
 }
 
 impl CalcArena {
-  fn register_linear(&self, linear: CalcLinear) -> *const () {
-    let mut linear_values = self.linear_values.borrow_mut();
+  fn register(&self, expression: CalcClamp<CalcLinear>) -> *const () {
+    let mut expressions = self.expressions.borrow_mut();
 
-    linear_values.push(linear);
-    encode_linear_id(linear_values.len())
+    expressions.push(expression);
+    encode_linear_id(expressions.len())
   }
 
   pub(crate) fn resolve_calc_value(&self, val: *const (), basis: f32) -> f32 {
@@ -37,10 +40,10 @@ impl CalcArena {
       return 0.0;
     };
 
-    let linear_values = self.linear_values.borrow();
-    linear_values
+    let expressions = self.expressions.borrow();
+    expressions
       .get(id - 1)
-      .map(|linear| linear.resolve(basis))
+      .map(|expression| expression.resolve(basis))
       .unwrap_or(0.0)
   }
 }
@@ -76,6 +79,62 @@ impl CalcLinear {
   }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Internal form of a `min(...)`, `max(...)` or `clamp(...)` expression: `value` bounded by `min` and `max`.
+pub struct CalcClamp<T> {
+  min: Option<T>,
+  value: T,
+  max: Option<T>,
+}
+
+impl<T: Copy> CalcClamp<T> {
+  fn map<U>(self, f: impl Fn(T) -> U) -> CalcClamp<U> {
+    CalcClamp {
+      min: self.min.map(&f),
+      value: f(self.value),
+      max: self.max.map(&f),
+    }
+  }
+}
+
+impl From<CalcLinear> for CalcClamp<CalcLinear> {
+  fn from(value: CalcLinear) -> Self {
+    Self {
+      min: None,
+      value,
+      max: None,
+    }
+  }
+}
+
+impl CalcClamp<CalcLinear> {
+  fn resolve(self, basis: f32) -> f32 {
+    let value = self.value.resolve(basis);
+    let value = self.max.map_or(value, |max| value.min(max.resolve(basis)));
+
+    // Like `clamp()`, the lower bound wins when the bounds cross.
+    self.min.map_or(value, |min| value.max(min.resolve(basis)))
+  }
+
+  fn as_linear(self) -> Option<CalcLinear> {
+    (self.min.is_none() && self.max.is_none()).then_some(self.value)
+  }
+
+  fn has_percent(self) -> bool {
+    [self.min, Some(self.value), self.max]
+      .into_iter()
+      .flatten()
+      .any(|linear| !is_near_zero(linear.percent))
+  }
+
+  fn into_handle(self) -> CalcHandle {
+    match self.as_linear() {
+      Some(linear) => CalcHandle::Linear(linear),
+      None => CalcHandle::LinearClamp(self),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 /// Internal symbolic form of a `calc(...)` expression before sizing is known.
 pub struct CalcFormula {
@@ -83,6 +142,8 @@ pub struct CalcFormula {
   percent: f32,
   rem: f32,
   em: f32,
+  ch: f32,
+  ex: f32,
   vh: f32,
   vw: f32,
   cm: f32,
@@ -122,6 +183,20 @@ impl CalcFormula {
     }
   }
 
+  fn ch(value: f32) -> Self {
+    Self {
+      ch: value,
+      ..Default::default()
+    }
+  }
+
+  fn ex(value: f32) -> Self {
+    Self {
+      ex: value,
+      ..Default::default()
+    }
+  }
+
   fn vh(value: f32) -> Self {
     Self {
       vh: value,
@@ -184,6 +259,8 @@ impl CalcFormula {
       percent: -self.percent,
       rem: -self.rem,
       em: -self.em,
+      ch: -self.ch,
+      ex: -self.ex,
       vh: -self.vh,
       vw: -self.vw,
       cm: -self.cm,
@@ -201,6 +278,8 @@ impl CalcFormula {
       percent: self.percent + rhs.percent,
       rem: self.rem + rhs.rem,
       em: self.em + rhs.em,
+      ch: self.ch + rhs.ch,
+      ex: self.ex + rhs.ex,
       vh: self.vh + rhs.vh,
       vw: self.vw + rhs.vw,
       cm: self.cm + rhs.cm,
@@ -218,6 +297,8 @@ impl CalcFormula {
       percent: self.percent - rhs.percent,
       rem: self.rem - rhs.rem,
       em: self.em - rhs.em,
+      ch: self.ch - rhs.ch,
+      ex: self.ex - rhs.ex,
       vh: self.vh - rhs.vh,
       vw: self.vw - rhs.vw,
       cm: self.cm - rhs.cm,
@@ -235,6 +316,8 @@ impl CalcFormula {
       percent: self.percent * factor,
       rem: self.rem * factor,
       em: self.em * factor,
+      ch: self.ch * factor,
+      ex: self.ex * factor,
       vh: self.vh * factor,
       vw: self.vw * factor,
       cm: self.cm * factor,
@@ -251,6 +334,8 @@ impl CalcFormula {
       px: self.px * sizing.viewport.device_pixel_ratio
         + self.rem * sizing.viewport.font_size * sizing.viewport.device_pixel_ratio
         + self.em * sizing.font_size
+        + self.ch * sizing.font_size * sizing.font_metrics.ch
+        + self.ex * sizing.font_size * sizing.font_metrics.ex
         + self.vh * sizing.viewport.height.unwrap_or_default() as f32 / 100.0
         + self.vw * sizing.viewport.width.unwrap_or_default() as f32 / 100.0
         + self.cm * ONE_CM_IN_PX * sizing.viewport.device_pixel_ratio
@@ -277,6 +362,106 @@ pub enum CalcHandle {
   Formula(CalcFormula),
   /// Internal handle for a resolved linear calc expression.
   Linear(CalcLinear),
+  /// Internal handle for a parsed `min`, `max` or `clamp` formula.
+  Clamp(CalcClamp<CalcFormula>),
+  /// Internal handle for a resolved `min`, `max` or `clamp` expression.
+  LinearClamp(CalcClamp<CalcLinear>),
+}
+
+/// The comparison math functions accepted as lengths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CalcComparison {
+  Min,
+  Max,
+  Clamp,
+}
+
+impl CalcComparison {
+  fn from_name(name: &str) -> Option<Self> {
+    match_ignore_ascii_case! {name,
+      "min" => Some(Self::Min),
+      "max" => Some(Self::Max),
+      "clamp" => Some(Self::Clamp),
+      _ => None,
+    }
+  }
+
+  fn argument_count(self) -> usize {
+    match self {
+      Self::Min | Self::Max => 2,
+      Self::Clamp => 3,
+    }
+  }
+
+  fn bound<T: Copy>(self, arguments: &[T]) -> CalcClamp<T> {
+    match (self, arguments) {
+      (Self::Min, &[value, max, ..]) => CalcClamp {
+        min: None,
+        value,
+        max: Some(max),
+      },
+      (Self::Max, &[min, value, ..]) => CalcClamp {
+        min: Some(min),
+        value,
+        max: None,
+      },
+      (Self::Clamp, &[min, value, max, ..]) => CalcClamp {
+        min: Some(min),
+        value,
+        max: Some(max),
+      },
+      _ => unreachable!(),
+    }
+  }
+}
+
+/// Parses the arguments of a `min()`, `max()` or `clamp()` function, which must all be numbers or all be lengths.
+fn parse_calc_comparison<'i, const DEFAULT_AUTO: bool>(
+  input: &mut Parser<'i, '_>,
+  comparison: CalcComparison,
+) -> ParseResult<'i, Length<DEFAULT_AUTO>> {
+  let arguments = input.parse_comma_separated(parse_calc_sum)?;
+
+  if arguments.len() != comparison.argument_count() {
+    return Err(<Length as FromCss<'i>>::unexpected_token_error(
+      input.current_source_location(),
+      &Token::Comma,
+    ));
+  }
+
+  let numbers = arguments
+    .iter()
+    .map(|argument| match argument {
+      CalcValue::Number(value) => Some(*value),
+      CalcValue::Formula(_) => None,
+    })
+    .collect::<Option<Vec<_>>>();
+
+  if let Some(numbers) = numbers {
+    let bounded = comparison.bound(&numbers);
+    let value = bounded
+      .max
+      .map_or(bounded.value, |max| bounded.value.min(max));
+
+    return Ok(Length::Px(bounded.min.map_or(value, |min| value.max(min))));
+  }
+
+  let formulas = arguments
+    .iter()
+    .map(|argument| match argument {
+      CalcValue::Formula(formula) => Some(*formula),
+      CalcValue::Number(_) => None,
+    })
+    .collect::<Option<Vec<_>>>();
+
+  let Some(formulas) = formulas else {
+    return Err(<Length as FromCss<'i>>::unexpected_token_error(
+      input.current_source_location(),
+      &Token::Comma,
+    ));
+  };
+
+  Ok(Length::Calc(CalcHandle::Clamp(comparison.bound(&formulas))))
 }
 
 fn parse_calc_sum<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, CalcValue> {
@@ -393,6 +578,8 @@ fn parse_calc_factor<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, CalcValu
       match_ignore_ascii_case! {unit,
         "px" => Ok(CalcValue::Formula(CalcFormula::px(*value))),
         "em" => Ok(CalcValue::Formula(CalcFormula::em(*value))),
+        "ch" => Ok(CalcValue::Formula(CalcFormula::ch(*value))),
+        "ex" => Ok(CalcValue::Formula(CalcFormula::ex(*value))),
         "rem" => Ok(CalcValue::Formula(CalcFormula::rem(*value))),
         "vw" => Ok(CalcValue::Formula(CalcFormula::vw(*value))),
         "vh" => Ok(CalcValue::Formula(CalcFormula::vh(*value))),
@@ -414,11 +601,13 @@ fn parse_calc_factor<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, CalcValu
   }
 }
 
-fn calc_handle_to_linear(handle: CalcHandle, sizing: &Sizing) -> CalcLinear {
+fn calc_handle_to_expression(handle: CalcHandle, sizing: &Sizing) -> CalcClamp<CalcLinear> {
   match handle {
-    CalcHandle::Formula(formula) => formula.resolve(sizing),
+    CalcHandle::Formula(formula) => formula.resolve(sizing).into(),
     // Already resolved via `formula.resolve(sizing)`, so this is intentionally a no-op.
-    CalcHandle::Linear(linear) => linear,
+    CalcHandle::Linear(linear) => linear.into(),
+    CalcHandle::Clamp(clamp) => clamp.map(|formula| formula.resolve(sizing)),
+    CalcHandle::LinearClamp(clamp) => clamp,
   }
 }
 
@@ -431,12 +620,25 @@ fn is_near_zero(value: f32) -> bool {
 pub enum Length<const DEFAULT_AUTO: bool = true> {
   /// Automatic sizing based on content
   Auto,
+  /// The smallest size the content can take without overflowing (`min-content`),
+  /// only parsed for the box sizing properties, see [`SizeLength`]
+  MinContent,
+  /// The size the content takes without any soft wrapping (`max-content`),
+  /// only parsed for the box sizing properties, see [`SizeLength`]
+  MaxContent,
+  /// The max-content size clamped between the min-content size and the available space (`fit-content`),
+  /// only parsed for the box sizing properties, see [`SizeLength`]
+  FitContent,
   /// Percentage value relative to parent container (0-100)
   Percentage(f32),
   /// Rem value relative to the root font size
   Rem(f32),
   /// Em value relative to the font size
   Em(f32),
+  /// Ch value relative to the advance width of the "0" glyph in the current font
+  Ch(f32),
+  /// Ex value relative to the x-height of the current font
+  Ex(f32),
   /// Vh value relative to the viewport height (0-100)
   Vh(f32),
   /// Vw value relative to the viewport width (0-100)
@@ -455,7 +657,7 @@ pub enum Length<const DEFAULT_AUTO: bool = true> {
   Pc(f32),
   /// Specific pixel value
   Px(f32),
-  /// calc(...) expression
+  /// calc(...), min(...), max(...) or clamp(...) expression
   Calc(CalcHandle),
 }
 
@@ -519,13 +721,26 @@ impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
     Self::Px(0.0)
   }
 
+  /// Returns whether this is one of the intrinsic sizing keywords.
+  pub(crate) const fn is_intrinsic(self) -> bool {
+    matches!(
+      self,
+      Length::MinContent | Length::MaxContent | Length::FitContent
+    )
+  }
+
   /// Returns a negative length unit.
   pub fn negative(self) -> Self {
     match self {
       Length::Auto => Length::Auto,
+      Length::MinContent => Length::MinContent,
+      Length::MaxContent => Length::MaxContent,
+      Length::FitContent => Length::FitContent,
       Length::Percentage(v) => Length::Percentage(-v),
       Length::Rem(v) => Length::Rem(-v),
       Length::Em(v) => Length::Em(-v),
+      Length::Ch(v) => Length::Ch(-v),
+      Length::Ex(v) => Length::Ex(-v),
       Length::Vh(v) => Length::Vh(-v),
       Length::Vw(v) => Length::Vw(-v),
       Length::Cm(v) => Length::Cm(-v),
@@ -539,8 +754,56 @@ impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
         Length::Calc(CalcHandle::Formula(formula.neg()))
       }
       Length::Calc(CalcHandle::Linear(linear)) => Length::Calc(CalcHandle::Linear(linear.neg())),
+      // Negating swaps the bounds, `-min(a, b)` is `max(-a, -b)`.
+      Length::Calc(CalcHandle::Clamp(clamp)) => Length::Calc(CalcHandle::Clamp(CalcClamp {
+        min: clamp.max.map(CalcFormula::neg),
+        value: clamp.value.neg(),
+        max: clamp.min.map(CalcFormula::neg),
+      })),
+      Length::Calc(CalcHandle::LinearClamp(clamp)) => {
+        Length::Calc(CalcHandle::LinearClamp(CalcClamp {
+          min: clamp.max.map(CalcLinear::neg),
+          value: clamp.value.neg(),
+          max: clamp.min.map(CalcLinear::neg),
+        }))
+      }
     }
   }
+
+  /// Returns `percentage` percent of the basis plus this length, like `calc(100% - 20px)` for `-20px`.
+  ///
+  /// Keywords and `min()`, `max()` or `clamp()` expressions aren't linear, so they return `None`.
+  pub(crate) fn plus_percentage(self, percentage: f32) -> Option<Self> {
+    let formula = match self {
+      Length::Auto | Length::MinContent | Length::MaxContent | Length::FitContent => return None,
+      Length::Calc(CalcHandle::Clamp(_) | CalcHandle::LinearClamp(_)) => return None,
+      Length::Calc(CalcHandle::Linear(linear)) => {
+        return Some(Length::Calc(CalcHandle::Linear(CalcLinear {
+          percent: linear.percent + percentage / 100.0,
+          ..linear
+        })));
+      }
+      Length::Calc(CalcHandle::Formula(formula)) => formula,
+      Length::Percentage(v) => CalcFormula::percentage(v / 100.0),
+      Length::Rem(v) => CalcFormula::rem(v),
+      Length::Em(v) => CalcFormula::em(v),
+      Length::Ch(v) => CalcFormula::ch(v),
+      Length::Ex(v) => CalcFormula::ex(v),
+      Length::Vh(v) => CalcFormula::vh(v),
+      Length::Vw(v) => CalcFormula::vw(v),
+      Length::Cm(v) => CalcFormula::cm(v),
+      Length::Mm(v) => CalcFormula::mm(v),
+      Length::In(v) => CalcFormula::inch(v),
+      Length::Q(v) => CalcFormula::q(v),
+      Length::Pt(v) => CalcFormula::pt(v),
+      Length::Pc(v) => CalcFormula::pc(v),
+      Length::Px(v) => CalcFormula::px(v),
+    };
+
+    Some(Length::Calc(CalcHandle::Formula(
+      CalcFormula::percentage(percentage / 100.0).add(formula),
+    )))
+  }
 }
 
 impl<const DEFAULT_AUTO: bool> From<f32> for Length<DEFAULT_AUTO> {
@@ -565,10 +828,19 @@ impl<'i, const DEFAULT_AUTO: bool> FromCss<'i> for Length<DEFAULT_AUTO> {
           CalcValue::Formula(formula) => Ok(Self::Calc(CalcHandle::Formula(formula))),
         }
       }
+      Token::Function(function) => {
+        let Some(comparison) = CalcComparison::from_name(function) else {
+          return Err(Self::unexpected_token_error(location, token));
+        };
+
+        input.parse_nested_block(|input| parse_calc_comparison(input, comparison))
+      }
       Token::Dimension { value, unit, .. } => {
         match_ignore_ascii_case! {unit.as_ref(),
           "px" => Ok(Self::Px(*value)),
           "em" => Ok(Self::Em(*value)),
+          "ch" => Ok(Self::Ch(*value)),
+          "ex" => Ok(Self::Ex(*value)),
           "rem" => Ok(Self::Rem(*value)),
           "vw" => Ok(Self::Vw(*value)),
           "vh" => Ok(Self::Vh(*value)),
@@ -592,14 +864,75 @@ impl<'i, const DEFAULT_AUTO: bool> FromCss<'i> for Length<DEFAULT_AUTO> {
   }
 }
 
+/// A length for `width`, `height` and their min and max variants, which also accept the
+/// `min-content`, `max-content` and `fit-content` keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SizeLength(pub Length);
+
+impl Deref for SizeLength {
+  type Target = Length;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<Length> for SizeLength {
+  fn from(length: Length) -> Self {
+    Self(length)
+  }
+}
+
+impl<const DEFAULT_INHERIT: bool> From<Length> for CssValue<SizeLength, DEFAULT_INHERIT> {
+  fn from(length: Length) -> Self {
+    CssValue::Value(SizeLength(length))
+  }
+}
+
+impl<'i> FromCss<'i> for SizeLength {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if let Ok(length) = input.try_parse(Length::from_css) {
+      return Ok(Self(length));
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+
+    match_ignore_ascii_case! {
+      &ident,
+      "min-content" => Ok(Self(Length::MinContent)),
+      "max-content" => Ok(Self(Length::MaxContent)),
+      "fit-content" => Ok(Self(Length::FitContent)),
+      _ => Err(Self::unexpected_token_error(location, &Token::Ident(ident.clone()))),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Token("length"),
+      CssToken::Keyword("min-content"),
+      CssToken::Keyword("max-content"),
+      CssToken::Keyword("fit-content"),
+    ]
+  }
+}
+
+impl MakeComputed for SizeLength {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    self.0.make_computed(sizing);
+  }
+}
+
 impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
   fn to_px_pre_dpr(self, sizing: &Sizing, percentage_full_px: f32) -> f32 {
     match self {
-      Length::Auto => 0.0,
+      Length::Auto | Length::MinContent | Length::MaxContent | Length::FitContent => 0.0,
       Length::Px(value) => value,
       Length::Percentage(value) => (value / 100.0) * percentage_full_px,
       Length::Rem(value) => value * sizing.viewport.font_size,
       Length::Em(value) => value * sizing.font_size,
+      Length::Ch(value) => value * sizing.font_size * sizing.font_metrics.ch,
+      Length::Ex(value) => value * sizing.font_size * sizing.font_metrics.ex,
       Length::Vh(value) => value * sizing.viewport.height.unwrap_or_default() as f32 / 100.0,
       Length::Vw(value) => value * sizing.viewport.width.unwrap_or_default() as f32 / 100.0,
       Length::Cm(value) => value * ONE_CM_IN_PX,
@@ -609,18 +942,23 @@ impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
       Length::Pt(value) => value * ONE_PT_IN_PX,
       Length::Pc(value) => value * ONE_PC_IN_PX,
       // Calc linear values are already in device pixels.
-      Length::Calc(handle) => calc_handle_to_linear(handle, sizing).resolve(percentage_full_px),
+      Length::Calc(handle) => calc_handle_to_expression(handle, sizing).resolve(percentage_full_px),
     }
   }
 
   pub(crate) fn to_compact_length(self, sizing: &Sizing) -> CompactLength {
     match self {
-      Length::Auto => CompactLength::auto(),
+      // Intrinsic keywords are resolved by measuring the subtree during layout.
+      Length::Auto | Length::MinContent | Length::MaxContent | Length::FitContent => {
+        CompactLength::auto()
+      }
       Length::Percentage(value) => CompactLength::percent(value / 100.0),
       Length::Rem(value) => CompactLength::length(
         value * sizing.viewport.font_size * sizing.viewport.device_pixel_ratio,
       ),
-      Length::Em(value) => CompactLength::length(value * sizing.font_size),
+      Length::Em(_) | Length::Ch(_) | Length::Ex(_) => {
+        CompactLength::length(self.to_px_pre_dpr(sizing, 0.0))
+      }
       Length::Vh(value) => {
         CompactLength::length(sizing.viewport.height.unwrap_or_default() as f32 * value / 100.0)
       }
@@ -628,17 +966,19 @@ impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
         CompactLength::length(sizing.viewport.width.unwrap_or_default() as f32 * value / 100.0)
       }
       Length::Calc(handle) => {
-        let linear = calc_handle_to_linear(handle, sizing);
+        let expression = calc_handle_to_expression(handle, sizing);
 
-        if is_near_zero(linear.percent) {
-          return CompactLength::length(linear.px);
+        if !expression.has_percent() {
+          return CompactLength::length(expression.resolve(0.0));
         }
 
-        if is_near_zero(linear.px) {
+        if let Some(linear) = expression.as_linear()
+          && is_near_zero(linear.px)
+        {
           return CompactLength::percent(linear.percent);
         }
 
-        CompactLength::calc(sizing.calc_arena.register_linear(linear))
+        CompactLength::calc(sizing.calc_arena.register(expression))
       }
       _ => {
         CompactLength::length(self.to_px(sizing, sizing.viewport.width.unwrap_or_default() as f32))
@@ -662,10 +1002,15 @@ impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
     if matches!(
       self,
       Length::Auto
+        | Length::MinContent
+        | Length::MaxContent
+        | Length::FitContent
         | Length::Percentage(_)
         | Length::Vh(_)
         | Length::Vw(_)
         | Length::Em(_)
+        | Length::Ch(_)
+        | Length::Ex(_)
         | Length::Calc(_)
     ) {
       return value;
@@ -681,19 +1026,44 @@ impl<const DEFAULT_AUTO: bool> Length<DEFAULT_AUTO> {
   pub(crate) fn resolve_to_dimension(self, sizing: &Sizing) -> Dimension {
     self.resolve_to_length_percentage_auto(sizing).into()
   }
+
+  /// Adds `px` device pixels to this length, keeping any percentage part.
+  pub(crate) fn grow_by_px(self, px: f32, sizing: &Sizing) -> Self {
+    let expression = match self {
+      Length::Auto | Length::MinContent | Length::MaxContent | Length::FitContent => return self,
+      Length::Percentage(value) => CalcLinear {
+        px: 0.0,
+        percent: value / 100.0,
+      }
+      .into(),
+      Length::Calc(handle) => calc_handle_to_expression(handle, sizing),
+      _ => CalcLinear {
+        px: self.to_px(sizing, 0.0),
+        percent: 0.0,
+      }
+      .into(),
+    };
+
+    // Growing every term grows the bounded result by the same amount.
+    Length::Calc(
+      expression
+        .map(|linear| CalcLinear {
+          px: linear.px + px,
+          ..linear
+        })
+        .into_handle(),
+    )
+  }
 }
 
 impl<const DEFAULT_AUTO: bool> MakeComputed for Length<DEFAULT_AUTO> {
   fn make_computed(&mut self, sizing: &Sizing) {
-    if let Self::Em(em) = *self {
+    // Font-relative units are resolved against the element's own font, so they compute to absolute lengths.
+    if let Self::Em(_) | Self::Ch(_) | Self::Ex(_) = *self {
       let dpr = sizing.viewport.device_pixel_ratio;
-      let font_size = if dpr > 0.0 {
-        sizing.font_size / dpr
-      } else {
-        sizing.font_size
-      };
+      let px = self.to_px_pre_dpr(sizing, 0.0);
 
-      *self = Self::Px(em * font_size);
+      *self = Self::Px(if dpr > 0.0 { px / dpr } else { px });
       return;
     }
 
@@ -711,6 +1081,18 @@ impl<const DEFAULT_AUTO: bool> MakeComputed for Length<DEFAULT_AUTO> {
       }
 
       *self = Self::Calc(CalcHandle::Linear(linear));
+      return;
+    }
+
+    if let Self::Calc(CalcHandle::Clamp(clamp)) = *self {
+      let expression = clamp.map(|formula| formula.resolve(sizing));
+
+      if !expression.has_percent() {
+        *self = Self::Px(expression.resolve(0.0) / sizing.viewport.device_pixel_ratio);
+        return;
+      }
+
+      *self = Self::Calc(CalcHandle::LinearClamp(expression));
     }
   }
 }
@@ -720,7 +1102,7 @@ mod tests {
   use std::rc::Rc;
 
   use super::*;
-  use crate::layout::Viewport;
+  use crate::{layout::Viewport, rendering::FontMetrics};
 
   fn sizing() -> Sizing {
     Sizing {
@@ -731,6 +1113,7 @@ mod tests {
         device_pixel_ratio: 2.0,
       },
       font_size: 10.0,
+      font_metrics: FontMetrics { ch: 0.75, ex: 0.5 },
       calc_arena: Rc::new(CalcArena::default()),
     }
   }
@@ -874,4 +1257,179 @@ mod tests {
     assert_eq!(value, Length::Px(7.5));
     assert_eq!(value.to_px(&sizing, 0.0), 15.0);
   }
+
+  #[test]
+  fn parse_font_relative_units() {
+    assert_eq!(Length::<true>::from_str("2ch"), Ok(Length::Ch(2.0)));
+    assert_eq!(Length::<true>::from_str("1.5ex"), Ok(Length::Ex(1.5)));
+  }
+
+  #[test]
+  fn em_resolves_against_own_font_size() {
+    let px = Length::<true>::Em(2.0).to_px(&sizing(), 100.0);
+    assert_near(px, 20.0);
+  }
+
+  #[test]
+  fn ch_resolves_against_zero_advance() {
+    let px = Length::<true>::Ch(2.0).to_px(&sizing(), 100.0);
+    assert_near(px, 15.0);
+  }
+
+  #[test]
+  fn ex_resolves_against_x_height() {
+    let px = Length::<true>::Ex(2.0).to_px(&sizing(), 100.0);
+    assert_near(px, 10.0);
+  }
+
+  #[test]
+  fn calc_resolves_font_relative_units() {
+    let px =
+      Length::<true>::from_str("calc(1ch + 1ex + 1em)").map(|value| value.to_px(&sizing(), 0.0));
+    assert_eq!(px, Ok(22.5));
+  }
+
+  #[test]
+  fn make_computed_ch_and_ex_apply_dpr_only_once_in_to_px() {
+    let sizing = sizing();
+
+    let mut ch: Length<true> = Length::Ch(1.0);
+    ch.make_computed(&sizing);
+    assert_eq!(ch, Length::Px(3.75));
+    assert_eq!(ch.to_px(&sizing, 0.0), 7.5);
+
+    let mut ex: Length<true> = Length::Ex(1.0);
+    ex.make_computed(&sizing);
+    assert_eq!(ex, Length::Px(2.5));
+    assert_eq!(ex.to_px(&sizing, 0.0), 5.0);
+  }
+
+  #[test]
+  fn grow_by_px_keeps_percentage_part() {
+    let sizing = sizing();
+    let grown = Length::<false>::Percentage(10.0).grow_by_px(30.0, &sizing);
+
+    assert_near(grown.to_px(&sizing, 200.0), 50.0);
+  }
+
+  #[test]
+  fn grow_by_px_adds_to_device_pixels() {
+    let sizing = sizing();
+    let grown = Length::<false>::Px(4.0).grow_by_px(30.0, &sizing);
+
+    assert_near(grown.to_px(&sizing, 0.0), 38.0);
+  }
+
+  #[test]
+  fn clamp_resolves_to_each_regime_by_viewport_width() {
+    let value = Length::<true>::from_str("clamp(16px, 4vw, 32px)");
+    let resolve_at = |width: u32| {
+      let sizing = Sizing {
+        viewport: Viewport {
+          width: Some(width),
+          device_pixel_ratio: 1.0,
+          ..sizing().viewport
+        },
+        ..sizing()
+      };
+
+      value.as_ref().ok().map(|value| value.to_px(&sizing, 0.0))
+    };
+
+    // 4vw is below the minimum.
+    assert_eq!(resolve_at(200), Some(16.0));
+    // 4vw is between the bounds.
+    assert_eq!(resolve_at(600), Some(24.0));
+    // 4vw is above the maximum.
+    assert_eq!(resolve_at(1000), Some(32.0));
+  }
+
+  #[test]
+  fn min_and_max_resolve_percentages_against_basis() {
+    let sizing = sizing();
+
+    let min = Length::<true>::from_str("min(50%, 30px)").map(|value| value.to_px(&sizing, 100.0));
+    assert_eq!(min, Ok(50.0));
+
+    let max = Length::<true>::from_str("max(50%, 30px)").map(|value| value.to_px(&sizing, 200.0));
+    assert_eq!(max, Ok(100.0));
+  }
+
+  #[test]
+  fn comparison_of_numbers_becomes_px() {
+    assert_eq!(Length::<true>::from_str("min(4, 2)"), Ok(Length::Px(2.0)));
+    assert_eq!(
+      Length::<true>::from_str("clamp(1, 5, 3)"),
+      Ok(Length::Px(3.0))
+    );
+  }
+
+  #[test]
+  fn parse_comparison_rejects_mixed_or_missing_arguments() {
+    assert!(Length::<true>::from_str("min(1, 2px)").is_err());
+    assert!(Length::<true>::from_str("max(2px)").is_err());
+    assert!(Length::<true>::from_str("clamp(1px, 2px)").is_err());
+  }
+
+  #[test]
+  fn make_computed_collapses_clamp_without_percent_to_px() {
+    let mut value = Length::<true>::from_str("clamp(1rem, 10vw, 100px)").unwrap_or_default();
+    value.make_computed(&sizing());
+
+    // 10vw of the 200px wide viewport is 20 device pixels, below the 1rem minimum.
+    assert_eq!(value, Length::Px(16.0));
+  }
+
+  #[test]
+  fn compact_length_clamp_with_percent_resolves_through_callback() {
+    let value = Length::<true>::from_str("clamp(10px, 50%, 40px)").unwrap_or_default();
+    let sizing = sizing();
+    let compact = value.to_compact_length(&sizing);
+    assert!(compact.is_calc());
+
+    let resolve = |basis| {
+      sizing
+        .calc_arena
+        .resolve_calc_value(compact.calc_value(), basis)
+    };
+    assert_near(resolve(10.0), 20.0);
+    assert_near(resolve(60.0), 30.0);
+    assert_near(resolve(200.0), 80.0);
+  }
+
+  #[test]
+  fn negative_min_becomes_max_of_negated_terms() {
+    let value = Length::<true>::from_str("min(10px, 20%)").unwrap_or_default();
+    let sizing = sizing();
+
+    assert_near((-value).to_px(&sizing, 200.0), -20.0);
+    assert_near((-value).to_px(&sizing, 50.0), -10.0);
+  }
+
+  #[test]
+  fn parse_intrinsic_sizing_keywords() {
+    assert_eq!(
+      SizeLength::from_str("min-content"),
+      Ok(SizeLength(Length::MinContent))
+    );
+    assert_eq!(
+      SizeLength::from_str("max-content"),
+      Ok(SizeLength(Length::MaxContent))
+    );
+    assert_eq!(
+      SizeLength::from_str("fit-content"),
+      Ok(SizeLength(Length::FitContent))
+    );
+    assert_eq!(
+      SizeLength::from_str("10px"),
+      Ok(SizeLength(Length::Px(10.0)))
+    );
+  }
+
+  #[test]
+  fn reject_intrinsic_sizing_keywords_outside_sizing() {
+    assert!(Length::<true>::from_str("min-content").is_err());
+    assert!(Length::<false>::from_str("fit-content").is_err());
+    assert_eq!(Length::<true>::parse_tw("max"), None);
+  }
 }
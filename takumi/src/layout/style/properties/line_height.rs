@@ -67,7 +67,34 @@ impl<'i> FromCss<'i> for LineHeight {
   }
 }
 
+/// Approximate `normal` line height relative to the font size, for when font metrics aren't at hand.
+const NORMAL_LINE_HEIGHT: f32 = 1.2;
+
 impl LineHeight {
+  /// Resolves the line height to pixels, approximating `normal` from the font size.
+  pub(crate) fn to_px(self, sizing: &Sizing) -> f32 {
+    match self {
+      Self::Normal => sizing.font_size * NORMAL_LINE_HEIGHT,
+      Self::Length(length) => length.to_px(sizing, sizing.font_size),
+      Self::Unitless(value) => value * sizing.font_size,
+    }
+  }
+
+  /// Grows a resolved line height by `extra` pixels, approximating `normal` from the font size.
+  pub(crate) fn grow_parley(
+    line_height: parley::LineHeight,
+    font_size: f32,
+    extra: f32,
+  ) -> parley::LineHeight {
+    parley::LineHeight::Absolute(
+      match line_height {
+        parley::LineHeight::MetricsRelative(value) => value * font_size * NORMAL_LINE_HEIGHT,
+        parley::LineHeight::FontSizeRelative(value) => value * font_size,
+        parley::LineHeight::Absolute(value) => value,
+      } + extra,
+    )
+  }
+
   pub(crate) fn into_parley(self, sizing: &Sizing) -> parley::LineHeight {
     match self {
       Self::Normal => parley::LineHeight::MetricsRelative(1.0),
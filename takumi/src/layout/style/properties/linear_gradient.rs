@@ -1,5 +1,6 @@
 use cssparser::{Parser, Token};
 use image::{GenericImageView, Rgba};
+use smallvec::SmallVec;
 use std::ops::{Deref, Neg};
 
 use super::gradient_utils::{
@@ -26,6 +27,17 @@ impl MakeComputed for LinearGradient {
   }
 }
 
+impl LinearGradient {
+  /// Resolves the stops along a gradient line of the given length, in pixels from its start.
+  pub(crate) fn resolve_stops(
+    &self,
+    axis_length: f32,
+    context: &RenderContext,
+  ) -> SmallVec<[ResolvedGradientStop; 4]> {
+    resolve_stops_along_axis(&self.stops, axis_length.max(1e-6), context)
+  }
+}
+
 impl GenericImageView for LinearGradientTile {
   type Pixel = Rgba<u8>;
 
@@ -100,7 +112,7 @@ impl LinearGradientTile {
     let max_extent = ((width as f32 * dir_x.abs()) + (height as f32 * dir_y.abs())) / 2.0;
     let axis_length = 2.0 * max_extent;
 
-    let resolved_stops = resolve_stops_along_axis(&gradient.stops, axis_length.max(1e-6), context);
+    let resolved_stops = gradient.resolve_stops(axis_length, context);
 
     // Pre-compute color lookup table with adaptive size.
     let lut_size = adaptive_lut_size(axis_length);
@@ -645,6 +657,27 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_linear_gradient_transparent_stop() {
+    assert_eq!(
+      LinearGradient::from_str("linear-gradient(blue, transparent)"),
+      Ok(LinearGradient {
+        angle: Angle::new(180.0),
+        stops: [
+          GradientStop::ColorHint {
+            color: ColorInput::Value(Color::from_rgb(0x0000ff)),
+            hint: None,
+          },
+          GradientStop::ColorHint {
+            color: ColorInput::Value(Color::transparent()),
+            hint: None,
+          },
+        ]
+        .into(),
+      })
+    );
+  }
+
   #[test]
   fn test_parse_gradient_hint_color() {
     assert_eq!(
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use crate::layout::style::declare_enum_from_css_impl;
+
+/// The marker drawn before each child of a list container.
+///
+/// Unlike CSS, where the property is inherited by `display: list-item` elements, it is set on the
+/// container and every in-flow child becomes a list item. Nested lists count their items from 1 again.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ListStyleType {
+  /// No marker.
+  #[default]
+  None,
+  /// A filled circle.
+  Disc,
+  /// A hollow circle.
+  Circle,
+  /// A filled square.
+  Square,
+  /// Decimal numbers starting from 1.
+  Decimal,
+}
+
+declare_enum_from_css_impl!(
+  ListStyleType,
+  "none" => ListStyleType::None,
+  "disc" => ListStyleType::Disc,
+  "circle" => ListStyleType::Circle,
+  "square" => ListStyleType::Square,
+  "decimal" => ListStyleType::Decimal,
+);
+
+impl ListStyleType {
+  /// Returns the marker text of the item at zero-based `index`, including the trailing gap.
+  pub(crate) fn marker_text(self, index: usize) -> Option<Cow<'static, str>> {
+    match self {
+      ListStyleType::None => None,
+      ListStyleType::Disc => Some(Cow::Borrowed("• ")),
+      ListStyleType::Circle => Some(Cow::Borrowed("◦ ")),
+      ListStyleType::Square => Some(Cow::Borrowed("▪ ")),
+      ListStyleType::Decimal => Some(Cow::Owned(format!("{}. ", index + 1))),
+    }
+  }
+
+  /// Returns the text of a `counter()` with the value, without the trailing gap of markers.
+  pub(crate) fn counter_text(self, value: i32) -> Cow<'static, str> {
+    match self {
+      ListStyleType::None => Cow::Borrowed(""),
+      ListStyleType::Disc => Cow::Borrowed("•"),
+      ListStyleType::Circle => Cow::Borrowed("◦"),
+      ListStyleType::Square => Cow::Borrowed("▪"),
+      ListStyleType::Decimal => Cow::Owned(value.to_string()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::layout::style::FromCss;
+
+  #[test]
+  fn test_parse_list_style_type() {
+    assert_eq!(ListStyleType::from_str("disc"), Ok(ListStyleType::Disc));
+    assert_eq!(
+      ListStyleType::from_str("DECIMAL"),
+      Ok(ListStyleType::Decimal)
+    );
+    assert!(ListStyleType::from_str("upper-roman").is_err());
+  }
+
+  #[test]
+  fn test_list_marker_text() {
+    assert_eq!(ListStyleType::None.marker_text(0), None);
+    assert_eq!(ListStyleType::Disc.marker_text(4).as_deref(), Some("• "));
+    assert_eq!(
+      ListStyleType::Decimal.marker_text(2).as_deref(),
+      Some("3. ")
+    );
+  }
+}
@@ -11,10 +11,13 @@ mod background_repeat;
 mod background_size;
 mod blend_mode;
 mod border;
+mod border_image;
 mod box_shadow;
 mod clip_path;
 mod color;
+mod column_count;
 mod conic_gradient;
+mod content;
 mod filter;
 mod flex;
 mod flex_grow;
@@ -22,14 +25,18 @@ mod font_feature_settings;
 mod font_stretch;
 mod font_style;
 mod font_synthesis;
+mod font_variant_ligatures;
+mod font_variant_position;
 mod font_variation_settings;
 mod font_weight;
 mod gradient_utils;
 mod grid;
+mod lang;
 mod length;
 mod line_clamp;
 mod line_height;
 mod linear_gradient;
+mod list_style_type;
 mod noise_v1;
 mod overflow;
 mod overflow_wrap;
@@ -37,7 +44,9 @@ mod percentage_number;
 mod radial_gradient;
 mod sides;
 mod space_pair;
+mod tab_size;
 mod text_decoration;
+mod text_emphasis;
 mod text_overflow;
 mod text_shadow;
 mod text_stroke;
@@ -55,10 +64,13 @@ pub use background_repeat::*;
 pub use background_size::*;
 pub use blend_mode::*;
 pub use border::*;
+pub use border_image::*;
 pub use box_shadow::*;
 pub use clip_path::*;
 pub use color::*;
+pub use column_count::*;
 pub use conic_gradient::*;
+pub use content::*;
 pub use filter::*;
 pub use flex::*;
 pub use flex_grow::*;
@@ -66,13 +78,17 @@ pub use font_feature_settings::*;
 pub use font_stretch::*;
 pub use font_style::*;
 pub use font_synthesis::*;
+pub use font_variant_ligatures::*;
+pub use font_variant_position::*;
 pub use font_variation_settings::*;
 pub use font_weight::*;
 pub use grid::*;
+pub use lang::*;
 pub use length::*;
 pub use line_clamp::*;
 pub use line_height::*;
 pub use linear_gradient::*;
+pub use list_style_type::*;
 pub use noise_v1::*;
 pub use overflow::*;
 pub use overflow_wrap::*;
@@ -80,7 +96,9 @@ pub use percentage_number::*;
 pub use radial_gradient::*;
 pub use sides::*;
 pub use space_pair::*;
+pub use tab_size::*;
 pub use text_decoration::*;
+pub use text_emphasis::*;
 pub use text_overflow::*;
 pub use text_shadow::*;
 pub use text_stroke::*;
@@ -106,6 +124,9 @@ use crate::rendering::Sizing;
 /// Parser result type alias for CSS property parsers.
 pub type ParseResult<'i, T> = Result<T, ParseError<'i, Cow<'i, str>>>;
 
+/// Prefix of [`FromCss::unexpected_token_error`] messages, other custom errors explain why a value is invalid.
+pub(crate) const UNEXPECTED_TOKEN_MESSAGE_PREFIX: &str = "unexpected token:";
+
 /// Enum representing CSS tokens.
 pub enum CssToken {
   /// A CSS keyword.
@@ -144,6 +165,12 @@ pub trait FromCss<'i> {
   /// Returns the list of valid CSS tokens for this type.
   fn valid_tokens() -> &'static [CssToken];
 
+  /// Whether `none` is accepted for an optional property of this type, leaving it as `None`.
+  ///
+  /// Turned off for longhands whose `None` only means "defer to the shorthand", as `none` isn't
+  /// one of their CSS values.
+  const ACCEPTS_NONE: bool = true;
+
   /// Returns a message to be used in error messages.
   fn expect_message() -> Cow<'static, str> {
     Cow::Owned(format!(
@@ -205,12 +232,17 @@ fn create_unexpected_token_error<'i>(
 ) -> ParseError<'i, Cow<'i, str>> {
   #[cfg(feature = "detailed_css_error")]
   let message = format!(
-    "unexpected token: {}, {}.",
+    "{} {}, {}.",
+    UNEXPECTED_TOKEN_MESSAGE_PREFIX,
     token.to_css_string(),
     expect_message
   );
   #[cfg(not(feature = "detailed_css_error"))]
-  let message = format!("unexpected token: {}.", token.to_css_string());
+  let message = format!(
+    "{} {}.",
+    UNEXPECTED_TOKEN_MESSAGE_PREFIX,
+    token.to_css_string()
+  );
 
   ParseError {
     location,
@@ -254,12 +286,14 @@ macro_rules! impl_from_taffy_enum {
 /// Declares a CSS enum parser with automatic value list generation.
 macro_rules! declare_enum_from_css_impl {
   (
-    $enum_type:ty,
+    $enum_type:ty $(where accepts_none = $accepts_none:expr)?,
     $($css_value:expr => $variant:expr),* $(,)?
   ) => {
     impl crate::layout::style::MakeComputed for $enum_type {}
 
     impl<'i> crate::layout::style::FromCss<'i> for $enum_type {
+      $(const ACCEPTS_NONE: bool = $accepts_none;)?
+
       fn valid_tokens() -> &'static [crate::layout::style::CssToken] {
         &[$(crate::layout::style::CssToken::Keyword($css_value)),*]
       }
@@ -355,6 +389,46 @@ impl TailwindPropertyParser for BackgroundClip {
   }
 }
 
+/// Defines the box the background positioning area starts from.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BackgroundOrigin {
+  /// The background is positioned relative to the border box
+  BorderBox,
+  /// The background is positioned relative to the padding box
+  #[default]
+  PaddingBox,
+  /// The background is positioned relative to the content box
+  ContentBox,
+}
+
+declare_enum_from_css_impl!(
+  BackgroundOrigin,
+  "border-box" => BackgroundOrigin::BorderBox,
+  "padding-box" => BackgroundOrigin::PaddingBox,
+  "content-box" => BackgroundOrigin::ContentBox
+);
+
+impl From<BackgroundOrigin> for BackgroundClip {
+  fn from(origin: BackgroundOrigin) -> Self {
+    match origin {
+      BackgroundOrigin::BorderBox => BackgroundClip::BorderBox,
+      BackgroundOrigin::PaddingBox => BackgroundClip::PaddingBox,
+      BackgroundOrigin::ContentBox => BackgroundClip::ContentBox,
+    }
+  }
+}
+
+impl TailwindPropertyParser for BackgroundOrigin {
+  fn parse_tw(token: &str) -> Option<Self> {
+    match_ignore_ascii_case! {token,
+      "border" => Some(BackgroundOrigin::BorderBox),
+      "padding" => Some(BackgroundOrigin::PaddingBox),
+      "content" => Some(BackgroundOrigin::ContentBox),
+      _ => None,
+    }
+  }
+}
+
 /// Represents the CSS `border-radius` property, supporting elliptical corners.
 ///
 /// Each corner has independent horizontal and vertical radii, allowing for both circular and elliptical shapes.
@@ -426,7 +500,8 @@ pub enum TextAlign {
   Right,
   /// Centers inline content within the line box
   Center,
-  /// Expands inline content to fill the entire line box
+  /// Expands inline content to fill the entire line box by widening the space between words,
+  /// except on the last line and lines ending in a forced break
   Justify,
   /// Aligns inline content to the start edge of the line box (language-dependent)
   #[default]
@@ -455,6 +530,41 @@ impl_from_taffy_enum!(
   TextAlign, Alignment, Left, Right, Center, Justify, Start, End
 );
 
+impl TextAlign {
+  /// Resolves `start` and `end` against the inline base direction into a physical alignment.
+  ///
+  /// Without a direction, `start` and `end` follow the base direction detected from the text.
+  pub(crate) fn resolve(self, direction: Option<Direction>) -> Alignment {
+    match (self, direction) {
+      (TextAlign::Start, Some(Direction::Ltr)) | (TextAlign::End, Some(Direction::Rtl)) => {
+        Alignment::Left
+      }
+      (TextAlign::Start, Some(Direction::Rtl)) | (TextAlign::End, Some(Direction::Ltr)) => {
+        Alignment::Right
+      }
+      (align, _) => align.into(),
+    }
+  }
+}
+
+/// The inline base direction of text, like the CSS `direction` property.
+///
+/// It sets which edge `text-align: start` and `end` refer to. When unset, they follow the
+/// direction of the first strong character of the paragraph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+  /// Text flows from left to right.
+  Ltr,
+  /// Text flows from right to left, as in Arabic and Hebrew.
+  Rtl,
+}
+
+declare_enum_from_css_impl!(
+  Direction,
+  "ltr" => Direction::Ltr,
+  "rtl" => Direction::Rtl
+);
+
 /// Defines whether an element creates a new stacking context.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum Isolation {
@@ -537,15 +647,30 @@ pub enum Position {
   /// The element is removed from the normal document flow and positioned relative to its nearest positioned ancestor.
   /// Offsets (top, right, bottom, left) specify the distance from the ancestor.
   Absolute,
+  /// The element is removed from the normal document flow and positioned relative to the viewport.
+  /// Offsets (top, right, bottom, left) specify the distance from the viewport edges.
+  ///
+  /// Unlike browsers, transformed ancestors do not establish a containing block for fixed elements,
+  /// the element is always anchored to the viewport and painted above the in-flow content.
+  Fixed,
 }
 
 declare_enum_from_css_impl!(
   Position,
   "relative" => Position::Relative,
-  "absolute" => Position::Absolute
+  "absolute" => Position::Absolute,
+  "fixed" => Position::Fixed
 );
 
-impl_from_taffy_enum!(Position, taffy::Position, Relative, Absolute);
+impl From<Position> for taffy::Position {
+  fn from(value: Position) -> Self {
+    match value {
+      Position::Relative => taffy::Position::Relative,
+      // Fixed elements are hoisted to the root node, so they behave like absolute children of the viewport.
+      Position::Absolute | Position::Fixed => taffy::Position::Absolute,
+    }
+  }
+}
 
 /// Defines the direction of flex items within a flex container.
 ///
@@ -617,7 +742,7 @@ pub enum JustifyContent {
 }
 
 declare_enum_from_css_impl!(
-  JustifyContent,
+  JustifyContent where accepts_none = false,
   "normal" => JustifyContent::Normal,
   "start" => JustifyContent::Start,
   "end" => JustifyContent::End,
@@ -772,7 +897,7 @@ pub enum AlignItems {
 }
 
 declare_enum_from_css_impl!(
-  AlignItems,
+  AlignItems where accepts_none = false,
   "normal" => AlignItems::Normal,
   "start" => AlignItems::Start,
   "end" => AlignItems::End,
@@ -839,6 +964,10 @@ pub enum TextTransform {
   Lowercase,
   /// Uppercase the first letter of each word
   Capitalize,
+  /// Convert ASCII characters and spaces to their full-width forms
+  FullWidth,
+  /// Convert small kana to their full-size forms
+  FullSizeKana,
 }
 
 declare_enum_from_css_impl!(
@@ -846,7 +975,54 @@ declare_enum_from_css_impl!(
   "none" => TextTransform::None,
   "uppercase" => TextTransform::Uppercase,
   "lowercase" => TextTransform::Lowercase,
-  "capitalize" => TextTransform::Capitalize
+  "capitalize" => TextTransform::Capitalize,
+  "full-width" => TextTransform::FullWidth,
+  "full-size-kana" => TextTransform::FullSizeKana
+);
+
+/// Controls the extra spacing inserted between ideographs and non-ideographic letters or numbers.
+///
+/// Unlike CSS, where `normal` is the initial value, spacing is only added when requested.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TextAutospace {
+  /// Do not insert any spacing
+  #[default]
+  NoAutospace,
+  /// Insert spacing between ideographs and both letters and numbers
+  Normal,
+  /// Insert spacing between ideographs and letters only
+  IdeographAlpha,
+  /// Insert spacing between ideographs and numbers only
+  IdeographNumeric,
+}
+
+declare_enum_from_css_impl!(
+  TextAutospace,
+  "no-autospace" => TextAutospace::NoAutospace,
+  "normal" => TextAutospace::Normal,
+  "ideograph-alpha" => TextAutospace::IdeographAlpha,
+  "ideograph-numeric" => TextAutospace::IdeographNumeric
+);
+
+/// Controls whether characters with both a text and an emoji form are drawn as text or as emoji.
+///
+/// The presentation is forced by replacing the variation selector after each such character.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FontVariantEmoji {
+  /// Use the presentation chosen by the text, or the default presentation of the character
+  #[default]
+  Normal,
+  /// Draw monochrome text glyphs, which follow `color`
+  Text,
+  /// Draw color emoji glyphs
+  Emoji,
+}
+
+declare_enum_from_css_impl!(
+  FontVariantEmoji,
+  "normal" => FontVariantEmoji::Normal,
+  "text" => FontVariantEmoji::Text,
+  "emoji" => FontVariantEmoji::Emoji
 );
 
 /// Controls whether text decoration should skip descenders.
@@ -865,6 +1041,28 @@ declare_enum_from_css_impl!(
   "none" => TextDecorationSkipInk::None
 );
 
+/// Controls where the underline is placed relative to the text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TextUnderlinePosition {
+  /// Use the font's underline offset, at the alphabetic baseline.
+  #[default]
+  Auto,
+  /// Place the underline below the descenders of the run.
+  Under,
+  /// Place the underline on the left side in vertical writing modes, behaves like `auto` otherwise.
+  Left,
+  /// Place the underline on the right side in vertical writing modes, behaves like `auto` otherwise.
+  Right,
+}
+
+declare_enum_from_css_impl!(
+  TextUnderlinePosition,
+  "auto" => TextUnderlinePosition::Auto,
+  "under" => TextUnderlinePosition::Under,
+  "left" => TextUnderlinePosition::Left,
+  "right" => TextUnderlinePosition::Right
+);
+
 /// Represents a font family for text rendering.
 /// Multi value fallback is supported.
 #[derive(Debug, Clone, PartialEq)]
@@ -936,6 +1134,9 @@ pub enum WhiteSpaceCollapse {
   PreserveSpaces,
   /// Preserve breaks and collapse spaces.
   PreserveBreaks,
+  /// Preserve whitespace as is. Trailing spaces still hang at the end of a line like
+  /// [`Self::Preserve`] instead of wrapping, as the line breaker can't make them take up space.
+  BreakSpaces,
 }
 
 declare_enum_from_css_impl!(
@@ -944,6 +1145,7 @@ declare_enum_from_css_impl!(
   "collapse" => WhiteSpaceCollapse::Collapse,
   "preserve-spaces" => WhiteSpaceCollapse::PreserveSpaces,
   "preserve-breaks" => WhiteSpaceCollapse::PreserveBreaks,
+  "break-spaces" => WhiteSpaceCollapse::BreakSpaces,
 );
 
 /// Defines how images should be scaled when rendered.
@@ -959,13 +1161,18 @@ pub enum ImageScalingAlgorithm {
   /// The image is scaled using nearest neighbor interpolation,
   /// which is suitable for pixel art or images where sharp edges are desired.
   Pixelated,
+  /// The image is scaled up by whole factors using nearest neighbor interpolation,
+  /// then the remaining fractional scale is smoothed with bilinear interpolation.
+  /// This keeps edges sharp without the uneven pixel sizes of `pixelated` at non-integer scales.
+  CrispEdges,
 }
 
 declare_enum_from_css_impl!(
   ImageScalingAlgorithm,
   "auto" => ImageScalingAlgorithm::Auto,
   "smooth" => ImageScalingAlgorithm::Smooth,
-  "pixelated" => ImageScalingAlgorithm::Pixelated
+  "pixelated" => ImageScalingAlgorithm::Pixelated,
+  "crisp-edges" => ImageScalingAlgorithm::CrispEdges
 );
 
 /// Represents border style options.
@@ -990,12 +1197,32 @@ impl TailwindPropertyParser for BorderStyle {
   }
 }
 
+/// Controls whether the borders shared by adjacent children are merged into a single line.
+///
+/// Unlike CSS, where it applies to tables and is inherited, it is set on the container
+/// and applies to its in-flow children, like the cells of a grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum BorderCollapse {
+  /// Every child draws all of its borders.
+  #[default]
+  Separate,
+  /// Where two children touch, only the wider of the two facing borders is drawn.
+  Collapse,
+}
+
+declare_enum_from_css_impl!(
+  BorderCollapse,
+  "separate" => BorderCollapse::Separate,
+  "collapse" => BorderCollapse::Collapse,
+);
+
 impl From<ImageScalingAlgorithm> for FilterType {
   fn from(algorithm: ImageScalingAlgorithm) -> Self {
     match algorithm {
       ImageScalingAlgorithm::Auto => FilterType::CatmullRom,
       ImageScalingAlgorithm::Smooth => FilterType::Lanczos3,
       ImageScalingAlgorithm::Pixelated => FilterType::Nearest,
+      ImageScalingAlgorithm::CrispEdges => FilterType::Triangle,
     }
   }
 }
@@ -1010,6 +1237,9 @@ impl From<ImageScalingAlgorithm> for ResizeAlg {
         ResizeAlg::Convolution(fast_image_resize::FilterType::Lanczos3)
       }
       ImageScalingAlgorithm::Pixelated => ResizeAlg::Nearest,
+      ImageScalingAlgorithm::CrispEdges => {
+        ResizeAlg::Convolution(fast_image_resize::FilterType::Bilinear)
+      }
     }
   }
 }
@@ -22,6 +22,9 @@ fn hash_2d(x: u32, y: u32, seed: u32) -> u8 {
 /// Procedural noise gradient that generates organic, natural-looking patterns using fractal Brownian motion.
 /// This creates dynamic textures that can be used as backgrounds or overlays with customizable parameters
 /// for controlling the noise characteristics and visual appearance.
+///
+/// The noise comes from an integer hash of the device pixel coordinates rather than a platform RNG,
+/// so the output is deterministic given the seed, the tile size and the device pixel ratio.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct NoiseV1 {
   /// Random seed value that determines the unique noise pattern generated
@@ -100,3 +103,43 @@ impl<'i> FromCss<'i> for NoiseV1 {
     &[CssToken::Token("seed()"), CssToken::Token("opacity()")]
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn render_tile(noise: NoiseV1) -> Vec<Rgba<u8>> {
+    NoiseV1Tile::new(noise, 64, 64)
+      .pixels()
+      .map(|(_, _, pixel)| pixel)
+      .collect()
+  }
+
+  #[test]
+  fn test_parse_noise_v1_seed() {
+    assert_eq!(
+      NoiseV1::from_str("noise-v1(seed(42) opacity(0.5))"),
+      Ok(NoiseV1 {
+        seed: Some(42),
+        opacity: Some(0.5),
+      })
+    );
+  }
+
+  #[test]
+  fn test_noise_v1_same_seed_is_identical() {
+    let noise = NoiseV1 {
+      seed: Some(42),
+      opacity: Some(1.0),
+    };
+
+    assert_eq!(render_tile(noise), render_tile(noise));
+    assert_ne!(
+      render_tile(noise),
+      render_tile(NoiseV1 {
+        seed: Some(7),
+        ..noise
+      })
+    );
+  }
+}
@@ -1,7 +1,16 @@
-use cssparser::match_ignore_ascii_case;
+use cssparser::{Parser, Token, match_ignore_ascii_case};
 use taffy::Overflow as TaffyOverflow;
 
-use crate::layout::style::{declare_enum_from_css_impl, tw::TailwindPropertyParser};
+use crate::{
+  layout::style::{
+    CssToken, FromCss, Length, MakeComputed, ParseResult, declare_enum_from_css_impl,
+    tw::TailwindPropertyParser,
+  },
+  rendering::Sizing,
+};
+
+/// Width of the space reserved for a scrollbar gutter, in CSS pixels.
+const SCROLLBAR_GUTTER_WIDTH: f32 = 15.0;
 
 /// How children overflowing their container should affect layout
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -16,6 +25,10 @@ pub enum Overflow {
   /// The automatic minimum size of this node as a flexbox/grid item should be `0`.
   /// Content that overflows this node should *not* contribute to the scroll region of its parent.
   Hidden,
+  /// Like `hidden`, but the node is a scroll container. Since there is no interactivity, content is clipped.
+  Scroll,
+  /// Like `scroll`, but scrollbars are only shown when content overflows. Content is clipped.
+  Auto,
 }
 
 declare_enum_from_css_impl!(
@@ -23,6 +36,8 @@ declare_enum_from_css_impl!(
   "visible" => Overflow::Visible,
   "clip" => Overflow::Clip,
   "hidden" => Overflow::Hidden,
+  "scroll" => Overflow::Scroll,
+  "auto" => Overflow::Auto,
 );
 
 impl TailwindPropertyParser for Overflow {
@@ -31,6 +46,8 @@ impl TailwindPropertyParser for Overflow {
       "visible" => Some(Overflow::Visible),
       "clip" => Some(Overflow::Clip),
       "hidden" => Some(Overflow::Hidden),
+      "scroll" => Some(Overflow::Scroll),
+      "auto" => Some(Overflow::Auto),
       _ => None,
     }
   }
@@ -42,14 +59,81 @@ impl From<Overflow> for TaffyOverflow {
       Overflow::Visible => TaffyOverflow::Visible,
       Overflow::Clip => TaffyOverflow::Clip,
       Overflow::Hidden => TaffyOverflow::Hidden,
+      Overflow::Scroll => TaffyOverflow::Scroll,
+      // Scrollbars never show up in a static image, so `auto` lays out like `hidden`.
+      Overflow::Auto => TaffyOverflow::Hidden,
     }
   }
 }
 
+impl Overflow {
+  /// Whether this node is a scroll container that may reserve a scrollbar gutter.
+  pub(crate) fn is_scroll_container(self) -> bool {
+    matches!(self, Overflow::Hidden | Overflow::Scroll | Overflow::Auto)
+  }
+}
+
+/// Controls whether space is reserved for the vertical scrollbar of a scroll container.
+///
+/// Scrollbars are never painted, so the gutter is left empty.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScrollbarGutter {
+  /// No space is reserved, as if scrollbars were overlaid on the content.
+  #[default]
+  Auto,
+  /// Space is reserved on the inline-end edge when `overflow-y` is `hidden`, `scroll` or `auto`.
+  Stable,
+  /// Like `stable`, but the same space is also reserved on the inline-start edge.
+  StableBothEdges,
+}
+
+impl MakeComputed for ScrollbarGutter {}
+
+impl<'i> FromCss<'i> for ScrollbarGutter {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?.clone();
+
+    match_ignore_ascii_case! { &ident,
+      "auto" => Ok(ScrollbarGutter::Auto),
+      "stable" => {
+        if input
+          .try_parse(|input| input.expect_ident_matching("both-edges"))
+          .is_ok()
+        {
+          return Ok(ScrollbarGutter::StableBothEdges);
+        }
+
+        Ok(ScrollbarGutter::Stable)
+      },
+      _ => Err(Self::unexpected_token_error(location, &Token::Ident(ident.clone()))),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("auto"),
+      CssToken::Keyword("stable"),
+      CssToken::Keyword("stable both-edges"),
+    ]
+  }
+}
+
+impl ScrollbarGutter {
+  /// Returns the gutter width in device pixels, or `0` when no gutter is reserved for `overflow_y`.
+  pub(crate) fn to_px(self, overflow_y: Overflow, sizing: &Sizing) -> f32 {
+    if self == ScrollbarGutter::Auto || !overflow_y.is_scroll_container() {
+      return 0.0;
+    }
+
+    Length::<false>::Px(SCROLLBAR_GUTTER_WIDTH).to_px(sizing, 0.0)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::layout::style::FromCss;
+  use crate::layout::style::{FromCss, SpacePair};
 
   #[test]
   fn parses_css_clip() {
@@ -60,4 +144,33 @@ mod tests {
   fn parses_tailwind_clip() {
     assert_eq!(Overflow::parse_tw("clip"), Some(Overflow::Clip));
   }
+
+  #[test]
+  fn parses_scrollbar_gutter_keywords() {
+    assert_eq!(ScrollbarGutter::from_str("auto"), Ok(ScrollbarGutter::Auto));
+    assert_eq!(
+      ScrollbarGutter::from_str("stable"),
+      Ok(ScrollbarGutter::Stable)
+    );
+    assert_eq!(
+      ScrollbarGutter::from_str("stable both-edges"),
+      Ok(ScrollbarGutter::StableBothEdges)
+    );
+  }
+
+  #[test]
+  fn parses_shorthand_single_value_for_both_axes() {
+    assert_eq!(
+      SpacePair::<Overflow>::from_str("hidden"),
+      Ok(SpacePair::from_single(Overflow::Hidden))
+    );
+  }
+
+  #[test]
+  fn parses_shorthand_two_values_as_x_then_y() {
+    assert_eq!(
+      SpacePair::<Overflow>::from_str("hidden auto"),
+      Ok(SpacePair::from_pair(Overflow::Hidden, Overflow::Auto))
+    );
+  }
 }
@@ -1,4 +1,4 @@
-use cssparser::Parser;
+use cssparser::{BasicParseErrorKind, Parser, Token, match_ignore_ascii_case};
 use image::{GenericImageView, Rgba};
 
 use super::gradient_utils::{
@@ -28,6 +28,7 @@ pub struct RadialGradient {
 impl MakeComputed for RadialGradient {
   fn make_computed(&mut self, sizing: &Sizing) {
     self.center.make_computed(sizing);
+    self.size.make_computed(sizing);
     self.stops.make_computed(sizing);
   }
 }
@@ -48,7 +49,7 @@ declare_enum_from_css_impl!(
   "ellipse" => RadialShape::Ellipse,
 );
 
-/// Supported size keywords for radial gradients
+/// Supported sizes for radial gradients
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum RadialSize {
   /// The gradient end stops at the nearest side from the center
@@ -60,15 +61,50 @@ pub enum RadialSize {
   /// The gradient end stops at the farthest corner from the center
   #[default]
   FarthestCorner,
+  /// Explicit radii, equal for a circle
+  Explicit {
+    /// Horizontal radius, percentages resolve against the width
+    radius_x: Length<false>,
+    /// Vertical radius, percentages resolve against the height
+    radius_y: Length<false>,
+  },
 }
 
-declare_enum_from_css_impl!(
-  RadialSize,
-  "closest-side" => RadialSize::ClosestSide,
-  "farthest-side" => RadialSize::FarthestSide,
-  "closest-corner" => RadialSize::ClosestCorner,
-  "farthest-corner" => RadialSize::FarthestCorner,
-);
+impl<'i> FromCss<'i> for RadialSize {
+  /// Parses a size keyword. Explicit radii are parsed by [`RadialGradient`], as their count depends on the shape.
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+
+    match_ignore_ascii_case! {
+      &ident,
+      "closest-side" => Ok(RadialSize::ClosestSide),
+      "farthest-side" => Ok(RadialSize::FarthestSide),
+      "closest-corner" => Ok(RadialSize::ClosestCorner),
+      "farthest-corner" => Ok(RadialSize::FarthestCorner),
+      _ => Err(Self::unexpected_token_error(location, &Token::Ident(ident.clone()))),
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("closest-side"),
+      CssToken::Keyword("farthest-side"),
+      CssToken::Keyword("closest-corner"),
+      CssToken::Keyword("farthest-corner"),
+      CssToken::Token("length"),
+    ]
+  }
+}
+
+impl MakeComputed for RadialSize {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    if let Self::Explicit { radius_x, radius_y } = self {
+      radius_x.make_computed(sizing);
+      radius_y.make_computed(sizing);
+    }
+  }
+}
 
 /// Precomputed drawing context for repeated sampling of a `RadialGradient`.
 #[derive(Debug, Clone)]
@@ -132,8 +168,8 @@ impl RadialGradientTile {
     context: &RenderContext,
     buffer_pool: &mut crate::rendering::BufferPool,
   ) -> Self {
-    let cx = Length::from(gradient.center.0.x).to_px(&context.sizing, width as f32);
-    let cy = Length::from(gradient.center.0.y).to_px(&context.sizing, height as f32);
+    let cx = gradient.center.0.x.to_px(&context.sizing, width as f32);
+    let cy = gradient.center.0.y.to_px(&context.sizing, height as f32);
 
     // Distances to sides and corners
     let dx_left = cx;
@@ -142,6 +178,10 @@ impl RadialGradientTile {
     let dy_bottom = height as f32 - cy;
 
     let (radius_x, radius_y) = match (gradient.shape, gradient.size) {
+      (_, RadialSize::Explicit { radius_x, radius_y }) => (
+        radius_x.to_px(&context.sizing, width as f32),
+        radius_y.to_px(&context.sizing, height as f32),
+      ),
       (RadialShape::Ellipse, RadialSize::FarthestCorner) => {
         // ellipse radii to farthest corner: take farthest side per axis
         (dx_left.max(dx_right), dy_top.max(dy_bottom))
@@ -229,13 +269,20 @@ impl<'i> FromCss<'i> for RadialGradient {
     input.expect_function_matching("radial-gradient")?;
 
     input.parse_nested_block(|input| {
-      let mut shape = RadialShape::Ellipse;
+      let mut shape = None;
       let mut size = RadialSize::FarthestCorner;
+      let mut radii = None;
       let mut center = BackgroundPosition::default();
 
       loop {
         if let Ok(s) = input.try_parse(RadialShape::from_css) {
-          shape = s;
+          shape = Some(s);
+          continue;
+        }
+
+        if let Ok(radius_x) = input.try_parse(Length::<false>::from_css) {
+          let radius_y = input.try_parse(Length::<false>::from_css).ok();
+          radii = Some((radius_x, radius_y));
           continue;
         }
 
@@ -254,6 +301,25 @@ impl<'i> FromCss<'i> for RadialGradient {
         break;
       }
 
+      // A single radius makes a circle and two make an ellipse, percentages only apply to ellipses.
+      let shape = match (radii, shape) {
+        (None, shape) => shape.unwrap_or_default(),
+        (Some((radius, None)), None | Some(RadialShape::Circle))
+          if !matches!(radius, Length::Percentage(_)) =>
+        {
+          size = RadialSize::Explicit {
+            radius_x: radius,
+            radius_y: radius,
+          };
+          RadialShape::Circle
+        }
+        (Some((radius_x, Some(radius_y))), None | Some(RadialShape::Ellipse)) => {
+          size = RadialSize::Explicit { radius_x, radius_y };
+          RadialShape::Ellipse
+        }
+        _ => return Err(input.new_error(BasicParseErrorKind::QualifiedRuleInvalid)),
+      };
+
       let stops = GradientStops::from_css(input)?;
 
       Ok(RadialGradient {
@@ -446,6 +512,85 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_radial_gradient_circle_explicit_radius() {
+    let gradient = RadialGradient::from_str("radial-gradient(circle 100px at center, red, blue)");
+
+    assert_eq!(
+      gradient.map(|gradient| (gradient.shape, gradient.size)),
+      Ok((
+        RadialShape::Circle,
+        RadialSize::Explicit {
+          radius_x: Length::Px(100.0),
+          radius_y: Length::Px(100.0),
+        }
+      ))
+    );
+  }
+
+  #[test]
+  fn test_parse_radial_gradient_single_radius_implies_circle() {
+    let gradient = RadialGradient::from_str("radial-gradient(40px, red, blue)");
+
+    assert_eq!(
+      gradient.map(|gradient| gradient.shape),
+      Ok(RadialShape::Circle)
+    );
+  }
+
+  #[test]
+  fn test_parse_radial_gradient_ellipse_explicit_radii() {
+    let gradient =
+      RadialGradient::from_str("radial-gradient(ellipse 50% 30% at 25% 75%, red, blue)");
+
+    assert_eq!(
+      gradient.map(|gradient| (gradient.shape, gradient.size)),
+      Ok((
+        RadialShape::Ellipse,
+        RadialSize::Explicit {
+          radius_x: Length::Percentage(50.0),
+          radius_y: Length::Percentage(30.0),
+        }
+      ))
+    );
+  }
+
+  #[test]
+  fn test_parse_radial_gradient_rejects_mismatched_radii() {
+    assert!(RadialGradient::from_str("radial-gradient(circle 10px 20px, red, blue)").is_err());
+    assert!(RadialGradient::from_str("radial-gradient(ellipse 10px, red, blue)").is_err());
+    assert!(RadialGradient::from_str("radial-gradient(circle 50%, red, blue)").is_err());
+  }
+
+  #[test]
+  fn test_radial_gradient_explicit_circle_radius() {
+    let gradient = RadialGradient::from_str("radial-gradient(circle 100px at center, red, blue)");
+
+    let context = GlobalContext::default();
+    let dummy_context = RenderContext::new(&context, (400, 400).into(), Default::default());
+    let mut buffer_pool = crate::rendering::BufferPool::default();
+    let tile = gradient.map(|gradient| {
+      RadialGradientTile::new(&gradient, 400, 400, &dummy_context, &mut buffer_pool)
+    });
+
+    assert!(
+      tile
+        .as_ref()
+        .is_ok_and(|tile| tile.radius_x == 100.0 && tile.radius_y == 100.0)
+    );
+
+    if let Ok(tile) = tile {
+      assert_eq!(tile.get_pixel(200, 200), Rgba([255, 0, 0, 255]));
+
+      // The last stop is reached 100px from the center, not at the farthest corner.
+      assert_eq!(tile.get_pixel(300, 200), Rgba([0, 0, 255, 255]));
+      assert_eq!(tile.get_pixel(200, 100), Rgba([0, 0, 255, 255]));
+
+      let Rgba([r, _, b, _]) = tile.get_pixel(250, 200);
+      assert!(r > 64 && b > 64);
+    }
+  }
+
   #[test]
   fn resolve_stops_percentage_and_px_radial() {
     let gradient = RadialGradient {
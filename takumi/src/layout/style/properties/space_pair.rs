@@ -4,7 +4,8 @@ use taffy::{LengthPercentage, Point, Size};
 
 use crate::{
   layout::style::{
-    CssToken, FromCss, Length, MakeComputed, Overflow, ParseResult, merge_enum_values,
+    AlignItems, CssToken, FromCss, JustifyContent, Length, MakeComputed, Overflow, ParseResult,
+    merge_enum_values,
   },
   rendering::Sizing,
 };
@@ -21,6 +22,12 @@ pub struct SpacePair<T: Copy, const Y_FIRST: bool = false> {
 /// A pair of gap values which has the vertical value first.
 pub type Gap = SpacePair<Length<false>, true>;
 
+/// A pair of `align-content` and `justify-content` values for the `place-content` shorthand.
+pub type PlaceContent = SpacePair<JustifyContent, true>;
+
+/// A pair of `align-items` and `justify-items` values for the `place-items` shorthand.
+pub type PlaceItems = SpacePair<AlignItems, true>;
+
 impl<T: Copy + Default, const Y_FIRST: bool> Default for SpacePair<T, Y_FIRST> {
   fn default() -> Self {
     Self::from_single(T::default())
@@ -0,0 +1,93 @@
+use cssparser::Parser;
+
+use crate::{
+  layout::style::{CssToken, FromCss, Length, MakeComputed, ParseResult},
+  rendering::Sizing,
+};
+
+/// Represents the `tab-size` property, the width of a tab in preserved white space.
+///
+/// Tabs advance to the next multiple of the tab size rather than by a fixed amount,
+/// so columns of preformatted code line up.
+///
+/// Tabs are expanded to spaces, so tab stops are counted in characters rather than pixels. A length
+/// is converted to a number of spaces by the advance of the `0` glyph, so it is only exact in
+/// monospace fonts, where every character has that advance.
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TabSize {
+  /// A number of space characters.
+  Spaces(f32),
+  /// A length, rounded to a whole number of `0` glyph advances and drawn as that many spaces.
+  Length(Length),
+}
+
+impl Default for TabSize {
+  fn default() -> Self {
+    Self::Spaces(8.0)
+  }
+}
+
+impl TabSize {
+  /// Resolves the tab size to a number of columns.
+  ///
+  /// Lengths are measured in advances of the `0` glyph, which is exact for monospace fonts.
+  pub(crate) fn columns(self, sizing: &Sizing) -> usize {
+    let columns = match self {
+      Self::Spaces(spaces) => spaces,
+      Self::Length(length) => {
+        let advance = sizing.font_size * sizing.font_metrics.ch;
+
+        if advance <= 0.0 {
+          return 0;
+        }
+
+        length.to_px(sizing, sizing.font_size) / advance
+      }
+    };
+
+    columns.round().max(0.0) as usize
+  }
+}
+
+impl MakeComputed for TabSize {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    if let Self::Length(length) = self {
+      length.make_computed(sizing);
+    }
+  }
+}
+
+impl<'i> FromCss<'i> for TabSize {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let location = input.current_source_location();
+
+    let Ok(number) = input.try_parse(Parser::expect_number) else {
+      return Length::from_css(input).map(Self::Length);
+    };
+
+    if number < 0.0 {
+      return Err(location.new_custom_error(Self::expect_message()));
+    }
+
+    Ok(Self::Spaces(number))
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[CssToken::Token("number"), CssToken::Token("length")]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_tab_size() {
+    assert_eq!(TabSize::from_str("4"), Ok(TabSize::Spaces(4.0)));
+    assert_eq!(
+      TabSize::from_str("2em"),
+      Ok(TabSize::Length(Length::Em(2.0)))
+    );
+    assert!(TabSize::from_str("-1").is_err());
+  }
+}
@@ -0,0 +1,240 @@
+use cssparser::{Parser, match_ignore_ascii_case};
+
+use crate::{
+  layout::style::{ColorInput, CssToken, FromCss, MakeComputed, ParseResult},
+  rendering::Sizing,
+};
+
+/// Whether emphasis marks are filled or drawn as an outline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextEmphasisFill {
+  /// The marks are filled with the emphasis color.
+  #[default]
+  Filled,
+  /// The marks are hollow, only their outline is drawn.
+  Open,
+}
+
+/// The shape of emphasis marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextEmphasisShape {
+  /// A small dot.
+  Dot,
+  /// A large circle.
+  #[default]
+  Circle,
+  /// A dot surrounded by a ring.
+  DoubleCircle,
+  /// An upward pointing triangle.
+  Triangle,
+}
+
+/// Parsed `text-emphasis-style` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextEmphasisStyle {
+  /// No emphasis marks.
+  #[default]
+  None,
+  /// Draw a mark above each emphasized character.
+  Mark {
+    /// Whether the mark is filled or open.
+    fill: TextEmphasisFill,
+    /// The shape of the mark.
+    shape: TextEmphasisShape,
+  },
+}
+
+impl MakeComputed for TextEmphasisStyle {}
+
+enum EmphasisKeyword {
+  Fill(TextEmphasisFill),
+  Shape(TextEmphasisShape),
+}
+
+impl<'i> FromCss<'i> for TextEmphasisStyle {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    if input
+      .try_parse(|input| input.expect_ident_matching("none"))
+      .is_ok()
+    {
+      return Ok(TextEmphasisStyle::None);
+    }
+
+    let mut fill = None;
+    let mut shape = None;
+
+    // `filled`/`open` and the shape can appear in any order, each at most once.
+    while fill.is_none() || shape.is_none() {
+      let state = input.state();
+      let Ok(ident) = input.expect_ident() else {
+        input.reset(&state);
+        break;
+      };
+
+      let keyword = match_ignore_ascii_case! {ident,
+        "filled" => Some(EmphasisKeyword::Fill(TextEmphasisFill::Filled)),
+        "open" => Some(EmphasisKeyword::Fill(TextEmphasisFill::Open)),
+        "dot" => Some(EmphasisKeyword::Shape(TextEmphasisShape::Dot)),
+        "circle" => Some(EmphasisKeyword::Shape(TextEmphasisShape::Circle)),
+        "double-circle" => Some(EmphasisKeyword::Shape(TextEmphasisShape::DoubleCircle)),
+        "triangle" => Some(EmphasisKeyword::Shape(TextEmphasisShape::Triangle)),
+        _ => None,
+      };
+
+      match keyword {
+        Some(EmphasisKeyword::Fill(value)) if fill.is_none() => fill = Some(value),
+        Some(EmphasisKeyword::Shape(value)) if shape.is_none() => shape = Some(value),
+        _ => {
+          input.reset(&state);
+          break;
+        }
+      }
+    }
+
+    if fill.is_none() && shape.is_none() {
+      return Err(Self::unexpected_token_error(
+        input.current_source_location(),
+        input.next()?,
+      ));
+    }
+
+    Ok(TextEmphasisStyle::Mark {
+      fill: fill.unwrap_or_default(),
+      shape: shape.unwrap_or_default(),
+    })
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("none"),
+      CssToken::Keyword("filled"),
+      CssToken::Keyword("open"),
+      CssToken::Keyword("dot"),
+      CssToken::Keyword("circle"),
+      CssToken::Keyword("double-circle"),
+      CssToken::Keyword("triangle"),
+    ]
+  }
+}
+
+/// Parsed `text-emphasis` value.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TextEmphasis {
+  /// The emphasis mark style.
+  pub style: TextEmphasisStyle,
+  /// Optional emphasis mark color, defaults to `currentColor`.
+  pub color: Option<ColorInput>,
+}
+
+impl MakeComputed for TextEmphasis {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    self.color.make_computed(sizing);
+  }
+}
+
+impl<'i> FromCss<'i> for TextEmphasis {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let mut style = None;
+    let mut color = None;
+
+    while !input.is_exhausted() {
+      if style.is_none()
+        && let Ok(value) = input.try_parse(TextEmphasisStyle::from_css)
+      {
+        style = Some(value);
+        continue;
+      }
+
+      if color.is_none()
+        && let Ok(value) = input.try_parse(ColorInput::from_css)
+      {
+        color = Some(value);
+        continue;
+      }
+
+      return Err(Self::unexpected_token_error(
+        input.current_source_location(),
+        input.next()?,
+      ));
+    }
+
+    Ok(TextEmphasis {
+      style: style.unwrap_or_default(),
+      color,
+    })
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("none"),
+      CssToken::Keyword("filled"),
+      CssToken::Keyword("open"),
+      CssToken::Keyword("dot"),
+      CssToken::Keyword("circle"),
+      CssToken::Keyword("double-circle"),
+      CssToken::Keyword("triangle"),
+      CssToken::Token("color"),
+    ]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::layout::style::properties::Color;
+
+  #[test]
+  fn test_parse_text_emphasis_style_keywords() {
+    assert_eq!(
+      TextEmphasisStyle::from_str("open dot"),
+      Ok(TextEmphasisStyle::Mark {
+        fill: TextEmphasisFill::Open,
+        shape: TextEmphasisShape::Dot,
+      })
+    );
+    assert_eq!(
+      TextEmphasisStyle::from_str("triangle filled"),
+      Ok(TextEmphasisStyle::Mark {
+        fill: TextEmphasisFill::Filled,
+        shape: TextEmphasisShape::Triangle,
+      })
+    );
+    assert_eq!(
+      TextEmphasisStyle::from_str("none"),
+      Ok(TextEmphasisStyle::None)
+    );
+  }
+
+  #[test]
+  fn test_parse_text_emphasis_style_defaults() {
+    assert_eq!(
+      TextEmphasisStyle::from_str("open"),
+      Ok(TextEmphasisStyle::Mark {
+        fill: TextEmphasisFill::Open,
+        shape: TextEmphasisShape::Circle,
+      })
+    );
+    assert_eq!(
+      TextEmphasisStyle::from_str("dot"),
+      Ok(TextEmphasisStyle::Mark {
+        fill: TextEmphasisFill::Filled,
+        shape: TextEmphasisShape::Dot,
+      })
+    );
+    assert!(TextEmphasis::from_str("dot circle").is_err());
+  }
+
+  #[test]
+  fn test_parse_text_emphasis_shorthand() {
+    assert_eq!(
+      TextEmphasis::from_str("filled dot red"),
+      Ok(TextEmphasis {
+        style: TextEmphasisStyle::Mark {
+          fill: TextEmphasisFill::Filled,
+          shape: TextEmphasisShape::Dot,
+        },
+        color: Some(ColorInput::Value(Color([255, 0, 0, 255]))),
+      })
+    );
+  }
+}
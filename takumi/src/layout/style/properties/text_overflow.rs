@@ -12,6 +12,9 @@ pub enum TextOverflow {
   Clip,
   /// Text is truncated with an ellipsis (…) at the end when it overflows
   Ellipsis,
+  /// Text keeps its start and end and the middle is replaced with an ellipsis (…), set by the
+  /// non-standard `ellipsis-middle` keyword. Useful for file paths and URLs.
+  EllipsisMiddle,
   /// Text is truncated with a custom string at the end when it overflows
   Custom(String),
 }
@@ -25,6 +28,7 @@ impl<'i> FromCss<'i> for TextOverflow {
     match_ignore_ascii_case! {string,
       "clip" => Ok(TextOverflow::Clip),
       "ellipsis" => Ok(TextOverflow::Ellipsis),
+      "ellipsis-middle" => Ok(TextOverflow::EllipsisMiddle),
       _ => Ok(TextOverflow::Custom(string.to_string())),
     }
   }
@@ -33,6 +37,7 @@ impl<'i> FromCss<'i> for TextOverflow {
     &[
       CssToken::Keyword("clip"),
       CssToken::Keyword("ellipsis"),
+      CssToken::Keyword("ellipsis-middle"),
       CssToken::Token("string"),
     ]
   }
@@ -4,12 +4,40 @@ use cssparser::{Parser, Token, match_ignore_ascii_case};
 use taffy::{Point, Size};
 
 use crate::{
-  layout::style::{Angle, CssToken, FromCss, Length, MakeComputed, ParseResult, PercentageNumber},
+  layout::style::{
+    Angle, CssToken, FromCss, Length, MakeComputed, ParseResult, PercentageNumber,
+    declare_enum_from_css_impl,
+  },
   rendering::Sizing,
 };
 
 const DEFAULT_SCALE: f32 = 1.0;
 
+/// The box that `transform-origin` and percentages in transforms refer to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TransformBox {
+  /// The content box.
+  ContentBox,
+  /// The border box.
+  #[default]
+  BorderBox,
+  /// The object bounding box, which is the content box for boxes laid out with CSS.
+  FillBox,
+  /// The stroke bounding box, which is the border box for boxes laid out with CSS.
+  StrokeBox,
+  /// The nearest SVG viewport, which is the border box for boxes laid out with CSS.
+  ViewBox,
+}
+
+declare_enum_from_css_impl!(
+  TransformBox,
+  "content-box" => TransformBox::ContentBox,
+  "border-box" => TransformBox::BorderBox,
+  "fill-box" => TransformBox::FillBox,
+  "stroke-box" => TransformBox::StrokeBox,
+  "view-box" => TransformBox::ViewBox
+);
+
 /// Represents a single CSS transform operation
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Transform {
@@ -243,7 +271,12 @@ impl Affine {
         Transform::Scale(x_scale, y_scale) => Affine::scale(x_scale, y_scale),
         Transform::Rotate(angle) => Affine::rotation(angle),
         Transform::Skew(x_angle, y_angle) => Affine::skew(x_angle, y_angle),
-        Transform::Matrix(affine) => affine,
+        // The translation components are CSS pixels, so they are scaled like `translate()`.
+        Transform::Matrix(affine) => Affine {
+          x: Length::<false>::Px(affine.x).to_px(sizing, border_box.width),
+          y: Length::<false>::Px(affine.y).to_px(sizing, border_box.height),
+          ..affine
+        },
       };
     }
 
@@ -285,6 +318,32 @@ impl<'i> FromCss<'i> for Affine {
   }
 }
 
+impl Affine {
+  /// Parses the 16 values of `matrix3d()` and flattens them into a 2D transform.
+  ///
+  /// Only the components that affect the XY plane are kept, the depth and perspective ones are dropped.
+  fn from_css_matrix3d<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    let mut values = [0.0; 16];
+
+    for (index, value) in values.iter_mut().enumerate() {
+      if index > 0 {
+        input.expect_comma()?;
+      }
+
+      *value = input.expect_number()?;
+    }
+
+    Ok(Affine {
+      a: values[0],
+      b: values[1],
+      c: values[4],
+      d: values[5],
+      x: values[12],
+      y: values[13],
+    })
+  }
+}
+
 /// A collection of transform operations that can be applied together
 pub type Transforms = Box<[Transform]>;
 
@@ -353,7 +412,11 @@ impl<'i> FromCss<'i> for Transform {
       ))),
       "skew" => parser.parse_nested_block(|input| {
         let x = Angle::from_css(input)?;
-        input.expect_comma()?;
+
+        if input.try_parse(Parser::expect_comma).is_err() {
+          return Ok(Transform::Skew(x, Angle::default()));
+        }
+
         let y = Angle::from_css(input)?;
 
         Ok(Transform::Skew(x, y))
@@ -372,6 +435,9 @@ impl<'i> FromCss<'i> for Transform {
       "matrix" => parser.parse_nested_block(|input| Ok(Transform::Matrix(
         Affine::from_css(input)?,
       ))),
+      "matrix3d" => parser.parse_nested_block(|input| Ok(Transform::Matrix(
+        Affine::from_css_matrix3d(input)?,
+      ))),
       _ => Err(Self::unexpected_token_error(location, token)),
     }
   }
@@ -384,6 +450,36 @@ impl<'i> FromCss<'i> for Transform {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::{GlobalContext, rendering::RenderContext};
+
+  fn compose(source: &str) -> Option<Affine> {
+    let context = GlobalContext::default();
+    let render_context = RenderContext::new(&context, (40, 40).into(), Default::default());
+
+    Transforms::from_str(source).ok().map(|transforms| {
+      Affine::from_transforms(
+        transforms.iter(),
+        &render_context.sizing,
+        Size {
+          width: 40.0,
+          height: 40.0,
+        },
+      )
+    })
+  }
+
+  fn assert_affine_near(lhs: Option<Affine>, rhs: Affine) {
+    assert!(
+      lhs.is_some_and(|lhs| {
+        lhs
+          .to_cols_array()
+          .iter()
+          .zip(rhs.to_cols_array())
+          .all(|(lhs, rhs)| (lhs - rhs).abs() < 1e-5)
+      }),
+      "lhs={lhs:?}, rhs={rhs:?}"
+    );
+  }
 
   #[test]
   fn test_transform_from_str() {
@@ -417,4 +513,58 @@ mod tests {
         && (random_point.y - processed_point.y).abs() < 1.0
     }));
   }
+
+  #[test]
+  fn test_transform_skew_x_affine() {
+    assert_affine_near(
+      compose("skewX(30deg)"),
+      Affine {
+        c: 30.0f32.to_radians().tan(),
+        ..Affine::IDENTITY
+      },
+    );
+  }
+
+  #[test]
+  fn test_transform_skew_single_angle() {
+    assert_eq!(
+      Transform::from_str("skew(10deg)"),
+      Ok(Transform::Skew(Angle::new(10.0), Angle::default()))
+    );
+  }
+
+  #[test]
+  fn test_transform_matrix_affine() {
+    assert_affine_near(
+      compose("matrix(1, 0, 0, 1, 10, 20)"),
+      Affine::translation(10.0, 20.0),
+    );
+  }
+
+  #[test]
+  fn test_transform_matrix3d_is_flattened() {
+    assert_affine_near(
+      compose("matrix3d(2, 0, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 10, 20, 5, 1)"),
+      Affine {
+        a: 2.0,
+        d: 3.0,
+        x: 10.0,
+        y: 20.0,
+        ..Affine::IDENTITY
+      },
+    );
+  }
+
+  #[test]
+  fn test_transforms_compose_left_to_right() {
+    assert_affine_near(
+      compose("translate(10px, 0) scale(2)"),
+      Affine {
+        a: 2.0,
+        d: 2.0,
+        x: 10.0,
+        ..Affine::IDENTITY
+      },
+    );
+  }
 }
@@ -1,6 +1,10 @@
+use cssparser::{Parser, Token, match_ignore_ascii_case};
 use parley::LineMetrics;
 
-use crate::layout::style::{tw::TailwindPropertyParser, *};
+use crate::{
+  layout::style::{tw::TailwindPropertyParser, *},
+  rendering::Sizing,
+};
 
 /// Defines the vertical alignment of an inline-level box.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -22,27 +26,91 @@ pub enum VerticalAlign {
   Sub,
   /// Aligns the baseline of the box with the superscript-baseline of the parent box.
   Super,
+  /// Raises the baseline of the box by the length, percentages refer to the line height.
+  Length(Length<false>),
 }
 
-declare_enum_from_css_impl!(
-  VerticalAlign,
-  "baseline" => VerticalAlign::Baseline,
-  "top" => VerticalAlign::Top,
-  "middle" => VerticalAlign::Middle,
-  "bottom" => VerticalAlign::Bottom,
-  "text-top" => VerticalAlign::TextTop,
-  "text-bottom" => VerticalAlign::TextBottom,
-  "sub" => VerticalAlign::Sub,
-  "super" => VerticalAlign::Super
-);
+/// Baseline shift of `sub` text runs relative to the parent font size, positive being downwards.
+const SUB_BASELINE_SHIFT: f32 = 0.2;
+/// Baseline shift of `super` text runs relative to the parent font size, positive being downwards.
+const SUPER_BASELINE_SHIFT: f32 = -0.34;
+
+impl MakeComputed for VerticalAlign {
+  fn make_computed(&mut self, sizing: &Sizing) {
+    if let Self::Length(length) = self {
+      length.make_computed(sizing);
+    }
+  }
+}
+
+impl<'i> FromCss<'i> for VerticalAlign {
+  fn from_css(input: &mut Parser<'i, '_>) -> ParseResult<'i, Self> {
+    // `auto` isn't a valid `vertical-align`, so it falls through to the keyword error below.
+    if let Ok(length) = input.try_parse(Length::from_css)
+      && length != Length::Auto
+    {
+      return Ok(VerticalAlign::Length(length));
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+
+    match_ignore_ascii_case! {ident,
+      "baseline" => Ok(VerticalAlign::Baseline),
+      "top" => Ok(VerticalAlign::Top),
+      "middle" => Ok(VerticalAlign::Middle),
+      "bottom" => Ok(VerticalAlign::Bottom),
+      "text-top" => Ok(VerticalAlign::TextTop),
+      "text-bottom" => Ok(VerticalAlign::TextBottom),
+      "sub" => Ok(VerticalAlign::Sub),
+      "super" => Ok(VerticalAlign::Super),
+      _ => {
+        let token = Token::Ident(ident.clone());
+        Err(Self::unexpected_token_error(location, &token))
+      }
+    }
+  }
+
+  fn valid_tokens() -> &'static [CssToken] {
+    &[
+      CssToken::Keyword("baseline"),
+      CssToken::Keyword("top"),
+      CssToken::Keyword("middle"),
+      CssToken::Keyword("bottom"),
+      CssToken::Keyword("text-top"),
+      CssToken::Keyword("text-bottom"),
+      CssToken::Keyword("sub"),
+      CssToken::Keyword("super"),
+      CssToken::Token("length"),
+    ]
+  }
+}
 
 impl VerticalAlign {
+  /// Returns the baseline shift of a text run, positive being downwards.
+  ///
+  /// Only `sub`, `super` and lengths move text runs, the other keywords align whole boxes.
+  pub(crate) fn text_baseline_shift(
+    self,
+    sizing: &Sizing,
+    parent_font_size: f32,
+    line_height: LineHeight,
+  ) -> f32 {
+    match self {
+      VerticalAlign::Sub => parent_font_size * SUB_BASELINE_SHIFT,
+      VerticalAlign::Super => parent_font_size * SUPER_BASELINE_SHIFT,
+      VerticalAlign::Length(length) => -length.to_px(sizing, line_height.to_px(sizing)),
+      _ => 0.0,
+    }
+  }
+
   pub(crate) fn apply(
     self,
     y: &mut f32,
     metrics: &LineMetrics,
     box_height: f32,
     parent_x_height: Option<f32>,
+    sizing: &Sizing,
   ) {
     match self {
       VerticalAlign::Baseline => *y = metrics.baseline - box_height,
@@ -62,6 +130,9 @@ impl VerticalAlign {
       VerticalAlign::TextBottom => *y = metrics.baseline + metrics.descent - box_height,
       VerticalAlign::Sub => *y = metrics.baseline + (metrics.descent * 0.2), // Places top below baseline
       VerticalAlign::Super => *y = metrics.baseline - metrics.ascent + (metrics.ascent * 0.4), // Places top high up
+      VerticalAlign::Length(length) => {
+        *y = metrics.baseline - box_height - length.to_px(sizing, metrics.line_height);
+      }
     }
   }
 }
@@ -71,3 +142,28 @@ impl TailwindPropertyParser for VerticalAlign {
     Self::from_str(token).ok()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_vertical_align_length() {
+    assert_eq!(
+      VerticalAlign::from_str("4px"),
+      Ok(VerticalAlign::Length(Length::Px(4.0)))
+    );
+    assert_eq!(
+      VerticalAlign::from_str("-25%"),
+      Ok(VerticalAlign::Length(Length::Percentage(-25.0)))
+    );
+    assert_eq!(VerticalAlign::from_str("super"), Ok(VerticalAlign::Super));
+  }
+
+  #[test]
+  fn test_parse_vertical_align_rejects_non_length_keywords() {
+    for value in ["auto", "min-content", "max-content", "fit-content"] {
+      assert!(VerticalAlign::from_str(value).is_err(), "{value}");
+    }
+  }
+}
@@ -24,6 +24,7 @@ impl TailwindPropertyParser for WhiteSpace {
       "pre" => Some(WhiteSpace::pre()),
       "pre-wrap" => Some(WhiteSpace::pre_wrap()),
       "pre-line" => Some(WhiteSpace::pre_line()),
+      "break-spaces" => Some(WhiteSpace::break_spaces()),
       _ => None,
     }
   }
@@ -69,6 +70,14 @@ impl WhiteSpace {
       white_space_collapse: WhiteSpaceCollapse::PreserveBreaks,
     }
   }
+
+  /// Creates a `WhiteSpace` instance with `break-spaces` behavior, rendered like `pre-wrap`.
+  pub const fn break_spaces() -> Self {
+    Self {
+      text_wrap_mode: TextWrapMode::Wrap,
+      white_space_collapse: WhiteSpaceCollapse::BreakSpaces,
+    }
+  }
 }
 
 fn parse_white_space_keyword<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i, WhiteSpace> {
@@ -80,6 +89,7 @@ fn parse_white_space_keyword<'i>(input: &mut Parser<'i, '_>) -> ParseResult<'i,
     "pre" => Ok(WhiteSpace::pre()),
     "pre-wrap" => Ok(WhiteSpace::pre_wrap()),
     "pre-line" => Ok(WhiteSpace::pre_line()),
+    "break-spaces" => Ok(WhiteSpace::break_spaces()),
     _ => Err(location.new_basic_unexpected_token_error(Token::Ident(ident.clone())).into())
   }
 }
@@ -121,6 +131,7 @@ impl<'i> FromCss<'i> for WhiteSpace {
       CssToken::Keyword("pre"),
       CssToken::Keyword("pre-wrap"),
       CssToken::Keyword("pre-line"),
+      CssToken::Keyword("break-spaces"),
       CssToken::Token("text-wrap-mode"),
       CssToken::Token("white-space-collapse"),
     ]
@@ -135,4 +146,17 @@ mod tests {
   fn test_parse_white_space_no_wrap() {
     assert_eq!(WhiteSpace::from_str("nowrap"), Ok(WhiteSpace::no_wrap()));
   }
+
+  #[test]
+  fn test_parse_white_space_keywords() {
+    assert_eq!(WhiteSpace::from_str("pre-line"), Ok(WhiteSpace::pre_line()));
+    assert_eq!(
+      WhiteSpace::from_str("break-spaces"),
+      Ok(WhiteSpace::break_spaces())
+    );
+    assert_eq!(
+      WhiteSpace::from_str("preserve-breaks wrap"),
+      Ok(WhiteSpace::pre_line())
+    );
+  }
 }
@@ -1,10 +1,14 @@
-use std::{borrow::Cow, marker::PhantomData};
+use std::{borrow::Cow, collections::HashMap, marker::PhantomData};
 
+use cssparser::{Delimiter, ParseError, Parser, ParserInput};
 use derive_builder::Builder;
-use parley::{FontSettings, FontStack, TextStyle};
-use serde::Deserialize;
+use parley::{FontFeature, FontSettings, FontStack, TextStyle};
+use serde::{
+  Deserialize,
+  de::{IntoDeserializer, value::StrDeserializer},
+};
 use smallvec::SmallVec;
-use taffy::{Point, Rect, Size, prelude::FromLength};
+use taffy::{Layout, Overflow as TaffyOverflow, Point, Rect, Size, prelude::FromLength};
 
 use crate::{
   layout::{
@@ -66,6 +70,22 @@ macro_rules! define_style {
           self.$property = other.$property.or(std::mem::take(&mut self.$property));
         )*
       }
+
+      /// Parses `value` into the property named by the kebab-case CSS `name`.
+      ///
+      /// Returns `Ok(false)` when no property has that name.
+      fn set_css_property(&mut self, name: &str, value: &str) -> Result<bool, serde::de::value::Error> {
+        let deserializer: StrDeserializer<'_, serde::de::value::Error> = value.into_deserializer();
+
+        $(
+          if is_css_property_name(name, stringify!($property)) {
+            self.$property = Deserialize::deserialize(deserializer)?;
+            return Ok(true);
+          }
+        )*
+
+        Ok(false)
+      }
     }
 
     /// A resolved set of style properties.
@@ -89,12 +109,12 @@ define_style!(
   box_sizing: BoxSizing,
   opacity: PercentageNumber,
   display: Display,
-  width: Length,
-  height: Length,
-  max_width: Length,
-  max_height: Length,
-  min_width: Length,
-  min_height: Length,
+  width: SizeLength,
+  height: SizeLength,
+  max_width: SizeLength,
+  max_height: SizeLength,
+  min_width: SizeLength,
+  min_height: SizeLength,
   aspect_ratio: AspectRatio,
   padding: Sides<Length<false>> => [
     padding_inline,
@@ -133,10 +153,12 @@ define_style!(
   left: Option<Length>,
   flex_direction: FlexDirection,
   justify_self: AlignItems,
-  justify_content: JustifyContent,
-  align_content: JustifyContent,
-  justify_items: AlignItems,
-  align_items: AlignItems,
+  place_content: PlaceContent => [align_content, justify_content],
+  justify_content: Option<JustifyContent>,
+  align_content: Option<JustifyContent>,
+  place_items: PlaceItems => [align_items, justify_items],
+  justify_items: Option<AlignItems>,
+  align_items: Option<AlignItems>,
   align_self: AlignItems,
   flex_wrap: FlexWrap,
   flex_basis: Option<Length>,
@@ -147,6 +169,7 @@ define_style!(
   scale_y: Option<PercentageNumber>,
   transform: Option<Transforms> => [translate, rotate, scale, translate_x, translate_y, scale_x, scale_y],
   transform_origin: Option<BackgroundPosition>,
+  transform_box: TransformBox,
   translate: Option<SpacePair<Length>> => [translate_x, translate_y],
   translate_x: Option<Length>,
   translate_y: Option<Length>,
@@ -155,9 +178,14 @@ define_style!(
   mask_size: Option<BackgroundSizes>,
   mask_position: Option<BackgroundPositions>,
   mask_repeat: Option<BackgroundRepeats>,
-  gap: Gap => [column_gap, row_gap],
+  gap: Option<Gap> => [column_gap, row_gap],
   column_gap: Option<Length<false>>,
   row_gap: Option<Length<false>>,
+  column_count: ColumnCount,
+  column_rule: Border => [column_rule_width, column_rule_style, column_rule_color],
+  column_rule_width: Option<Length>,
+  column_rule_style: Option<BorderStyle>,
+  column_rule_color: Option<ColorInput>,
   flex: Option<Flex> => [flex_basis, flex_grow, flex_shrink],
   flex_grow: Option<FlexGrow>,
   flex_shrink: Option<FlexGrow>,
@@ -187,6 +215,12 @@ define_style!(
   border_left_width: Option<Length>,
   border_style: Option<BorderStyle>,
   border_color: Option<ColorInput>,
+  border_collapse: BorderCollapse,
+  border_image_source: Option<BackgroundImage>,
+  border_image_slice: BorderImageSlice,
+  border_image_width: Sides<BorderImageWidth>,
+  border_image_outset: Sides<BorderImageOutset>,
+  border_image_repeat: BorderImageRepeat,
   border: Border => [
     border_width,
     border_inline_width,
@@ -207,6 +241,8 @@ define_style!(
   overflow: SpacePair<Overflow> => [overflow_x, overflow_y],
   overflow_x: Option<Overflow>,
   overflow_y: Option<Overflow>,
+  scrollbar_gutter: ScrollbarGutter,
+  overflow_clip_margin: Length<false>,
   object_position: BackgroundPosition where inherit = true,
   background: Backgrounds => [
     background_image,
@@ -216,6 +252,7 @@ define_style!(
     background_blend_mode,
     background_color,
     background_clip,
+    background_origin,
   ],
   background_image: Option<BackgroundImages>,
   background_position: Option<BackgroundPositions>,
@@ -224,6 +261,7 @@ define_style!(
   background_blend_mode: Option<BlendModes>,
   background_color: Option<ColorInput<false>>,
   background_clip: BackgroundClip,
+  background_origin: Option<BackgroundOrigin>,
   box_shadow: Option<BoxShadows>,
   grid_auto_columns: Option<GridTrackSizes>,
   grid_auto_rows: Option<GridTrackSizes>,
@@ -234,7 +272,13 @@ define_style!(
   grid_template_rows: Option<GridTemplateComponents>,
   grid_template_areas: Option<GridTemplateAreas>,
   text_overflow: TextOverflow,
+  list_style_type: ListStyleType,
+  content: Option<Content>,
+  counter_reset: Option<CounterReset>,
+  counter_increment: Option<CounterIncrement>,
   text_transform: TextTransform where inherit = true,
+  text_autospace: TextAutospace where inherit = true,
+  font_variant_emoji: FontVariantEmoji where inherit = true,
   font_style: FontStyle where inherit = true,
   font_stretch: FontStretch where inherit = true,
   color: ColorInput where inherit = true,
@@ -242,15 +286,20 @@ define_style!(
   backdrop_filter: Filters,
   font_size: Option<Length> where inherit = true,
   font_family: Option<FontFamily> where inherit = true,
+  lang: Option<Lang> where inherit = true,
   line_height: LineHeight where inherit = true,
   font_weight: FontWeight where inherit = true,
   font_variation_settings: Option<FontVariationSettings> where inherit = true,
   font_feature_settings: Option<FontFeatureSettings> where inherit = true,
+  font_variant_position: FontVariantPosition where inherit = true,
+  font_kerning: FontKerning where inherit = true,
+  font_variant_ligatures: FontVariantLigatures where inherit = true,
   font_synthesis: FontSynthesis where inherit = true => [font_synthesis_weight, font_synthesis_style],
   font_synthesis_weight: Option<FontSynthesic> where inherit = true,
   font_synthesis_style: Option<FontSynthesic> where inherit = true,
   line_clamp: Option<LineClamp> where inherit = true,
   text_align: TextAlign where inherit = true,
+  direction: Option<Direction> where inherit = true,
   #[serde(rename = "WebkitTextStroke", alias = "textStroke")]
   webkit_text_stroke: Option<TextStroke> where inherit = true => [
     webkit_text_stroke_width,
@@ -270,14 +319,19 @@ define_style!(
   text_decoration_color: Option<ColorInput>,
   text_decoration_thickness: Option<TextDecorationThickness>,
   text_decoration_skip_ink: TextDecorationSkipInk where inherit = true,
+  text_underline_position: TextUnderlinePosition where inherit = true,
+  text_emphasis: TextEmphasis where inherit = true => [text_emphasis_style, text_emphasis_color],
+  text_emphasis_style: Option<TextEmphasisStyle> where inherit = true,
+  text_emphasis_color: Option<ColorInput> where inherit = true,
   letter_spacing: Option<Length> where inherit = true,
   word_spacing: Option<Length> where inherit = true,
   image_rendering: ImageScalingAlgorithm where inherit = true,
   overflow_wrap: OverflowWrap where inherit = true,
   word_break: WordBreak where inherit = true,
-  clip_path: Option<BasicShape>,
+  clip_path: Option<ClipPath>,
   clip_rule: FillRule where inherit = true,
   white_space: WhiteSpace where inherit = true,
+  tab_size: TabSize where inherit = true,
   white_space_collapse: Option<WhiteSpaceCollapse> where inherit = true,
   text_wrap_mode: Option<TextWrapMode> where inherit = true,
   text_wrap_style: Option<TextWrapStyle> where inherit = true,
@@ -301,14 +355,47 @@ pub(crate) struct SizedFontStyle<'s> {
   pub text_stroke_color: Color,
   pub text_decoration_color: Color,
   pub text_decoration_thickness: SizedTextDecorationThickness,
+  pub text_emphasis_style: TextEmphasisStyle,
+  pub text_emphasis_color: Color,
+  /// The `font-variant-position` feature, when the font provides it.
+  pub position_feature: Option<FontFeature>,
+  /// The baseline shift of synthesized subscript or superscript glyphs.
+  pub synthesized_baseline_shift: Option<f32>,
+  /// The baseline shift from `vertical-align` on the enclosing inline boxes.
+  pub vertical_align_shift: f32,
   pub sizing: Sizing,
 }
 
 impl<'s> From<&'s SizedFontStyle<'s>> for TextStyle<'s, InlineBrush> {
   fn from(style: &'s SizedFontStyle<'s>) -> Self {
+    let font_size = if style.synthesized_baseline_shift.is_some() {
+      style.sizing.font_size * SYNTHESIZED_POSITION_SCALE
+    } else {
+      style.sizing.font_size
+    };
+    let font_feature_settings = style.parent.font_feature_settings.as_deref().unwrap_or(&[]);
+    let variant_features: SmallVec<[FontFeature; 8]> = style
+      .position_feature
+      .into_iter()
+      .chain(style.parent.font_kerning.feature())
+      .chain(style.parent.font_variant_ligatures.features())
+      .collect();
+
+    // Parley splits the leading evenly around the baseline of each run, so a shifted run needs
+    // twice the shift on top of its line height to keep its glyphs inside the line box.
+    let line_height = if style.vertical_align_shift == 0.0 {
+      style.line_height
+    } else {
+      LineHeight::grow_parley(
+        style.line_height,
+        font_size,
+        2.0 * style.vertical_align_shift.abs(),
+      )
+    };
+
     TextStyle {
-      font_size: style.sizing.font_size,
-      line_height: style.line_height,
+      font_size,
+      line_height,
       font_weight: style.parent.font_weight.into(),
       font_style: style.parent.font_style.into(),
       font_variations: FontSettings::List(Cow::Borrowed(
@@ -318,15 +405,18 @@ impl<'s> From<&'s SizedFontStyle<'s>> for TextStyle<'s, InlineBrush> {
           .as_deref()
           .unwrap_or(&[]),
       )),
-      font_features: FontSettings::List(Cow::Borrowed(
-        style.parent.font_feature_settings.as_deref().unwrap_or(&[]),
-      )),
-      font_stack: style
-        .parent
-        .font_family
-        .as_ref()
-        .map(Into::into)
-        .unwrap_or(FontStack::Source(Cow::Borrowed("sans-serif"))),
+      // Explicit font-feature-settings come last so they take precedence.
+      font_features: FontSettings::List(if variant_features.is_empty() {
+        Cow::Borrowed(font_feature_settings)
+      } else {
+        Cow::Owned(
+          variant_features
+            .into_iter()
+            .chain(font_feature_settings.iter().copied())
+            .collect(),
+        )
+      }),
+      font_stack: style.parent.font_stack(),
       letter_spacing: style.letter_spacing.unwrap_or_default(),
       word_spacing: style.word_spacing.unwrap_or_default(),
       word_break: style.parent.word_break.into(),
@@ -346,6 +436,9 @@ impl<'s> From<&'s SizedFontStyle<'s>> for TextStyle<'s, InlineBrush> {
           .text_decoration_line
           .unwrap_or(style.parent.text_decoration.line),
         decoration_skip_ink: style.parent.text_decoration_skip_ink,
+        underline_position: style.parent.text_underline_position,
+        emphasis_style: style.text_emphasis_style,
+        emphasis_color: style.text_emphasis_color,
         stroke_color: style.text_stroke_color,
         font_synthesis: FontSynthesis {
           weight: style
@@ -358,11 +451,13 @@ impl<'s> From<&'s SizedFontStyle<'s>> for TextStyle<'s, InlineBrush> {
             .unwrap_or(style.parent.font_synthesis.style),
         },
         vertical_align: style.parent.vertical_align,
+        baseline_shift: style.synthesized_baseline_shift.unwrap_or_default()
+          + style.vertical_align_shift,
       },
       text_wrap_mode: style.parent.text_wrap_mode_and_line_clamp().0.into(),
       font_width: style.parent.font_stretch.into(),
 
-      locale: None,
+      locale: style.parent.lang.as_ref().map(Lang::as_str),
       has_underline: false,
       underline_offset: None,
       underline_size: None,
@@ -375,6 +470,44 @@ impl<'s> From<&'s SizedFontStyle<'s>> for TextStyle<'s, InlineBrush> {
   }
 }
 
+/// Font size used when measuring font-relative metrics, which are stored as fractions of it.
+const FONT_METRICS_REFERENCE_SIZE: f32 = 100.0;
+
+impl InheritedStyle {
+  /// The font families to select from, `sans-serif` if unset.
+  fn font_stack(&self) -> FontStack<'_> {
+    self
+      .font_family
+      .as_ref()
+      .map(Into::into)
+      .unwrap_or(FontStack::Source(Cow::Borrowed("sans-serif")))
+  }
+
+  /// Whether both styles select the same font face, so font metrics can be shared.
+  pub(crate) fn has_same_font_face(&self, other: &Self) -> bool {
+    self.font_family == other.font_family
+      && self.font_weight == other.font_weight
+      && self.font_style == other.font_style
+      && self.font_stretch == other.font_stretch
+      && self.font_variation_settings == other.font_variation_settings
+  }
+
+  /// Text style used to measure [`FontMetrics`](crate::rendering::FontMetrics) for this element's font face.
+  pub(crate) fn to_font_metrics_style(&self) -> TextStyle<'_, InlineBrush> {
+    TextStyle {
+      font_size: FONT_METRICS_REFERENCE_SIZE,
+      font_weight: self.font_weight.into(),
+      font_style: self.font_style.into(),
+      font_width: self.font_stretch.into(),
+      font_variations: FontSettings::List(Cow::Borrowed(
+        self.font_variation_settings.as_deref().unwrap_or(&[]),
+      )),
+      font_stack: self.font_stack(),
+      ..TextStyle::default()
+    }
+  }
+}
+
 impl InheritedStyle {
   /// Normalize inheritable text-related values to computed values for this node.
   pub(crate) fn make_computed(&mut self, sizing: &Sizing) {
@@ -394,6 +527,21 @@ impl InheritedStyle {
     self.opacity.0 == 0.0 || self.display == Display::None || self.visibility == Visibility::Hidden
   }
 
+  /// Whether the node is the containing block of its `position: fixed` descendants instead of the viewport.
+  // https://drafts.csswg.org/css-position/#fixed-cb
+  pub(crate) fn contains_fixed_descendants(&self) -> bool {
+    self.transform.is_some()
+      || self.translate.is_some()
+      || self.translate_x.is_some()
+      || self.translate_y.is_some()
+      || self.rotate.is_some()
+      || self.scale.is_some()
+      || self.scale_x.is_some()
+      || self.scale_y.is_some()
+      || !self.filter.is_empty()
+      || !self.backdrop_filter.is_empty()
+  }
+
   // https://developer.mozilla.org/en-US/docs/Web/CSS/Guides/Positioned_layout/Stacking_context#features_creating_stacking_contexts
   pub(crate) fn is_isolated(&self) -> bool {
     self.isolation == Isolation::Isolate
@@ -413,17 +561,39 @@ impl InheritedStyle {
       })
   }
 
-  pub(crate) fn has_non_identity_transform(&self, border_box: Size<f32>, sizing: &Sizing) -> bool {
+  /// Builds the node's own transform in its border box coordinates, pivoting around the
+  /// `transform-origin` within the `transform-box`.
+  pub(crate) fn local_transform(&self, layout: &Layout, sizing: &Sizing) -> Affine {
+    let (offset, reference_box) = match self.transform_box {
+      TransformBox::ContentBox | TransformBox::FillBox => (
+        Point {
+          x: layout.border.left + layout.padding.left,
+          y: layout.border.top + layout.padding.top,
+        },
+        layout.content_box_size(),
+      ),
+      TransformBox::BorderBox | TransformBox::StrokeBox | TransformBox::ViewBox => {
+        (Point::ZERO, layout.size)
+      }
+    };
+
     let transform_origin = self.transform_origin.unwrap_or_default();
-    let origin = transform_origin.to_point(sizing, border_box);
+    let origin = transform_origin.to_point(sizing, reference_box);
+    let origin = Point {
+      x: offset.x + origin.x,
+      y: offset.y + origin.y,
+    };
+
+    // CSS Transforms Level 2 order: T(origin) * translate * rotate * scale * transform * T(-origin)
+    // Ref: https://www.w3.org/TR/css-transforms-2/#ctm
 
     let mut local = Affine::translation(origin.x, origin.y);
 
     let translate = self.translate();
     if translate != SpacePair::default() {
       local *= Affine::translation(
-        translate.x.to_px(sizing, border_box.width),
-        translate.y.to_px(sizing, border_box.height),
+        translate.x.to_px(sizing, reference_box.width),
+        translate.y.to_px(sizing, reference_box.height),
       );
     }
 
@@ -437,12 +607,14 @@ impl InheritedStyle {
     }
 
     if let Some(node_transform) = &self.transform {
-      local *= Affine::from_transforms(node_transform.iter(), sizing, border_box);
+      local *= Affine::from_transforms(node_transform.iter(), sizing, reference_box);
     }
 
-    local *= Affine::translation(-origin.x, -origin.y);
+    local * Affine::translation(-origin.x, -origin.y)
+  }
 
-    !local.is_identity()
+  pub(crate) fn has_non_identity_transform(&self, layout: &Layout, sizing: &Sizing) -> bool {
+    !self.local_transform(layout, sizing).is_identity()
   }
 
   pub(crate) fn resolve_overflows(&self) -> SpacePair<Overflow> {
@@ -452,6 +624,20 @@ impl InheritedStyle {
     )
   }
 
+  pub(crate) fn resolve_place_content(&self) -> PlaceContent {
+    SpacePair::from_pair(
+      self.align_content.unwrap_or(self.place_content.y),
+      self.justify_content.unwrap_or(self.place_content.x),
+    )
+  }
+
+  pub(crate) fn resolve_place_items(&self) -> PlaceItems {
+    SpacePair::from_pair(
+      self.align_items.unwrap_or(self.place_items.y),
+      self.justify_items.unwrap_or(self.place_items.x),
+    )
+  }
+
   pub(crate) fn translate(&self) -> SpacePair<Length> {
     SpacePair::from_pair(
       self
@@ -483,11 +669,24 @@ impl InheritedStyle {
       .unwrap_or_default()
   }
 
+  pub(crate) fn background_origin(&self) -> BackgroundOrigin {
+    if let Some(origin) = self.background_origin {
+      return origin;
+    }
+
+    self
+      .background
+      .iter()
+      .map(|bg| bg.origin)
+      .next_back()
+      .unwrap_or_default()
+  }
+
   pub(crate) fn ellipsis_char(&self) -> &str {
     const ELLIPSIS_CHAR: &str = "…";
 
     match &self.text_overflow {
-      TextOverflow::Ellipsis => return ELLIPSIS_CHAR,
+      TextOverflow::Ellipsis | TextOverflow::EllipsisMiddle => return ELLIPSIS_CHAR,
       TextOverflow::Custom(custom) => return custom.as_str(),
       _ => {}
     }
@@ -503,23 +702,37 @@ impl InheritedStyle {
     ELLIPSIS_CHAR
   }
 
+  /// Whether overflowing text ends with [`Self::ellipsis_char`], set by `text-overflow` or by a
+  /// `line-clamp` ellipsis string. A bare `line-clamp` cuts the last line cleanly.
+  pub(crate) fn should_ellipsize(&self) -> bool {
+    self.text_overflow != TextOverflow::Clip
+      || self
+        .line_clamp
+        .as_ref()
+        .is_some_and(|clamp| clamp.ellipsis.is_some())
+  }
+
   pub(crate) fn white_space_collapse(&self) -> WhiteSpaceCollapse {
     self
       .white_space_collapse
       .unwrap_or(self.white_space.white_space_collapse)
   }
 
-  pub(crate) fn text_wrap_mode_and_line_clamp(&self) -> (TextWrapMode, Option<Cow<'_, LineClamp>>) {
-    let mut text_wrap_mode = self
+  fn resolved_text_wrap_mode(&self) -> TextWrapMode {
+    self
       .text_wrap_mode
       .or(self.text_wrap.mode)
-      .unwrap_or(self.white_space.text_wrap_mode);
+      .unwrap_or(self.white_space.text_wrap_mode)
+  }
+
+  pub(crate) fn text_wrap_mode_and_line_clamp(&self) -> (TextWrapMode, Option<Cow<'_, LineClamp>>) {
+    let mut text_wrap_mode = self.resolved_text_wrap_mode();
 
     let mut line_clamp = self.line_clamp.as_ref().map(Cow::Borrowed);
 
     // Special case: when nowrap + ellipsis, parley will layout all the text even when it overflows.
     // So we need to use a fixed line clamp of 1 instead.
-    if text_wrap_mode == TextWrapMode::NoWrap && self.text_overflow == TextOverflow::Ellipsis {
+    if text_wrap_mode == TextWrapMode::NoWrap && self.text_overflow != TextOverflow::Clip {
       line_clamp = Some(Cow::Owned(LineClamp {
         count: 1,
         ellipsis: Some(self.ellipsis_char().to_string()),
@@ -676,11 +889,160 @@ impl InheritedStyle {
     )
   }
 
+  /// Resolves `min-width` and `min-height`, pinning the minimum of a sized axis when `aspect-ratio` is set.
+  ///
+  /// Taffy transfers min constraints through the aspect ratio, so `min-height: 150px` with `aspect-ratio: 3 / 1`
+  /// would become `min-width: 450px` and override an explicit `width`. Per CSS the definite size wins,
+  /// and only the other axis is floored by its min constraint. A flex item that can shrink gets a zero
+  /// minimum instead, so it can still shrink below its size.
+  ///
+  /// Unwrapped text truncated by `text-overflow` fits any width, so its automatic minimum width is 0
+  /// instead of the width of the text, like with `overflow: hidden`. This lets shrinking flex and
+  /// grid items truncate their text instead of overflowing.
+  pub(crate) fn resolved_min_size(&self) -> Size<Length> {
+    let mut min_size = Size {
+      width: *self.min_width,
+      height: *self.min_height,
+    };
+
+    if min_size.width == Length::Auto
+      && self.text_overflow != TextOverflow::Clip
+      && self.resolved_text_wrap_mode() == TextWrapMode::NoWrap
+    {
+      min_size.width = Length::Px(0.0);
+    }
+
+    if self.aspect_ratio == AspectRatio::Auto {
+      return min_size;
+    }
+
+    let shrinks = self.resolved_flex_shrink() > 0.0;
+
+    if min_size.width == Length::Auto
+      && min_size.height != Length::Auto
+      && *self.max_width == Length::Auto
+    {
+      min_size.width = if shrinks {
+        Length::Px(0.0)
+      } else {
+        *self.width
+      };
+    } else if min_size.height == Length::Auto
+      && min_size.width != Length::Auto
+      && *self.max_height == Length::Auto
+    {
+      min_size.height = if shrinks {
+        Length::Px(0.0)
+      } else {
+        *self.height
+      };
+    }
+
+    min_size
+  }
+
+  /// Resolves `max-width` and `max-height`, pinning the maximum of a sized axis when `aspect-ratio` is set.
+  ///
+  /// Taffy transfers max constraints through the aspect ratio too, so `max-height: 50px` with `aspect-ratio: 3 / 1`
+  /// would become `max-width: 150px` and shrink an explicit `width`. Per CSS the definite size wins,
+  /// and only the other axis is capped by its max constraint. A flex item that can grow gets an unbounded
+  /// maximum instead, so it can still grow past its size.
+  pub(crate) fn resolved_max_size(&self) -> Size<Length> {
+    let mut max_size = Size {
+      width: *self.max_width,
+      height: *self.max_height,
+    };
+
+    if self.aspect_ratio == AspectRatio::Auto {
+      return max_size;
+    }
+
+    let grows = self.resolved_flex_grow() > 0.0;
+
+    if max_size.width == Length::Auto
+      && max_size.height != Length::Auto
+      && *self.width != Length::Auto
+    {
+      max_size.width = if grows {
+        Length::Px(f32::INFINITY)
+      } else {
+        *self.width
+      };
+    } else if max_size.height == Length::Auto
+      && max_size.width != Length::Auto
+      && *self.height != Length::Auto
+    {
+      max_size.height = if grows {
+        Length::Px(f32::INFINITY)
+      } else {
+        *self.height
+      };
+    }
+
+    max_size
+  }
+
+  /// Resolves `flex-grow`, falling back to the `flex` shorthand.
+  fn resolved_flex_grow(&self) -> f32 {
+    self
+      .flex_grow
+      .map(|grow| grow.0)
+      .or_else(|| self.flex.map(|flex| flex.grow))
+      .unwrap_or(0.0)
+  }
+
+  /// Resolves `flex-shrink`, falling back to the `flex` shorthand.
+  fn resolved_flex_shrink(&self) -> f32 {
+    self
+      .flex_shrink
+      .map(|shrink| shrink.0)
+      .or_else(|| self.flex.map(|flex| flex.shrink))
+      .unwrap_or(1.0)
+  }
+
+  /// Resolves the gap between columns of a multi-column layout, where `normal` is `1em`.
+  pub(crate) fn resolved_column_gap(&self, sizing: &Sizing, width: f32) -> f32 {
+    self
+      .column_gap
+      .or(self.gap.map(|gap| gap.x))
+      .map_or(sizing.font_size, |gap| gap.to_px(sizing, width))
+  }
+
+  /// Resolves the width and color of the `column-rule`, if one is visible.
+  pub(crate) fn resolved_column_rule(
+    &self,
+    sizing: &Sizing,
+    current_color: Color,
+    width: f32,
+  ) -> Option<(f32, Color)> {
+    let style = self.column_rule_style.unwrap_or(self.column_rule.style);
+    let rule_width = self
+      .column_rule_width
+      .unwrap_or(self.column_rule.width)
+      .to_px(sizing, width)
+      .max(0.0);
+    let color = self
+      .column_rule_color
+      .unwrap_or(self.column_rule.color)
+      .resolve(current_color);
+
+    (style != BorderStyle::None && rule_width > 0.0 && color.0[3] > 0)
+      .then_some((rule_width, color))
+  }
+
+  /// The gaps between columns and rows as the horizontal and vertical values,
+  /// so percentages resolve against the width and height of the container.
   #[inline]
   fn resolved_gap(&self) -> SpacePair<Length<false>> {
     SpacePair::from_pair(
-      self.row_gap.unwrap_or(self.gap.x),
-      self.column_gap.unwrap_or(self.gap.y),
+      self
+        .column_gap
+        .or(self.gap.map(|gap| gap.x))
+        .unwrap_or_default(),
+      self
+        .row_gap
+        .or(self.gap.map(|gap| gap.y))
+        .unwrap_or_default(),
     )
   }
 
@@ -721,9 +1083,25 @@ impl InheritedStyle {
       .unwrap_or_default()
       .to_px(&context.sizing, context.sizing.font_size);
 
+    // Subscript and superscript glyphs are synthesized when the font doesn't provide them.
+    let position_tag = self.font_variant_position.feature_tag();
+    let supports_position_feature = position_tag.is_some_and(|tag| {
+      context
+        .global
+        .font_context
+        .supports_feature(self.to_font_metrics_style(), tag)
+    });
+    let synthesized_baseline_shift = (position_tag.is_some() && !supports_position_feature)
+      .then(|| self.font_variant_position.synthesized_baseline_shift() * context.sizing.font_size);
+
     SizedFontStyle {
       sizing: context.sizing.clone(),
       parent: self,
+      position_feature: supports_position_feature
+        .then(|| self.font_variant_position.feature())
+        .flatten(),
+      synthesized_baseline_shift,
+      vertical_align_shift: 0.0,
       line_height,
       stroke_width: resolved_stroke_width,
       letter_spacing: self
@@ -770,6 +1148,12 @@ impl InheritedStyle {
           thickness.to_px(&context.sizing, context.sizing.font_size),
         ),
       },
+      text_emphasis_style: self.text_emphasis_style.unwrap_or(self.text_emphasis.style),
+      text_emphasis_color: self
+        .text_emphasis_color
+        .or(self.text_emphasis.color)
+        .unwrap_or(ColorInput::CurrentColor)
+        .resolve(context.current_color),
     }
   }
 
@@ -782,6 +1166,17 @@ impl InheritedStyle {
 
     let border_style = self.border_style.unwrap_or(self.border.style);
 
+    let overflow = self.resolve_overflows();
+    let scrollbar_gutter = self.scrollbar_gutter.to_px(overflow.y, &context.sizing);
+
+    let place_content = self.resolve_place_content();
+    let place_items = self.resolve_place_items();
+
+    let mut padding = self.resolved_padding();
+    if self.scrollbar_gutter == ScrollbarGutter::StableBothEdges && scrollbar_gutter > 0.0 {
+      padding.left = padding.left.grow_by_px(scrollbar_gutter, &context.sizing);
+    }
+
     taffy::Style {
       box_sizing: self.box_sizing.into(),
       size: Size {
@@ -795,9 +1190,7 @@ impl InheritedStyle {
           .resolved_border_width()
           .map(|border| border.resolve_to_length_percentage(&context.sizing))
       },
-      padding: self
-        .resolved_padding()
-        .map(|padding| padding.resolve_to_length_percentage(&context.sizing)),
+      padding: padding.map(|padding| padding.resolve_to_length_percentage(&context.sizing)),
       inset: self
         .resolved_inset()
         .map(|inset| inset.resolve_to_length_percentage_auto(&context.sizing)),
@@ -807,35 +1200,25 @@ impl InheritedStyle {
       display: self.display.into(),
       flex_direction: self.flex_direction.into(),
       position: self.position.into(),
-      justify_content: self.justify_content.into(),
-      align_content: self.align_content.into(),
-      justify_items: self.justify_items.into(),
-      flex_grow: self
-        .flex_grow
-        .map(|grow| grow.0)
-        .or_else(|| self.flex.map(|flex| flex.grow))
-        .unwrap_or(0.0),
-      align_items: self.align_items.into(),
+      justify_content: place_content.x.into(),
+      align_content: place_content.y.into(),
+      justify_items: place_items.x.into(),
+      flex_grow: self.resolved_flex_grow(),
+      align_items: place_items.y.into(),
       gap: self.resolved_gap().resolve_to_size(&context.sizing),
       flex_basis: self
         .flex_basis
         .or_else(|| self.flex.map(|flex| flex.basis))
         .unwrap_or(Length::Auto)
         .resolve_to_dimension(&context.sizing),
-      flex_shrink: self
-        .flex_shrink
-        .map(|shrink| shrink.0)
-        .or_else(|| self.flex.map(|flex| flex.shrink))
-        .unwrap_or(1.0),
+      flex_shrink: self.resolved_flex_shrink(),
       flex_wrap: self.flex_wrap.into(),
-      min_size: Size {
-        width: self.min_width.resolve_to_dimension(&context.sizing),
-        height: self.min_height.resolve_to_dimension(&context.sizing),
-      },
-      max_size: Size {
-        width: self.max_width.resolve_to_dimension(&context.sizing),
-        height: self.max_height.resolve_to_dimension(&context.sizing),
-      },
+      min_size: self
+        .resolved_min_size()
+        .map(|min| min.resolve_to_dimension(&context.sizing)),
+      max_size: self
+        .resolved_max_size()
+        .map(|max| max.resolve_to_dimension(&context.sizing)),
       grid_auto_columns: self.grid_auto_columns.as_ref().map_or_else(Vec::new, |v| {
         v.iter().map(|s| s.to_min_max(&context.sizing)).collect()
       }),
@@ -864,36 +1247,161 @@ impl InheritedStyle {
       aspect_ratio: self.aspect_ratio.into(),
       align_self: self.align_self.into(),
       justify_self: self.justify_self.into(),
-      overflow: Point::from(self.resolve_overflows()).map(Into::into),
+      // Taffy only reserves scrollbar space for `scroll`, and does so on both axes,
+      // so the gutter is expressed by turning the vertical axis into a scroll container.
+      overflow: if scrollbar_gutter > 0.0 {
+        Point {
+          x: match overflow.x {
+            Overflow::Scroll => TaffyOverflow::Hidden,
+            overflow => overflow.into(),
+          },
+          y: TaffyOverflow::Scroll,
+        }
+      } else {
+        Point::from(overflow).map(Into::into)
+      },
       dummy: PhantomData,
       item_is_table: false,
       item_is_replaced: false,
-      scrollbar_width: 0.0,
+      scrollbar_width: scrollbar_gutter,
       text_align: taffy::TextAlign::Auto,
     }
   }
 }
 
+/// A map of named style presets that nodes can reference by name.
+pub type StylePresets = HashMap<String, Style>;
+
+/// The lowest priority style layer of a node.
+///
+/// It is either given inline (e.g. default styles of an HTML element type),
+/// or references a preset registered with [`GlobalContext::register_preset`](crate::GlobalContext::register_preset).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum StylePreset {
+  /// The name of a registered preset.
+  Named(String),
+  /// An inline preset style.
+  Inline(Style),
+}
+
+impl From<Style> for StylePreset {
+  fn from(style: Style) -> Self {
+    StylePreset::Inline(style)
+  }
+}
+
+impl StylePreset {
+  /// Resolves the preset into a [`Style`], returns `None` if the named preset is not registered.
+  pub(crate) fn resolve(self, presets: &StylePresets) -> Option<Style> {
+    match self {
+      StylePreset::Named(name) => presets.get(&name).cloned(),
+      StylePreset::Inline(style) => Some(style),
+    }
+  }
+}
+
+/// Whether the kebab-case CSS property `css_name` refers to the snake-case `field` of [`Style`].
+fn is_css_property_name(css_name: &str, field: &str) -> bool {
+  css_name.len() == field.len()
+    && css_name
+      .bytes()
+      .zip(field.bytes())
+      .all(|(css, field)| css.to_ascii_lowercase() == if field == b'_' { b'-' } else { field })
+}
+
+/// A declaration skipped by [`Style::from_css_declarations`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssDeclarationWarning {
+  /// The property name as written in the declaration block.
+  pub property: String,
+  /// Why the declaration was skipped.
+  pub message: String,
+}
+
+impl Style {
+  /// Parses a CSS declaration block such as `color: red; padding: 10px 20px` into a [`Style`].
+  ///
+  /// Each value is parsed by the property's [`FromCss`] implementation. Declarations apply in order,
+  /// so a shorthand resets the longhands declared before it, like in a stylesheet.
+  /// Unknown properties and invalid values are skipped and returned as warnings.
+  pub fn from_css_declarations(css: &str) -> (Self, Vec<CssDeclarationWarning>) {
+    let mut style = Style::default();
+    let mut warnings = Vec::new();
+
+    let mut input = ParserInput::new(css);
+    let mut parser = Parser::new(&mut input);
+
+    while !parser.is_exhausted() {
+      let declaration = parser.parse_until_after(Delimiter::Semicolon, |input| {
+        if input.is_exhausted() {
+          return Ok(None);
+        }
+
+        let name = input.expect_ident()?.clone();
+        input.expect_colon()?;
+
+        let start = input.position();
+        while input.next().is_ok() {}
+
+        Ok::<_, ParseError<'_, ()>>(Some((name, input.slice_from(start))))
+      });
+
+      let (name, value) = match declaration {
+        Ok(Some(declaration)) => declaration,
+        Ok(None) => continue,
+        Err(_) => {
+          warnings.push(CssDeclarationWarning {
+            property: String::new(),
+            message: "expected a `property: value` declaration".to_string(),
+          });
+          continue;
+        }
+      };
+
+      let value = value.trim();
+      let value = value
+        .strip_suffix("!important")
+        .map_or(value, str::trim_end);
+
+      let mut declared = Style::default();
+      match declared.set_css_property(&name, value) {
+        Ok(true) => style.merge_from(declared),
+        Ok(false) => warnings.push(CssDeclarationWarning {
+          property: name.to_string(),
+          message: "unknown property".to_string(),
+        }),
+        Err(error) => warnings.push(CssDeclarationWarning {
+          property: name.to_string(),
+          message: error.to_string(),
+        }),
+      }
+    }
+
+    (style, warnings)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use std::rc::Rc;
 
-  use taffy::Size;
+  use taffy::{Layout, Point, Rect, Size};
 
   use crate::{
     layout::{
       Viewport,
-      style::{CssValue, InheritedStyle, Style, properties::*},
+      style::{CssDeclarationWarning, CssValue, InheritedStyle, Style, properties::*},
     },
-    rendering::Sizing,
+    rendering::{FontMetrics, Sizing},
   };
 
   #[test]
   fn test_merge_from_inline_over_tailwind() {
     // Tailwind style (lower priority)
     let mut tw_style = Style {
-      width: CssValue::Value(Length::Rem(10.0)),
-      height: CssValue::Value(Length::Rem(20.0)),
+      width: Length::Rem(10.0).into(),
+      height: Length::Rem(20.0).into(),
       color: CssValue::Value(ColorInput::Value(Color([255, 0, 0, 255]))), // red
       ..Default::default()
     };
@@ -902,7 +1410,7 @@ mod tests {
     // height is Unset
     // color is Unset
     let inline_style = Style {
-      width: CssValue::Value(Length::Px(100.0)),
+      width: Length::Px(100.0).into(),
       ..Default::default()
     };
 
@@ -910,8 +1418,8 @@ mod tests {
     tw_style.merge_from(inline_style);
 
     // Check results
-    assert_eq!(tw_style.width, CssValue::Value(Length::Px(100.0))); // from inline
-    assert_eq!(tw_style.height, CssValue::Value(Length::Rem(20.0))); // from tw
+    assert_eq!(tw_style.width, Length::Px(100.0).into()); // from inline
+    assert_eq!(tw_style.height, Length::Rem(20.0).into()); // from tw
     assert_eq!(
       tw_style.color,
       CssValue::Value(ColorInput::Value(Color([255, 0, 0, 255])))
@@ -967,6 +1475,97 @@ mod tests {
     assert_eq!(resolved.left, Length::Px(0.0));
   }
 
+  #[test]
+  fn test_deserialize_overflow_shorthand_two_values() {
+    let style = serde_json::from_value::<Style>(serde_json::json!({
+      "overflow": "hidden auto",
+    }))
+    .map(|style| {
+      style
+        .inherit(&InheritedStyle::default())
+        .resolve_overflows()
+    });
+
+    assert_eq!(
+      style.ok(),
+      Some(SpacePair::from_pair(Overflow::Hidden, Overflow::Auto))
+    );
+  }
+
+  #[test]
+  fn test_deserialize_place_content_single_value_sets_both_axes() {
+    let style = serde_json::from_value::<Style>(serde_json::json!({
+      "placeContent": "center",
+    }))
+    .map(|style| {
+      style
+        .inherit(&InheritedStyle::default())
+        .resolve_place_content()
+    });
+
+    assert_eq!(
+      style.ok(),
+      Some(PlaceContent {
+        x: JustifyContent::Center,
+        y: JustifyContent::Center,
+      })
+    );
+  }
+
+  #[test]
+  fn test_deserialize_place_items_two_values() {
+    let style = serde_json::from_value::<Style>(serde_json::json!({
+      "placeItems": "start end",
+    }))
+    .map(|style| {
+      style
+        .inherit(&InheritedStyle::default())
+        .resolve_place_items()
+    });
+
+    // The first value is `align-items`, the second is `justify-items`.
+    assert_eq!(
+      style.ok(),
+      Some(PlaceItems {
+        x: AlignItems::End,
+        y: AlignItems::Start,
+      })
+    );
+  }
+
+  #[test]
+  fn test_place_content_longhand_overrides_shorthand() {
+    let (style, warnings) =
+      Style::from_css_declarations("place-content: center; justify-content: space-between");
+
+    assert!(warnings.is_empty());
+
+    let resolved = style
+      .inherit(&InheritedStyle::default())
+      .resolve_place_content();
+
+    assert_eq!(resolved.x, JustifyContent::SpaceBetween);
+    assert_eq!(resolved.y, JustifyContent::Center);
+  }
+
+  #[test]
+  fn test_place_longhands_reject_none() {
+    for property in [
+      "justifyContent",
+      "alignContent",
+      "justifyItems",
+      "alignItems",
+    ] {
+      assert!(
+        serde_json::from_value::<Style>(serde_json::json!({ property: "none" })).is_err(),
+        "{property}"
+      );
+    }
+
+    let (_, warnings) = Style::from_css_declarations("justify-content: none");
+    assert_eq!(warnings.len(), 1);
+  }
+
   #[test]
   fn test_merge_from_text_decoration_shorthand_clears_lower_priority_color() {
     let mut preset_style = Style {
@@ -1128,7 +1727,7 @@ mod tests {
     let mut style = InheritedStyle::default();
     assert!(!style.is_isolated());
 
-    style.clip_path = BasicShape::from_str("inset(10px)").ok();
+    style.clip_path = ClipPath::from_str("inset(10px)").ok();
     assert!(style.is_isolated());
 
     style.clip_path = None;
@@ -1143,20 +1742,67 @@ mod tests {
     let sizing = Sizing {
       viewport: Viewport::new(Some(1200), Some(630)),
       font_size: 16.0,
+      font_metrics: FontMetrics::default(),
       calc_arena: Rc::new(CalcArena::default()),
     };
-    let border_box = Size {
-      width: 200.0,
-      height: 100.0,
+    let layout = Layout {
+      size: Size {
+        width: 200.0,
+        height: 100.0,
+      },
+      ..Layout::new()
     };
 
-    assert!(!style.has_non_identity_transform(border_box, &sizing));
+    assert!(!style.has_non_identity_transform(&layout, &sizing));
 
     style.transform = Some(vec![Transform::Rotate(Angle::new(0.0))].into_boxed_slice());
-    assert!(!style.has_non_identity_transform(border_box, &sizing));
+    assert!(!style.has_non_identity_transform(&layout, &sizing));
 
     style.transform = Some(vec![Transform::Rotate(Angle::new(10.0))].into_boxed_slice());
-    assert!(style.has_non_identity_transform(border_box, &sizing));
+    assert!(style.has_non_identity_transform(&layout, &sizing));
+  }
+
+  #[test]
+  fn test_transform_box_moves_the_pivot_to_the_content_box() {
+    let sizing = Sizing {
+      viewport: Viewport::new(Some(1200), Some(630)),
+      font_size: 16.0,
+      font_metrics: FontMetrics::default(),
+      calc_arena: Rc::new(CalcArena::default()),
+    };
+    let layout = Layout {
+      size: Size {
+        width: 200.0,
+        height: 100.0,
+      },
+      padding: Rect {
+        left: 40.0,
+        right: 0.0,
+        top: 20.0,
+        bottom: 0.0,
+      },
+      ..Layout::new()
+    };
+
+    let mut style = InheritedStyle {
+      rotate: Some(Angle::new(90.0)),
+      ..Default::default()
+    };
+
+    let is_pivot = |transform: Affine, x: f32, y: f32| {
+      let point = transform.transform_point(Point { x, y });
+      (point.x - x).abs() < 1e-3 && (point.y - y).abs() < 1e-3
+    };
+
+    // The center of the border box stays in place by default.
+    let border_box_transform = style.local_transform(&layout, &sizing);
+    assert!(is_pivot(border_box_transform, 100.0, 50.0));
+
+    // With the content box, the pivot moves to the center of the area inside the padding.
+    style.transform_box = TransformBox::ContentBox;
+    let content_box_transform = style.local_transform(&layout, &sizing);
+    assert!(is_pivot(content_box_transform, 120.0, 60.0));
+    assert!(!is_pivot(content_box_transform, 100.0, 50.0));
   }
 
   #[test]
@@ -1179,6 +1825,73 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_truncated_nowrap_text_has_zero_automatic_min_width() {
+    let mut style = InheritedStyle {
+      text_wrap_mode: Some(TextWrapMode::NoWrap),
+      text_overflow: TextOverflow::Ellipsis,
+      ..Default::default()
+    };
+    assert_eq!(style.resolved_min_size().width, Length::Px(0.0));
+
+    style.min_width = SizeLength(Length::Px(40.0));
+    assert_eq!(style.resolved_min_size().width, Length::Px(40.0));
+
+    style.min_width = SizeLength(Length::Auto);
+    style.text_wrap_mode = Some(TextWrapMode::Wrap);
+    assert_eq!(style.resolved_min_size().width, Length::Auto);
+
+    style.text_wrap_mode = Some(TextWrapMode::NoWrap);
+    style.text_overflow = TextOverflow::Clip;
+    assert_eq!(style.resolved_min_size().width, Length::Auto);
+  }
+
+  #[test]
+  fn test_column_gap_treats_explicit_zero_gap_as_set() {
+    let sizing = Sizing {
+      viewport: Viewport::new(Some(1200), Some(630)),
+      font_size: 16.0,
+      font_metrics: FontMetrics::default(),
+      calc_arena: Rc::new(CalcArena::default()),
+    };
+    let mut style = InheritedStyle::default();
+    assert_eq!(style.resolved_column_gap(&sizing, 400.0), 16.0);
+
+    style.gap = Some(Gap::from_single(Length::Px(0.0)));
+    assert_eq!(style.resolved_column_gap(&sizing, 400.0), 0.0);
+
+    style.column_gap = Some(Length::Px(8.0));
+    assert_eq!(style.resolved_column_gap(&sizing, 400.0), 8.0);
+  }
+
+  #[test]
+  fn test_line_clamp_only_ellipsizes_when_requested() {
+    let mut style = InheritedStyle {
+      line_clamp: Some(2.into()),
+      ..Default::default()
+    };
+    assert!(!style.should_ellipsize());
+
+    style.text_overflow = TextOverflow::Ellipsis;
+    assert!(style.should_ellipsize());
+
+    style.text_overflow = TextOverflow::Custom("~".to_string());
+    assert!(style.should_ellipsize());
+    assert_eq!(style.ellipsis_char(), "~");
+
+    style.text_overflow = TextOverflow::EllipsisMiddle;
+    assert!(style.should_ellipsize());
+    assert_eq!(style.ellipsis_char(), "…");
+
+    style.text_overflow = TextOverflow::Clip;
+    style.line_clamp = Some(LineClamp {
+      count: 2,
+      ellipsis: Some("-".to_string()),
+    });
+    assert!(style.should_ellipsize());
+    assert_eq!(style.ellipsis_char(), "-");
+  }
+
   #[test]
   fn test_inherited_em_text_lengths_are_computed_once() {
     let mut parent = Style {
@@ -1191,6 +1904,7 @@ mod tests {
     parent.make_computed(&Sizing {
       viewport: Viewport::new(Some(1200), Some(630)),
       font_size: 32.0,
+      font_metrics: FontMetrics::default(),
       calc_arena: Rc::new(CalcArena::default()),
     });
 
@@ -1198,6 +1912,7 @@ mod tests {
     let inherited_child_sizing = Sizing {
       viewport: Viewport::new(Some(1200), Some(630)),
       font_size: 32.0,
+      font_metrics: FontMetrics::default(),
       calc_arena: Rc::new(CalcArena::default()),
     };
     let inherited_font_size = inherited_child
@@ -1214,6 +1929,7 @@ mod tests {
     let child_sizing = Sizing {
       viewport: Viewport::new(Some(1200), Some(630)),
       font_size: 10.0,
+      font_metrics: FontMetrics::default(),
       calc_arena: Rc::new(CalcArena::default()),
     };
 
@@ -1229,4 +1945,55 @@ mod tests {
     };
     assert_eq!(inherited_line_height, 48.0);
   }
+
+  #[test]
+  fn test_from_css_declarations_parses_shorthands() {
+    let (style, warnings) =
+      Style::from_css_declarations("color: red; font-size: 24px; padding: 10px 20px;");
+
+    assert!(warnings.is_empty());
+    assert_eq!(
+      style.color,
+      CssValue::Value(ColorInput::Value(Color([255, 0, 0, 255])))
+    );
+    assert_eq!(style.font_size, CssValue::Value(Some(Length::Px(24.0))));
+    assert_eq!(
+      style.padding,
+      CssValue::Value(Sides([
+        Length::Px(10.0),
+        Length::Px(20.0),
+        Length::Px(10.0),
+        Length::Px(20.0),
+      ]))
+    );
+  }
+
+  #[test]
+  fn test_from_css_declarations_applies_in_order() {
+    let (style, _) = Style::from_css_declarations("padding-top: 5px; padding: 10px");
+    assert_eq!(style.padding_top, CssValue::Unset);
+    assert_eq!(style.padding, CssValue::Value(Sides([Length::Px(10.0); 4])));
+
+    let (style, _) = Style::from_css_declarations("padding: 10px; padding-top: 5px !important");
+    assert_eq!(style.padding_top, CssValue::Value(Some(Length::Px(5.0))));
+    assert_eq!(style.padding, CssValue::Value(Sides([Length::Px(10.0); 4])));
+  }
+
+  #[test]
+  fn test_from_css_declarations_skips_unknown_and_invalid() {
+    let (style, warnings) =
+      Style::from_css_declarations("colour: red; opacity: 0.5; display: sideways");
+
+    assert_eq!(style.opacity, CssValue::Value(PercentageNumber(0.5)));
+    assert_eq!(style.display, CssValue::Unset);
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+      warnings[0],
+      CssDeclarationWarning {
+        property: "colour".to_string(),
+        message: "unknown property".to_string(),
+      }
+    );
+    assert_eq!(warnings[1].property, "display");
+  }
 }
@@ -43,6 +43,7 @@ pub enum PropertyParser {
   Flex(fn(Flex) -> TailwindProperty),
   Angle(fn(Angle) -> TailwindProperty),
   BackgroundClip(fn(BackgroundClip) -> TailwindProperty),
+  BackgroundOrigin(fn(BackgroundOrigin) -> TailwindProperty),
   Blur(fn(TwBlur) -> TailwindProperty),
   Filter(fn(Filters) -> TailwindProperty),
   DropShadow(fn(TextShadow) -> TailwindProperty),
@@ -57,6 +58,7 @@ impl PropertyParser {
   pub fn parse(&self, suffix: &str) -> Option<TailwindProperty> {
     match self {
       Self::BackgroundClip(f) => BackgroundClip::parse_tw_with_arbitrary(suffix).map(f),
+      Self::BackgroundOrigin(f) => BackgroundOrigin::parse_tw_with_arbitrary(suffix).map(f),
       Self::ObjectFit(f) => ObjectFit::parse_tw_with_arbitrary(suffix).map(f),
       Self::BgPosition(f) => BackgroundPosition::parse_tw_with_arbitrary(suffix).map(f),
       Self::BgSize(f) => BackgroundSize::parse_tw_with_arbitrary(suffix).map(f),
@@ -117,6 +119,7 @@ pub static PREFIX_PARSERS: phf::Map<&str, &[PropertyParser]> = phf_map! {
     PropertyParser::BgSize(TailwindProperty::BackgroundSize),
   ],
   "bg-clip" => &[PropertyParser::BackgroundClip(TailwindProperty::BackgroundClip)],
+  "bg-origin" => &[PropertyParser::BackgroundOrigin(TailwindProperty::BackgroundOrigin)],
   "bg-linear" => &[PropertyParser::Angle(TailwindProperty::BgLinearAngle)],
   "bg-conic" => &[PropertyParser::Angle(TailwindProperty::BgConicAngle)],
   "from" => &[PropertyParser::ColorCurrent(TailwindProperty::GradientFrom)],
@@ -305,10 +308,16 @@ pub static FIXED_PROPERTIES: phf::Map<&str, TailwindProperty> = phf_map! {
   "flex-none" => TailwindProperty::Flex(Flex::none()),
   "absolute" => TailwindProperty::Position(Position::Absolute),
   "relative" => TailwindProperty::Position(Position::Relative),
+  "fixed" => TailwindProperty::Position(Position::Fixed),
   "uppercase" => TailwindProperty::TextTransform(TextTransform::Uppercase),
   "lowercase" => TailwindProperty::TextTransform(TextTransform::Lowercase),
   "capitalize" => TailwindProperty::TextTransform(TextTransform::Capitalize),
   "normal-case" => TailwindProperty::TextTransform(TextTransform::None),
+  "list-none" => TailwindProperty::ListStyleType(ListStyleType::None),
+  "list-disc" => TailwindProperty::ListStyleType(ListStyleType::Disc),
+  "list-decimal" => TailwindProperty::ListStyleType(ListStyleType::Decimal),
+  "border-collapse" => TailwindProperty::BorderCollapse(BorderCollapse::Collapse),
+  "border-separate" => TailwindProperty::BorderCollapse(BorderCollapse::Separate),
   "underline" => TailwindProperty::TextDecorationLine(TextDecorationLines::UNDERLINE),
   "overline" => TailwindProperty::TextDecorationLine(TextDecorationLines::OVERLINE),
   "line-through" => TailwindProperty::TextDecorationLine(TextDecorationLines::LINE_THROUGH),
@@ -321,6 +330,24 @@ pub static FIXED_PROPERTIES: phf::Map<&str, TailwindProperty> = phf_map! {
   "min-h-screen" => TailwindProperty::MinHeight(Length::Vh(100.0)),
   "max-w-screen" => TailwindProperty::MaxWidth(Length::Vw(100.0)),
   "max-h-screen" => TailwindProperty::MaxHeight(Length::Vh(100.0)),
+  "w-min" => TailwindProperty::Width(Length::MinContent),
+  "w-max" => TailwindProperty::Width(Length::MaxContent),
+  "w-fit" => TailwindProperty::Width(Length::FitContent),
+  "h-min" => TailwindProperty::Height(Length::MinContent),
+  "h-max" => TailwindProperty::Height(Length::MaxContent),
+  "h-fit" => TailwindProperty::Height(Length::FitContent),
+  "min-w-min" => TailwindProperty::MinWidth(Length::MinContent),
+  "min-w-max" => TailwindProperty::MinWidth(Length::MaxContent),
+  "min-w-fit" => TailwindProperty::MinWidth(Length::FitContent),
+  "min-h-min" => TailwindProperty::MinHeight(Length::MinContent),
+  "min-h-max" => TailwindProperty::MinHeight(Length::MaxContent),
+  "min-h-fit" => TailwindProperty::MinHeight(Length::FitContent),
+  "max-w-min" => TailwindProperty::MaxWidth(Length::MinContent),
+  "max-w-max" => TailwindProperty::MaxWidth(Length::MaxContent),
+  "max-w-fit" => TailwindProperty::MaxWidth(Length::FitContent),
+  "max-h-min" => TailwindProperty::MaxHeight(Length::MinContent),
+  "max-h-max" => TailwindProperty::MaxHeight(Length::MaxContent),
+  "max-h-fit" => TailwindProperty::MaxHeight(Length::FitContent),
   "truncate" => TailwindProperty::Truncate,
   "text-ellipsis" => TailwindProperty::TextOverflow(TextOverflow::Ellipsis),
   "text-clip" => TailwindProperty::TextOverflow(TextOverflow::Clip),
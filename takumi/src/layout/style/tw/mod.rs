@@ -251,7 +251,10 @@ impl Breakpoint {
     };
 
     let breakpoint_width = match self.0 {
-      Length::Rem(value) => value * viewport.font_size * viewport.device_pixel_ratio,
+      // Font-relative units in media queries resolve against the initial font size.
+      Length::Rem(value) | Length::Em(value) => {
+        value * viewport.font_size * viewport.device_pixel_ratio
+      }
       Length::Px(value) => value * viewport.device_pixel_ratio,
       Length::Vw(value) => (value / 100.0) * viewport_width as f32,
       _ => 0.0,
@@ -267,6 +270,8 @@ impl Breakpoint {
 pub enum TailwindProperty {
   /// `background-clip` property.
   BackgroundClip(BackgroundClip),
+  /// `background-origin` property.
+  BackgroundOrigin(BackgroundOrigin),
   /// `box-sizing` property.
   BoxSizing(BoxSizing),
   /// `flex-grow` property.
@@ -335,6 +340,10 @@ pub enum TailwindProperty {
   TextDecorationThickness(TextDecorationThickness),
   /// `text-transform` property.
   TextTransform(TextTransform),
+  /// `list-style-type` property.
+  ListStyleType(ListStyleType),
+  /// `border-collapse` property.
+  BorderCollapse(BorderCollapse),
   /// `width` and `height` property.
   Size(Length),
   /// `width` property.
@@ -730,6 +739,9 @@ impl TailwindProperty {
       TailwindProperty::BackgroundClip(background_clip) => {
         style.background_clip = background_clip.into();
       }
+      TailwindProperty::BackgroundOrigin(background_origin) => {
+        style.background_origin = Some(background_origin).into();
+      }
       TailwindProperty::Gap(gap) => {
         style.gap = SpacePair::from_single(gap).into();
       }
@@ -811,6 +823,12 @@ impl TailwindProperty {
       TailwindProperty::TextTransform(text_transform) => {
         style.text_transform = text_transform.into();
       }
+      TailwindProperty::ListStyleType(list_style_type) => {
+        style.list_style_type = list_style_type.into();
+      }
+      TailwindProperty::BorderCollapse(border_collapse) => {
+        style.border_collapse = border_collapse.into();
+      }
       TailwindProperty::Size(size) => {
         style.width = size.into();
         style.height = size.into();
@@ -1178,7 +1196,7 @@ impl TailwindProperty {
       }
       TailwindProperty::Filter(ref filters) => {
         for f in filters {
-          append_filter!(style, filter, *f);
+          append_filter!(style, filter, f.clone());
         }
       }
       TailwindProperty::BackdropBlur(tw_blur) => {
@@ -1214,7 +1232,7 @@ impl TailwindProperty {
       }
       TailwindProperty::BackdropFilter(ref filters) => {
         for f in filters {
-          append_filter!(style, backdrop_filter, *f);
+          append_filter!(style, backdrop_filter, f.clone());
         }
       }
       TailwindProperty::TextShadow(text_shadow) => {
@@ -1257,6 +1275,19 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_intrinsic_sizes_only_for_sizing() {
+    assert_eq!(
+      TailwindProperty::parse("w-fit"),
+      Some(TailwindProperty::Width(Length::FitContent))
+    );
+    assert_eq!(
+      TailwindProperty::parse("max-w-min"),
+      Some(TailwindProperty::MaxWidth(Length::MinContent))
+    );
+    assert_eq!(TailwindProperty::parse("p-fit"), None);
+  }
+
   #[test]
   fn test_parse_color() {
     assert_eq!(
@@ -1480,6 +1511,7 @@ mod tests {
       "font-stretch-75%",
       "uppercase",
       "tracking-wide",
+      "list-decimal",
       // Flexbox
       "justify-center",
       "items-end",
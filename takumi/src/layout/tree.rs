@@ -1,25 +1,26 @@
-use std::{iter::Copied, mem::take, slice::Iter};
+use std::{iter::Copied, mem::take, slice::Iter, sync::OnceLock};
 
 use taffy::{
-  AvailableSpace, Cache, CacheTree, Display as TaffyDisplay, Layout, LayoutBlockContainer,
-  LayoutFlexboxContainer, LayoutGridContainer, LayoutInput, LayoutOutput, LayoutPartialTree,
-  NodeId, RoundTree, RunMode, Size, Style, TaffyError, TraversePartialTree, TraverseTree,
-  compute_block_layout, compute_cached_layout, compute_flexbox_layout, compute_grid_layout,
-  compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+  AbsoluteAxis, AlignItems, AvailableSpace, Cache, CacheTree, Dimension, Display as TaffyDisplay,
+  Layout, LayoutBlockContainer, LayoutFlexboxContainer, LayoutGridContainer, LayoutInput,
+  LayoutOutput, LayoutPartialTree, NodeId, Rect, RoundTree, RunMode, Size, Style, TaffyError,
+  TraversePartialTree, TraverseTree, compute_block_layout, compute_cached_layout,
+  compute_flexbox_layout, compute_grid_layout, compute_hidden_layout, compute_leaf_layout,
+  compute_root_layout, round_layout,
 };
 
 use crate::{
   Result,
   layout::{
     inline::{
-      InlineLayoutStage, ProcessedInlineSpan, collect_inline_items, create_inline_constraint,
-      create_inline_layout, measure_inline_layout,
+      InlineContentKind, InlineLayoutStage, ProcessedInlineSpan, collect_inline_items,
+      create_inline_constraint, create_inline_layout, measure_inline_layout, measure_width_limit,
     },
-    node::Node,
-    style::{Affine, Display, InheritedStyle},
+    node::{Node, check_text_length},
+    style::{Affine, Counters, Display, InheritedStyle, Length, Position, Sides},
   },
   rendering::{
-    Canvas, MaxHeight, RenderContext, Sizing,
+    Canvas, MaxHeight, RenderContext,
     inline_drawing::{draw_inline_box, draw_inline_layout},
   },
 };
@@ -68,6 +69,8 @@ struct LayoutNodeState {
   unrounded_layout: Layout,
   final_layout: Layout,
   is_inline_children: bool,
+  /// Whether the parent aligns this node by its first baseline.
+  needs_first_baseline: bool,
   children: Box<[NodeId]>,
 }
 
@@ -76,26 +79,43 @@ pub(crate) struct RenderNode<'g, N: Node<N>> {
   pub(crate) context: RenderContext<'g>,
   pub(crate) node: Option<N>,
   pub(crate) children: Option<Box<[RenderNode<'g, N>]>>,
+  /// The min-content and max-content layouts used to resolve intrinsic width keywords, cached so
+  /// a nested intrinsic box is measured once rather than again for every ancestor being measured.
+  intrinsic_layouts: OnceLock<(Layout, Layout)>,
 }
 
 fn push_layout_node<'r, 'g, N: Node<N>>(
   nodes: &mut Vec<LayoutNodeState>,
   render_nodes: &mut Vec<&'r RenderNode<'g, N>>,
   render_node: &'r RenderNode<'g, N>,
+  parent_align_items: Option<AlignItems>,
+  resolve_intrinsic_widths: bool,
 ) -> NodeId {
   let node_index = nodes.len();
   let node_id = NodeId::from(node_index);
   render_nodes.push(render_node);
 
+  let mut style = render_node
+    .context
+    .style
+    .to_taffy_style(&render_node.context);
+
+  if resolve_intrinsic_widths {
+    render_node.resolve_intrinsic_widths(&mut style);
+  }
+
+  let needs_first_baseline = matches!(
+    style.align_self.or(parent_align_items),
+    Some(AlignItems::Baseline)
+  );
+
   nodes.push(LayoutNodeState {
-    style: render_node
-      .context
-      .style
-      .to_taffy_style(&render_node.context),
+    style,
     cache: Cache::new(),
     unrounded_layout: Layout::new(),
     final_layout: Layout::new(),
     is_inline_children: render_node.should_create_inline_layout(),
+    needs_first_baseline,
     children: Box::new([]),
   });
 
@@ -104,12 +124,14 @@ fn push_layout_node<'r, 'g, N: Node<N>>(
   }
 
   if let Some(children) = render_node.children.as_deref() {
+    let align_items = nodes[node_index].style.align_items;
+
     nodes.reserve(children.len());
     render_nodes.reserve(children.len());
     nodes[node_index].children = Box::from_iter(
       children
         .iter()
-        .map(|child| push_layout_node(nodes, render_nodes, child)),
+        .map(|child| push_layout_node(nodes, render_nodes, child, align_items, true)),
     );
   }
 
@@ -118,9 +140,21 @@ fn push_layout_node<'r, 'g, N: Node<N>>(
 
 impl<'r, 'g, N: Node<N>> LayoutTree<'r, 'g, N> {
   pub(crate) fn from_render_node(render_root: &'r RenderNode<'g, N>) -> Self {
+    Self::new(render_root, true)
+  }
+
+  /// Builds the tree, optionally leaving intrinsic width keywords on the root unresolved (as `auto`),
+  /// which is what measuring the root's own min-content and max-content sizes needs.
+  fn new(render_root: &'r RenderNode<'g, N>, resolve_root_intrinsic_widths: bool) -> Self {
     let mut nodes = Vec::with_capacity(1);
     let mut render_nodes = Vec::with_capacity(1);
-    let root_id = push_layout_node(&mut nodes, &mut render_nodes, render_root);
+    let root_id = push_layout_node(
+      &mut nodes,
+      &mut render_nodes,
+      render_root,
+      None,
+      resolve_root_intrinsic_widths,
+    );
 
     debug_assert_eq!(root_id, NodeId::from(0usize));
 
@@ -255,32 +289,49 @@ impl<N: Node<N>> LayoutPartialTree for LayoutTree<'_, '_, N> {
         (TaffyDisplay::Block, true) => compute_block_layout(tree, node, inputs),
         (TaffyDisplay::Flex, true) => compute_flexbox_layout(tree, node, inputs),
         (TaffyDisplay::Grid, true) => compute_grid_layout(tree, node, inputs),
-        (_, false) => compute_leaf_layout(
-          inputs,
-          &node_data.style,
-          |val, basis| tree.resolve_calc_value(val, basis),
-          |known_dimensions, available_space| {
-            if let Size {
-              width: Some(width),
-              height: Some(height),
-            } = known_dimensions.maybe_apply_aspect_ratio(node_data.style.aspect_ratio)
-            {
-              return Size { width, height };
+        (_, false) => {
+          let mut output = compute_leaf_layout(
+            inputs,
+            &node_data.style,
+            |val, basis| tree.resolve_calc_value(val, basis),
+            |known_dimensions, available_space| {
+              if let Size {
+                width: Some(width),
+                height: Some(height),
+              } = known_dimensions.maybe_apply_aspect_ratio(node_data.style.aspect_ratio)
+              {
+                return Size { width, height };
+              }
+
+              let idx: usize = node.into();
+              let Some(render_node) = tree.render_nodes.get(idx) else {
+                unreachable!()
+              };
+
+              render_node.measure(
+                available_space,
+                known_dimensions,
+                &node_data.style,
+                node_data.is_inline_children,
+              )
+            },
+          );
+
+          // Leaf text isn't laid out by taffy, so report its first baseline for
+          // `align-items: baseline` in flex and grid containers.
+          if inputs.run_mode == RunMode::PerformLayout && node_data.needs_first_baseline {
+            let idx: usize = node.into();
+            if let Some(render_node) = tree.render_nodes.get(idx) {
+              output.first_baselines.y = render_node.first_baseline(
+                output.size,
+                inputs.parent_size.width.unwrap_or(0.0),
+                node_data.is_inline_children,
+              );
             }
+          }
 
-            let idx: usize = node.into();
-            let Some(render_node) = tree.render_nodes.get(idx) else {
-              unreachable!()
-            };
-
-            render_node.measure(
-              available_space,
-              known_dimensions,
-              &node_data.style,
-              node_data.is_inline_children,
-            )
-          },
-        ),
+          output
+        }
       }
     })
   }
@@ -403,6 +454,23 @@ impl<N: Node<N>> RoundTree for LayoutTree<'_, '_, N> {
 }
 
 impl<'g, N: Node<N>> RenderNode<'g, N> {
+  /// Fails if the text of any node, including generated content, exceeds `max_text_length` characters.
+  pub(crate) fn check_text_length(&self, max_text_length: usize) -> Result<()> {
+    if let Some(InlineContentKind::Text(text)) = self.node.as_ref().and_then(Node::inline_content) {
+      check_text_length(&text, max_text_length)?;
+    }
+
+    if let Some(content) = &self.context.style.content {
+      check_text_length(&content.text(), max_text_length)?;
+    }
+
+    for child in self.children.iter().flatten() {
+      child.check_text_length(max_text_length)?;
+    }
+
+    Ok(())
+  }
+
   pub(crate) fn draw_shell(&self, canvas: &mut Canvas, layout: Layout) -> Result<()> {
     let Some(node) = &self.node else {
       return Ok(());
@@ -476,6 +544,30 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
     Ok(())
   }
 
+  /// Whether the node takes part in its parent's layout, being displayed and not out of flow.
+  pub(crate) fn is_in_flow(&self) -> bool {
+    self.context.style.display != Display::None
+      && !matches!(
+        self.context.style.position,
+        Position::Absolute | Position::Fixed
+      )
+  }
+
+  /// Whether the node gets a marker and a counter value when its parent sets `list-style-type`.
+  pub(crate) fn is_list_item(&self) -> bool {
+    self.is_in_flow()
+  }
+
+  /// The number of nodes in this subtree, not counting anonymous boxes.
+  pub(crate) fn node_count(&self) -> usize {
+    let children = self
+      .children
+      .as_deref()
+      .map_or(0, |children| children.iter().map(Self::node_count).sum());
+
+    usize::from(self.node.is_some()) + children
+  }
+
   pub fn is_inline_level(&self) -> bool {
     self.context.style.display.is_inline_level()
   }
@@ -488,58 +580,188 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
   }
 
   pub fn should_create_inline_layout(&self) -> bool {
+    self
+      .children
+      .as_deref()
+      .is_some_and(|children| self.should_create_inline_layout_for(children))
+  }
+
+  fn should_create_inline_layout_for(&self, children: &[Self]) -> bool {
     matches!(
       self.context.style.display,
       Display::Block | Display::InlineBlock
-    ) && self.children.as_ref().is_some_and(|children| {
-      !children.is_empty() && children.iter().all(RenderNode::is_inline_level)
-    })
+    ) && !children.is_empty()
+      && children.iter().all(RenderNode::is_inline_level)
   }
 
-  pub fn from_node(parent_context: &RenderContext<'g>, node: N) -> Self {
-    let mut tree = Self::from_node_impl(parent_context, node);
+  /// Builds the render tree of a node drawn with the safe area inset around it.
+  pub fn from_node(
+    parent_context: &RenderContext<'g>,
+    node: N,
+    safe_area_inset: Rect<f32>,
+  ) -> Self {
+    let mut fixed_nodes = Vec::new();
+    let mut tree = Self::from_node_impl(
+      parent_context,
+      node,
+      &mut fixed_nodes,
+      None,
+      &mut Counters::default(),
+    );
 
     if tree.is_inline_level() {
       tree.context.style.display.blockify();
     }
 
+    if !fixed_nodes.is_empty() {
+      let viewport_box = tree.viewport_box(fixed_nodes, safe_area_inset);
+      tree.append_fixed_nodes(vec![viewport_box]);
+    }
+
     tree
   }
 
-  fn from_node_impl(parent_context: &RenderContext<'g>, mut node: N) -> Self {
-    let mut style =
-      node.create_inherited_style(&parent_context.style, parent_context.sizing.viewport);
+  /// Wraps the `position: fixed` nodes in an anonymous box covering the whole image, so they are
+  /// positioned and sized against the viewport rather than the padding box of the root.
+  fn viewport_box(&self, fixed_nodes: Vec<Self>, safe_area_inset: Rect<f32>) -> Self {
+    let border = self
+      .context
+      .style
+      .resolved_border_width()
+      .map(|length| length.to_px(&self.context.sizing, 0.0));
+    let device_pixel_ratio = self.context.sizing.viewport.device_pixel_ratio;
+    // The insets are relative to the padding box of the root, which starts inside its border and
+    // the safe area inset. Lengths are in CSS pixels, so the device pixel offsets are scaled back.
+    let outset = |inset: f32, border: f32| Length::Px(-(inset + border) / device_pixel_ratio);
+
+    let style = InheritedStyle {
+      display: Display::Block,
+      position: Position::Absolute,
+      inset: Sides([
+        outset(safe_area_inset.top, border.top),
+        outset(safe_area_inset.right, border.right),
+        outset(safe_area_inset.bottom, border.bottom),
+        outset(safe_area_inset.left, border.left),
+      ]),
+      ..InheritedStyle::default()
+    };
 
-    let font_size = style
-      .font_size
-      .map(|font_size| font_size.to_px(&parent_context.sizing, parent_context.sizing.font_size))
-      .unwrap_or(parent_context.sizing.font_size);
+    Self {
+      context: self.context.with_child_style(style),
+      node: None,
+      children: Some(fixed_nodes.into_boxed_slice()),
+      intrinsic_layouts: OnceLock::new(),
+    }
+  }
 
-    let current_color = style.color.resolve(parent_context.current_color);
+  /// Appends out-of-flow nodes after the children, so they are painted after all the in-flow content.
+  fn append_fixed_nodes(&mut self, mut fixed_nodes: Vec<Self>) {
+    let mut final_children = Vec::with_capacity(fixed_nodes.len());
+
+    if let Some(children) = self.children.take() {
+      if self.should_create_inline_layout_for(&children) {
+        // Inline children can't be mixed with block-level fixed nodes, wrap them into an anonymous box.
+        let mut inline_group = children.into_vec();
+        let anonymous_box_style = InheritedStyle {
+          display: Display::Block,
+          ..InheritedStyle::default()
+        };
+
+        flush_inline_group(
+          &mut inline_group,
+          &mut final_children,
+          &anonymous_box_style,
+          &self.context,
+        );
+      } else {
+        final_children.extend(children);
+      }
+    }
 
-    let sizing = Sizing {
-      font_size,
-      ..parent_context.sizing.clone()
-    };
+    for fixed_node in &mut fixed_nodes {
+      fixed_node.context.style.display.blockify();
+    }
 
-    style.make_computed(&sizing);
+    final_children.extend(fixed_nodes);
 
-    let mut render_context = RenderContext {
-      global: parent_context.global,
-      transform: parent_context.transform,
-      style,
-      current_color,
-      draw_debug_border: parent_context.draw_debug_border,
-      fetched_resources: parent_context.fetched_resources.clone(),
-      sizing,
-    };
+    self.children = Some(final_children.into_boxed_slice());
+  }
+
+  /// Builds the render tree of a node, collecting its `position: fixed` descendants into `fixed_nodes`
+  /// unless a node in between contains them.
+  ///
+  /// `ancestor_opacity` is the opacity of the ancestors below the node that contains the fixed nodes,
+  /// which is applied to detached fixed nodes since they are no longer painted inside those ancestors.
+  /// It is `None` for the root, which contains the fixed nodes through [`Self::viewport_box`].
+  fn from_node_impl(
+    parent_context: &RenderContext<'g>,
+    mut node: N,
+    fixed_nodes: &mut Vec<Self>,
+    ancestor_opacity: Option<f32>,
+    counters: &mut Counters,
+  ) -> Self {
+    let style = node.create_inherited_style(
+      &parent_context.style,
+      parent_context.sizing.viewport,
+      &parent_context.global.style_presets,
+    );
+
+    let mut render_context = parent_context.with_child_style(style);
+
+    // Like CSS, nodes that aren't rendered don't touch the counters.
+    if render_context.style.display != Display::None {
+      counters.apply(
+        render_context.style.counter_reset.as_ref(),
+        render_context.style.counter_increment.as_ref(),
+      );
+    }
+
+    // Generated `content` replaces the children of the node.
+    if let Some(content) = &render_context.style.content {
+      render_context.style.content = Some(content.resolve(counters));
+      node.take_children();
+    }
 
     let children = node.take_children().map(|children| {
-      Box::from_iter(
-        children
-          .into_iter()
-          .map(|child| Self::from_node_impl(&render_context, child)),
-      )
+      let mut in_flow_children = Vec::with_capacity(children.len());
+      let mut contained_fixed_nodes = Vec::new();
+
+      // Like CSS, transformed and filtered nodes become the containing block of fixed descendants.
+      let (fixed_nodes, children_opacity) = match ancestor_opacity {
+        _ if render_context.style.contains_fixed_descendants() => (&mut contained_fixed_nodes, 1.0),
+        None => (&mut *fixed_nodes, 1.0),
+        Some(opacity) => (&mut *fixed_nodes, opacity * *render_context.style.opacity),
+      };
+
+      // Counters reset by the children are scoped to them and their following siblings.
+      let outer_counters = counters.len();
+
+      for child in children {
+        let mut child = Self::from_node_impl(
+          &render_context,
+          child,
+          fixed_nodes,
+          Some(children_opacity),
+          counters,
+        );
+
+        // Fixed nodes are anchored to their containing block, so they are detached from their ancestors here.
+        if child.context.style.position == Position::Fixed {
+          child.context.style.opacity.0 *= children_opacity;
+          fixed_nodes.push(child);
+        } else {
+          in_flow_children.push(child);
+        }
+      }
+
+      counters.truncate(outer_counters);
+
+      for mut fixed_node in contained_fixed_nodes {
+        fixed_node.context.style.display.blockify();
+        in_flow_children.push(fixed_node);
+      }
+
+      in_flow_children.into_boxed_slice()
     });
 
     let Some(mut children) = children else {
@@ -547,6 +769,7 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
         context: render_context,
         node: Some(node),
         children: None,
+        intrinsic_layouts: OnceLock::new(),
       };
     };
 
@@ -559,6 +782,7 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
         context: render_context,
         node: Some(node),
         children: Some(children),
+        intrinsic_layouts: OnceLock::new(),
       };
     }
 
@@ -572,6 +796,7 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
         context: render_context,
         node: Some(node),
         children: Some(children),
+        intrinsic_layouts: OnceLock::new(),
       };
     }
 
@@ -612,6 +837,89 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
       context: render_context,
       node: Some(node),
       children: Some(final_children.into_boxed_slice()),
+      intrinsic_layouts: OnceLock::new(),
+    }
+  }
+
+  /// Lays out this subtree with its own width left as `auto`, returning the min-content and
+  /// max-content root layouts.
+  fn measure_intrinsic_layouts(&self, height: AvailableSpace) -> (Layout, Layout) {
+    let measure_with = |width: AvailableSpace| {
+      let mut tree = LayoutTree::new(self, false);
+
+      // Hack: Use Flexbox to avoid Block's "expand to fill" behavior when calculating max-content.
+      // We want the content's preferred width, not the container's available width.
+      if width == AvailableSpace::MaxContent
+        && let Some(node) = tree.get_layout_node_mut_ref(tree.root_node_id())
+        && node.style.display == TaffyDisplay::Block
+      {
+        node.style.display = TaffyDisplay::Flex;
+        node.style.flex_direction = taffy::FlexDirection::Row;
+        node.style.justify_content = Some(taffy::JustifyContent::Start);
+      }
+
+      tree.compute_layout(Size { width, height });
+
+      let results = tree.into_results();
+      results
+        .layout(results.root_node_id())
+        .map_or_else(|_| Layout::new(), |layout| *layout)
+    };
+
+    (
+      measure_with(AvailableSpace::MinContent),
+      measure_with(AvailableSpace::MaxContent),
+    )
+  }
+
+  /// Replaces `min-content`, `max-content` and `fit-content` widths with lengths measured from
+  /// the subtree, since taffy has no intrinsic sizing keywords for the box size.
+  fn resolve_intrinsic_widths(&self, style: &mut Style) {
+    let width = *self.context.style.width;
+    let min_width = self.context.style.resolved_min_size().width;
+    let max_width = self.context.style.resolved_max_size().width;
+
+    if !width.is_intrinsic() && !min_width.is_intrinsic() && !max_width.is_intrinsic() {
+      return;
+    }
+
+    let (min_content, max_content) = *self
+      .intrinsic_layouts
+      .get_or_init(|| self.measure_intrinsic_layouts(AvailableSpace::MaxContent));
+    let to_dimension = |layout: Layout| {
+      let width = match style.box_sizing {
+        taffy::BoxSizing::BorderBox => layout.size.width,
+        taffy::BoxSizing::ContentBox => {
+          layout.size.width
+            - layout.padding.left
+            - layout.padding.right
+            - layout.border.left
+            - layout.border.right
+        }
+      };
+
+      Dimension::length(width.max(0.0))
+    };
+    let resolve = |length: Length, fallback: Dimension| match length {
+      Length::MinContent => to_dimension(min_content),
+      Length::MaxContent | Length::FitContent => to_dimension(max_content),
+      _ => fallback,
+    };
+
+    style.size.width = resolve(width, style.size.width);
+    style.min_size.width = resolve(min_width, style.min_size.width);
+    style.max_size.width = resolve(max_width, style.max_size.width);
+
+    // fit-content = min(max-content, max(min-content, available)), expressed through the
+    // min and max widths unless they are already set.
+    if width == Length::FitContent {
+      if min_width == Length::Auto {
+        style.min_size.width = to_dimension(min_content);
+      }
+
+      if max_width == Length::Auto {
+        style.max_size.width = Dimension::percent(1.0);
+      }
     }
   }
 
@@ -633,53 +941,82 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
       // CSS shrink-to-fit for inline-level atomic boxes:
       // width = min(max-content, max(min-content, available)).
       // Reference: https://www.w3.org/TR/CSS22/visudet.html#float-width
-      let min_content = measure_with(AvailableSpace::MinContent);
-      let max_content = {
-        let mut tree = LayoutTree::from_render_node(self);
-        // Hack: Use Flexbox to avoid Block's "expand to fill" behavior when calculating max-content.
-        // We want the content's preferred width, not the container's available width.
-        if let Some(node) = tree.get_layout_node_mut_ref(tree.root_node_id())
-          && node.style.display == TaffyDisplay::Block
-        {
-          node.style.display = TaffyDisplay::Flex;
-          node.style.flex_direction = taffy::FlexDirection::Row;
-          node.style.justify_content = Some(taffy::JustifyContent::Start);
-        }
-
-        tree.compute_layout(Size {
-          width: AvailableSpace::MaxContent,
-          height: available_space.height,
-        });
-
-        let results = tree.into_results();
-        results
-          .layout(results.root_node_id())
-          .map_or(Size::zero(), |layout| layout.size)
-      };
+      let (min_content, max_content) = self.measure_intrinsic_layouts(available_space.height);
 
       let used_width = match available_space.width {
-        AvailableSpace::Definite(available) => {
-          max_content.width.min(min_content.width.max(available))
-        }
-        AvailableSpace::MinContent => min_content.width,
-        AvailableSpace::MaxContent => max_content.width,
+        AvailableSpace::Definite(available) => max_content
+          .size
+          .width
+          .min(min_content.size.width.max(available)),
+        AvailableSpace::MinContent => min_content.size.width,
+        AvailableSpace::MaxContent => max_content.size.width,
       };
 
-      let mut tree = LayoutTree::from_render_node(self);
-      tree.compute_layout(Size {
-        width: AvailableSpace::Definite(used_width),
-        height: available_space.height,
-      });
-      let results = tree.into_results();
-
-      return results
-        .layout(results.root_node_id())
-        .map_or(Size::zero(), |layout| layout.size);
+      return measure_with(AvailableSpace::Definite(used_width));
     }
 
     measure_with(available_space.width)
   }
 
+  /// Returns the distance from the top of the border box to the baseline of the first line,
+  /// or `None` if the node has no inline text content.
+  pub(crate) fn first_baseline(
+    &self,
+    border_box: Size<f32>,
+    parent_width: f32,
+    is_inline_children: bool,
+  ) -> Option<f32> {
+    let has_text = is_inline_children
+      || self.context.style.content.is_some()
+      || matches!(
+        self.node.as_ref().and_then(Node::inline_content),
+        Some(InlineContentKind::Text(_))
+      );
+
+    if !has_text {
+      return None;
+    }
+
+    let padding = self
+      .context
+      .style
+      .resolved_padding()
+      .map(|length| length.to_px(&self.context.sizing, parent_width));
+    let border = self
+      .context
+      .style
+      .resolved_border_width()
+      .map(|length| length.to_px(&self.context.sizing, parent_width));
+
+    let content_width = (border_box.width
+      - padding.grid_axis_sum(AbsoluteAxis::Horizontal)
+      - border.grid_axis_sum(AbsoluteAxis::Horizontal))
+    .max(0.0);
+
+    let available_space = Size {
+      width: AvailableSpace::Definite(content_width),
+      height: AvailableSpace::MaxContent,
+    };
+    let (max_width, max_height) =
+      create_inline_constraint(&self.context, available_space, Size::NONE);
+
+    let font_style = self.context.style.to_sized_font_style(&self.context);
+
+    let (layout, _, _) = create_inline_layout(
+      collect_inline_items(self).into_iter(),
+      available_space,
+      max_width,
+      max_height,
+      &font_style,
+      self.context.global,
+      InlineLayoutStage::Measure,
+    );
+
+    let baseline = layout.lines().next()?.metrics().baseline;
+
+    Some(border.top + padding.top + baseline)
+  }
+
   pub(crate) fn measure(
     &self,
     available_space: Size<AvailableSpace>,
@@ -703,7 +1040,10 @@ impl<'g, N: Node<N>> RenderNode<'g, N> {
         InlineLayoutStage::Measure,
       );
 
-      return measure_inline_layout(&mut layout, max_width);
+      return measure_inline_layout(
+        &mut layout,
+        measure_width_limit(available_space, known_dimensions, max_width),
+      );
     }
 
     assert_ne!(
@@ -747,10 +1087,16 @@ fn flush_inline_group<'g, N: Node<N>>(
         sizing: parent_render_context.sizing.clone(),
         current_color: parent_render_context.current_color,
         draw_debug_border: parent_render_context.draw_debug_border,
+        snap_text_to_pixel: parent_render_context.snap_text_to_pixel,
+        debug_highlight_ids: parent_render_context.debug_highlight_ids.clone(),
         fetched_resources: Default::default(),
+        resolved_images: parent_render_context.resolved_images.clone(),
+        failed_images: parent_render_context.failed_images.clone(),
+        collapsed_borders: Sides([false; 4]),
       },
       children: Some(take(inline_group).into_boxed_slice()),
       node: None,
+      intrinsic_layouts: OnceLock::new(),
     });
   }
 }
@@ -45,9 +45,13 @@
 //!       style: None, // Construct with `StyleBuilder`
 //!       tw: None, // Tailwind properties
 //!       preset: None,
+//!       id: None,
+//!       font_size_fit: None,
+//!       first_letter_style: None,
 //!     }),
 //!   ])),
 //!   preset: None,
+//!   id: None,
 //!   style: None,
 //!   tw: None, // Tailwind properties
 //! });
@@ -84,6 +88,7 @@
 //! - `woff`: Enable WOFF font support.
 //! - `svg`: Enable SVG support.
 //! - `rayon`: Enable rayon support.
+//! - `avif`: Enable AVIF support. Off by default, as it links the system [dav1d](https://code.videolan.org/videolan/dav1d) library.
 //!
 //! # Credits
 //!
@@ -116,7 +121,14 @@ pub use parley;
 pub use taffy;
 use xxhash_rust::xxh3::Xxh3DefaultBuilder;
 
-use crate::resources::{font::FontContext, image::PersistentImageStore};
+use crate::{
+  layout::style::{CustomFilter, CustomFilters, Style, StylePresets},
+  resources::{
+    font::FontContext,
+    glyph_cache::GlyphCache,
+    image::{ImageResolver, PersistentImageStore},
+  },
+};
 
 /// The main context for image rendering.
 ///
@@ -128,6 +140,41 @@ pub struct GlobalContext {
   pub font_context: FontContext,
   /// The image store for persisting contents
   pub persistent_image_store: PersistentImageStore,
+  /// Named style presets that nodes can reference with `preset`
+  pub style_presets: StylePresets,
+  /// Resolves images missing from the fetched resources and the persistent store.
+  /// Resolved images are cached in the persistent store, so each source is resolved once.
+  pub image_resolver: Option<Box<dyn ImageResolver>>,
+  /// Named filters that nodes can reference with `filter: url(#name)`
+  pub custom_filters: CustomFilters,
+  /// Rasterized glyph masks shared between renders, so repeated text is rasterized once
+  pub glyph_cache: GlyphCache,
+  /// The thread pool for the parallel sections of rendering, instead of the global rayon pool.
+  /// Useful to cap the threads used by takumi on servers that already manage a pool.
+  #[cfg(feature = "rayon")]
+  pub thread_pool: Option<std::sync::Arc<rayon::ThreadPool>>,
+}
+
+impl GlobalContext {
+  /// Registers a named style preset, replacing any preset with the same name.
+  ///
+  /// Nodes reference it by name with `preset`, and it is merged beneath their `tw` and `style`.
+  pub fn register_preset(&mut self, name: impl Into<String>, style: Style) {
+    self.style_presets.insert(name.into(), style);
+  }
+
+  /// Registers a named custom filter, replacing any filter with the same name.
+  ///
+  /// Nodes apply it with `filter: url(#name)`, after the filters listed before it.
+  pub fn register_filter(&mut self, name: impl Into<String>, filter: impl CustomFilter + 'static) {
+    self.custom_filters.insert(name.into(), Box::new(filter));
+  }
+
+  /// Sets the font family used when the requested `font-family` isn't loaded or lacks a glyph,
+  /// so text never depends on which font happened to load first. Pass `None` to unset it.
+  pub fn set_fallback_family(&mut self, family_name: Option<&str>) {
+    self.font_context.set_fallback_family(family_name);
+  }
 }
 
 /// Type alias for HashSet using XXH3 hasher
@@ -0,0 +1,378 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use derive_builder::Builder;
+
+use crate::{
+  GlobalContext, Result,
+  layout::{
+    Viewport,
+    node::Node,
+    style::{Angle, Color, ColorInput, CssValue, Length, PercentageNumber, SpacePair, Style},
+  },
+  rendering::{AnimationFrame, RenderOptionsBuilder, render},
+  resources::image::ImageSource,
+};
+
+/// Number of Newton iterations used to solve a cubic bezier for its parameter.
+const CUBIC_BEZIER_ITERATIONS: u32 = 8;
+
+/// An easing function applied to the progress between two keyframes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Easing {
+  /// Constant speed.
+  #[default]
+  Linear,
+  /// Equivalent to `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+  Ease,
+  /// Equivalent to `cubic-bezier(0.42, 0, 1, 1)`.
+  EaseIn,
+  /// Equivalent to `cubic-bezier(0, 0, 0.58, 1)`.
+  EaseOut,
+  /// Equivalent to `cubic-bezier(0.42, 0, 0.58, 1)`.
+  EaseInOut,
+  /// A cubic bezier curve with the control points `(x1, y1)` and `(x2, y2)`.
+  CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+  /// Maps the linear progress `t` (0..1) to the eased progress.
+  pub fn apply(self, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+
+    match self {
+      Easing::Linear => t,
+      Easing::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+      Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+      Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+      Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+      Easing::CubicBezier(x1, y1, x2, y2) => {
+        cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t)
+      }
+    }
+  }
+}
+
+/// Evaluates one axis of a cubic bezier running from 0 to 1 with the control values `a` and `b`.
+fn bezier_axis(a: f32, b: f32, s: f32) -> f32 {
+  let inv = 1.0 - s;
+
+  3.0 * inv * inv * s * a + 3.0 * inv * s * s * b + s * s * s
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, t: f32) -> f32 {
+  // Solve x(s) = t for s, then evaluate y(s).
+  let mut s = t;
+
+  for _ in 0..CUBIC_BEZIER_ITERATIONS {
+    let inv = 1.0 - s;
+    let slope = 3.0 * inv * inv * x1 + 6.0 * inv * s * (x2 - x1) + 3.0 * s * s * (1.0 - x2);
+
+    if slope.abs() < 1e-6 {
+      break;
+    }
+
+    s = (s - (bezier_axis(x1, x2, s) - t) / slope).clamp(0.0, 1.0);
+  }
+
+  bezier_axis(y1, y2, s)
+}
+
+/// A style applied to the animated node at a point of the animation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+  /// Where the keyframe sits in the animation, from 0 (start) to 1 (end).
+  pub offset: f32,
+  /// The style merged over the node's own style at this keyframe.
+  pub style: Style,
+}
+
+impl Keyframe {
+  /// Creates a new keyframe.
+  pub fn new(offset: f32, style: Style) -> Self {
+    Self { offset, style }
+  }
+}
+
+#[derive(Clone, Builder)]
+/// Options for rendering a tweened animation. Construct using [`AnimationBuilder`] to avoid breaking changes.
+pub struct Animation<'g, N: Node<N>> {
+  /// The viewport to render the frames in.
+  pub(crate) viewport: Viewport,
+  /// The global context.
+  pub(crate) global: &'g GlobalContext,
+  /// The node to animate. Keyframe styles are merged over its own style.
+  pub(crate) node: N,
+  /// The keyframes, in any order.
+  pub(crate) keyframes: Vec<Keyframe>,
+  /// The easing applied between each pair of keyframes.
+  #[builder(default)]
+  pub(crate) easing: Easing,
+  /// The number of frames to render.
+  pub(crate) frame_count: u32,
+  /// The total duration of the animation in milliseconds, split as evenly as possible across frames.
+  pub(crate) duration_ms: u32,
+  /// The resources fetched externally.
+  #[builder(default)]
+  pub(crate) fetched_resources: HashMap<Arc<str>, Arc<ImageSource>>,
+  /// The maximum number of characters in a single text node, checked for every frame.
+  ///
+  /// See [`crate::rendering::RenderOptions`] for how the limit applies.
+  #[builder(default)]
+  pub(crate) max_text_length: Option<usize>,
+}
+
+/// Renders every frame of a tweened animation.
+///
+/// Only animatable numeric properties (`opacity`, `translate`, `scale`, `rotate` and `color`) are
+/// interpolated between keyframes. Every other property keeps the value of the earlier keyframe.
+///
+/// Frames always have straight alpha ([`crate::rendering::AlphaMode::Straight`]), which is what
+/// the animated encoders in [`crate::rendering`] expect.
+pub fn render_animation<'g, N: Node<N>>(
+  animation: Animation<'g, N>,
+) -> Result<Vec<AnimationFrame>> {
+  let mut keyframes = animation.keyframes;
+  keyframes.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+  let last_frame = animation.frame_count.saturating_sub(1).max(1) as f32;
+
+  (0..animation.frame_count)
+    .map(|frame| {
+      let mut node = animation.node.clone();
+
+      if let Some(keyframe_style) =
+        style_at_progress(&keyframes, animation.easing, frame as f32 / last_frame)
+        && let Some(style) = node.get_style_mut()
+      {
+        style.merge_from(keyframe_style);
+      }
+
+      let Ok(options) = RenderOptionsBuilder::default()
+        .viewport(animation.viewport)
+        .global(animation.global)
+        .node(node)
+        .fetched_resources(animation.fetched_resources.clone())
+        .max_text_length(animation.max_text_length)
+        // `render` drops the stats, so the passes don't need to be timed.
+        .clock((|| Duration::ZERO) as fn() -> Duration)
+        .build()
+      else {
+        unreachable!()
+      };
+
+      let image = render(options)?;
+
+      Ok(AnimationFrame::new(
+        image,
+        frame_duration_ms(animation.duration_ms, animation.frame_count, frame),
+      ))
+    })
+    .collect()
+}
+
+/// The duration of `frame`, with the remainder of an uneven split spread across the frames so
+/// they add up to exactly `duration_ms`.
+fn frame_duration_ms(duration_ms: u32, frame_count: u32, frame: u32) -> u32 {
+  let frame_count = u64::from(frame_count.max(1));
+  let end = u64::from(duration_ms) * u64::from(frame + 1) / frame_count;
+  let start = u64::from(duration_ms) * u64::from(frame) / frame_count;
+
+  (end - start) as u32
+}
+
+/// Resolves the style at `progress` from keyframes sorted by offset.
+fn style_at_progress(keyframes: &[Keyframe], easing: Easing, progress: f32) -> Option<Style> {
+  let next_index = keyframes.partition_point(|keyframe| keyframe.offset <= progress);
+
+  let (from, to) = match (next_index.checked_sub(1), keyframes.get(next_index)) {
+    (Some(from), Some(to)) => (&keyframes[from], to),
+    (Some(from), None) => return Some(keyframes[from].style.clone()),
+    (None, Some(to)) => return Some(to.style.clone()),
+    (None, None) => return None,
+  };
+
+  let span = to.offset - from.offset;
+  let t = if span > 0.0 {
+    (progress - from.offset) / span
+  } else {
+    1.0
+  };
+
+  Some(interpolate_style(&from.style, &to.style, easing.apply(t)))
+}
+
+fn interpolate_style(from: &Style, to: &Style, t: f32) -> Style {
+  Style {
+    opacity: interpolate_value(&from.opacity, &to.opacity, t),
+    rotate: interpolate_value(&from.rotate, &to.rotate, t),
+    scale: interpolate_value(&from.scale, &to.scale, t),
+    scale_x: interpolate_value(&from.scale_x, &to.scale_x, t),
+    scale_y: interpolate_value(&from.scale_y, &to.scale_y, t),
+    translate: interpolate_value(&from.translate, &to.translate, t),
+    translate_x: interpolate_value(&from.translate_x, &to.translate_x, t),
+    translate_y: interpolate_value(&from.translate_y, &to.translate_y, t),
+    color: interpolate_value(&from.color, &to.color, t),
+    ..from.clone()
+  }
+}
+
+fn interpolate_value<T: Interpolate + Clone, const DEFAULT_INHERIT: bool>(
+  from: &CssValue<T, DEFAULT_INHERIT>,
+  to: &CssValue<T, DEFAULT_INHERIT>,
+  t: f32,
+) -> CssValue<T, DEFAULT_INHERIT> {
+  if let (CssValue::Value(from), CssValue::Value(to)) = (from, to)
+    && let Some(value) = from.clone().interpolate(to.clone(), t)
+  {
+    return CssValue::Value(value);
+  }
+
+  from.clone()
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+  from + (to - from) * t
+}
+
+/// A value that can be blended towards another value of the same kind.
+trait Interpolate: Sized {
+  /// Returns the value `t` of the way to `to`, or `None` if the two values can't be blended.
+  fn interpolate(self, to: Self, t: f32) -> Option<Self>;
+}
+
+impl Interpolate for PercentageNumber {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    Some(PercentageNumber(lerp(self.0, to.0, t)))
+  }
+}
+
+impl Interpolate for Angle {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    Some(Angle::new(lerp(*self, *to, t)))
+  }
+}
+
+impl Interpolate for Color {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    let mut color = self;
+
+    for (channel, target) in color.0.iter_mut().zip(to.0) {
+      *channel = lerp(*channel as f32, target as f32, t)
+        .round()
+        .clamp(0.0, 255.0) as u8;
+    }
+
+    Some(color)
+  }
+}
+
+impl<const DEFAULT_CURRENT_COLOR: bool> Interpolate for ColorInput<DEFAULT_CURRENT_COLOR> {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    match (self, to) {
+      (ColorInput::Value(from), ColorInput::Value(to)) => from.interpolate(to, t).map(Into::into),
+      _ => None,
+    }
+  }
+}
+
+impl<const DEFAULT_AUTO: bool> Interpolate for Length<DEFAULT_AUTO> {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    // Only lengths in the same unit are blended, mixed units would need layout information.
+    let value = match (self, to) {
+      (Length::Px(from), Length::Px(to)) => Length::Px(lerp(from, to, t)),
+      (Length::Percentage(from), Length::Percentage(to)) => Length::Percentage(lerp(from, to, t)),
+      (Length::Rem(from), Length::Rem(to)) => Length::Rem(lerp(from, to, t)),
+      (Length::Em(from), Length::Em(to)) => Length::Em(lerp(from, to, t)),
+      (Length::Vw(from), Length::Vw(to)) => Length::Vw(lerp(from, to, t)),
+      (Length::Vh(from), Length::Vh(to)) => Length::Vh(lerp(from, to, t)),
+      _ => return None,
+    };
+
+    Some(value)
+  }
+}
+
+impl<T: Interpolate + Copy, const Y_FIRST: bool> Interpolate for SpacePair<T, Y_FIRST> {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    Some(SpacePair {
+      x: self.x.interpolate(to.x, t)?,
+      y: self.y.interpolate(to.y, t)?,
+    })
+  }
+}
+
+impl<T: Interpolate> Interpolate for Option<T> {
+  fn interpolate(self, to: Self, t: f32) -> Option<Self> {
+    match (self, to) {
+      (Some(from), Some(to)) => from.interpolate(to, t).map(Some),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::layout::style::SizeLength;
+
+  fn assert_near(lhs: f32, rhs: f32) {
+    assert!((lhs - rhs).abs() < 0.001, "lhs={lhs}, rhs={rhs}");
+  }
+
+  #[test]
+  fn test_easing_endpoints() {
+    for easing in [
+      Easing::Linear,
+      Easing::Ease,
+      Easing::EaseIn,
+      Easing::EaseOut,
+      Easing::EaseInOut,
+      Easing::CubicBezier(0.1, 0.7, 1.0, 0.1),
+    ] {
+      assert_near(easing.apply(0.0), 0.0);
+      assert_near(easing.apply(1.0), 1.0);
+    }
+  }
+
+  #[test]
+  fn test_ease_in_out_is_symmetric() {
+    assert_near(Easing::EaseInOut.apply(0.5), 0.5);
+    assert!(Easing::EaseInOut.apply(0.25) < 0.25);
+    assert!(Easing::EaseInOut.apply(0.75) > 0.75);
+  }
+
+  #[test]
+  fn test_style_at_progress_blends_numeric_properties_only() {
+    let from = Style {
+      opacity: CssValue::Value(PercentageNumber(0.0)),
+      translate_x: CssValue::Value(Some(Length::Px(0.0))),
+      width: Length::Px(10.0).into(),
+      ..Style::default()
+    };
+    let to = Style {
+      opacity: CssValue::Value(PercentageNumber(1.0)),
+      translate_x: CssValue::Value(Some(Length::Px(100.0))),
+      width: Length::Px(20.0).into(),
+      ..Style::default()
+    };
+    let keyframes = [Keyframe::new(0.0, from), Keyframe::new(1.0, to)];
+
+    let Some(style) = style_at_progress(&keyframes, Easing::Linear, 0.25) else {
+      unreachable!()
+    };
+
+    assert_eq!(style.opacity, CssValue::Value(PercentageNumber(0.25)));
+    assert_eq!(style.translate_x, CssValue::Value(Some(Length::Px(25.0))));
+    assert_eq!(style.width, CssValue::Value(SizeLength(Length::Px(10.0))));
+  }
+
+  #[test]
+  fn test_frame_durations_add_up_to_total() {
+    let durations: Vec<u32> = (0..3)
+      .map(|frame| frame_duration_ms(1000, 3, frame))
+      .collect();
+
+    assert_eq!(durations, [333, 333, 334]);
+    assert_eq!(durations.iter().sum::<u32>(), 1000);
+  }
+}
@@ -2,7 +2,7 @@ use std::{borrow::Cow, iter::successors};
 
 use image::{GenericImageView, Rgba, RgbaImage};
 use smallvec::{SmallVec, smallvec};
-use taffy::Size;
+use taffy::{Layout, Point, Size};
 
 use crate::{
   Result,
@@ -19,6 +19,60 @@ pub(crate) struct TileLayer {
 
 pub(crate) type TileLayers = Vec<TileLayer>;
 
+/// The areas a background layer is positioned in and painted over.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct BackgroundArea {
+  /// The size of the painting area, which is the border box.
+  pub painting: Size<u32>,
+  /// The offset of the positioning area from the border box.
+  pub origin: Point<i32>,
+  /// The size of the positioning area.
+  pub positioning: Size<u32>,
+}
+
+impl From<Size<u32>> for BackgroundArea {
+  fn from(border_box: Size<u32>) -> Self {
+    Self {
+      painting: border_box,
+      origin: Point { x: 0, y: 0 },
+      positioning: border_box,
+    }
+  }
+}
+
+impl BackgroundArea {
+  /// Creates the background area of a layout, positioned relative to the `background-origin` box.
+  pub(crate) fn from_layout(layout: Layout, origin: BackgroundOrigin) -> Self {
+    let (left, top, right, bottom) = match origin {
+      BackgroundOrigin::BorderBox => (0.0, 0.0, 0.0, 0.0),
+      BackgroundOrigin::PaddingBox => (
+        layout.border.left,
+        layout.border.top,
+        layout.border.right,
+        layout.border.bottom,
+      ),
+      BackgroundOrigin::ContentBox => (
+        layout.border.left + layout.padding.left,
+        layout.border.top + layout.padding.top,
+        layout.border.right + layout.padding.right,
+        layout.border.bottom + layout.padding.bottom,
+      ),
+    };
+
+    Self {
+      painting: layout.size.map(|x| x as u32),
+      origin: Point {
+        x: left as i32,
+        y: top as i32,
+      },
+      positioning: Size {
+        width: (layout.size.width - left - right).max(0.0) as u32,
+        height: (layout.size.height - top - bottom).max(0.0) as u32,
+      },
+    }
+  }
+}
+
 pub(crate) fn rasterize_layers(
   layers: TileLayers,
   size: Size<u32>,
@@ -124,6 +178,18 @@ pub(crate) fn resolve_length_against_area(unit: Length, area: u32, sizing: &Sizi
   }
 }
 
+/// Returns the intrinsic size of a url background, gradients have none.
+fn intrinsic_background_size(
+  image: &BackgroundImage,
+  context: &RenderContext,
+) -> Option<(f32, f32)> {
+  let (url, scale) = image.selected_url(context.sizing.viewport.device_pixel_ratio)?;
+
+  let (width, height) = resolve_image(url, context).ok()?.size();
+
+  (width > 0.0 && height > 0.0).then_some((width * scale, height * scale))
+}
+
 pub(crate) fn resolve_background_size(
   size: BackgroundSize,
   area: Size<u32>,
@@ -131,56 +197,58 @@ pub(crate) fn resolve_background_size(
   context: &RenderContext,
 ) -> (u32, u32) {
   match size {
-    BackgroundSize::Explicit { width, height } => (
-      resolve_length_against_area(width, area.width, &context.sizing),
-      resolve_length_against_area(height, area.height, &context.sizing),
-    ),
-    BackgroundSize::Cover => {
-      // Get intrinsic image dimensions
-      let (intrinsic_width, intrinsic_height) = if let BackgroundImage::Url(url) = image
-        && let Ok(source) = resolve_image(url, context)
-      {
-        source.size()
+    BackgroundSize::Explicit { width, height } => {
+      // An `auto` axis follows the intrinsic size or ratio, images without one fill the area.
+      let intrinsic = if width == Length::Auto || height == Length::Auto {
+        intrinsic_background_size(image, context)
       } else {
-        return (0, 0);
+        None
       };
 
-      if intrinsic_width == 0.0 || intrinsic_height == 0.0 {
-        return (0, 0);
-      }
-
-      // Calculate scale factors for both dimensions
-      let scale_x = area.width as f32 / intrinsic_width;
-      let scale_y = area.height as f32 / intrinsic_height;
-
-      // Use the larger scale to ensure the image covers the entire area
-      let scale = scale_x.max(scale_y);
+      match (width, height, intrinsic) {
+        (Length::Auto, Length::Auto, Some((intrinsic_width, intrinsic_height))) => (
+          intrinsic_width.round() as u32,
+          intrinsic_height.round() as u32,
+        ),
+        (Length::Auto, height, Some((intrinsic_width, intrinsic_height))) => {
+          let height = resolve_length_against_area(height, area.height, &context.sizing);
+
+          (
+            (height as f32 * intrinsic_width / intrinsic_height).round() as u32,
+            height,
+          )
+        }
+        (width, Length::Auto, Some((intrinsic_width, intrinsic_height))) => {
+          let width = resolve_length_against_area(width, area.width, &context.sizing);
 
-      (
-        (intrinsic_width * scale).round() as u32,
-        (intrinsic_height * scale).round() as u32,
-      )
+          (
+            width,
+            (width as f32 * intrinsic_height / intrinsic_width).round() as u32,
+          )
+        }
+        _ => (
+          resolve_length_against_area(width, area.width, &context.sizing),
+          resolve_length_against_area(height, area.height, &context.sizing),
+        ),
+      }
     }
-    BackgroundSize::Contain => {
-      // Get intrinsic image dimensions
-      let (intrinsic_width, intrinsic_height) = if let BackgroundImage::Url(url) = image
-        && let Ok(source) = resolve_image(url, context)
-      {
-        source.size()
-      } else {
+    BackgroundSize::Cover | BackgroundSize::Contain => {
+      let Some((intrinsic_width, intrinsic_height)) = intrinsic_background_size(image, context)
+      else {
         return (0, 0);
       };
 
-      if intrinsic_width == 0.0 || intrinsic_height == 0.0 {
-        return (0, 0);
-      }
-
       // Calculate scale factors for both dimensions
       let scale_x = area.width as f32 / intrinsic_width;
       let scale_y = area.height as f32 / intrinsic_height;
 
-      // Use the smaller scale to ensure the image is fully contained
-      let scale = scale_x.min(scale_y);
+      // Cover uses the larger scale so the image covers the entire area,
+      // contain uses the smaller one so the image is fully contained.
+      let scale = if size == BackgroundSize::Cover {
+        scale_x.max(scale_y)
+      } else {
+        scale_x.min(scale_y)
+      };
 
       (
         (intrinsic_width * scale).round() as u32,
@@ -212,10 +280,19 @@ pub(crate) fn resolve_position_component_x(
     PositionComponent::KeywordX(PositionKeywordX::Left) => 0,
     PositionComponent::KeywordX(PositionKeywordX::Center) => available / 2,
     PositionComponent::KeywordX(PositionKeywordX::Right) => available,
-    PositionComponent::KeywordY(_) => available / 2,
+    PositionComponent::KeywordY(_) | PositionComponent::OffsetY(..) => available / 2,
     PositionComponent::Length(length) => {
       resolve_length_to_position_component(length, available, sizing)
     }
+    PositionComponent::OffsetX(edge, offset) => {
+      let offset = resolve_length_to_position_component(offset, available, sizing);
+
+      match edge {
+        PositionKeywordX::Left => offset,
+        PositionKeywordX::Center => available / 2 + offset,
+        PositionKeywordX::Right => available - offset,
+      }
+    }
   }
 }
 
@@ -230,10 +307,19 @@ pub(crate) fn resolve_position_component_y(
     PositionComponent::KeywordY(PositionKeywordY::Top) => 0,
     PositionComponent::KeywordY(PositionKeywordY::Center) => available / 2,
     PositionComponent::KeywordY(PositionKeywordY::Bottom) => available,
-    PositionComponent::KeywordX(_) => available / 2,
+    PositionComponent::KeywordX(_) | PositionComponent::OffsetX(..) => available / 2,
     PositionComponent::Length(length) => {
       resolve_length_to_position_component(length, available, sizing)
     }
+    PositionComponent::OffsetY(edge, offset) => {
+      let offset = resolve_length_to_position_component(offset, available, sizing);
+
+      match edge {
+        PositionKeywordY::Top => offset,
+        PositionKeywordY::Center => available / 2 + offset,
+        PositionKeywordY::Bottom => available - offset,
+      }
+    }
   }
 }
 
@@ -271,7 +357,11 @@ pub(crate) fn render_tile(
     BackgroundImage::Noise(noise) => Some(BackgroundTile::Noise(NoiseV1Tile::new(
       *noise, tile_w, tile_h,
     ))),
-    BackgroundImage::Url(url) => {
+    BackgroundImage::Url(_) | BackgroundImage::ImageSet(_) => {
+      let Some((url, _)) = image.selected_url(context.sizing.viewport.device_pixel_ratio) else {
+        return Ok(None);
+      };
+
       if let Ok(source) = resolve_image(url, context) {
         Some(BackgroundTile::Image(
           source
@@ -293,52 +383,74 @@ pub(crate) fn resolve_layer_tiles(
   size: BackgroundSize,
   repeat: BackgroundRepeat,
   blend_mode: BlendMode,
-  area: Size<u32>,
+  area: BackgroundArea,
   context: &RenderContext,
   buffer_pool: &mut BufferPool,
 ) -> Result<Option<TileLayer>> {
-  let (initial_w, initial_h) = resolve_background_size(size, area, image, context);
+  let BackgroundArea {
+    painting,
+    origin,
+    positioning,
+  } = area;
+  let (initial_w, initial_h) = resolve_background_size(size, positioning, image, context);
 
   if initial_w == 0 || initial_h == 0 {
     return Ok(None);
   }
 
+  // Repeated tiles still cover the whole painting area, while the others stay within the positioning area.
   let (xs, tile_w) = match repeat.0 {
     BackgroundRepeatStyle::Repeat => {
-      let origin_x = resolve_position_component_x(pos, initial_w, area.width, &context.sizing);
+      let origin_x =
+        resolve_position_component_x(pos, initial_w, positioning.width, &context.sizing);
       (
-        collect_repeat_tile_positions(area.width, initial_w, origin_x),
+        collect_repeat_tile_positions(painting.width, initial_w, origin.x + origin_x),
         initial_w,
       )
     }
     BackgroundRepeatStyle::NoRepeat => {
-      let origin_x = resolve_position_component_x(pos, initial_w, area.width, &context.sizing);
-      (smallvec![origin_x], initial_w)
+      let origin_x =
+        resolve_position_component_x(pos, initial_w, positioning.width, &context.sizing);
+      (smallvec![origin.x + origin_x], initial_w)
     }
     BackgroundRepeatStyle::Space => (
-      collect_spaced_tile_positions(area.width, initial_w),
+      offset_tile_positions(
+        collect_spaced_tile_positions(positioning.width, initial_w),
+        origin.x,
+      ),
       initial_w,
     ),
-    BackgroundRepeatStyle::Round => collect_stretched_tile_positions(area.width, initial_w),
+    BackgroundRepeatStyle::Round => {
+      let (xs, tile_w) = collect_stretched_tile_positions(positioning.width, initial_w);
+      (offset_tile_positions(xs, origin.x), tile_w)
+    }
   };
 
   let (ys, tile_h) = match repeat.1 {
     BackgroundRepeatStyle::Repeat => {
-      let origin_y = resolve_position_component_y(pos, initial_h, area.height, &context.sizing);
+      let origin_y =
+        resolve_position_component_y(pos, initial_h, positioning.height, &context.sizing);
       (
-        collect_repeat_tile_positions(area.height, initial_h, origin_y),
+        collect_repeat_tile_positions(painting.height, initial_h, origin.y + origin_y),
         initial_h,
       )
     }
     BackgroundRepeatStyle::NoRepeat => {
-      let origin_y = resolve_position_component_y(pos, initial_h, area.height, &context.sizing);
-      (smallvec![origin_y], initial_h)
+      let origin_y =
+        resolve_position_component_y(pos, initial_h, positioning.height, &context.sizing);
+      (smallvec![origin.y + origin_y], initial_h)
     }
     BackgroundRepeatStyle::Space => (
-      collect_spaced_tile_positions(area.height, initial_h),
+      offset_tile_positions(
+        collect_spaced_tile_positions(positioning.height, initial_h),
+        origin.y,
+      ),
       initial_h,
     ),
-    BackgroundRepeatStyle::Round => collect_stretched_tile_positions(area.height, initial_h),
+    BackgroundRepeatStyle::Round => {
+      let (ys, tile_h) = collect_stretched_tile_positions(positioning.height, initial_h);
+      (offset_tile_positions(ys, origin.y), tile_h)
+    }
   };
 
   if xs.is_empty() || ys.is_empty() {
@@ -357,6 +469,17 @@ pub(crate) fn resolve_layer_tiles(
   }))
 }
 
+/// Shifts tile positions from the positioning area into the painting area.
+fn offset_tile_positions(mut positions: SmallVec<[i32; 1]>, offset: i32) -> SmallVec<[i32; 1]> {
+  if offset != 0 {
+    for position in &mut positions {
+      *position += offset;
+    }
+  }
+
+  positions
+}
+
 /// Collects a list of tile positions to place along an axis.
 /// Starts from the "origin" and collects tile positions until the "area_size" is reached.
 pub(crate) fn collect_repeat_tile_positions(
@@ -435,7 +558,7 @@ pub(crate) fn resolve_tile_layers(
   repeats: &[BackgroundRepeat],
   blend_modes: &[BlendMode],
   context: &RenderContext,
-  border_box: Size<u32>,
+  area: BackgroundArea,
   buffer_pool: &mut BufferPool,
 ) -> Result<TileLayers> {
   let last_position = positions.last().copied().unwrap_or_default();
@@ -456,7 +579,7 @@ pub(crate) fn resolve_tile_layers(
       size,
       repeat,
       blend_mode,
-      border_box,
+      area,
       context,
       buffer_pool,
     )?);
@@ -536,7 +659,7 @@ pub(crate) fn create_mask(
       }),
     &[], // no blending mode for mask
     context,
-    border_box.map(|x| x as u32),
+    border_box.map(|x| x as u32).into(),
     buffer_pool,
   )?;
 
@@ -592,9 +715,11 @@ pub(crate) fn create_mask(
 
 pub(crate) fn collect_background_layers(
   context: &RenderContext,
-  border_box: Size<f32>,
+  layout: Layout,
   buffer_pool: &mut BufferPool,
 ) -> Result<TileLayers> {
+  let area = BackgroundArea::from_layout(layout, context.style.background_origin());
+
   let background_image = context
     .style
     .background_image
@@ -674,7 +799,7 @@ pub(crate) fn collect_background_layers(
         )
       }),
     context,
-    border_box.map(|x| x as u32),
+    area,
     buffer_pool,
   )?;
 
@@ -689,8 +814,8 @@ pub(crate) fn collect_background_layers(
       TileLayer {
         tile: BackgroundTile::Color(ColorTile {
           color: background_color.into(),
-          width: border_box.width as u32,
-          height: border_box.height as u32,
+          width: area.painting.width,
+          height: area.painting.height,
         }),
         xs: [0].into(),
         ys: [0].into(),
@@ -701,3 +826,121 @@ pub(crate) fn collect_background_layers(
 
   Ok(layers)
 }
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashMap, sync::Arc};
+
+  use super::*;
+  use crate::{GlobalContext, resources::image::ImageSource};
+
+  fn resolve_with_ratio_image(size: BackgroundSize, image: &BackgroundImage) -> (u32, u32) {
+    let context = GlobalContext::default();
+    let fetched_resources = HashMap::from([(
+      Arc::from("ratio.png"),
+      Arc::new(ImageSource::Bitmap(RgbaImage::new(200, 100))),
+    )]);
+    let render_context = RenderContext::new(&context, (300, 300).into(), fetched_resources);
+
+    resolve_background_size(
+      size,
+      Size {
+        width: 300,
+        height: 300,
+      },
+      image,
+      &render_context,
+    )
+  }
+
+  #[test]
+  fn test_background_size_auto_height_follows_image_ratio() {
+    let size = BackgroundSize::Explicit {
+      width: Length::Px(100.0),
+      height: Length::Auto,
+    };
+
+    assert_eq!(
+      resolve_with_ratio_image(size, &BackgroundImage::Url("ratio.png".into())),
+      (100, 50)
+    );
+  }
+
+  #[test]
+  fn test_background_size_auto_without_intrinsic_size() {
+    let auto = BackgroundSize::Explicit {
+      width: Length::Auto,
+      height: Length::Auto,
+    };
+    let image = BackgroundImage::Url("ratio.png".into());
+
+    assert_eq!(resolve_with_ratio_image(auto, &image), (200, 100));
+
+    // Gradients have no intrinsic size, so `auto` falls back to the area.
+    let Ok(gradient) = BackgroundImage::from_str("linear-gradient(red, blue)") else {
+      unreachable!()
+    };
+    let size = BackgroundSize::Explicit {
+      width: Length::Px(100.0),
+      height: Length::Auto,
+    };
+
+    assert_eq!(resolve_with_ratio_image(size, &gradient), (100, 300));
+  }
+
+  /// Resolves the positions of a contained 100x200 portrait image in a 400x200 landscape area.
+  fn contained_portrait_tile_positions(pos: BackgroundPosition) -> (Vec<i32>, Vec<i32>) {
+    let context = GlobalContext::default();
+    let fetched_resources = HashMap::from([(
+      Arc::from("portrait.png"),
+      Arc::new(ImageSource::Bitmap(RgbaImage::new(100, 200))),
+    )]);
+    let render_context = RenderContext::new(&context, (400, 200).into(), fetched_resources);
+
+    let Ok(Some(layer)) = resolve_layer_tiles(
+      &BackgroundImage::Url("portrait.png".into()),
+      pos,
+      BackgroundSize::Contain,
+      BackgroundRepeat::no_repeat(),
+      BlendMode::Normal,
+      Size {
+        width: 400,
+        height: 200,
+      }
+      .into(),
+      &render_context,
+      &mut BufferPool::default(),
+    ) else {
+      unreachable!()
+    };
+
+    (layer.xs.to_vec(), layer.ys.to_vec())
+  }
+
+  #[test]
+  fn test_contained_tile_is_centered_by_default() {
+    assert_eq!(
+      contained_portrait_tile_positions(BackgroundPosition::default()),
+      (vec![150], vec![0])
+    );
+  }
+
+  #[test]
+  fn test_contained_tile_follows_background_position() {
+    let Ok(right) = BackgroundPosition::from_str("right") else {
+      unreachable!()
+    };
+    let Ok(offset) = BackgroundPosition::from_str("left 20% top") else {
+      unreachable!()
+    };
+
+    assert_eq!(
+      contained_portrait_tile_positions(right),
+      (vec![300], vec![0])
+    );
+    assert_eq!(
+      contained_portrait_tile_positions(offset),
+      (vec![60], vec![0])
+    );
+  }
+}
@@ -0,0 +1,275 @@
+use image::{GenericImageView, RgbaImage, imageops::crop_imm};
+use smallvec::{SmallVec, smallvec};
+use taffy::{Layout, Rect, Size};
+
+use crate::{
+  Result,
+  layout::{node::resolve_image, style::*},
+  rendering::{
+    BackgroundTile, BorderProperties, Canvas, RenderContext, collect_repeat_tile_positions,
+    collect_spaced_tile_positions, collect_stretched_tile_positions, fast_resize, overlay_image,
+    render_tile,
+  },
+};
+
+/// Draws the `border-image` of a node in place of its border.
+///
+/// Returns `false` when the node has no border image or it can't be loaded,
+/// in which case the regular border should be drawn instead.
+pub(crate) fn draw_border_image(
+  context: &RenderContext,
+  canvas: &mut Canvas,
+  layout: Layout,
+) -> Result<bool> {
+  let Some(source) = &context.style.border_image_source else {
+    return Ok(false);
+  };
+
+  // The border image area is the border box extended by `border-image-outset`.
+  let [outset_top, outset_right, outset_bottom, outset_left] = context.style.border_image_outset.0;
+  let resolve_outset = |value: BorderImageOutset, border: f32| match value {
+    BorderImageOutset::Length(length) => length.to_px(&context.sizing, 0.0),
+    BorderImageOutset::Number(number) => number * border,
+  };
+  let outset = Rect {
+    top: resolve_outset(outset_top, layout.border.top),
+    right: resolve_outset(outset_right, layout.border.right),
+    bottom: resolve_outset(outset_bottom, layout.border.bottom),
+    left: resolve_outset(outset_left, layout.border.left),
+  };
+
+  let area = Size {
+    width: layout.size.width + outset.left + outset.right,
+    height: layout.size.height + outset.top + outset.bottom,
+  };
+
+  let Some(image) = rasterize_source(source, area, context, canvas)? else {
+    return Ok(false);
+  };
+
+  let image_width = image.width() as f32;
+  let image_height = image.height() as f32;
+
+  let slice = context.style.border_image_slice;
+  let [slice_top, slice_right, slice_bottom, slice_left] = slice.offsets.0;
+  let slices = Rect {
+    top: slice_top.resolve(image_height),
+    right: slice_right.resolve(image_width),
+    bottom: slice_bottom.resolve(image_height),
+    left: slice_left.resolve(image_width),
+  };
+
+  let [width_top, width_right, width_bottom, width_left] = context.style.border_image_width.0;
+  let resolve_width = |value: BorderImageWidth, border: f32, slice: f32, basis: f32| match value {
+    BorderImageWidth::Length(length) => length.to_px(&context.sizing, basis),
+    BorderImageWidth::Number(number) => number * border,
+    BorderImageWidth::Auto => slice,
+  };
+  let mut widths = Rect {
+    top: resolve_width(width_top, layout.border.top, slices.top, area.height),
+    right: resolve_width(width_right, layout.border.right, slices.right, area.width),
+    bottom: resolve_width(
+      width_bottom,
+      layout.border.bottom,
+      slices.bottom,
+      area.height,
+    ),
+    left: resolve_width(width_left, layout.border.left, slices.left, area.width),
+  };
+
+  // Opposite widths that don't fit in the area are scaled down proportionally.
+  let scale = (area.width / (widths.left + widths.right))
+    .min(area.height / (widths.top + widths.bottom))
+    .min(1.0);
+
+  if scale < 1.0 {
+    widths = widths.map(|width| width * scale);
+  }
+
+  let destination_xs = [0.0, widths.left, area.width - widths.right, area.width];
+  let destination_ys = [0.0, widths.top, area.height - widths.bottom, area.height];
+  let source_xs = [0.0, slices.left, image_width - slices.right, image_width];
+  let source_ys = [0.0, slices.top, image_height - slices.bottom, image_height];
+
+  // Edges keep the aspect ratio of their slice across the axis they tile along.
+  let ratio = |width: f32, slice: f32| (slice > 0.0).then(|| width / slice);
+  let top_scale = ratio(widths.top, slices.top);
+  let bottom_scale = ratio(widths.bottom, slices.bottom);
+  let left_scale = ratio(widths.left, slices.left);
+  let right_scale = ratio(widths.right, slices.right);
+
+  let repeat = context.style.border_image_repeat;
+
+  for row in 0..3 {
+    for column in 0..3 {
+      if row == 1 && column == 1 && !slice.fill {
+        continue;
+      }
+
+      let x = destination_xs[column].round();
+      let y = destination_ys[row].round();
+      let width = destination_xs[column + 1].round() - x;
+      let height = destination_ys[row + 1].round() - y;
+
+      let source_x = source_xs[column].round();
+      let source_y = source_ys[row].round();
+      let source_width = source_xs[column + 1].round() - source_x;
+      let source_height = source_ys[row + 1].round() - source_y;
+
+      if width <= 0.0 || height <= 0.0 || source_width <= 0.0 || source_height <= 0.0 {
+        continue;
+      }
+
+      let (tile_xs, tile_width) = if column == 1 {
+        let scale = match row {
+          0 => top_scale,
+          2 => bottom_scale,
+          _ => top_scale.or(bottom_scale),
+        };
+
+        tile_axis(
+          repeat.0,
+          width as u32,
+          (source_width * scale.unwrap_or(1.0)).round() as u32,
+        )
+      } else {
+        (smallvec![0], width as u32)
+      };
+
+      let (tile_ys, tile_height) = if row == 1 {
+        let scale = match column {
+          0 => left_scale,
+          2 => right_scale,
+          _ => left_scale.or(right_scale),
+        };
+
+        tile_axis(
+          repeat.1,
+          height as u32,
+          (source_height * scale.unwrap_or(1.0)).round() as u32,
+        )
+      } else {
+        (smallvec![0], height as u32)
+      };
+
+      if tile_width == 0 || tile_height == 0 {
+        continue;
+      }
+
+      let region_source = crop_imm(
+        &image,
+        source_x as u32,
+        source_y as u32,
+        source_width as u32,
+        source_height as u32,
+      )
+      .to_image();
+      let tile = fast_resize(
+        &region_source,
+        tile_width,
+        tile_height,
+        context.style.image_rendering,
+      )?;
+
+      let mut region = canvas
+        .buffer_pool
+        .acquire_image(width as u32, height as u32)?;
+
+      for &tile_x in &tile_xs {
+        for &tile_y in &tile_ys {
+          overlay_image(
+            &mut region,
+            &tile,
+            BorderProperties::default(),
+            Affine::translation(tile_x as f32, tile_y as f32),
+            context.style.image_rendering,
+            BlendMode::Normal,
+            &[],
+            &mut canvas.mask_memory,
+            &mut canvas.buffer_pool,
+          );
+        }
+      }
+
+      canvas.overlay_image(
+        &region,
+        BorderProperties::default(),
+        context.transform * Affine::translation(x - outset.left, y - outset.top),
+        context.style.image_rendering,
+        BlendMode::Normal,
+      );
+
+      canvas.buffer_pool.release_image(region);
+    }
+  }
+
+  Ok(true)
+}
+
+/// Rasterizes the border image source, images at their natural size and gradients at the size of the area.
+fn rasterize_source(
+  source: &BackgroundImage,
+  area: Size<f32>,
+  context: &RenderContext,
+  canvas: &mut Canvas,
+) -> Result<Option<RgbaImage>> {
+  let (width, height) = match source {
+    BackgroundImage::None => return Ok(None),
+    BackgroundImage::Url(_) | BackgroundImage::ImageSet(_) => {
+      let Some((url, scale)) = source.selected_url(context.sizing.viewport.device_pixel_ratio)
+      else {
+        return Ok(None);
+      };
+      let Ok(image) = resolve_image(url, context) else {
+        return Ok(None);
+      };
+      let (width, height) = image.size();
+
+      (
+        (width * scale).round() as u32,
+        (height * scale).round() as u32,
+      )
+    }
+    _ => (area.width.round() as u32, area.height.round() as u32),
+  };
+
+  if width == 0 || height == 0 {
+    return Ok(None);
+  }
+
+  let tile = render_tile(source, width, height, context, &mut canvas.buffer_pool)?;
+
+  Ok(tile.map(|tile| match tile {
+    BackgroundTile::Image(image) => image,
+    tile => RgbaImage::from_fn(width, height, |x, y| tile.get_pixel(x, y)),
+  }))
+}
+
+/// Lays out the tiles of a border image region along one axis, returning their positions and size.
+fn tile_axis(
+  style: BorderImageRepeatStyle,
+  area_size: u32,
+  tile_size: u32,
+) -> (SmallVec<[i32; 1]>, u32) {
+  if tile_size == 0 {
+    return (smallvec![0], area_size);
+  }
+
+  match style {
+    BorderImageRepeatStyle::Stretch => (smallvec![0], area_size),
+    // Repeated tiles are centered in the region, clipping the tiles at both ends.
+    BorderImageRepeatStyle::Repeat => (
+      collect_repeat_tile_positions(
+        area_size,
+        tile_size,
+        (area_size as i32 - tile_size as i32) / 2,
+      ),
+      tile_size,
+    ),
+    BorderImageRepeatStyle::Round => collect_stretched_tile_positions(area_size, tile_size),
+    BorderImageRepeatStyle::Space => (
+      collect_spaced_tile_positions(area_size, tile_size),
+      tile_size,
+    ),
+  }
+}
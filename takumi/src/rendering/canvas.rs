@@ -10,8 +10,8 @@ use image::{
   error::{ParameterError, ParameterErrorKind},
 };
 use smallvec::SmallVec;
-use taffy::{Layout, Point, Size};
-use zeno::{Mask, PathData, Placement, Scratch};
+use taffy::{Layout, Point, Rect, Size};
+use zeno::{Command, Mask, PathData, Placement, Scratch};
 
 use crate::{Result, layout::style::BlendMode};
 use crate::{
@@ -117,8 +117,8 @@ impl CanvasConstrainResult {
 
 pub(crate) enum CanvasConstrain {
   Overflow {
-    from: Point<u32>,
-    to: Point<u32>,
+    from: Point<i32>,
+    to: Point<i32>,
     inverse_transform: Affine,
     border_radius_mask: Option<(Vec<u8>, u32)>,
   },
@@ -126,6 +126,11 @@ pub(crate) enum CanvasConstrain {
     mask: Vec<u8>,
     placement: Placement,
   },
+  /// An `overflow` clip under a non-translation transform, rasterized in canvas space.
+  TransformedOverflow {
+    mask: Vec<u8>,
+    placement: Placement,
+  },
   MaskImage {
     mask: Vec<u8>,
     from: Point<u32>,
@@ -134,6 +139,23 @@ pub(crate) enum CanvasConstrain {
   },
 }
 
+/// Rasterizes an overflow clip shape with the node transform applied.
+fn transformed_overflow_constrain(
+  paths: &[Command],
+  transform: Affine,
+  mask_memory: &mut MaskMemory,
+  buffer_pool: &mut BufferPool,
+) -> CanvasConstrainResult {
+  let (mask, placement) = mask_memory.render(paths, Some(transform), None, buffer_pool);
+
+  if placement.width == 0 || placement.height == 0 {
+    buffer_pool.release(mask);
+    return CanvasConstrainResult::SkipRendering;
+  }
+
+  CanvasConstrainResult::Some(CanvasConstrain::TransformedOverflow { mask, placement })
+}
+
 impl CanvasConstrain {
   pub(crate) fn from_node(
     context: &RenderContext,
@@ -144,8 +166,10 @@ impl CanvasConstrain {
     buffer_pool: &mut BufferPool,
   ) -> Result<CanvasConstrainResult> {
     // Clip path would just clip everything, and behaves like overflow: hidden.
-    if let Some(clip_path) = &style.clip_path {
-      let (mask, placement) = clip_path.render_mask(context, layout.size, mask_memory, buffer_pool);
+    if let Some(clip_path) = &style.clip_path
+      && !clip_path.is_empty()
+    {
+      let (mask, placement) = clip_path.render_mask(context, &layout, mask_memory, buffer_pool);
 
       let end_x = placement.left + placement.width as i32;
       let end_y = placement.top + placement.height as i32;
@@ -186,35 +210,76 @@ impl CanvasConstrain {
       return Ok(CanvasConstrainResult::None);
     }
 
-    if (clip_x && layout.content_box_width() < f32::EPSILON)
-      || (clip_y && layout.content_box_height() < f32::EPSILON)
-    {
+    // `overflow-clip-margin` only extends the clip edge of axes with `overflow: clip`.
+    let clip_margin = style
+      .overflow_clip_margin
+      .to_px(&context.sizing, 0.0)
+      .max(0.0);
+    let clip_margin_x = if overflow.x == Overflow::Clip {
+      clip_margin
+    } else {
+      0.0
+    };
+    let clip_margin_y = if overflow.y == Overflow::Clip {
+      clip_margin
+    } else {
+      0.0
+    };
+
+    // The scrollbar gutter sits inside the padding box but never shows content.
+    let clip_width = (layout.content_box_width() - layout.scrollbar_size.width).max(0.0);
+    let clip_height = (layout.content_box_height() - layout.scrollbar_size.height).max(0.0);
+
+    if (clip_x && clip_width < f32::EPSILON) || (clip_y && clip_height < f32::EPSILON) {
       return Ok(CanvasConstrainResult::SkipRendering);
     }
 
+    // Under rotation, skew or scale, the clip shape is rasterized in canvas space like a clip path,
+    // so its edges stay antialiased instead of snapping to the pixels of the untransformed mask.
+    let rasterize_transformed =
+      clip_x && clip_y && !transform.only_translation() && layout.scrollbar_size == Size::zero();
+
     // When border-radius is non-zero, create a mask-based overflow constraint
     // so that children (including abs-pos) are clipped to the padding-box
     // rounded corners (inset from the border edge by border widths).
     let border_props = BorderProperties::from_context(context, layout.size, layout.border);
     if !border_props.is_zero() {
       // Compute padding-box: border-box inset by border widths on each side.
+      // Both are then grown outward by the clip margin.
       let padding_box = Size {
-        width: (layout.size.width - layout.border.left - layout.border.right).max(0.0),
-        height: (layout.size.height - layout.border.top - layout.border.bottom).max(0.0),
+        width: (layout.size.width - layout.border.left - layout.border.right).max(0.0)
+          + clip_margin_x * 2.0,
+        height: (layout.size.height - layout.border.top - layout.border.bottom).max(0.0)
+          + clip_margin_y * 2.0,
       };
 
       // Shrink corner radii inward by border widths to get padding-box radii.
       let mut inner_props = border_props;
       inner_props.inset_by_border_width();
+      inner_props.expand_by(Rect {
+        left: clip_margin_x,
+        right: clip_margin_x,
+        top: clip_margin_y,
+        bottom: clip_margin_y,
+      });
 
       let mut paths = Vec::with_capacity(10);
       // Offset origin so the mask starts at the padding edge (inside the border).
       let padding_origin = Point {
-        x: layout.border.left,
-        y: layout.border.top,
+        x: layout.border.left - clip_margin_x,
+        y: layout.border.top - clip_margin_y,
       };
       inner_props.append_mask_commands(&mut paths, padding_box, padding_origin);
 
+      if rasterize_transformed {
+        return Ok(transformed_overflow_constrain(
+          &paths,
+          transform,
+          mask_memory,
+          buffer_pool,
+        ));
+      }
+
       let (mask_data, placement) = mask_memory.render(&paths, None, None, buffer_pool);
 
       if placement.width == 0 || placement.height == 0 {
@@ -223,43 +288,73 @@ impl CanvasConstrain {
       }
 
       let from = Point {
-        x: placement.left.max(0) as u32,
-        y: placement.top.max(0) as u32,
+        x: placement.left,
+        y: placement.top,
       };
 
+      // The mask keeps the full padding-box shape, only its scrollbar gutter is cut off.
       return Ok(CanvasConstrainResult::Some(CanvasConstrain::Overflow {
         from,
         to: Point {
-          x: from.x + placement.width,
-          y: from.y + placement.height,
+          x: from.x
+            + placement
+              .width
+              .saturating_sub(layout.scrollbar_size.width as u32) as i32,
+          y: from.y
+            + placement
+              .height
+              .saturating_sub(layout.scrollbar_size.height as u32) as i32,
         },
         inverse_transform,
         border_radius_mask: Some((mask_data, placement.width)),
       }));
     }
 
+    if rasterize_transformed {
+      let mut paths = Vec::with_capacity(5);
+      BorderProperties::zero().append_mask_commands(
+        &mut paths,
+        Size {
+          width: clip_width + clip_margin_x * 2.0,
+          height: clip_height + clip_margin_y * 2.0,
+        },
+        Point {
+          x: layout.padding.left + layout.border.left - clip_margin_x,
+          y: layout.padding.top + layout.border.top - clip_margin_y,
+        },
+      );
+
+      return Ok(transformed_overflow_constrain(
+        &paths,
+        transform,
+        mask_memory,
+        buffer_pool,
+      ));
+    }
+
+    // Axes that aren't clipped stay unbounded, including towards negative coordinates.
     let from = Point {
       x: if clip_x {
-        (layout.padding.left + layout.border.left) as u32
+        (layout.padding.left + layout.border.left - clip_margin_x) as i32
       } else {
-        0
+        i32::MIN
       },
       y: if clip_y {
-        (layout.padding.top + layout.border.top) as u32
+        (layout.padding.top + layout.border.top - clip_margin_y) as i32
       } else {
-        0
+        i32::MIN
       },
     };
     let to = Point {
       x: if clip_x {
-        from.x + layout.content_box_width() as u32
+        from.x + (clip_width + clip_margin_x * 2.0) as i32
       } else {
-        u32::MAX
+        i32::MAX
       },
       y: if clip_y {
-        from.y + layout.content_box_height() as u32
+        from.y + (clip_height + clip_margin_y * 2.0) as i32
       } else {
-        u32::MAX
+        i32::MAX
       },
     };
 
@@ -279,16 +374,12 @@ impl CanvasConstrain {
         inverse_transform,
         ref border_radius_mask,
       } => {
-        let original_point = inverse_transform.transform_point(Point {
-          x: x as f32,
-          y: y as f32,
-        });
-
-        if original_point.x < 0.0 || original_point.y < 0.0 {
-          return 0;
-        }
-
-        let original_point = original_point.map(|point| point as u32);
+        let original_point = inverse_transform
+          .transform_point(Point {
+            x: x as f32,
+            y: y as f32,
+          })
+          .map(|point| point.floor() as i32);
 
         let is_contained = original_point.x >= from.x
           && original_point.x < to.x
@@ -301,8 +392,8 @@ impl CanvasConstrain {
 
         // Apply border-radius mask if present
         if let Some((mask, mask_w)) = border_radius_mask {
-          let mx = original_point.x - from.x;
-          let my = original_point.y - from.y;
+          let mx = original_point.x.abs_diff(from.x);
+          let my = original_point.y.abs_diff(from.y);
           return mask[mask_index_from_coord(mx, my, *mask_w)];
         }
 
@@ -339,6 +430,10 @@ impl CanvasConstrain {
       CanvasConstrain::ClipPath {
         ref mask,
         placement,
+      }
+      | CanvasConstrain::TransformedOverflow {
+        ref mask,
+        placement,
       } => {
         let mask_x = x as i32 - placement.left;
         let mask_y = y as i32 - placement.top;
@@ -552,6 +647,10 @@ pub struct Canvas {
   // we can just include the memory here instead of making the function argument bloated.
   pub(crate) mask_memory: MaskMemory,
   pub(crate) buffer_pool: BufferPool,
+  /// How many isolated images are currently stacked on top of the base image.
+  isolated_depth: usize,
+  /// The deepest stack of isolated images seen so far.
+  peak_isolated_depth: usize,
 }
 
 impl Canvas {
@@ -562,6 +661,20 @@ impl Canvas {
       constrains: SmallVec::new(),
       mask_memory: MaskMemory::default(),
       buffer_pool: BufferPool::default(),
+      isolated_depth: 0,
+      peak_isolated_depth: 0,
+    }
+  }
+
+  /// Creates a canvas that draws on top of an existing image.
+  pub(crate) fn from_image(image: RgbaImage) -> Self {
+    Self {
+      image,
+      constrains: SmallVec::new(),
+      mask_memory: MaskMemory::default(),
+      buffer_pool: BufferPool::default(),
+      isolated_depth: 0,
+      peak_isolated_depth: 0,
     }
   }
 
@@ -570,9 +683,27 @@ impl Canvas {
 
     let new_image = self.buffer_pool.acquire_image(size.width, size.height)?;
 
+    self.isolated_depth += 1;
+    self.peak_isolated_depth = self.peak_isolated_depth.max(self.isolated_depth);
+
     Ok(replace(&mut self.image, new_image))
   }
 
+  /// Puts back an image taken by [`Self::replace_new_image`], returning the isolated image to the pool.
+  pub(crate) fn restore_image(&mut self, image: RgbaImage) {
+    let isolated_image = replace(&mut self.image, image);
+    self.buffer_pool.release_image(isolated_image);
+
+    self.isolated_depth = self.isolated_depth.saturating_sub(1);
+  }
+
+  /// The most bytes held by full-size canvas images at once, counting isolated layers.
+  pub(crate) fn peak_image_bytes(&self) -> usize {
+    let size = self.size();
+
+    (size.width as usize * size.height as usize * 4) * (self.peak_isolated_depth + 1)
+  }
+
   pub(crate) fn push_constrain(&mut self, overflow_constrain: CanvasConstrain) {
     self.constrains.push(overflow_constrain);
   }
@@ -586,7 +717,8 @@ impl Canvas {
         } => {
           self.buffer_pool.release(mask);
         }
-        CanvasConstrain::ClipPath { mask, .. } => {
+        CanvasConstrain::ClipPath { mask, .. }
+        | CanvasConstrain::TransformedOverflow { mask, .. } => {
           self.buffer_pool.release(mask);
         }
         CanvasConstrain::MaskImage { mask, .. } => {
@@ -608,6 +740,64 @@ impl Canvas {
     }
   }
 
+  /// The width and height of the canvas, in pixels.
+  pub fn dimensions(&self) -> (u32, u32) {
+    self.image.dimensions()
+  }
+
+  /// Fills a rectangle with a color, blended over the existing pixels.
+  ///
+  /// The parts of the rectangle outside the canvas are skipped.
+  pub fn fill_color(&mut self, x: i32, y: i32, width: u32, height: u32, color: Color) {
+    let color: Rgba<u8> = color.into();
+    let (canvas_width, canvas_height) = self.image.dimensions();
+
+    let (Some((x, _, width)), Some((y, _, height))) = (
+      visible_span(x, width, canvas_width),
+      visible_span(y, height, canvas_height),
+    ) else {
+      return;
+    };
+
+    overlay_area(
+      &mut self.image,
+      Point {
+        x: x as f32,
+        y: y as f32,
+      },
+      Size { width, height },
+      BlendMode::Normal,
+      &self.constrains,
+      |_, _| color,
+    );
+  }
+
+  /// Draws an image with its top-left corner at `x`, `y`, blended over the existing pixels.
+  ///
+  /// The parts of the image outside the canvas are skipped.
+  pub fn draw_image(&mut self, image: &RgbaImage, x: i32, y: i32) {
+    let (canvas_width, canvas_height) = self.image.dimensions();
+
+    let (Some((x, skip_x, width)), Some((y, skip_y, height))) = (
+      visible_span(x, image.width(), canvas_width),
+      visible_span(y, image.height(), canvas_height),
+    ) else {
+      return;
+    };
+
+    overlay_area(
+      &mut self.image,
+      Point {
+        x: x as f32,
+        y: y as f32,
+      },
+      Size { width, height },
+      BlendMode::Normal,
+      &self.constrains,
+      |x, y| *image.get_pixel(x + skip_x, y + skip_y),
+    );
+  }
+
   /// Overlays an image onto the canvas with optional border radius.
   pub(crate) fn overlay_image<I: GenericImageView<Pixel = Rgba<u8>>>(
     &mut self,
@@ -933,6 +1123,26 @@ pub(crate) fn mask_index_from_coord(x: u32, y: u32, width: u32) -> usize {
   (y * width + x) as usize
 }
 
+/// Clips a span of `length` pixels starting at `start` to `0..limit`.
+///
+/// Returns the visible start, how many pixels were skipped at the start of the span and the
+/// visible length, or `None` if nothing is visible. The math is done in `i64` so spans far
+/// outside the canvas can't overflow.
+fn visible_span(start: i32, length: u32, limit: u32) -> Option<(u32, u32, u32)> {
+  let start = i64::from(start);
+  let limit = i64::from(limit);
+  let visible_start = start.clamp(0, limit);
+  let visible_end = (start + i64::from(length)).clamp(0, limit);
+
+  (visible_start < visible_end).then(|| {
+    (
+      visible_start as u32,
+      (visible_start - start) as u32,
+      (visible_end - visible_start) as u32,
+    )
+  })
+}
+
 pub(crate) fn overlay_area(
   bottom: &mut RgbaImage,
   offset: Point<f32>,
@@ -952,14 +1162,16 @@ pub(crate) fn overlay_area(
 
   // Calculate the valid range in the destination image
   let dest_y_min = offset_y.max(0);
-  let dest_y_max = (offset_y + top_size.height as i32).min(bottom_height);
+  let dest_y_max =
+    (i64::from(offset_y) + i64::from(top_size.height)).min(i64::from(bottom_height)) as i32;
 
   if dest_y_min >= dest_y_max {
     return; // No overlap
   }
 
   let dest_x_min = offset_x.max(0);
-  let dest_x_max = (offset_x + top_size.width as i32).min(bottom_width);
+  let dest_x_max =
+    (i64::from(offset_x) + i64::from(top_size.width)).min(i64::from(bottom_width)) as i32;
 
   if dest_x_min >= dest_x_max {
     return; // No horizontal overlap on this row
@@ -0,0 +1,202 @@
+/// The D50 illuminant of the ICC profile connection space.
+const D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+/// Bradford adaptation from the D65 white point of sRGB to D50.
+const D65_TO_D50: [f64; 9] = [
+  1.047882, 0.022918, -0.050217, //
+  0.029586, 0.990478, -0.017075, //
+  -0.009233, 0.015075, 0.751678,
+];
+
+/// The sRGB primaries adapted to D50, as the columns of the RGB to XYZ matrix.
+const PRIMARIES: [[f64; 3]; 3] = [
+  [0.436075, 0.222504, 0.013932],
+  [0.385065, 0.716879, 0.097105],
+  [0.143080, 0.060617, 0.714173],
+];
+
+/// The sRGB transfer function as ICC parametric curve type 3: `g, a, b, c, d`.
+const SRGB_CURVE: [f64; 5] = [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045];
+
+const HEADER_SIZE: usize = 128;
+
+/// Returns a compact ICC v4 profile for the sRGB color space.
+///
+/// Embedding it with [`crate::rendering::write_image`] tags the output as sRGB,
+/// so viewers that assume another color space for untagged images don't shift the colors.
+pub fn srgb_color_profile() -> Vec<u8> {
+  let description = mluc("sRGB");
+  let copyright = mluc("No copyright, use freely");
+  let white_point = xyz(D50);
+  let adaptation = sf32(D65_TO_D50);
+  let [red, green, blue] = PRIMARIES.map(xyz);
+  let curve = parametric_curve(SRGB_CURVE);
+
+  let tags: [(&[u8; 4], &[u8]); 10] = [
+    (b"desc", description.as_slice()),
+    (b"cprt", copyright.as_slice()),
+    (b"wtpt", white_point.as_slice()),
+    (b"chad", adaptation.as_slice()),
+    (b"rXYZ", red.as_slice()),
+    (b"gXYZ", green.as_slice()),
+    (b"bXYZ", blue.as_slice()),
+    (b"rTRC", curve.as_slice()),
+    (b"gTRC", curve.as_slice()),
+    (b"bTRC", curve.as_slice()),
+  ];
+
+  let data_offset = HEADER_SIZE + 4 + tags.len() * 12;
+  let mut table = Vec::with_capacity(tags.len() * 12);
+  let mut data = Vec::new();
+  let mut written: Vec<(&[u8], usize)> = Vec::new();
+
+  for (signature, tag) in tags {
+    // Tags with the same data, like the transfer curves of the three channels, share it.
+    let offset = match written.iter().find(|(written, _)| *written == tag) {
+      Some((_, offset)) => *offset,
+      None => {
+        let offset = data_offset + data.len();
+
+        data.extend_from_slice(tag);
+        data.resize(data.len().next_multiple_of(4), 0);
+        written.push((tag, offset));
+
+        offset
+      }
+    };
+
+    table.extend_from_slice(signature);
+    table.extend_from_slice(&(offset as u32).to_be_bytes());
+    table.extend_from_slice(&(tag.len() as u32).to_be_bytes());
+  }
+
+  let size = data_offset + data.len();
+  let mut profile = Vec::with_capacity(size);
+
+  profile.extend_from_slice(&(size as u32).to_be_bytes());
+  // Preferred CMM type.
+  profile.extend_from_slice(&[0; 4]);
+  // Version 4.3.
+  profile.extend_from_slice(&[4, 0x30, 0, 0]);
+  profile.extend_from_slice(b"mntr");
+  profile.extend_from_slice(b"RGB ");
+  profile.extend_from_slice(b"XYZ ");
+  // Creation date, 2024-01-01 00:00:00.
+  for field in [2024u16, 1, 1, 0, 0, 0] {
+    profile.extend_from_slice(&field.to_be_bytes());
+  }
+  profile.extend_from_slice(b"acsp");
+  // Platform, flags, device manufacturer, model, attributes and the perceptual rendering intent.
+  profile.extend_from_slice(&[0; 28]);
+  profile.extend_from_slice(&white_point[8..]);
+  // Creator, profile ID and reserved bytes.
+  profile.resize(HEADER_SIZE, 0);
+
+  profile.extend_from_slice(&(tags.len() as u32).to_be_bytes());
+  profile.extend_from_slice(&table);
+  profile.extend_from_slice(&data);
+
+  profile
+}
+
+fn s15_fixed16(value: f64) -> [u8; 4] {
+  ((value * 65536.0).round() as i32).to_be_bytes()
+}
+
+fn xyz(value: [f64; 3]) -> Vec<u8> {
+  let mut tag = b"XYZ \0\0\0\0".to_vec();
+
+  for component in value {
+    tag.extend_from_slice(&s15_fixed16(component));
+  }
+
+  tag
+}
+
+fn sf32(matrix: [f64; 9]) -> Vec<u8> {
+  let mut tag = b"sf32\0\0\0\0".to_vec();
+
+  for component in matrix {
+    tag.extend_from_slice(&s15_fixed16(component));
+  }
+
+  tag
+}
+
+fn parametric_curve(parameters: [f64; 5]) -> Vec<u8> {
+  // Function type 3, followed by two reserved bytes.
+  let mut tag = b"para\0\0\0\0\0\x03\0\0".to_vec();
+
+  for parameter in parameters {
+    tag.extend_from_slice(&s15_fixed16(parameter));
+  }
+
+  tag
+}
+
+/// A multi-localized unicode tag with a single en-US record.
+fn mluc(text: &str) -> Vec<u8> {
+  let text = text.encode_utf16().flat_map(u16::to_be_bytes);
+  let mut tag = b"mluc\0\0\0\0".to_vec();
+
+  tag.extend_from_slice(&1u32.to_be_bytes());
+  tag.extend_from_slice(&12u32.to_be_bytes());
+  tag.extend_from_slice(b"enUS");
+
+  let length_offset = tag.len();
+  tag.extend_from_slice(&[0; 4]);
+  tag.extend_from_slice(&28u32.to_be_bytes());
+  tag.extend(text);
+
+  let length = (tag.len() - 28) as u32;
+  tag[length_offset..length_offset + 4].copy_from_slice(&length.to_be_bytes());
+
+  tag
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn read_u32(bytes: &[u8], offset: usize) -> usize {
+    let mut value = [0; 4];
+    value.copy_from_slice(&bytes[offset..offset + 4]);
+
+    u32::from_be_bytes(value) as usize
+  }
+
+  #[test]
+  fn test_srgb_color_profile_tags_are_in_bounds() {
+    let profile = srgb_color_profile();
+
+    assert_eq!(read_u32(&profile, 0), profile.len());
+    assert_eq!(&profile[36..40], b"acsp");
+
+    let tag_count = read_u32(&profile, HEADER_SIZE);
+    assert_eq!(tag_count, 10);
+
+    for index in 0..tag_count {
+      let entry = HEADER_SIZE + 4 + index * 12;
+      let offset = read_u32(&profile, entry + 4);
+      let size = read_u32(&profile, entry + 8);
+
+      assert_eq!(offset % 4, 0);
+      assert!(offset + size <= profile.len());
+    }
+  }
+
+  #[test]
+  fn test_srgb_color_profile_curves_share_data() {
+    let profile = srgb_color_profile();
+    let entry = |signature: &[u8]| {
+      (0..read_u32(&profile, HEADER_SIZE))
+        .map(|index| HEADER_SIZE + 4 + index * 12)
+        .find(|&entry| &profile[entry..entry + 4] == signature)
+        .map(|entry| &profile[entry + 4..entry + 12])
+    };
+
+    assert_eq!(entry(b"rTRC"), entry(b"gTRC"));
+    assert_eq!(entry(b"rTRC"), entry(b"bTRC"));
+    assert!(entry(b"rTRC").is_some());
+  }
+}
@@ -65,7 +65,11 @@ impl<'a> BlurFormat<'a> {
   }
 }
 
-/// Applies a Gaussian approximation using 3-pass Box Blur.
+/// Applies a Gaussian approximation using 3-pass separable Box Blur.
+///
+/// RGBA input is blurred in premultiplied space so transparent neighbours don't
+/// bleed black into the edges, and pixels outside the image are clamped to the
+/// nearest edge pixel.
 pub(crate) fn apply_blur(
   format: BlurFormat<'_>,
   radius: f32,
@@ -154,7 +158,7 @@ macro_rules! update_h_pixel {
     } else {
       for c in 0..STRIDE {
         unsafe {
-          *$dst.get_unchecked_mut($out + c) = (($sum[c] * $mul) >> $shift) as u8;
+          *$dst.get_unchecked_mut($out + c) = rounded_div($sum[c], $mul, $shift);
           $sum[c] += *$src.get_unchecked($entering + c) as u32;
           $sum[c] -= *$src.get_unchecked($leaving + c) as u32;
         }
@@ -255,7 +259,7 @@ macro_rules! update_v_pixel {
       }
     } else {
       unsafe {
-        *$dst.get_unchecked_mut($out + $x) = ((sum * $mul) >> $shift) as u8;
+        *$dst.get_unchecked_mut($out + $x) = rounded_div(sum, $mul, $shift);
         $sums[$x] = sum + entering - *$src.get_unchecked($leaving + $x) as u32;
       }
     }
@@ -352,6 +356,13 @@ fn box_blur_v(src: &[u8], dst: &mut [u8], params: BlurPassParams, sums: &mut [u3
   }
 }
 
+/// Divides a box sum by the window size with rounding, so repeated passes don't
+/// gradually darken solid areas.
+#[inline(always)]
+fn rounded_div(sum: u32, mul: u32, shg: i32) -> u8 {
+  ((sum * mul + (1 << (shg - 1))) >> shg).min(255) as u8
+}
+
 #[inline(always)]
 fn compute_mul_shg(d: u32) -> (u32, i32) {
   let shg = 23;
@@ -0,0 +1,94 @@
+use zeno::{Command, PathBuilder};
+
+use crate::{
+  layout::style::{Affine, BlendMode, Color},
+  rendering::{Canvas, draw_mask},
+};
+
+/// Returns the center of each gap between horizontally separated boxes, given their `(left, right)`
+/// extents. Boxes whose extents overlap are merged first, so no gap is reported before the first box,
+/// after the last one, or across an item.
+pub(crate) fn column_gap_centers(mut extents: Vec<(f32, f32)>) -> Vec<f32> {
+  extents.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+  let mut extents = extents.into_iter();
+  let Some((_, mut end)) = extents.next() else {
+    return Vec::new();
+  };
+
+  let mut centers = Vec::new();
+
+  for (start, next_end) in extents {
+    if start > end {
+      centers.push((end + start) / 2.0);
+    }
+
+    end = end.max(next_end);
+  }
+
+  centers
+}
+
+/// Draws a vertical `column-rule` of the given width centered on each x position, spanning from `top`
+/// down by `height` in the node's local coordinates.
+pub(crate) fn draw_column_rules(
+  canvas: &mut Canvas,
+  centers: &[f32],
+  top: f32,
+  height: f32,
+  (width, color): (f32, Color),
+  transform: Affine,
+) {
+  if centers.is_empty() || height <= 0.0 {
+    return;
+  }
+
+  let mut paths = Vec::<Command>::with_capacity(centers.len() * 5);
+
+  for center in centers {
+    paths.add_rect((center - width / 2.0, top), width, height);
+  }
+
+  let (mask, placement) =
+    canvas
+      .mask_memory
+      .render(&paths, Some(transform), None, &mut canvas.buffer_pool);
+
+  draw_mask(
+    &mut canvas.image,
+    &mask,
+    placement,
+    color,
+    BlendMode::Normal,
+    &canvas.constrains,
+  );
+
+  canvas.buffer_pool.release(mask);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_column_gap_centers_only_between_items() {
+    assert_eq!(
+      column_gap_centers(vec![(120.0, 200.0), (0.0, 80.0), (240.0, 320.0)]),
+      vec![100.0, 220.0]
+    );
+  }
+
+  #[test]
+  fn test_column_gap_centers_merges_items_in_the_same_column() {
+    assert_eq!(
+      column_gap_centers(vec![
+        (0.0, 80.0),
+        (0.0, 80.0),
+        (100.0, 180.0),
+        (40.0, 120.0)
+      ]),
+      Vec::<f32>::new()
+    );
+    assert!(column_gap_centers(Vec::new()).is_empty());
+  }
+}
@@ -1,7 +1,9 @@
 mod blur;
 mod border;
+mod column_rule;
 mod shadow;
 
 pub(crate) use blur::*;
 pub(crate) use border::*;
+pub(crate) use column_rule::*;
 pub(crate) use shadow::*;
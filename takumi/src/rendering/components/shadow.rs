@@ -1,5 +1,5 @@
 use image::RgbaImage;
-use taffy::{Layout, Point, Size};
+use taffy::{Point, Size};
 use zeno::{Command, Fill, PathData, Placement};
 
 use crate::{
@@ -182,17 +182,18 @@ impl SizedShadow {
     Ok(())
   }
 
+  /// Draws the inset shadow over the padding box, with `transform` placing its top-left corner.
   pub fn draw_inset(
     &self,
     transform: Affine,
     border_radius: BorderProperties,
     canvas: &mut Canvas,
-    layout: Layout,
+    padding_box: Size<f32>,
   ) -> Result<()> {
     let image = draw_inset_shadow(
       self,
       border_radius,
-      layout.size,
+      padding_box,
       &mut canvas.mask_memory,
       &mut canvas.buffer_pool,
     )?;
@@ -213,12 +214,12 @@ impl SizedShadow {
 pub(crate) fn draw_inset_shadow(
   shadow: &SizedShadow,
   mut border: BorderProperties,
-  border_box: Size<f32>,
+  padding_box: Size<f32>,
   mask_memory: &mut MaskMemory,
   buffer_pool: &mut BufferPool,
 ) -> Result<RgbaImage> {
   let mut shadow_image =
-    buffer_pool.acquire_image(border_box.width as u32, border_box.height as u32)?;
+    buffer_pool.acquire_image(padding_box.width as u32, padding_box.height as u32)?;
 
   // Fill with shadow color (BufferPool returns zeroed/dirty buffers)
   let shadow_raw = shadow_image.as_mut();
@@ -232,22 +233,24 @@ pub(crate) fn draw_inset_shadow(
     y: shadow.offset_y,
   };
 
+  // A positive spread shrinks the unshadowed hole and its radii, a negative one grows them.
+  // Once the spread swallows the padding box there is no hole, the whole box is shadowed.
+  let hole_size = padding_box.map(|size| (size - shadow.spread_radius * 2.0).max(0.0));
+
   let mut paths = Vec::new();
 
-  border.expand_by(Sides([-shadow.spread_radius; 4]).into());
-  border.append_mask_commands(
-    &mut paths,
-    border_box
-      - Size {
-        width: shadow.spread_radius * 2.0,
-        height: shadow.spread_radius * 2.0,
-      },
-    offset
-      + Point {
-        x: shadow.spread_radius,
-        y: shadow.spread_radius,
-      },
-  );
+  if hole_size.width > 0.0 && hole_size.height > 0.0 {
+    border.expand_by(Sides([-shadow.spread_radius; 4]).into());
+    border.append_mask_commands(
+      &mut paths,
+      hole_size,
+      offset
+        + Point {
+          x: shadow.spread_radius,
+          y: shadow.spread_radius,
+        },
+    );
+  }
 
   let (mask, placement) = mask_memory.render(&paths, None, Some(Fill::NonZero.into()), buffer_pool);
 
@@ -37,3 +37,15 @@ pub fn draw_debug_border(canvas: &mut Canvas, layout: Layout, transform: Affine)
     None,
   );
 }
+
+/// Draws a magenta outline around the node's border-box, for nodes listed in `debug_highlight_ids`.
+pub fn draw_debug_highlight(canvas: &mut Canvas, layout: Layout, transform: Affine) {
+  BorderProperties {
+    width: Sides([2.0; 4]).into(),
+    color: Color([255, 0, 255, 255]), // magenta
+    radius: Sides([SpacePair::from_single(0.0); 4]),
+    image_rendering: ImageScalingAlgorithm::Auto,
+    style: BorderStyle::Solid,
+  }
+  .draw::<RgbaImage>(canvas, layout.size, transform, None);
+}
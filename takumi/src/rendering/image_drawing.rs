@@ -7,8 +7,8 @@ use crate::layout::style::BlendMode;
 use crate::rendering::CowImage;
 use crate::{
   Result,
-  layout::style::{Affine, ImageScalingAlgorithm, Length, ObjectFit},
-  rendering::{BorderProperties, Canvas, RenderContext},
+  layout::style::{Affine, ImageScalingAlgorithm, ObjectFit},
+  rendering::{BorderProperties, Canvas, RenderContext, install},
   resources::image::{ImageResourceError, ImageSource},
 };
 
@@ -40,10 +40,9 @@ pub fn process_image_for_object_fit<'i>(
 ) -> Result<(CowImage<'i>, Point<f32>)> {
   let (image_width, image_height) = image.size();
 
-  let object_position_x =
-    Length::from(context.style.object_position.0.x).to_px(&context.sizing, content_box.width);
-  let object_position_y =
-    Length::from(context.style.object_position.0.y).to_px(&context.sizing, content_box.height);
+  let object_position = context.style.object_position.0;
+  let object_position_x = object_position.x.to_px(&context.sizing, content_box.width);
+  let object_position_y = object_position.y.to_px(&context.sizing, content_box.height);
 
   match context.style.object_fit {
     ObjectFit::Fill => Ok((
@@ -157,41 +156,24 @@ pub fn process_image_for_object_fit<'i>(
       ))
     }
     ObjectFit::None => {
-      // If the image is smaller than the content box, we don't need to crop
-      if image_width <= content_box.width && image_height <= content_box.height {
-        let available_x = (content_box.width - image_width).max(0.0);
-        let available_y = (content_box.height - image_height).max(0.0);
-
-        let offset_x =
-          calculate_object_position_offset(available_x, content_box.width, object_position_x);
-        let offset_y =
-          calculate_object_position_offset(available_y, content_box.height, object_position_y);
-
-        return Ok((
-          image
-            .render_to_rgba_image(
-              image_width as u32,
-              image_height as u32,
-              context.style.image_rendering,
-            )?
-            .into(),
-          Point {
-            x: offset_x,
-            y: offset_y,
-          },
-        ));
-      }
-
-      let available_crop_x = (image_width - content_box.width).max(0.0);
-      let available_crop_y = (image_height - content_box.height).max(0.0);
-
-      let crop_x =
-        calculate_object_position_offset(available_crop_x, content_box.width, object_position_x);
-      let crop_y =
-        calculate_object_position_offset(available_crop_y, content_box.height, object_position_y);
-
-      let crop_width = content_box.width.min(image_width);
-      let crop_height = content_box.height.min(image_height);
+      // Percentages resolve against the space left over by the image, which is negative when
+      // the image is larger than the box, so the image may start before the content box.
+      let offset_x = object_position
+        .x
+        .to_px(&context.sizing, content_box.width - image_width);
+      let offset_y = object_position
+        .y
+        .to_px(&context.sizing, content_box.height - image_height);
+
+      // Only keep the part of the image that falls inside the content box.
+      let crop_x = (-offset_x).clamp(0.0, image_width);
+      let crop_y = (-offset_y).clamp(0.0, image_height);
+      let crop_width = (content_box.width - offset_x.max(0.0))
+        .min(image_width - crop_x)
+        .max(0.0);
+      let crop_height = (content_box.height - offset_y.max(0.0))
+        .min(image_height - crop_y)
+        .max(0.0);
 
       let source_image = image.render_to_rgba_image(
         image_width as u32,
@@ -207,22 +189,11 @@ pub fn process_image_for_object_fit<'i>(
         crop_height as u32,
       );
 
-      let offset_x = calculate_object_position_offset(
-        (content_box.width - crop_width).max(0.0),
-        content_box.width,
-        object_position_x,
-      );
-      let offset_y = calculate_object_position_offset(
-        (content_box.height - crop_height).max(0.0),
-        content_box.height,
-        object_position_y,
-      );
-
       Ok((
         cropped,
         Point {
-          x: offset_x,
-          y: offset_y,
+          x: offset_x.max(0.0),
+          y: offset_y.max(0.0),
         },
       ))
     }
@@ -269,15 +240,47 @@ pub(crate) fn fast_resize(
   width: u32,
   height: u32,
   algorithm: ImageScalingAlgorithm,
+) -> std::result::Result<RgbaImage, ImageResourceError> {
+  // `crisp-edges` first scales up by the largest whole factor, so only the fractional rest is smoothed.
+  if algorithm == ImageScalingAlgorithm::CrispEdges && image.width() > 0 && image.height() > 0 {
+    let factor_x = (width / image.width()).max(1);
+    let factor_y = (height / image.height()).max(1);
+
+    if factor_x > 1 || factor_y > 1 {
+      let prescaled = resize_with(
+        image,
+        image.width() * factor_x,
+        image.height() * factor_y,
+        ImageScalingAlgorithm::Pixelated,
+      )?;
+
+      if prescaled.dimensions() == (width, height) {
+        return Ok(prescaled);
+      }
+
+      return resize_with(&prescaled, width, height, algorithm);
+    }
+  }
+
+  resize_with(image, width, height, algorithm)
+}
+
+fn resize_with(
+  image: &RgbaImage,
+  width: u32,
+  height: u32,
+  algorithm: ImageScalingAlgorithm,
 ) -> std::result::Result<RgbaImage, ImageResourceError> {
   let mut resizer = Resizer::new();
   let mut dest = Image::new(width, height, PixelType::U8x4);
 
-  resizer.resize(
-    image,
-    &mut dest,
-    Some(&ResizeOptions::default().resize_alg(algorithm.into())),
-  )?;
+  install(|| {
+    resizer.resize(
+      image,
+      &mut dest,
+      Some(&ResizeOptions::default().resize_alg(algorithm.into())),
+    )
+  })?;
 
   RgbaImage::from_raw(dest.width(), dest.height(), dest.into_vec())
     .ok_or(ImageResourceError::MismatchedBufferSize)
@@ -4,6 +4,7 @@ use image::{GenericImageView, Rgba};
 use parley::{GlyphRun, PositionedInlineBox, PositionedLayoutItem};
 use swash::FontRef;
 use taffy::{Layout, Point};
+use zeno::{Command, Fill, PathBuilder, Stroke};
 
 use crate::{
   Result,
@@ -12,16 +13,21 @@ use crate::{
     node::Node,
     style::{
       Affine, BackgroundClip, BlendMode, Color, ImageScalingAlgorithm, SizedFontStyle,
-      SizedTextDecorationThickness, TextDecorationLines, TextDecorationSkipInk,
+      SizedTextDecorationThickness, TextDecorationLines, TextDecorationSkipInk, TextEmphasisFill,
+      TextEmphasisShape, TextEmphasisStyle, TextUnderlinePosition,
     },
-    tree::LayoutTree,
+    tree::{LayoutTree, RenderNode},
   },
   rendering::{
-    BackgroundTile, BorderProperties, Canvas, ColorTile, RenderContext, collect_background_layers,
-    collect_outline_paths, draw_decoration, draw_glyph, draw_glyph_clip_image,
-    draw_glyph_text_shadow, mask_index_from_coord, rasterize_layers, render::render_node,
+    BackgroundTile, BorderProperties, Canvas, ColorTile, RenderContext, SizedShadow,
+    collect_background_layers, collect_outline_paths, draw_decoration, draw_glyph,
+    draw_glyph_clip_image, draw_glyph_text_shadow, draw_mask, mask_index_from_coord,
+    rasterize_layers, render::render_node,
+  },
+  resources::{
+    font::{FontError, ResolvedGlyph},
+    glyph_cache::{GlyphCacheKey, glyph_run_cache_key},
   },
-  resources::font::{FontError, ResolvedGlyph},
 };
 use taffy::{AvailableSpace, geometry::Size};
 
@@ -29,6 +35,8 @@ const UNDERLINE_SKIP_INK_ALPHA_THRESHOLD: u8 = 16;
 const SKIP_PADDING_RATIO: f32 = 0.6;
 const SKIP_PADDING_MIN: f32 = 1.0;
 const SKIP_PADDING_MAX: f32 = 3.0;
+/// Emphasis marks are drawn at half the font size, like browsers render them.
+const EMPHASIS_MARK_SCALE: f32 = 0.5;
 
 #[derive(Clone, Copy)]
 struct GlyphLocalBounds {
@@ -279,7 +287,13 @@ fn draw_glyph_run_under_overline(
     .decoration_line
     .contains(TextDecorationLines::UNDERLINE)
   {
-    let offset = glyph_run.baseline() - metrics.underline_offset;
+    let offset = match brush.underline_position {
+      // There are no vertical writing modes, so `left` and `right` fall back to `auto`.
+      TextUnderlinePosition::Auto | TextUnderlinePosition::Left | TextUnderlinePosition::Right => {
+        glyph_run.baseline() - metrics.underline_offset
+      }
+      TextUnderlinePosition::Under => glyph_run.baseline() + metrics.descent,
+    };
     let size = match brush.decoration_thickness {
       SizedTextDecorationThickness::Value(v) => v,
       SizedTextDecorationThickness::FromFont => metrics.underline_size,
@@ -367,6 +381,103 @@ fn draw_glyph_run_line_through(
   Ok(())
 }
 
+/// Appends the path of a single emphasis mark, sized relative to `mark_size` (half the font size).
+fn append_emphasis_mark(
+  paths: &mut Vec<Command>,
+  shape: TextEmphasisShape,
+  fill: TextEmphasisFill,
+  center: (f32, f32),
+  mark_size: f32,
+) {
+  match shape {
+    TextEmphasisShape::Dot => paths.add_circle(center, mark_size * 0.15),
+    TextEmphasisShape::Circle => paths.add_circle(center, mark_size * 0.4),
+    TextEmphasisShape::DoubleCircle => {
+      paths.add_circle(center, mark_size * 0.4);
+      // Filled marks rely on the even-odd rule to punch a ring around the inner dot.
+      if fill == TextEmphasisFill::Filled {
+        paths.add_circle(center, mark_size * 0.3);
+      }
+      paths.add_circle(center, mark_size * 0.18);
+    }
+    TextEmphasisShape::Triangle => {
+      let half_side = mark_size * 0.4;
+      let height = half_side * 3.0_f32.sqrt();
+
+      paths.move_to((center.0, center.1 - height * 2.0 / 3.0));
+      paths.line_to((center.0 + half_side, center.1 + height / 3.0));
+      paths.line_to((center.0 - half_side, center.1 + height / 3.0));
+      paths.close();
+    }
+  }
+}
+
+fn has_ink(glyph: &ResolvedGlyph) -> bool {
+  match glyph {
+    ResolvedGlyph::Image(bitmap) => bitmap.placement.width > 0 && bitmap.placement.height > 0,
+    ResolvedGlyph::Outline(outline) => !outline.verbs().is_empty(),
+  }
+}
+
+/// Draws `text-emphasis` marks centered above each glyph with an advance and visible ink,
+/// so spaces and combining marks don't get their own mark.
+fn draw_glyph_run_emphasis(
+  glyph_run: &GlyphRun<'_, InlineBrush>,
+  resolved_glyphs: &HashMap<u32, ResolvedGlyph>,
+  canvas: &mut Canvas,
+  layout: Layout,
+  context: &RenderContext,
+) {
+  let brush = &glyph_run.style().brush;
+  let TextEmphasisStyle::Mark { fill, shape } = brush.emphasis_style else {
+    return;
+  };
+
+  let run = glyph_run.run();
+  let mark_size = run.font_size() * EMPHASIS_MARK_SCALE;
+  // There are no vertical writing modes, so marks always sit over the text.
+  let center_y = layout.border.top + layout.padding.top + glyph_run.baseline()
+    - run.metrics().ascent
+    - mark_size / 2.0;
+
+  let mut paths = Vec::new();
+  for glyph in glyph_run.positioned_glyphs() {
+    if glyph.advance <= 0.0 || !resolved_glyphs.get(&glyph.id).is_some_and(has_ink) {
+      continue;
+    }
+
+    let center_x = layout.border.left + layout.padding.left + glyph.x + glyph.advance / 2.0;
+    append_emphasis_mark(&mut paths, shape, fill, (center_x, center_y), mark_size);
+  }
+
+  if paths.is_empty() {
+    return;
+  }
+
+  let style: zeno::Style = match fill {
+    TextEmphasisFill::Filled => Fill::EvenOdd.into(),
+    TextEmphasisFill::Open => Stroke::new((mark_size * 0.08).max(1.0)).into(),
+  };
+
+  let (mask, placement) = canvas.mask_memory.render(
+    &paths,
+    Some(context.transform),
+    Some(style),
+    &mut canvas.buffer_pool,
+  );
+
+  draw_mask(
+    &mut canvas.image,
+    &mask,
+    placement,
+    brush.emphasis_color,
+    BlendMode::Normal,
+    &canvas.constrains,
+  );
+
+  canvas.buffer_pool.release(mask);
+}
+
 fn draw_glyph_run_content<I: GenericImageView<Pixel = Rgba<u8>>>(
   style: &SizedFontStyle,
   glyph_run: &GlyphRun<'_, InlineBrush>,
@@ -381,6 +492,7 @@ fn draw_glyph_run_content<I: GenericImageView<Pixel = Rgba<u8>>>(
   let font = FontRef::from_index(run.font().data.as_ref(), run.font().index as usize)
     .ok_or(FontError::InvalidFontIndex)?;
   let palette = font.color_palettes().next();
+  let run_key = glyph_run_cache_key(glyph_run);
 
   if let Some(clip_image) = clip_image {
     for glyph in glyph_run.positioned_glyphs() {
@@ -420,8 +532,16 @@ fn draw_glyph_run_content<I: GenericImageView<Pixel = Rgba<u8>>>(
       style,
       context.transform,
       inline_offset,
+      context.snap_text_to_pixel,
       glyph_run.style().brush.color,
       palette,
+      &context.global.glyph_cache,
+      GlyphCacheKey {
+        run: run_key,
+        glyph_id: glyph.id,
+        subpixel_x: 0,
+        subpixel_y: 0,
+      },
     )?;
   }
 
@@ -429,7 +549,7 @@ fn draw_glyph_run_content<I: GenericImageView<Pixel = Rgba<u8>>>(
 }
 
 fn draw_glyph_run_text_shadow(
-  style: &SizedFontStyle,
+  shadow: &SizedShadow,
   glyph_run: &GlyphRun<'_, InlineBrush>,
   resolved_glyphs: &HashMap<u32, ResolvedGlyph>,
   canvas: &mut Canvas,
@@ -446,7 +566,7 @@ fn draw_glyph_run_text_shadow(
       y: layout.border.top + layout.padding.top + glyph.y,
     };
 
-    draw_glyph_text_shadow(content, canvas, style, context.transform, inline_offset)?;
+    draw_glyph_text_shadow(content, canvas, shadow, context.transform, inline_offset)?;
   }
 
   Ok(())
@@ -578,6 +698,69 @@ pub(crate) fn draw_inline_box<N: Node<N>>(
   Ok(())
 }
 
+/// Draws the `list-style-type` marker of a list item outside its border box, on the item's first baseline.
+pub(crate) fn draw_list_marker<N: Node<N>>(
+  item: &RenderNode<'_, N>,
+  item_layout: Layout,
+  marker: &str,
+  list_content_width: f32,
+  canvas: &mut Canvas,
+  transform: Affine,
+) -> Result<()> {
+  let font_style = item.context.style.to_sized_font_style(&item.context);
+  let font_context = &item.context.global.font_context;
+
+  let (mut marker_layout, _) = font_context.tree_builder((&font_style).into(), |builder| {
+    font_context.push_text_span(builder, marker, (&font_style).into());
+  });
+  marker_layout.break_all_lines(None);
+
+  let Some((width, height, marker_baseline)) = marker_layout.lines().next().map(|line| {
+    let metrics = line.metrics();
+    (metrics.advance, metrics.line_height, metrics.baseline)
+  }) else {
+    return Ok(());
+  };
+
+  // Items without text of their own put the marker on the first line of their content box.
+  let item_baseline = item
+    .first_baseline(
+      item_layout.size,
+      list_content_width,
+      item.should_create_inline_layout(),
+    )
+    .unwrap_or(item_layout.border.top + item_layout.padding.top + marker_baseline);
+
+  let context = RenderContext {
+    transform: transform
+      * Affine::translation(
+        item_layout.location.x - width,
+        item_layout.location.y + item_baseline - marker_baseline,
+      ),
+    ..item.context.clone()
+  };
+
+  draw_inline_layout::<N>(
+    &context,
+    canvas,
+    Layout {
+      size: Size { width, height },
+      ..Default::default()
+    },
+    marker_layout,
+    &font_style,
+    &[],
+  )?;
+
+  Ok(())
+}
+
+/// Offsets the layout by the baseline shift of synthesized subscript or superscript runs.
+fn shifted_run_layout(mut layout: Layout, glyph_run: &GlyphRun<'_, InlineBrush>) -> Layout {
+  layout.padding.top += glyph_run.style().brush.baseline_shift;
+  layout
+}
+
 pub(crate) fn draw_inline_layout<N: Node<N>>(
   context: &RenderContext,
   canvas: &mut Canvas,
@@ -588,7 +771,7 @@ pub(crate) fn draw_inline_layout<N: Node<N>>(
 ) -> Result<Vec<PositionedInlineBox>> {
   let resolved_glyph_runs = resolve_inline_layout_glyphs(context, &inline_layout)?;
   let clip_image = if context.style.background_clip == BackgroundClip::Text {
-    let layers = collect_background_layers(context, layout.size, &mut canvas.buffer_pool)?;
+    let layers = collect_background_layers(context, layout, &mut canvas.buffer_pool)?;
 
     rasterize_layers(
       layers,
@@ -606,23 +789,35 @@ pub(crate) fn draw_inline_layout<N: Node<N>>(
   let mut positioned_inline_boxes = Vec::new();
 
   // Reference: https://www.w3.org/TR/css-text-decor-3/#painting-order
+  // Each shadow layer covers the whole text before the next one, and the first shadow is on top.
+  if let Some(shadows) = &font_style.text_shadow {
+    for shadow in shadows.iter().rev() {
+      for (glyph_run, resolved_glyphs) in
+        glyph_runs_with_resolved(&inline_layout, &resolved_glyph_runs)
+      {
+        draw_glyph_run_text_shadow(
+          shadow,
+          &glyph_run,
+          resolved_glyphs,
+          canvas,
+          shifted_run_layout(layout, &glyph_run),
+          context,
+        )?;
+      }
+    }
+  }
+
   for (glyph_run, resolved_glyphs) in glyph_runs_with_resolved(&inline_layout, &resolved_glyph_runs)
   {
-    draw_glyph_run_text_shadow(
-      font_style,
+    draw_glyph_run_under_overline(
       &glyph_run,
       resolved_glyphs,
       canvas,
-      layout,
+      shifted_run_layout(layout, &glyph_run),
       context,
     )?;
   }
 
-  for (glyph_run, resolved_glyphs) in glyph_runs_with_resolved(&inline_layout, &resolved_glyph_runs)
-  {
-    draw_glyph_run_under_overline(&glyph_run, resolved_glyphs, canvas, layout, context)?;
-  }
-
   let parent_x_height = get_parent_x_height(context, font_style);
   let mut glyph_runs_with_resolved = glyph_runs_with_resolved(&inline_layout, &resolved_glyph_runs);
   for line in inline_layout.lines() {
@@ -637,7 +832,7 @@ pub(crate) fn draw_inline_layout<N: Node<N>>(
             &glyph_run,
             resolved_glyphs,
             canvas,
-            layout,
+            shifted_run_layout(layout, &glyph_run),
             context,
             clip_image.as_ref(),
           )?;
@@ -652,6 +847,7 @@ pub(crate) fn draw_inline_layout<N: Node<N>>(
               line.metrics(),
               inline_box.height,
               parent_x_height,
+              &item.render_node.context.sizing,
             );
           }
           positioned_inline_boxes.push(inline_box)
@@ -661,7 +857,23 @@ pub(crate) fn draw_inline_layout<N: Node<N>>(
   }
 
   for glyph_run in glyph_runs(&inline_layout) {
-    draw_glyph_run_line_through(&glyph_run, canvas, layout, context)?;
+    draw_glyph_run_line_through(
+      &glyph_run,
+      canvas,
+      shifted_run_layout(layout, &glyph_run),
+      context,
+    )?;
+  }
+
+  for (glyph_run, resolved_glyphs) in glyph_runs_with_resolved(&inline_layout, &resolved_glyph_runs)
+  {
+    draw_glyph_run_emphasis(
+      &glyph_run,
+      resolved_glyphs,
+      canvas,
+      shifted_run_layout(layout, &glyph_run),
+      context,
+    );
   }
 
   if let Some(BackgroundTile::Image(image)) = clip_image {
@@ -1,8 +1,14 @@
+/// Keyframe tweening for animated images
+mod animation;
 /// Background and color drawing functions
 mod background_drawing;
 mod blend;
+/// Border image drawing functions
+mod border_image_drawing;
 /// Canvas operations and image blending
 mod canvas;
+/// ICC color profiles for the encoded output
+mod color_profile;
 mod components;
 /// Debug drawing utilities
 mod debug_drawing;
@@ -11,31 +17,62 @@ mod image_drawing;
 pub(crate) mod inline_drawing;
 /// Main image renderer and viewport management
 mod render;
+/// SVG export of the layout tree
+mod svg_export;
 /// Text drawing functions
 mod text_drawing;
+/// Thread pool selection for parallel sections
+mod thread_pool;
 mod write;
 
-use std::{collections::HashMap, rc::Rc, sync::Arc};
+use std::{
+  cell::RefCell,
+  collections::{HashMap, HashSet},
+  rc::Rc,
+  sync::Arc,
+};
 
+pub use animation::*;
 pub(crate) use background_drawing::*;
 pub(crate) use blend::*;
+pub(crate) use border_image_drawing::*;
+pub use canvas::Canvas;
 pub(crate) use canvas::*;
+pub use color_profile::*;
 pub(crate) use components::*;
 pub(crate) use debug_drawing::*;
 pub(crate) use image_drawing::*;
 pub use render::*;
+pub use svg_export::*;
 pub(crate) use text_drawing::*;
+pub(crate) use thread_pool::*;
 pub use write::*;
 
 use crate::{
   GlobalContext,
   layout::{
     Viewport,
-    style::{Affine, CalcArena, Color, InheritedStyle},
+    style::{Affine, CalcArena, Color, InheritedStyle, Sides},
   },
   resources::image::ImageSource,
 };
 
+/// Font metrics used to resolve the `ch` and `ex` units, in fractions of the font size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FontMetrics {
+  /// The advance width of the "0" glyph.
+  pub(crate) ch: f32,
+  /// The x-height of the font.
+  pub(crate) ex: f32,
+}
+
+impl Default for FontMetrics {
+  fn default() -> Self {
+    // CSS fallback when the metrics can't be measured from the font.
+    Self { ch: 0.5, ex: 0.5 }
+  }
+}
+
 /// The sizing context used for length value resolving.
 #[derive(Clone)]
 pub(crate) struct Sizing {
@@ -43,6 +80,8 @@ pub(crate) struct Sizing {
   pub(crate) viewport: Viewport,
   /// The font size in pixels.
   pub(crate) font_size: f32,
+  /// The metrics of the current font, for font-relative units.
+  pub(crate) font_metrics: FontMetrics,
   /// The calc arena shared by the current layout tree.
   pub(crate) calc_arena: Rc<CalcArena>,
 }
@@ -62,8 +101,19 @@ pub struct RenderContext<'g> {
   pub(crate) style: InheritedStyle,
   /// Whether to draw debug borders.
   pub(crate) draw_debug_border: bool,
+  /// Whether to round glyph origins to whole pixels.
+  pub(crate) snap_text_to_pixel: bool,
+  /// Ids of nodes to highlight with a debug outline, shared by the whole render.
+  pub(crate) debug_highlight_ids: Rc<HashSet<String>>,
   /// The resources fetched externally.
   pub(crate) fetched_resources: HashMap<Arc<str>, Arc<ImageSource>>,
+  /// The external image sources resolved so far, shared by the whole render.
+  pub(crate) resolved_images: Rc<RefCell<HashSet<String>>>,
+  /// The external image sources the image resolver failed on, shared by the whole render.
+  pub(crate) failed_images: Rc<RefCell<HashSet<String>>>,
+  /// The border sides (top, right, bottom, left) left to an adjacent sibling by the parent's
+  /// `border-collapse: collapse`.
+  pub(crate) collapsed_borders: Sides<bool>,
 }
 
 impl<'g> RenderContext<'g> {
@@ -72,18 +122,74 @@ impl<'g> RenderContext<'g> {
     viewport: Viewport,
     fetched_resources: HashMap<Arc<str>, Arc<ImageSource>>,
   ) -> Self {
+    let style = InheritedStyle::default();
+    let font_metrics = global
+      .font_context
+      .measure_font_metrics(style.to_font_metrics_style());
+
     Self {
       global,
       sizing: Sizing {
         viewport,
-        font_size: viewport.font_size,
+        // The viewport font size is in CSS pixels, like the `rem` unit resolved from it.
+        font_size: viewport.font_size * viewport.device_pixel_ratio,
+        font_metrics,
         calc_arena: Rc::new(CalcArena::default()),
       },
       transform: Affine::IDENTITY,
       current_color: Color::black(),
-      style: InheritedStyle::default(),
+      style,
       draw_debug_border: false,
+      snap_text_to_pixel: false,
+      debug_highlight_ids: Rc::default(),
       fetched_resources,
+      resolved_images: Rc::default(),
+      failed_images: Rc::default(),
+      collapsed_borders: Sides([false; 4]),
+    }
+  }
+
+  /// Creates the context of a child with the given inherited style, resolving its font size,
+  /// `currentColor` and font metrics against this context.
+  pub(crate) fn with_child_style(&self, mut style: InheritedStyle) -> Self {
+    let font_size = style
+      .font_size
+      .map(|font_size| font_size.to_px(&self.sizing, self.sizing.font_size))
+      .unwrap_or(self.sizing.font_size);
+
+    let current_color = style.color.resolve(self.current_color);
+
+    // Font metrics only depend on the font face, so they are re-measured only when it changes.
+    let font_metrics = if style.has_same_font_face(&self.style) {
+      self.sizing.font_metrics
+    } else {
+      self
+        .global
+        .font_context
+        .measure_font_metrics(style.to_font_metrics_style())
+    };
+
+    let sizing = Sizing {
+      font_size,
+      font_metrics,
+      ..self.sizing.clone()
+    };
+
+    style.make_computed(&sizing);
+
+    Self {
+      global: self.global,
+      transform: self.transform,
+      style,
+      current_color,
+      draw_debug_border: self.draw_debug_border,
+      snap_text_to_pixel: self.snap_text_to_pixel,
+      debug_highlight_ids: self.debug_highlight_ids.clone(),
+      fetched_resources: self.fetched_resources.clone(),
+      resolved_images: self.resolved_images.clone(),
+      failed_images: self.failed_images.clone(),
+      collapsed_borders: Sides([false; 4]),
+      sizing,
     }
   }
 }
@@ -1,10 +1,10 @@
-use std::{collections::HashMap, mem::replace, sync::Arc};
+use std::{collections::HashMap, rc::Rc, sync::Arc, time::Duration};
 
 use derive_builder::Builder;
 use image::RgbaImage;
 use parley::PositionedLayoutItem;
 use serde::Serialize;
-use taffy::{AvailableSpace, NodeId, geometry::Size};
+use taffy::{AvailableSpace, Layout, NodeId, Rect, geometry::Size};
 
 use crate::{
   Error, GlobalContext, Result,
@@ -16,14 +16,17 @@ use crate::{
     },
     node::Node,
     style::{
-      Affine, Filter, ImageScalingAlgorithm, InheritedStyle, SpacePair, apply_backdrop_filter,
-      apply_filters,
+      Affine, BorderCollapse, Display, Filter, ImageScalingAlgorithm, InheritedStyle,
+      ListStyleType, apply_backdrop_filter, apply_filters,
     },
     tree::{LayoutResults, LayoutTree, RenderNode},
   },
   rendering::{
     BorderProperties, Canvas, CanvasConstrain, CanvasConstrainResult, RenderContext, Sizing,
-    draw_debug_border, inline_drawing::get_parent_x_height, overlay_image,
+    ThreadPoolScope, column_gap_centers, draw_column_rules, draw_debug_border,
+    draw_debug_highlight,
+    inline_drawing::{draw_list_marker, get_parent_x_height},
+    overlay_image, premultiply_alpha,
   },
   resources::image::ImageSource,
 };
@@ -40,9 +43,131 @@ pub struct RenderOptions<'g, N: Node<N>> {
   /// Whether to draw debug borders.
   #[builder(default)]
   pub(crate) draw_debug_border: bool,
+  /// Ids of nodes to highlight with a debug outline, to pinpoint them in a large tree.
+  ///
+  /// Unlike [`Self::draw_debug_border`], only the nodes whose `id` is listed are outlined, on top
+  /// of their content. Text and image nodes laid out inline within a parent's text have no box
+  /// of their own, so only that parent can be highlighted.
+  #[builder(default)]
+  pub(crate) debug_highlight_ids: Vec<String>,
+  /// Whether to round glyph origins to whole pixels before rasterizing them.
+  ///
+  /// Small text looks sharper at the cost of slightly uneven glyph spacing. Transformed text
+  /// that isn't a pure translation is never snapped.
+  #[builder(default)]
+  pub(crate) snap_text_to_pixel: bool,
   /// The resources fetched externally.
   #[builder(default)]
   pub(crate) fetched_resources: HashMap<Arc<str>, Arc<ImageSource>>,
+  /// A pre-rendered image to draw the node on top of, instead of a transparent canvas.
+  ///
+  /// Useful for animations where only an overlay changes between frames: render the static part
+  /// once, then pass it here for every frame. Its size must match the rendered size, and it is
+  /// copied as-is, so the static part must not depend on anything that changes per frame.
+  #[builder(default)]
+  pub(crate) background: Option<&'g RgbaImage>,
+  /// The maximum width of the rendered image, in pixels.
+  #[builder(default)]
+  pub(crate) max_width: Option<u32>,
+  /// The maximum height of the rendered image, in pixels.
+  #[builder(default)]
+  pub(crate) max_height: Option<u32>,
+  /// The maximum area (width × height) of the rendered image, in pixels.
+  ///
+  /// Together with [`Self::max_width`] and [`Self::max_height`], this caps memory usage for
+  /// untrusted node trees, which can otherwise request an arbitrarily large auto-sized canvas.
+  #[builder(default)]
+  pub(crate) max_area: Option<u64>,
+  /// The maximum number of characters in a single text node, including generated content.
+  ///
+  /// Shaping and laying out huge strings is slow, so untrusted node trees are rejected with
+  /// [`crate::Error::TextTooLong`] before any text is laid out.
+  #[builder(default)]
+  pub(crate) max_text_length: Option<usize>,
+  /// An inset, in pixels, between the edges of the image and the root node.
+  ///
+  /// The root is laid out and drawn inside the inset rect, as if the viewport were smaller,
+  /// while the inset area keeps the canvas background. Content overflowing the root may
+  /// still paint into it. Viewport units keep referring to the whole viewport.
+  #[builder(default)]
+  pub(crate) safe_area_inset: Rect<f32>,
+  /// A monotonic clock used to time the passes reported by [`render_with_stats`].
+  ///
+  /// Defaults to [`std::time::Instant`], which isn't available on `wasm32-unknown-unknown`; there
+  /// the default clock always reads zero, so pass one backed by the host instead.
+  #[builder(default = "monotonic_clock as fn() -> Duration")]
+  pub(crate) clock: fn() -> Duration,
+  /// How the color channels of the rendered image relate to its alpha.
+  ///
+  /// The canvas is always drawn with straight alpha and converted at the end, so
+  /// [`Self::background`] must be straight regardless of this option.
+  #[builder(default)]
+  pub(crate) alpha_mode: AlphaMode,
+  /// A hook called with the canvas after the node tree is drawn, before alpha conversion.
+  ///
+  /// Useful for overlays that are easier to draw directly, like watermarks or debug markers.
+  /// Set it with [`RenderOptionsBuilder::on_draw`].
+  ///
+  /// Only [`render`] and [`render_with_stats`] draw onto a canvas, so the hook is not called by
+  /// [`measure_layout`], [`render_debug_layout`] or [`crate::rendering::render_to_svg`].
+  #[builder(default, setter(custom))]
+  pub(crate) on_draw: Option<DrawHook<'g>>,
+}
+
+/// A hook drawing on the canvas after the node tree, see [`RenderOptions::on_draw`].
+pub type DrawHook<'g> = Arc<dyn Fn(&mut Canvas) + Send + Sync + 'g>;
+
+impl<'g, N: Node<N>> RenderOptionsBuilder<'g, N> {
+  /// Sets a hook called with the canvas after the node tree is drawn.
+  ///
+  /// See [`RenderOptions::on_draw`] for which entry points call it.
+  pub fn on_draw(&mut self, hook: impl Fn(&mut Canvas) + Send + Sync + 'g) -> &mut Self {
+    self.on_draw = Some(Some(Arc::new(hook)));
+    self
+  }
+}
+
+/// How the color channels of a rendered image relate to its alpha channel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaMode {
+  /// The color channels are independent of the alpha, as expected by PNG and most encoders.
+  #[default]
+  Straight,
+  /// The color channels are multiplied by the alpha, as expected by many compositors.
+  Premultiplied,
+}
+
+/// The time elapsed since the first call, using [`std::time::Instant`].
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn monotonic_clock() -> Duration {
+  use std::{sync::LazyLock, time::Instant};
+
+  static EPOCH: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+  EPOCH.elapsed()
+}
+
+/// `Instant::now` panics on `wasm32-unknown-unknown`, so the default clock reads zero there.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn monotonic_clock() -> Duration {
+  Duration::ZERO
+}
+
+/// Metrics collected while rendering, returned by [`render_with_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RenderStats {
+  /// The time spent building the tree and computing its layout.
+  pub layout_time: Duration,
+  /// The time spent drawing the laid out tree onto the canvas.
+  pub draw_time: Duration,
+  /// The number of nodes in the tree, not counting anonymous boxes.
+  pub node_count: usize,
+  /// The number of distinct external images resolved, from the fetched resources,
+  /// the persistent image store or the image resolver.
+  pub resolved_image_count: usize,
+  /// The most bytes held at once by full-size canvas images, including isolated layers
+  /// created for transforms, filters and blending.
+  pub peak_canvas_bytes: usize,
 }
 
 /// Information about a text run in an inline layout.
@@ -77,22 +202,145 @@ pub struct MeasuredNode {
   pub runs: Vec<MeasuredTextRun>,
 }
 
+/// A subset of the computed style of a node, for debugging layout issues.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugLayoutStyle {
+  /// The computed `display` value.
+  pub display: String,
+  /// The computed `position` value.
+  pub position: String,
+  /// The computed `box-sizing` value.
+  pub box_sizing: String,
+  /// The computed `flex-direction` value.
+  pub flex_direction: String,
+  /// The computed `overflow-x` value.
+  pub overflow_x: String,
+  /// The computed `overflow-y` value.
+  pub overflow_y: String,
+  /// The resolved font size in pixels.
+  pub font_size: f32,
+}
+
+/// A node of the computed layout tree, for debugging layout issues.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugLayoutNode {
+  /// The type of the node, or `None` for anonymous boxes.
+  pub node_type: Option<&'static str>,
+  /// The x position relative to the parent.
+  pub x: f32,
+  /// The y position relative to the parent.
+  pub y: f32,
+  /// The width of the border box.
+  pub width: f32,
+  /// The height of the border box.
+  pub height: f32,
+  /// The resolved padding, in `[top, right, bottom, left]` order.
+  pub padding: [f32; 4],
+  /// The resolved border widths, in `[top, right, bottom, left]` order.
+  pub border: [f32; 4],
+  /// The resolved margin, in `[top, right, bottom, left]` order.
+  pub margin: [f32; 4],
+  /// A subset of the computed style.
+  pub style: DebugLayoutStyle,
+  /// The children of the node. Content of inline layouts is not expanded.
+  pub children: Vec<DebugLayoutNode>,
+}
+
+/// Computes the layout of a node and returns it as a tree, without rasterizing.
+pub fn render_debug_layout<'g, N: Node<N>>(
+  options: RenderOptions<'g, N>,
+) -> Result<DebugLayoutNode> {
+  let render_context =
+    RenderContext::new(options.global, options.viewport, options.fetched_resources);
+  let root = RenderNode::from_node(&render_context, options.node, options.safe_area_inset);
+
+  if let Some(max_text_length) = options.max_text_length {
+    root.check_text_length(max_text_length)?;
+  }
+
+  let mut tree = LayoutTree::from_render_node(&root);
+  tree.compute_layout(inset_available_space(
+    options.viewport,
+    options.safe_area_inset,
+  ));
+  let layout_results = tree.into_results();
+
+  collect_debug_layout(&root, &layout_results, layout_results.root_node_id())
+}
+
+fn collect_debug_layout<'g, Nodes: Node<Nodes>>(
+  node: &RenderNode<'g, Nodes>,
+  layout_results: &LayoutResults,
+  node_id: NodeId,
+) -> Result<DebugLayoutNode> {
+  let layout = *layout_results.layout(node_id)?;
+  let style = &node.context.style;
+  let overflows = style.resolve_overflows();
+
+  let mut children = Vec::new();
+
+  if !node.should_create_inline_layout()
+    && let Some(render_children) = node.children.as_deref()
+  {
+    let layout_children = layout_results.children(node_id)?;
+    for (child, child_id) in render_children.iter().zip(layout_children.iter().copied()) {
+      children.push(collect_debug_layout(child, layout_results, child_id)?);
+    }
+  }
+
+  Ok(DebugLayoutNode {
+    node_type: node.node.as_ref().map(<Nodes as Node<Nodes>>::node_type),
+    x: layout.location.x,
+    y: layout.location.y,
+    width: layout.size.width,
+    height: layout.size.height,
+    padding: rect_to_array(layout.padding),
+    border: rect_to_array(layout.border),
+    margin: rect_to_array(layout.margin),
+    style: DebugLayoutStyle {
+      display: format!("{:?}", style.display),
+      position: format!("{:?}", style.position),
+      box_sizing: format!("{:?}", style.box_sizing),
+      flex_direction: format!("{:?}", style.flex_direction),
+      overflow_x: format!("{:?}", overflows.x),
+      overflow_y: format!("{:?}", overflows.y),
+      font_size: node.context.sizing.font_size,
+    },
+    children,
+  })
+}
+
+fn rect_to_array(rect: taffy::Rect<f32>) -> [f32; 4] {
+  [rect.top, rect.right, rect.bottom, rect.left]
+}
+
 /// Measures the layout of a node.
 pub fn measure_layout<'g, N: Node<N>>(options: RenderOptions<'g, N>) -> Result<MeasuredNode> {
   let render_context = RenderContext {
     draw_debug_border: options.draw_debug_border,
+    snap_text_to_pixel: options.snap_text_to_pixel,
     ..RenderContext::new(options.global, options.viewport, options.fetched_resources)
   };
-  let root = RenderNode::from_node(&render_context, options.node);
+  let root = RenderNode::from_node(&render_context, options.node, options.safe_area_inset);
+
+  if let Some(max_text_length) = options.max_text_length {
+    root.check_text_length(max_text_length)?;
+  }
+
   let mut tree = LayoutTree::from_render_node(&root);
-  tree.compute_layout(render_context.sizing.viewport.into());
+  tree.compute_layout(inset_available_space(
+    options.viewport,
+    options.safe_area_inset,
+  ));
   let layout_results = tree.into_results();
 
   collect_measure_result(
     &root,
     &layout_results,
     layout_results.root_node_id(),
-    Affine::IDENTITY,
+    Affine::translation(options.safe_area_inset.left, options.safe_area_inset.top),
   )
 }
 
@@ -110,7 +358,7 @@ fn collect_measure_result<'g, Nodes: Node<Nodes>>(
   apply_transform(
     &mut local_transform,
     &node.context.style,
-    layout.size,
+    &layout,
     &node.context.sizing,
   );
 
@@ -170,6 +418,7 @@ fn collect_measure_result<'g, Nodes: Node<Nodes>>(
                 line.metrics(),
                 positioned_box.height,
                 parent_x_height,
+                &item.render_node.context.sizing,
               );
             }
 
@@ -213,22 +462,47 @@ fn collect_measure_result<'g, Nodes: Node<Nodes>>(
 }
 
 /// Renders a node to an image.
+///
+/// The pixels are RGBA8 in row-major order, straight (not premultiplied) unless
+/// [`AlphaMode::Premultiplied`] is set in the options.
 pub fn render<'g, N: Node<N>>(options: RenderOptions<'g, N>) -> Result<RgbaImage> {
+  render_with_stats(options).map(|(image, _)| image)
+}
+
+/// Renders a node to an image like [`render`], also reporting timings and counters of the render.
+pub fn render_with_stats<'g, N: Node<N>>(
+  options: RenderOptions<'g, N>,
+) -> Result<(RgbaImage, RenderStats)> {
+  let clock = options.clock;
+  let layout_start = clock();
+
   let viewport = options.viewport;
   let render_context = RenderContext {
     draw_debug_border: options.draw_debug_border,
+    snap_text_to_pixel: options.snap_text_to_pixel,
+    debug_highlight_ids: Rc::new(options.debug_highlight_ids.into_iter().collect()),
     ..RenderContext::new(options.global, options.viewport, options.fetched_resources)
   };
 
-  let mut root = RenderNode::from_node(&render_context, options.node);
+  let inset = options.safe_area_inset;
+
+  let mut root = RenderNode::from_node(&render_context, options.node, options.safe_area_inset);
+
+  if let Some(max_text_length) = options.max_text_length {
+    root.check_text_length(max_text_length)?;
+  }
+
   let mut tree = LayoutTree::from_render_node(&root);
-  tree.compute_layout(render_context.sizing.viewport.into());
+  tree.compute_layout(inset_available_space(viewport, inset));
   let layout_results = tree.into_results();
   let root_node_id = layout_results.root_node_id();
+  let layout_time = clock().saturating_sub(layout_start);
   let root_size = layout_results
     .layout(root_node_id)?
     .size
-    .map(|size| size.round() as u32);
+    .zip_map(inset_size(inset), |size, inset| {
+      (size + inset).round() as u32
+    });
 
   let root_size = root_size.zip_map(viewport.into(), |size, viewport| {
     if let AvailableSpace::Definite(defined) = viewport {
@@ -242,11 +516,90 @@ pub fn render<'g, N: Node<N>>(options: RenderOptions<'g, N>) -> Result<RgbaImage
     return Err(Error::InvalidViewport);
   }
 
-  let mut canvas = Canvas::new(root_size);
+  let exceeds_width = options
+    .max_width
+    .is_some_and(|max_width| root_size.width > max_width);
+  let exceeds_height = options
+    .max_height
+    .is_some_and(|max_height| root_size.height > max_height);
+  let exceeds_area = options
+    .max_area
+    .is_some_and(|max_area| u64::from(root_size.width) * u64::from(root_size.height) > max_area);
+
+  if exceeds_width || exceeds_height || exceeds_area {
+    return Err(Error::CanvasTooLarge {
+      width: root_size.width,
+      height: root_size.height,
+    });
+  }
+
+  let mut canvas = match options.background {
+    Some(background) => {
+      if background.dimensions() != (root_size.width, root_size.height) {
+        return Err(Error::BackgroundSizeMismatch {
+          expected_width: root_size.width,
+          expected_height: root_size.height,
+          width: background.width(),
+          height: background.height(),
+        });
+      }
+
+      Canvas::from_image(background.clone())
+    }
+    None => Canvas::new(root_size),
+  };
+
+  let draw_start = clock();
+  let thread_pool_scope = ThreadPoolScope::enter(options.global);
+
+  root.render(
+    &layout_results,
+    root_node_id,
+    &mut canvas,
+    Affine::translation(inset.left, inset.top),
+  )?;
+
+  drop(thread_pool_scope);
+
+  if let Some(on_draw) = &options.on_draw {
+    on_draw(&mut canvas);
+  }
+
+  let stats = RenderStats {
+    layout_time,
+    draw_time: clock().saturating_sub(draw_start),
+    node_count: root.node_count(),
+    resolved_image_count: render_context.resolved_images.borrow().len(),
+    peak_canvas_bytes: canvas.peak_image_bytes(),
+  };
+
+  let mut image = canvas.into_inner();
+
+  if options.alpha_mode == AlphaMode::Premultiplied {
+    for pixel in image.pixels_mut() {
+      premultiply_alpha(&mut pixel.0);
+    }
+  }
+
+  Ok((image, stats))
+}
+
+/// The total size taken by the safe area inset on each axis.
+fn inset_size(inset: Rect<f32>) -> Size<f32> {
+  Size {
+    width: inset.left + inset.right,
+    height: inset.top + inset.bottom,
+  }
+}
 
-  root.render(&layout_results, root_node_id, &mut canvas, Affine::IDENTITY)?;
+/// The space the root is laid out in, which is the viewport minus the safe area inset.
+pub(crate) fn inset_available_space(viewport: Viewport, inset: Rect<f32>) -> Size<AvailableSpace> {
+  let available_space: Size<AvailableSpace> = viewport.into();
 
-  Ok(canvas.into_inner())
+  available_space.zip_map(inset_size(inset), |space, inset| match space {
+    AvailableSpace::Definite(size) => AvailableSpace::Definite((size - inset).max(0.0)),
+    space => space,
+  })
 }
 
 impl<'g, Nodes: Node<Nodes>> RenderNode<'g, Nodes> {
@@ -264,41 +617,129 @@ impl<'g, Nodes: Node<Nodes>> RenderNode<'g, Nodes> {
 fn apply_transform(
   transform: &mut Affine,
   style: &InheritedStyle,
-  border_box: Size<f32>,
+  layout: &Layout,
   sizing: &Sizing,
 ) {
-  let transform_origin = style.transform_origin.unwrap_or_default();
-  let origin = transform_origin.to_point(sizing, border_box);
+  *transform *= style.local_transform(layout, sizing);
+}
 
-  // CSS Transforms Level 2 order: T(origin) * translate * rotate * scale * transform * T(-origin)
-  // Ref: https://www.w3.org/TR/css-transforms-2/#ctm
+/// Draws the `column-rule` in the gaps between the columns occupied by in-flow grid items.
+fn draw_grid_column_rules<Nodes: Node<Nodes>>(
+  node: &RenderNode<'_, Nodes>,
+  layout_results: &LayoutResults,
+  node_id: NodeId,
+  layout: Layout,
+  canvas: &mut Canvas,
+) -> Result<()> {
+  let Some(rule) = node.context.style.resolved_column_rule(
+    &node.context.sizing,
+    node.context.current_color,
+    layout.size.width,
+  ) else {
+    return Ok(());
+  };
 
-  let mut local = Affine::translation(origin.x, origin.y);
+  let Some(children) = node.children.as_deref() else {
+    return Ok(());
+  };
 
-  let translate = style.translate();
-  if translate != SpacePair::default() {
-    local *= Affine::translation(
-      translate.x.to_px(sizing, border_box.width),
-      translate.y.to_px(sizing, border_box.height),
-    );
-  }
+  let mut extents = Vec::with_capacity(children.len());
 
-  if let Some(rotate) = style.rotate {
-    local *= Affine::rotation(rotate);
-  }
+  for (child, child_id) in children.iter().zip(layout_results.children(node_id)?) {
+    if !child.is_in_flow() {
+      continue;
+    }
 
-  let scale = style.scale();
-  if scale != SpacePair::default() {
-    local *= Affine::scale(scale.x.0, scale.y.0);
+    let child_layout = layout_results.layout(*child_id)?;
+
+    extents.push((
+      child_layout.location.x,
+      child_layout.location.x + child_layout.size.width,
+    ));
   }
 
-  if let Some(node_transform) = &style.transform {
-    local *= Affine::from_transforms(node_transform.iter(), sizing, border_box);
+  draw_column_rules(
+    canvas,
+    &column_gap_centers(extents),
+    layout.border.top + layout.padding.top,
+    layout.content_box_height(),
+    rule,
+    node.context.transform,
+  );
+
+  Ok(())
+}
+
+/// Boxes whose edges are closer than this are considered touching.
+const ADJACENT_EDGE_EPSILON: f32 = 0.5;
+
+/// Marks the borders shared by touching in-flow children as collapsed, for `border-collapse: collapse`.
+///
+/// Of two facing borders the wider one is drawn, and on ties the one of the box on the left or top.
+fn collapse_child_borders<Nodes: Node<Nodes>>(
+  children: &mut [RenderNode<'_, Nodes>],
+  child_ids: &[NodeId],
+  layout_results: &LayoutResults,
+) -> Result<()> {
+  let mut boxes = Vec::with_capacity(children.len());
+
+  for (index, (child, child_id)) in children.iter().zip(child_ids).enumerate() {
+    if child.is_in_flow() {
+      boxes.push((index, *layout_results.layout(*child_id)?));
+    }
   }
 
-  local *= Affine::translation(-origin.x, -origin.y);
+  let overlaps = |start_a: f32, size_a: f32, start_b: f32, size_b: f32| {
+    start_a < start_b + size_b && start_b < start_a + size_a
+  };
 
-  *transform *= local;
+  for (position, &(first, first_layout)) in boxes.iter().enumerate() {
+    for &(second, second_layout) in &boxes[position + 1..] {
+      for (leading, leading_layout, trailing, trailing_layout) in [
+        (first, first_layout, second, second_layout),
+        (second, second_layout, first, first_layout),
+      ] {
+        let (leading_location, trailing_location) =
+          (leading_layout.location, trailing_layout.location);
+
+        // The leading box is on the left of the trailing one.
+        if (leading_location.x + leading_layout.size.width - trailing_location.x).abs()
+          < ADJACENT_EDGE_EPSILON
+          && overlaps(
+            leading_location.y,
+            leading_layout.size.height,
+            trailing_location.y,
+            trailing_layout.size.height,
+          )
+        {
+          if leading_layout.border.right >= trailing_layout.border.left {
+            children[trailing].context.collapsed_borders.0[3] = true;
+          } else {
+            children[leading].context.collapsed_borders.0[1] = true;
+          }
+        }
+
+        // The leading box is above the trailing one.
+        if (leading_location.y + leading_layout.size.height - trailing_location.y).abs()
+          < ADJACENT_EDGE_EPSILON
+          && overlaps(
+            leading_location.x,
+            leading_layout.size.width,
+            trailing_location.x,
+            trailing_layout.size.width,
+          )
+        {
+          if leading_layout.border.bottom >= trailing_layout.border.top {
+            children[trailing].context.collapsed_borders.0[0] = true;
+          } else {
+            children[leading].context.collapsed_borders.0[2] = true;
+          }
+        }
+      }
+    }
+  }
+
+  Ok(())
 }
 
 pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
@@ -319,7 +760,7 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
   apply_transform(
     &mut transform,
     &node.context.style,
-    layout.size,
+    &layout,
     &node.context.sizing,
   );
 
@@ -361,7 +802,7 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
     || node
       .context
       .style
-      .has_non_identity_transform(layout.size, &node.context.sizing);
+      .has_non_identity_transform(&layout, &node.context.sizing);
 
   let original_canvas_image = if should_isolate {
     Some(canvas.replace_new_image()?)
@@ -378,7 +819,7 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
         canvas.push_constrain(constrain);
         node.draw_shell(canvas, layout)?;
       }
-      CanvasConstrain::Overflow { .. } => {
+      CanvasConstrain::Overflow { .. } | CanvasConstrain::TransformedOverflow { .. } => {
         node.draw_shell(canvas, layout)?;
         canvas.push_constrain(constrain);
       }
@@ -388,6 +829,13 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
 
   node.draw_content(canvas, layout)?;
 
+  if matches!(
+    node.context.style.display,
+    Display::Grid | Display::InlineGrid
+  ) {
+    draw_grid_column_rules(node, layout_results, node_id, layout, canvas)?;
+  }
+
   if node.context.draw_debug_border {
     draw_debug_border(canvas, layout, transform);
   }
@@ -398,7 +846,31 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
     node.draw_inline(canvas, layout)?;
   } else if let Some(children) = node.children.as_deref_mut() {
     let layout_children = layout_results.children(node_id)?;
+    let list_style_type = node.context.style.list_style_type;
+    let mut list_item_index = 0;
+
+    if node.context.style.border_collapse == BorderCollapse::Collapse {
+      collapse_child_borders(children, layout_children, layout_results)?;
+    }
+
     for (child, child_id) in children.iter_mut().zip(layout_children.iter().copied()) {
+      if list_style_type != ListStyleType::None && child.is_list_item() {
+        if let Some(marker) = list_style_type.marker_text(list_item_index)
+          && !child.context.style.is_invisible()
+        {
+          draw_list_marker(
+            child,
+            *layout_results.layout(child_id)?,
+            &marker,
+            layout.content_box_width(),
+            canvas,
+            transform,
+          )?;
+        }
+
+        list_item_index += 1;
+      }
+
       render_node(child, layout_results, child_id, canvas, transform)?;
     }
   }
@@ -412,6 +884,7 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
       &node.context.sizing,
       node.context.current_color,
       &mut canvas.buffer_pool,
+      &node.context.global.custom_filters,
       node
         .context
         .style
@@ -435,13 +908,22 @@ pub(crate) fn render_node<'g, Nodes: Node<Nodes>>(
       &mut canvas.buffer_pool,
     );
 
-    let isolated_image = replace(&mut canvas.image, original_canvas_image);
-    canvas.buffer_pool.release_image(isolated_image);
+    canvas.restore_image(original_canvas_image);
   }
 
   if has_constrain {
     canvas.pop_constrain();
   }
 
+  // Drawn last, so neither the children, the clip nor the filters of the node hide it.
+  if node
+    .node
+    .as_ref()
+    .and_then(|node| node.id())
+    .is_some_and(|id| node.context.debug_highlight_ids.contains(id))
+  {
+    draw_debug_highlight(canvas, layout, transform);
+  }
+
   Ok(())
 }
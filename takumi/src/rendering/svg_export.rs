@@ -0,0 +1,525 @@
+use std::{borrow::Cow, fmt::Write as _};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use image::RgbaImage;
+use parley::PositionedLayoutItem;
+use swash::{FontRef, StringId};
+use taffy::{AvailableSpace, Layout, NodeId, Point, Size};
+use zeno::Command;
+
+use crate::{
+  Result,
+  layout::{
+    inline::{InlineContentKind, InlineLayoutStage, collect_inline_items, create_inline_layout},
+    node::Node,
+    style::{
+      Affine, BackgroundClip, BackgroundImage, BackgroundSize, BorderStyle, Color, LinearGradient,
+    },
+    tree::{LayoutResults, LayoutTree, RenderNode},
+  },
+  rendering::{
+    BackgroundArea, BorderProperties, Canvas, ImageOutputFormat, MaxHeight, RenderContext,
+    RenderOptions, inset_available_space, write_image,
+  },
+};
+
+/// Renders a node to an SVG document, reconstructed from the computed layout tree.
+///
+/// This is experimental and best-effort, meant for editing the result in design tools:
+/// - Background colors, solid borders and linear gradients become rects, paths and gradient defs.
+/// - Text becomes `<text>` elements per glyph run, with the font family and size of the run.
+/// - Images and other background images are embedded as PNG `<image>` elements.
+///
+/// Shadows, outlines, filters, clipping, blending and inline boxes in text are not exported.
+pub fn render_to_svg<'g, N: Node<N>>(options: RenderOptions<'g, N>) -> Result<String> {
+  let render_context =
+    RenderContext::new(options.global, options.viewport, options.fetched_resources);
+  let inset = options.safe_area_inset;
+
+  let mut root = RenderNode::from_node(&render_context, options.node, options.safe_area_inset);
+
+  if let Some(max_text_length) = options.max_text_length {
+    root.check_text_length(max_text_length)?;
+  }
+
+  let mut tree = LayoutTree::from_render_node(&root);
+  tree.compute_layout(inset_available_space(options.viewport, inset));
+  let layout_results = tree.into_results();
+  let root_node_id = layout_results.root_node_id();
+
+  let root_size = layout_results.layout(root_node_id)?.size;
+  let width = options
+    .viewport
+    .width
+    .map_or(root_size.width + inset.left + inset.right, |width| {
+      width as f32
+    });
+  let height = options
+    .viewport
+    .height
+    .map_or(root_size.height + inset.top + inset.bottom, |height| {
+      height as f32
+    });
+
+  let mut writer = SvgWriter::default();
+
+  writer.write_node(
+    &mut root,
+    &layout_results,
+    root_node_id,
+    Affine::translation(inset.left, inset.top),
+  )?;
+
+  let mut document = format!(
+    r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+  );
+
+  if !writer.defs.is_empty() {
+    let _ = write!(document, "<defs>{}</defs>", writer.defs);
+  }
+
+  document.push_str(&writer.body);
+  document.push_str("</svg>");
+
+  Ok(document)
+}
+
+/// Accumulates the gradient definitions and the elements of the document.
+#[derive(Default)]
+struct SvgWriter {
+  defs: String,
+  body: String,
+  gradient_count: usize,
+}
+
+impl SvgWriter {
+  fn write_node<'g, Nodes: Node<Nodes>>(
+    &mut self,
+    node: &mut RenderNode<'g, Nodes>,
+    layout_results: &LayoutResults,
+    node_id: NodeId,
+    mut transform: Affine,
+  ) -> Result<()> {
+    let layout = *layout_results.layout(node_id)?;
+
+    if node.context.style.is_invisible() {
+      return Ok(());
+    }
+
+    transform *= Affine::translation(layout.location.x, layout.location.y);
+    transform *= node
+      .context
+      .style
+      .local_transform(&layout, &node.context.sizing);
+
+    if !transform.is_invertible() {
+      return Ok(());
+    }
+
+    // Elements are drawn in the local space of the node, which is mapped by the group transform.
+    node.context.transform = Affine::IDENTITY;
+
+    let [a, b, c, d, x, y] = transform.to_cols_array();
+    let _ = write!(
+      self.body,
+      r#"<g transform="matrix({a} {b} {c} {d} {x} {y})">"#
+    );
+
+    self.write_background(node, layout)?;
+    self.write_border(&node.context, layout);
+
+    if node.node.as_ref().is_some_and(|inner| inner.is_replaced()) {
+      let mut canvas = Canvas::new(layout.size.map(|size| size.ceil() as u32));
+      node.draw_content(&mut canvas, layout)?;
+      self.write_raster(&canvas.into_inner(), Point::ZERO)?;
+    }
+
+    let is_text = matches!(
+      node
+        .node
+        .as_ref()
+        .and_then(<Nodes as Node<Nodes>>::inline_content),
+      Some(InlineContentKind::Text(_))
+    );
+    let should_create_inline = node.should_create_inline_layout();
+
+    if should_create_inline || is_text {
+      self.write_text(node, layout);
+    }
+
+    self.body.push_str("</g>");
+
+    if !should_create_inline && let Some(children) = node.children.as_deref_mut() {
+      let layout_children = layout_results.children(node_id)?;
+
+      for (child, child_id) in children.iter_mut().zip(layout_children.iter().copied()) {
+        self.write_node(child, layout_results, child_id, transform)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Writes the background as a filled shape when it only has a color and linear gradients,
+  /// otherwise embeds the rasterized background.
+  fn write_background<'g, Nodes: Node<Nodes>>(
+    &mut self,
+    node: &RenderNode<'g, Nodes>,
+    layout: Layout,
+  ) -> Result<()> {
+    let context = &node.context;
+    let style = &context.style;
+
+    let images = style
+      .background_image
+      .as_deref()
+      .map(Cow::Borrowed)
+      .unwrap_or_else(|| {
+        Cow::Owned(
+          style
+            .background
+            .iter()
+            .map(|background| background.image.clone())
+            .collect::<Vec<_>>(),
+        )
+      });
+    let has_default_size = style.background_size.as_deref().map_or_else(
+      || {
+        style
+          .background
+          .iter()
+          .all(|background| background.size == BackgroundSize::default())
+      },
+      |sizes| sizes.iter().all(|size| *size == BackgroundSize::default()),
+    );
+    let is_vector = has_default_size
+      && images
+        .iter()
+        .all(|image| matches!(image, BackgroundImage::None | BackgroundImage::Linear(_)));
+
+    let inset = match style.background_clip {
+      BackgroundClip::BorderBox => None,
+      BackgroundClip::PaddingBox => Some(layout.border),
+      BackgroundClip::ContentBox => Some(taffy::Rect {
+        top: layout.border.top + layout.padding.top,
+        right: layout.border.right + layout.padding.right,
+        bottom: layout.border.bottom + layout.padding.bottom,
+        left: layout.border.left + layout.padding.left,
+      }),
+      BackgroundClip::Text | BackgroundClip::BorderArea => return Ok(()),
+    };
+
+    if !is_vector {
+      if let Some(inner) = &node.node {
+        let mut canvas = Canvas::new(layout.size.map(|size| size.ceil() as u32));
+        inner.draw_background(context, &mut canvas, layout)?;
+        self.write_raster(&canvas.into_inner(), Point::ZERO)?;
+      }
+
+      return Ok(());
+    }
+
+    let mut border = BorderProperties::from_context(context, layout.size, layout.border);
+    let mut size = layout.size;
+    let mut offset = Point::ZERO;
+
+    if let Some(inset) = inset {
+      border.expand_by(inset.map(|size| -size));
+      size.width -= inset.left + inset.right;
+      size.height -= inset.top + inset.bottom;
+      offset = Point {
+        x: inset.left,
+        y: inset.top,
+      };
+    }
+
+    if size.width <= 0.0 || size.height <= 0.0 {
+      return Ok(());
+    }
+
+    let shape = shape(&border, size, offset);
+
+    let color = style.background_color().resolve(context.current_color);
+
+    if color.0[3] > 0 {
+      let _ = write!(self.body, "{shape}{}/>", paint("fill", color));
+    }
+
+    let area = BackgroundArea::from_layout(layout, style.background_origin());
+
+    // The first layer is drawn on top.
+    for image in images.iter().rev() {
+      if let BackgroundImage::Linear(gradient) = image {
+        let id = self.write_linear_gradient(gradient, area, context);
+        let _ = write!(self.body, r#"{shape} fill="url(#{id})"/>"#);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Writes a linear gradient spanning the positioning area as a definition, returning its id.
+  fn write_linear_gradient(
+    &mut self,
+    gradient: &LinearGradient,
+    area: BackgroundArea,
+    context: &RenderContext,
+  ) -> String {
+    let width = area.positioning.width as f32;
+    let height = area.positioning.height as f32;
+
+    let (dir_x, dir_y) = {
+      let radians = gradient.angle.to_radians();
+      (radians.sin(), -radians.cos())
+    };
+    let max_extent = (width * dir_x.abs() + height * dir_y.abs()) / 2.0;
+    let axis_length = 2.0 * max_extent;
+    let center_x = area.origin.x as f32 + width / 2.0;
+    let center_y = area.origin.y as f32 + height / 2.0;
+
+    self.gradient_count += 1;
+    let id = format!("gradient-{}", self.gradient_count);
+
+    let _ = write!(
+      self.defs,
+      r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+      center_x - dir_x * max_extent,
+      center_y - dir_y * max_extent,
+      center_x + dir_x * max_extent,
+      center_y + dir_y * max_extent,
+    );
+
+    for stop in gradient.resolve_stops(axis_length, context) {
+      let offset = if axis_length > 0.0 {
+        (stop.position / axis_length).clamp(0.0, 1.0)
+      } else {
+        0.0
+      };
+
+      let _ = write!(
+        self.defs,
+        r#"<stop offset="{offset}"{}/>"#,
+        paint("stop-color", stop.color)
+      );
+    }
+
+    self.defs.push_str("</linearGradient>");
+
+    id
+  }
+
+  /// Writes a solid border as the area between the outer and inner rounded rects.
+  fn write_border(&mut self, context: &RenderContext, layout: Layout) {
+    let border = BorderProperties::from_context(context, layout.size, layout.border);
+
+    if border.style != BorderStyle::Solid
+      || border.color.0[3] == 0
+      || (border.width.top <= 0.0
+        && border.width.right <= 0.0
+        && border.width.bottom <= 0.0
+        && border.width.left <= 0.0)
+    {
+      return;
+    }
+
+    let mut path = Vec::new();
+    border.append_mask_commands(&mut path, layout.size, Point::ZERO);
+
+    let inner_size = Size {
+      width: layout.size.width - border.width.left - border.width.right,
+      height: layout.size.height - border.width.top - border.width.bottom,
+    };
+
+    if inner_size.width > 0.0 && inner_size.height > 0.0 {
+      let mut inner = border;
+      inner.inset_by_border_width();
+      inner.append_mask_commands(
+        &mut path,
+        inner_size,
+        Point {
+          x: border.width.left,
+          y: border.width.top,
+        },
+      );
+    }
+
+    let _ = write!(
+      self.body,
+      r#"<path d="{}" fill-rule="evenodd"{}/>"#,
+      path_data(&path),
+      paint("fill", border.color)
+    );
+  }
+
+  /// Writes a `<text>` element for each glyph run of the inline content of a node.
+  fn write_text<'g, Nodes: Node<Nodes>>(&mut self, node: &RenderNode<'g, Nodes>, layout: Layout) {
+    let font_style = node.context.style.to_sized_font_style(&node.context);
+
+    let max_height = match font_style.parent.line_clamp.as_ref() {
+      Some(clamp) => Some(MaxHeight::HeightAndLines(
+        layout.content_box_height(),
+        clamp.count,
+      )),
+      None => Some(MaxHeight::Absolute(layout.content_box_height())),
+    };
+
+    let (inline_layout, text, _) = create_inline_layout(
+      collect_inline_items(node).into_iter(),
+      Size {
+        width: AvailableSpace::Definite(layout.content_box_width()),
+        height: AvailableSpace::Definite(layout.content_box_height()),
+      },
+      layout.content_box_width(),
+      max_height,
+      &font_style,
+      node.context.global,
+      InlineLayoutStage::Draw,
+    );
+
+    let content_x = layout.border.left + layout.padding.left;
+    let content_y = layout.border.top + layout.padding.top;
+
+    for line in inline_layout.lines() {
+      for item in line.items() {
+        let PositionedLayoutItem::GlyphRun(glyph_run) = item else {
+          continue;
+        };
+
+        let run = glyph_run.run();
+        let Some(run_text) = text.get(run.text_range()) else {
+          continue;
+        };
+
+        if run_text.trim().is_empty() {
+          continue;
+        }
+
+        let family = FontRef::from_index(run.font().data.as_ref(), run.font().index as usize)
+          .and_then(|font| font.localized_strings().find_by_id(StringId::Family, None))
+          .map_or_else(|| "sans-serif".to_string(), |name| name.chars().collect());
+
+        let _ = write!(
+          self.body,
+          r#"<text x="{}" y="{}" font-family="{}" font-size="{}" xml:space="preserve"{}>{}</text>"#,
+          content_x + glyph_run.offset(),
+          content_y + glyph_run.baseline(),
+          escape(&family),
+          run.font_size(),
+          paint("fill", glyph_run.style().brush.color),
+          escape(run_text),
+        );
+      }
+    }
+  }
+
+  /// Embeds an image as a PNG data URL, skipping fully transparent images.
+  fn write_raster(&mut self, image: &RgbaImage, offset: Point<f32>) -> Result<()> {
+    if image.pixels().all(|pixel| pixel.0[3] == 0) {
+      return Ok(());
+    }
+
+    let mut png = Vec::new();
+    write_image(image, &mut png, ImageOutputFormat::Png, None, None)?;
+
+    let _ = write!(
+      self.body,
+      r#"<image x="{}" y="{}" width="{}" height="{}" href="data:image/png;base64,{}"/>"#,
+      offset.x,
+      offset.y,
+      image.width(),
+      image.height(),
+      BASE64_STANDARD.encode(&png),
+    );
+
+    Ok(())
+  }
+}
+
+/// Formats a paint attribute, with the alpha as a separate opacity attribute when translucent.
+fn paint(name: &str, color: Color) -> String {
+  let [r, g, b, a] = color.0;
+
+  if a == u8::MAX {
+    format!(r#" {name}="rgb({r},{g},{b})""#)
+  } else {
+    let opacity_name = if name == "stop-color" {
+      "stop-opacity"
+    } else {
+      "fill-opacity"
+    };
+
+    format!(
+      r#" {name}="rgb({r},{g},{b})" {opacity_name}="{}""#,
+      a as f32 / 255.0
+    )
+  }
+}
+
+/// Opens a `<rect>` element for a box without rounded corners, otherwise a `<path>` element.
+fn shape(border: &BorderProperties, size: Size<f32>, offset: Point<f32>) -> String {
+  if border
+    .radius
+    .0
+    .iter()
+    .all(|radius| radius.x <= 0.0 && radius.y <= 0.0)
+  {
+    return format!(
+      r#"<rect x="{}" y="{}" width="{}" height="{}""#,
+      offset.x, offset.y, size.width, size.height
+    );
+  }
+
+  let mut path = Vec::new();
+  border.append_mask_commands(&mut path, size, offset);
+
+  format!(r#"<path d="{}""#, path_data(&path))
+}
+
+/// Converts path commands to SVG path data.
+fn path_data(commands: &[Command]) -> String {
+  let mut data = String::new();
+
+  for command in commands {
+    let _ = match command {
+      Command::MoveTo(to) => write!(data, "M{} {}", to.x, to.y),
+      Command::LineTo(to) => write!(data, "L{} {}", to.x, to.y),
+      Command::CurveTo(first, second, to) => write!(
+        data,
+        "C{} {} {} {} {} {}",
+        first.x, first.y, second.x, second.y, to.x, to.y
+      ),
+      Command::QuadTo(control, to) => {
+        write!(data, "Q{} {} {} {}", control.x, control.y, to.x, to.y)
+      }
+      Command::Close => write!(data, "Z"),
+    };
+  }
+
+  data
+}
+
+/// Escapes text for use in SVG content and attribute values.
+fn escape(text: &str) -> Cow<'_, str> {
+  if !text.contains(['&', '<', '>', '"']) {
+    return Cow::Borrowed(text);
+  }
+
+  Cow::Owned(
+    text
+      .replace('&', "&amp;")
+      .replace('<', "&lt;")
+      .replace('>', "&gt;")
+      .replace('"', "&quot;"),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_escape() {
+    assert_eq!(escape("a < b & \"c\""), "a &lt; b &amp; &quot;c&quot;");
+    assert!(matches!(escape("plain"), Cow::Borrowed("plain")));
+  }
+}
@@ -1,5 +1,6 @@
 use std::{borrow::Cow, convert::Into};
 use unicode_linebreak::linebreaks;
+use unicode_segmentation::UnicodeSegmentation;
 
 use image::{GenericImageView, Pixel, Rgba, RgbaImage};
 use parley::GlyphRun;
@@ -12,16 +13,19 @@ use crate::{
   layout::{
     inline::{InlineBrush, InlineLayout, break_lines},
     style::{
-      Affine, BlendMode, Color, ImageScalingAlgorithm, SizedFontStyle, TextTransform,
-      WhiteSpaceCollapse,
+      Affine, BlendMode, Color, FontVariantEmoji, ImageScalingAlgorithm, Lang, SizedFontStyle,
+      TextAutospace, TextTransform, WhiteSpaceCollapse,
     },
   },
   rendering::{
-    BorderProperties, BufferPool, Canvas, CanvasConstrain, ColorTile, MaskMemory,
+    BorderProperties, BufferPool, Canvas, CanvasConstrain, ColorTile, MaskMemory, SizedShadow,
     apply_mask_alpha_to_pixel, blend_pixel, draw_mask, mask_index_from_coord, overlay_area,
     sample_transformed_pixel,
   },
-  resources::font::ResolvedGlyph,
+  resources::{
+    font::ResolvedGlyph,
+    glyph_cache::{GLYPH_SUBPIXEL_STEPS, GlyphCache, GlyphCacheKey, GlyphMask},
+  },
 };
 
 struct SwashImageView<'a>(&'a swash::scale::image::Image);
@@ -101,11 +105,13 @@ pub(crate) fn draw_glyph_clip_image<I: GenericImageView<Pixel = Rgba<u8>>>(
   inline_offset: Point<f32>,
   clip_image: &I,
 ) -> Result<()> {
-  transform *= Affine::translation(inline_offset.x, inline_offset.y);
-
   match glyph {
     ResolvedGlyph::Image(bitmap) => {
-      transform *= Affine::translation(bitmap.placement.left as f32, -bitmap.placement.top as f32);
+      // The fill is sampled at whole pixels, so the bitmap is snapped to the same pixels to stay aligned with it.
+      transform *= Affine::translation(
+        inline_offset.x.trunc() + bitmap.placement.left as f32,
+        inline_offset.y.trunc() - bitmap.placement.top as f32,
+      );
 
       let mask_capacity = (bitmap.placement.width * bitmap.placement.height) as usize;
       let mut mask = canvas.buffer_pool.acquire_dirty(mask_capacity);
@@ -160,6 +166,8 @@ pub(crate) fn draw_glyph_clip_image<I: GenericImageView<Pixel = Rgba<u8>>>(
       canvas.buffer_pool.release(mask);
     }
     ResolvedGlyph::Outline(outline) => {
+      transform *= Affine::translation(inline_offset.x, inline_offset.y);
+
       // If the transform is not invertible, we can't draw the glyph
       let Some(inverse) = transform.invert() else {
         return Ok(());
@@ -195,8 +203,8 @@ pub(crate) fn draw_glyph_clip_image<I: GenericImageView<Pixel = Rgba<u8>>>(
             clip_image,
             inverse,
             style.parent.image_rendering,
-            (x as i32 + placement.left) as f32,
-            (y as i32 + placement.top) as f32,
+            (x as i32 + placement.left) as f32 + 0.5,
+            (y as i32 + placement.top) as f32 + 0.5,
             inline_offset,
           );
 
@@ -226,11 +234,18 @@ pub(crate) fn draw_glyph(
   style: &SizedFontStyle,
   mut transform: Affine,
   inline_offset: Point<f32>,
+  snap_to_pixel: bool,
   color: Color,
   palette: Option<ColorPalette>,
+  glyph_cache: &GlyphCache,
+  cache_key: GlyphCacheKey,
 ) -> Result<()> {
   transform *= Affine::translation(inline_offset.x, inline_offset.y);
 
+  if snap_to_pixel {
+    transform = snap_glyph_origin(transform);
+  }
+
   match glyph {
     ResolvedGlyph::Image(bitmap) => {
       transform *= Affine::translation(bitmap.placement.left as f32, -bitmap.placement.top as f32);
@@ -261,6 +276,10 @@ pub(crate) fn draw_glyph(
           &canvas.constrains,
           color.0[3],
         );
+      } else if transform.only_translation() {
+        // The stroke uses the same origin as the cached fill, so the two stay aligned.
+        transform = quantize_glyph_origin(transform);
+        draw_cached_glyph_mask(canvas, &paths, transform, color, glyph_cache, cache_key);
       } else {
         let (mask, placement) =
           canvas
@@ -286,6 +305,74 @@ pub(crate) fn draw_glyph(
   Ok(())
 }
 
+/// Rounds the glyph origin to whole pixels, trading exact glyph spacing for crisper stems.
+///
+/// Transforms that rotate, scale or skew are kept as-is, since there is no pixel grid to snap to.
+pub(crate) fn snap_glyph_origin(transform: Affine) -> Affine {
+  if !transform.only_translation() {
+    return transform;
+  }
+
+  Affine {
+    x: transform.x.round(),
+    y: transform.y.round(),
+    ..transform
+  }
+}
+
+/// Snaps the glyph origin to a quarter pixel, so a glyph mask only depends on the subpixel part of
+/// the translation and is shared by every occurrence of the glyph at the same subpixel offset.
+fn quantize_glyph_origin(transform: Affine) -> Affine {
+  Affine {
+    x: (transform.x * GLYPH_SUBPIXEL_STEPS).round() / GLYPH_SUBPIXEL_STEPS,
+    y: (transform.y * GLYPH_SUBPIXEL_STEPS).round() / GLYPH_SUBPIXEL_STEPS,
+    ..transform
+  }
+}
+
+/// Draws a glyph mask from the cache, rasterizing it on a miss.
+///
+/// The translation of `transform` has to be quantized with [`quantize_glyph_origin`] first.
+fn draw_cached_glyph_mask(
+  canvas: &mut Canvas,
+  paths: &[Command],
+  transform: Affine,
+  color: Color,
+  glyph_cache: &GlyphCache,
+  mut cache_key: GlyphCacheKey,
+) {
+  let Point { x, y } = transform.decompose_translation();
+  let whole_x = x.floor();
+  let whole_y = y.floor();
+
+  cache_key.subpixel_x = ((x - whole_x) * GLYPH_SUBPIXEL_STEPS) as u8;
+  cache_key.subpixel_y = ((y - whole_y) * GLYPH_SUBPIXEL_STEPS) as u8;
+
+  let mask = glyph_cache.get_or_insert_with(cache_key, || {
+    let (data, placement) = canvas.mask_memory.render(
+      paths,
+      Some(Affine::translation(x - whole_x, y - whole_y)),
+      None,
+      &mut canvas.buffer_pool,
+    );
+
+    GlyphMask { data, placement }
+  });
+
+  let mut placement = mask.placement;
+  placement.left += whole_x as i32;
+  placement.top += whole_y as i32;
+
+  draw_mask(
+    &mut canvas.image,
+    &mask.data,
+    placement,
+    color,
+    BlendMode::Normal,
+    &canvas.constrains,
+  );
+}
+
 fn draw_text_stroke_clip_image<I: GenericImageView<Pixel = Rgba<u8>>>(
   canvas: &mut Canvas,
   style: &SizedFontStyle,
@@ -331,8 +418,8 @@ fn draw_text_stroke_clip_image<I: GenericImageView<Pixel = Rgba<u8>>>(
         return Color::transparent().into();
       }
 
-      let inline_x = (x as i32 + stroke_placement.left) as f32;
-      let inline_y = (y as i32 + stroke_placement.top) as f32;
+      let inline_x = (x as i32 + stroke_placement.left) as f32 + 0.5;
+      let inline_y = (y as i32 + stroke_placement.top) as f32 + 0.5;
 
       let sampled_pixel = sample_transformed_pixel(
         clip_image,
@@ -393,27 +480,11 @@ fn draw_text_stroke(
   canvas.buffer_pool.release(stroke_mask);
 }
 
-fn draw_text_shadow(
-  canvas: &mut Canvas,
-  style: &SizedFontStyle,
-  transform: Affine,
-  paths: &[Command],
-) -> Result<()> {
-  let Some(ref shadows) = style.text_shadow else {
-    return Ok(());
-  };
-
-  for shadow in shadows.iter() {
-    shadow.draw_outset(canvas, paths, transform, Default::default(), None)?;
-  }
-
-  Ok(())
-}
-
+/// Draws one `text-shadow` layer of a glyph.
 pub(crate) fn draw_glyph_text_shadow(
   glyph: &ResolvedGlyph,
   canvas: &mut Canvas,
-  style: &SizedFontStyle,
+  shadow: &SizedShadow,
   mut transform: Affine,
   inline_offset: Point<f32>,
 ) -> Result<()> {
@@ -421,7 +492,13 @@ pub(crate) fn draw_glyph_text_shadow(
 
   if let ResolvedGlyph::Outline(outline) = glyph {
     let paths = collect_outline_paths(outline);
-    draw_text_shadow(canvas, style, transform, &paths)?;
+    shadow.draw_outset(
+      canvas,
+      paths.as_slice(),
+      transform,
+      Default::default(),
+      None,
+    )?;
   }
 
   Ok(())
@@ -489,10 +566,30 @@ pub(crate) enum MaxHeight {
   HeightAndLines(f32, u32),
 }
 
-/// Applies text transform to the input text.
-pub(crate) fn apply_text_transform<'a>(input: &'a str, transform: TextTransform) -> Cow<'a, str> {
+/// Applies text transform to the input text, using the case mappings of the given language.
+pub(crate) fn apply_text_transform<'a>(
+  input: &'a str,
+  transform: TextTransform,
+  lang: Option<&Lang>,
+) -> Cow<'a, str> {
+  let turkic = lang.is_some_and(Lang::is_turkic);
+
   match transform {
     TextTransform::None => Cow::Borrowed(input),
+    TextTransform::Uppercase if turkic => {
+      let mut result = String::with_capacity(input.len());
+      input
+        .chars()
+        .for_each(|ch| push_uppercase(&mut result, ch, turkic));
+      Cow::Owned(result)
+    }
+    TextTransform::Lowercase if turkic => {
+      let mut result = String::with_capacity(input.len());
+      input
+        .chars()
+        .for_each(|ch| push_lowercase(&mut result, ch, turkic));
+      Cow::Owned(result)
+    }
     TextTransform::Uppercase => Cow::Owned(input.to_uppercase()),
     TextTransform::Lowercase => Cow::Owned(input.to_lowercase()),
     TextTransform::Capitalize => {
@@ -501,10 +598,10 @@ pub(crate) fn apply_text_transform<'a>(input: &'a str, transform: TextTransform)
       for ch in input.chars() {
         if ch.is_alphabetic() {
           if start_of_word {
-            result.extend(ch.to_uppercase());
+            push_uppercase(&mut result, ch, turkic);
             start_of_word = false;
           } else {
-            result.extend(ch.to_lowercase());
+            push_lowercase(&mut result, ch, turkic);
           }
         } else {
           start_of_word = !ch.is_numeric();
@@ -513,6 +610,439 @@ pub(crate) fn apply_text_transform<'a>(input: &'a str, transform: TextTransform)
       }
       Cow::Owned(result)
     }
+    TextTransform::FullWidth => Cow::Owned(input.chars().map(to_full_width).collect()),
+    TextTransform::FullSizeKana => Cow::Owned(input.chars().map(to_full_size_kana).collect()),
+  }
+}
+
+/// Maps printable ASCII to its full-width form and a space to the ideographic space.
+fn to_full_width(ch: char) -> char {
+  match ch {
+    ' ' => '\u{3000}',
+    '!'..='~' => char::from_u32(ch as u32 - 0x21 + 0xFF01).unwrap_or(ch),
+    _ => ch,
+  }
+}
+
+/// Maps small kana to their full-size counterparts.
+///
+/// Reference: <https://drafts.csswg.org/css-text-3/#small-kana>
+fn to_full_size_kana(ch: char) -> char {
+  match ch {
+    'ぁ' => 'あ',
+    'ぃ' => 'い',
+    'ぅ' => 'う',
+    'ぇ' => 'え',
+    'ぉ' => 'お',
+    'ゕ' => 'か',
+    'ゖ' => 'け',
+    'っ' => 'つ',
+    'ゃ' => 'や',
+    'ゅ' => 'ゆ',
+    'ょ' => 'よ',
+    'ゎ' => 'わ',
+    'ァ' => 'ア',
+    'ィ' => 'イ',
+    'ゥ' => 'ウ',
+    'ェ' => 'エ',
+    'ォ' => 'オ',
+    'ヵ' => 'カ',
+    'ㇰ' => 'ク',
+    'ヶ' => 'ケ',
+    'ㇱ' => 'シ',
+    'ㇲ' => 'ス',
+    'ッ' => 'ツ',
+    'ㇳ' => 'ト',
+    'ㇴ' => 'ヌ',
+    'ㇵ' => 'ハ',
+    'ㇶ' => 'ヒ',
+    'ㇷ' => 'フ',
+    'ㇸ' => 'ヘ',
+    'ㇹ' => 'ホ',
+    'ㇺ' => 'ム',
+    'ャ' => 'ヤ',
+    'ュ' => 'ユ',
+    'ョ' => 'ヨ',
+    'ㇻ' => 'ラ',
+    'ㇼ' => 'リ',
+    'ㇽ' => 'ル',
+    'ㇾ' => 'レ',
+    'ㇿ' => 'ロ',
+    'ヮ' => 'ワ',
+    '\u{1B132}' => 'こ',
+    '\u{1B150}' => 'ゐ',
+    '\u{1B151}' => 'ゑ',
+    '\u{1B152}' => 'を',
+    '\u{1B155}' => 'コ',
+    '\u{1B164}' => 'ヰ',
+    '\u{1B165}' => 'ヱ',
+    '\u{1B166}' => 'ヲ',
+    '\u{1B167}' => 'ン',
+    'ｧ' => 'ｱ',
+    'ｨ' => 'ｲ',
+    'ｩ' => 'ｳ',
+    'ｪ' => 'ｴ',
+    'ｫ' => 'ｵ',
+    'ｯ' => 'ﾂ',
+    'ｬ' => 'ﾔ',
+    'ｭ' => 'ﾕ',
+    'ｮ' => 'ﾖ',
+    _ => ch,
+  }
+}
+
+/// The four-per-em space, inserted by `text-autospace` for the recommended 1/4em gap.
+const AUTOSPACE_SPACE: char = '\u{2005}';
+
+/// Inserts a quarter-em space between ideographs and adjacent letters or numbers.
+///
+/// `previous` is the last character of the preceding text in the same inline layout, so boundaries
+/// across spans get spaced too. Punctuation and spaces are neither ideographs nor letters,
+/// so no spacing is added next to them.
+pub(crate) fn apply_text_autospace<'a>(
+  input: Cow<'a, str>,
+  autospace: TextAutospace,
+  mut previous: Option<char>,
+) -> Cow<'a, str> {
+  if autospace == TextAutospace::NoAutospace {
+    return input;
+  }
+
+  let needs_space = |before: char, after: char| {
+    (is_ideograph(before) && is_autospace_target(after, autospace))
+      || (is_autospace_target(before, autospace) && is_ideograph(after))
+  };
+
+  let mut result = String::with_capacity(input.len());
+
+  for ch in input.chars() {
+    if previous.is_some_and(|before| needs_space(before, ch)) {
+      result.push(AUTOSPACE_SPACE);
+    }
+
+    result.push(ch);
+    previous = Some(ch);
+  }
+
+  Cow::Owned(result)
+}
+
+/// Forces the text or emoji presentation of characters that have both, by following each of them
+/// with the matching variation selector in place of the one from the text.
+///
+/// Emoji modifier sequences keep their emoji presentation, while regional indicators and ZWJ
+/// sequences are left untouched since a selector inside them would break the sequence apart.
+pub(crate) fn apply_font_variant_emoji<'a>(
+  input: Cow<'a, str>,
+  variant: FontVariantEmoji,
+) -> Cow<'a, str> {
+  let selector = match variant {
+    FontVariantEmoji::Normal => return input,
+    FontVariantEmoji::Text => TEXT_PRESENTATION_SELECTOR,
+    FontVariantEmoji::Emoji => EMOJI_PRESENTATION_SELECTOR,
+  };
+
+  if !input.chars().any(has_emoji_presentation_choice) {
+    return input;
+  }
+
+  let mut result = String::with_capacity(input.len());
+
+  for grapheme in input.graphemes(true) {
+    // A selector inside a ZWJ sequence would split it into its components.
+    if grapheme.contains(ZERO_WIDTH_JOINER) {
+      result.push_str(grapheme);
+      continue;
+    }
+
+    let mut chars = grapheme.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+      result.push(ch);
+
+      if !has_emoji_presentation_choice(ch) {
+        continue;
+      }
+
+      chars
+        .next_if(|&next| next == TEXT_PRESENTATION_SELECTOR || next == EMOJI_PRESENTATION_SELECTOR);
+
+      if chars.peek().is_some_and(|&next| is_emoji_modifier(next)) {
+        continue;
+      }
+
+      result.push(selector);
+    }
+  }
+
+  Cow::Owned(result)
+}
+
+const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Whether the character is a skin tone modifier.
+fn is_emoji_modifier(ch: char) -> bool {
+  matches!(ch, '\u{1F3FB}'..='\u{1F3FF}')
+}
+
+/// Whether the character is a symbol that may be drawn as either text or emoji.
+///
+/// This follows the bases listed in Unicode's `emoji-variation-sequences.txt`,
+/// but leaves out the keycap bases like digits so ordinary text is unaffected.
+fn has_emoji_presentation_choice(ch: char) -> bool {
+  matches!(
+    ch,
+    '\u{A9}'
+      | '\u{AE}'
+      | '\u{203C}'
+      | '\u{2049}'
+      | '\u{2122}'
+      | '\u{2139}'
+      | '\u{2194}'..='\u{2199}'
+      | '\u{21A9}'..='\u{21AA}'
+      | '\u{231A}'..='\u{231B}'
+      | '\u{2328}'
+      | '\u{23CF}'
+      | '\u{23E9}'..='\u{23F3}'
+      | '\u{23F8}'..='\u{23FA}'
+      | '\u{24C2}'
+      | '\u{25AA}'..='\u{25AB}'
+      | '\u{25B6}'
+      | '\u{25C0}'
+      | '\u{25FB}'..='\u{25FE}'
+      | '\u{2600}'..='\u{2604}'
+      | '\u{260E}'
+      | '\u{2611}'
+      | '\u{2614}'..='\u{2615}'
+      | '\u{2618}'
+      | '\u{261D}'
+      | '\u{2620}'
+      | '\u{2622}'..='\u{2623}'
+      | '\u{2626}'
+      | '\u{262A}'
+      | '\u{262E}'..='\u{262F}'
+      | '\u{2638}'..='\u{263A}'
+      | '\u{2640}'
+      | '\u{2642}'
+      | '\u{2648}'..='\u{2653}'
+      | '\u{265F}'..='\u{2660}'
+      | '\u{2663}'
+      | '\u{2665}'..='\u{2666}'
+      | '\u{2668}'
+      | '\u{267B}'
+      | '\u{267E}'..='\u{267F}'
+      | '\u{2692}'..='\u{2697}'
+      | '\u{2699}'
+      | '\u{269B}'..='\u{269C}'
+      | '\u{26A0}'..='\u{26A1}'
+      | '\u{26A7}'
+      | '\u{26AA}'..='\u{26AB}'
+      | '\u{26B0}'..='\u{26B1}'
+      | '\u{26BD}'..='\u{26BE}'
+      | '\u{26C4}'..='\u{26C5}'
+      | '\u{26C8}'
+      | '\u{26CE}'..='\u{26CF}'
+      | '\u{26D1}'
+      | '\u{26D3}'..='\u{26D4}'
+      | '\u{26E9}'..='\u{26EA}'
+      | '\u{26F0}'..='\u{26F5}'
+      | '\u{26F7}'..='\u{26FA}'
+      | '\u{26FD}'
+      | '\u{2702}'
+      | '\u{2705}'
+      | '\u{2708}'..='\u{270D}'
+      | '\u{270F}'
+      | '\u{2712}'
+      | '\u{2714}'
+      | '\u{2716}'
+      | '\u{271D}'
+      | '\u{2721}'
+      | '\u{2728}'
+      | '\u{2733}'..='\u{2734}'
+      | '\u{2744}'
+      | '\u{2747}'
+      | '\u{274C}'
+      | '\u{274E}'
+      | '\u{2753}'..='\u{2755}'
+      | '\u{2757}'
+      | '\u{2763}'..='\u{2764}'
+      | '\u{2795}'..='\u{2797}'
+      | '\u{27A1}'
+      | '\u{27B0}'
+      | '\u{27BF}'
+      | '\u{2934}'..='\u{2935}'
+      | '\u{2B05}'..='\u{2B07}'
+      | '\u{2B1B}'..='\u{2B1C}'
+      | '\u{2B50}'
+      | '\u{2B55}'
+      | '\u{3030}'
+      | '\u{303D}'
+      | '\u{3297}'
+      | '\u{3299}'
+      | '\u{1F004}'
+      | '\u{1F170}'..='\u{1F171}'
+      | '\u{1F17E}'..='\u{1F17F}'
+      | '\u{1F202}'
+      | '\u{1F21A}'
+      | '\u{1F22F}'
+      | '\u{1F237}'
+      | '\u{1F30D}'..='\u{1F30F}'
+      | '\u{1F315}'
+      | '\u{1F31C}'
+      | '\u{1F321}'
+      | '\u{1F324}'..='\u{1F32C}'
+      | '\u{1F336}'
+      | '\u{1F378}'
+      | '\u{1F37D}'
+      | '\u{1F393}'
+      | '\u{1F396}'..='\u{1F397}'
+      | '\u{1F399}'..='\u{1F39B}'
+      | '\u{1F39E}'..='\u{1F39F}'
+      | '\u{1F3A7}'
+      | '\u{1F3AC}'..='\u{1F3AE}'
+      | '\u{1F3C2}'
+      | '\u{1F3C4}'
+      | '\u{1F3C6}'
+      | '\u{1F3CA}'..='\u{1F3CE}'
+      | '\u{1F3D4}'..='\u{1F3E0}'
+      | '\u{1F3ED}'
+      | '\u{1F3F3}'
+      | '\u{1F3F5}'
+      | '\u{1F3F7}'
+      | '\u{1F408}'
+      | '\u{1F415}'
+      | '\u{1F41F}'
+      | '\u{1F426}'
+      | '\u{1F43F}'
+      | '\u{1F441}'..='\u{1F442}'
+      | '\u{1F446}'..='\u{1F449}'
+      | '\u{1F44D}'..='\u{1F44E}'
+      | '\u{1F453}'
+      | '\u{1F46A}'
+      | '\u{1F47D}'
+      | '\u{1F4A3}'
+      | '\u{1F4B0}'
+      | '\u{1F4B3}'
+      | '\u{1F4BB}'
+      | '\u{1F4BF}'
+      | '\u{1F4CB}'
+      | '\u{1F4DA}'
+      | '\u{1F4DF}'
+      | '\u{1F4E4}'..='\u{1F4E6}'
+      | '\u{1F4EA}'..='\u{1F4ED}'
+      | '\u{1F4F7}'
+      | '\u{1F4F9}'..='\u{1F4FB}'
+      | '\u{1F4FD}'
+      | '\u{1F508}'
+      | '\u{1F50D}'
+      | '\u{1F512}'..='\u{1F513}'
+      | '\u{1F549}'..='\u{1F54A}'
+      | '\u{1F550}'..='\u{1F567}'
+      | '\u{1F56F}'..='\u{1F570}'
+      | '\u{1F573}'..='\u{1F579}'
+      | '\u{1F587}'
+      | '\u{1F58A}'..='\u{1F58D}'
+      | '\u{1F590}'
+      | '\u{1F5A5}'
+      | '\u{1F5A8}'
+      | '\u{1F5B1}'..='\u{1F5B2}'
+      | '\u{1F5BC}'
+      | '\u{1F5C2}'..='\u{1F5C4}'
+      | '\u{1F5D1}'..='\u{1F5D3}'
+      | '\u{1F5DC}'..='\u{1F5DE}'
+      | '\u{1F5E1}'
+      | '\u{1F5E3}'
+      | '\u{1F5E8}'
+      | '\u{1F5EF}'
+      | '\u{1F5F3}'
+      | '\u{1F5FA}'
+      | '\u{1F610}'
+      | '\u{1F687}'
+      | '\u{1F68D}'
+      | '\u{1F691}'
+      | '\u{1F694}'
+      | '\u{1F698}'
+      | '\u{1F6AD}'
+      | '\u{1F6B2}'
+      | '\u{1F6B9}'..='\u{1F6BA}'
+      | '\u{1F6BC}'
+      | '\u{1F6CB}'
+      | '\u{1F6CD}'..='\u{1F6CF}'
+      | '\u{1F6E0}'..='\u{1F6E5}'
+      | '\u{1F6E9}'
+      | '\u{1F6F0}'
+      | '\u{1F6F3}'
+  )
+}
+
+/// Whether the character is a Han ideograph or kana.
+fn is_ideograph(ch: char) -> bool {
+  matches!(
+    ch,
+    '\u{3040}'..='\u{30FF}'
+      | '\u{31F0}'..='\u{31FF}'
+      | '\u{3400}'..='\u{4DBF}'
+      | '\u{4E00}'..='\u{9FFF}'
+      | '\u{F900}'..='\u{FAFF}'
+      | '\u{FF66}'..='\u{FF9F}'
+      | '\u{20000}'..='\u{3FFFF}'
+  )
+}
+
+/// Whether the character is a non-ideographic letter or number spaced by the given `text-autospace`.
+///
+/// Full-width forms already carry their own spacing and are not spaced again.
+fn is_autospace_target(ch: char, autospace: TextAutospace) -> bool {
+  if is_ideograph(ch) || ('\u{FF01}'..='\u{FF60}').contains(&ch) {
+    return false;
+  }
+
+  match autospace {
+    TextAutospace::NoAutospace => false,
+    TextAutospace::Normal => ch.is_alphanumeric(),
+    TextAutospace::IdeographAlpha => ch.is_alphabetic(),
+    TextAutospace::IdeographNumeric => ch.is_numeric(),
+  }
+}
+
+/// Pushes the uppercase form of `ch`, where Turkic languages map `i` to the dotted `İ`.
+fn push_uppercase(out: &mut String, ch: char, turkic: bool) {
+  match ch {
+    'i' if turkic => out.push('İ'),
+    _ => out.extend(ch.to_uppercase()),
+  }
+}
+
+/// Pushes the lowercase form of `ch`, where Turkic languages map `I` to the dotless `ı`.
+fn push_lowercase(out: &mut String, ch: char, turkic: bool) {
+  match ch {
+    'I' if turkic => out.push('ı'),
+    'İ' if turkic => out.push('i'),
+    _ => out.extend(ch.to_lowercase()),
+  }
+}
+
+/// Collapses white space and then applies `text-transform`, in the order CSS processes text.
+///
+/// Transforming first would let collapsing turn the U+3000 spaces made by `full-width` back
+/// into ASCII spaces.
+pub(crate) fn collapse_and_transform_text<'a>(
+  input: &'a str,
+  collapse: WhiteSpaceCollapse,
+  transform: TextTransform,
+  lang: Option<&Lang>,
+) -> Cow<'a, str> {
+  match apply_white_space_collapse(input, collapse) {
+    Cow::Borrowed(collapsed) => apply_text_transform(collapsed, transform, lang),
+    Cow::Owned(collapsed) => {
+      if let Cow::Owned(transformed) = apply_text_transform(&collapsed, transform, lang) {
+        return Cow::Owned(transformed);
+      }
+
+      Cow::Owned(collapsed)
+    }
   }
 }
 
@@ -522,7 +1052,7 @@ pub(crate) fn apply_white_space_collapse<'a>(
   collapse: WhiteSpaceCollapse,
 ) -> Cow<'a, str> {
   match collapse {
-    WhiteSpaceCollapse::Preserve => Cow::Borrowed(input),
+    WhiteSpaceCollapse::Preserve | WhiteSpaceCollapse::BreakSpaces => Cow::Borrowed(input),
 
     // Collapse sequences of whitespace (spaces, tabs, line breaks) into a single space
     // and trim leading/trailing spaces.
@@ -597,6 +1127,49 @@ pub(crate) fn apply_white_space_collapse<'a>(
   }
 }
 
+/// Expands tabs left by white space processing into spaces, up to the next multiple of `tab_size` columns.
+///
+/// `column` is the column the text starts at, continuing the line of the preceding spans, and is
+/// advanced past the end of the text.
+pub(crate) fn apply_tab_size<'a>(
+  input: Cow<'a, str>,
+  tab_size: usize,
+  column: &mut usize,
+) -> Cow<'a, str> {
+  if !input.contains('\t') {
+    match input.rfind('\n') {
+      Some(index) => *column = input[index + 1..].chars().count(),
+      None => *column += input.chars().count(),
+    }
+
+    return input;
+  }
+
+  let mut result = String::with_capacity(input.len());
+
+  for ch in input.chars() {
+    match ch {
+      '\t' if tab_size > 0 => {
+        let spaces = tab_size - *column % tab_size;
+
+        result.extend(std::iter::repeat_n(' ', spaces));
+        *column += spaces;
+      }
+      '\t' => {}
+      '\n' => {
+        result.push(ch);
+        *column = 0;
+      }
+      _ => {
+        result.push(ch);
+        *column += 1;
+      }
+    }
+  }
+
+  Cow::Owned(result)
+}
+
 /// Counts the number of word splits caused by line breaks.
 /// A word split occurs when a line break happens at a position that is not
 /// a legal Unicode line break opportunity (e.g. forced by break-word).
@@ -721,8 +1294,176 @@ pub(crate) fn make_pretty_text(
 
 #[cfg(test)]
 mod tests {
+  use crate::layout::style::FromCss;
+
   use super::*;
 
+  #[test]
+  fn test_text_transform_turkic_case_mapping() {
+    let Ok(turkish) = Lang::from_str("tr") else {
+      unreachable!()
+    };
+
+    assert_eq!(
+      apply_text_transform("istanbul", TextTransform::Uppercase, None),
+      "ISTANBUL"
+    );
+    assert_eq!(
+      apply_text_transform("istanbul", TextTransform::Uppercase, Some(&turkish)),
+      "İSTANBUL"
+    );
+    assert_eq!(
+      apply_text_transform("IŞIK İZMİR", TextTransform::Lowercase, Some(&turkish)),
+      "ışık izmir"
+    );
+    assert_eq!(
+      apply_text_transform("iyi günler", TextTransform::Capitalize, Some(&turkish)),
+      "İyi Günler"
+    );
+  }
+
+  #[test]
+  fn test_text_transform_full_width() {
+    assert_eq!(
+      apply_text_transform("Takumi 123!", TextTransform::FullWidth, None),
+      "Ｔａｋｕｍｉ\u{3000}１２３！"
+    );
+    // Characters that are already wide, like CJK, are kept as is.
+    assert_eq!(
+      apply_text_transform("匠 v2", TextTransform::FullWidth, None),
+      "匠\u{3000}ｖ２"
+    );
+  }
+
+  #[test]
+  fn test_text_transform_full_width_survives_collapse() {
+    assert_eq!(
+      collapse_and_transform_text(
+        " Takumi \n 123 ",
+        WhiteSpaceCollapse::Collapse,
+        TextTransform::FullWidth,
+        None
+      ),
+      "Ｔａｋｕｍｉ\u{3000}１２３"
+    );
+  }
+
+  #[test]
+  fn test_text_autospace_between_ideographs_and_latin() {
+    assert_eq!(
+      apply_text_autospace(
+        "Takumiは最高のライブラリ123です".into(),
+        TextAutospace::Normal,
+        None
+      ),
+      "Takumi\u{2005}は最高のライブラリ\u{2005}123\u{2005}です"
+    );
+    assert_eq!(
+      apply_text_autospace("Takumiは123".into(), TextAutospace::IdeographNumeric, None),
+      "Takumiは\u{2005}123"
+    );
+    assert_eq!(
+      apply_text_autospace("Takumiは".into(), TextAutospace::NoAutospace, None),
+      "Takumiは"
+    );
+  }
+
+  #[test]
+  fn test_tab_size_aligns_to_tab_stops() {
+    let mut column = 0;
+    assert_eq!(
+      apply_tab_size("\tif x:\n\t\treturn".into(), 4, &mut column),
+      "    if x:\n        return"
+    );
+    assert_eq!(column, 14);
+
+    let mut column = 0;
+    assert_eq!(
+      apply_tab_size("ab\tc\td".into(), 4, &mut column),
+      "ab  c   d"
+    );
+
+    // The column carries over from the preceding span.
+    let mut column = 3;
+    assert_eq!(apply_tab_size("\tx".into(), 4, &mut column), " x");
+    assert_eq!(apply_tab_size("\tx".into(), 0, &mut column), "x");
+  }
+
+  #[test]
+  fn test_font_variant_emoji_replaces_selectors() {
+    assert_eq!(
+      apply_font_variant_emoji("I ❤️ you ❤".into(), FontVariantEmoji::Text),
+      "I ❤\u{FE0E} you ❤\u{FE0E}"
+    );
+    assert_eq!(
+      apply_font_variant_emoji("❤\u{FE0E}☀".into(), FontVariantEmoji::Emoji),
+      "❤\u{FE0F}☀\u{FE0F}"
+    );
+    assert_eq!(
+      apply_font_variant_emoji("❤\u{FE0E}".into(), FontVariantEmoji::Normal),
+      "❤\u{FE0E}"
+    );
+  }
+
+  #[test]
+  fn test_font_variant_emoji_keeps_sequences() {
+    // Plain text, skin tone modifiers and flags are left as is.
+    assert_eq!(
+      apply_font_variant_emoji("Takumi 123".into(), FontVariantEmoji::Text),
+      "Takumi 123"
+    );
+    assert_eq!(
+      apply_font_variant_emoji("👍🏽🇯🇵".into(), FontVariantEmoji::Emoji),
+      "👍🏽🇯🇵"
+    );
+    // ZWJ sequences stay whole, whichever selectors they carry.
+    assert_eq!(
+      apply_font_variant_emoji(
+        "👩\u{200D}❤\u{FE0F}\u{200D}👨 ❤".into(),
+        FontVariantEmoji::Text
+      ),
+      "👩\u{200D}❤\u{FE0F}\u{200D}👨 ❤\u{FE0E}"
+    );
+    assert_eq!(
+      apply_font_variant_emoji("🏳\u{FE0F}\u{200D}🌈".into(), FontVariantEmoji::Emoji),
+      "🏳\u{FE0F}\u{200D}🌈"
+    );
+    // Symbols without a variation sequence are left alone.
+    assert_eq!(
+      apply_font_variant_emoji("☇⚀".into(), FontVariantEmoji::Text),
+      "☇⚀"
+    );
+  }
+
+  #[test]
+  fn test_text_autospace_skips_punctuation() {
+    assert_eq!(
+      apply_text_autospace(
+        "日本, Japan、東京 (Tokyo)".into(),
+        TextAutospace::Normal,
+        None
+      ),
+      "日本, Japan、東京 (Tokyo)"
+    );
+    // Spacing also applies across the boundary with the previous span.
+    assert_eq!(
+      apply_text_autospace("です".into(), TextAutospace::Normal, Some('3')),
+      "\u{2005}です"
+    );
+  }
+
+  #[test]
+  fn test_text_transform_full_size_kana() {
+    assert_eq!(
+      apply_text_transform("ちょっとキャッシュ", TextTransform::FullSizeKana, None),
+      "ちよつとキヤツシユ"
+    );
+    assert_eq!(
+      apply_text_transform("ｷｬｯｼｭ", TextTransform::FullSizeKana, None),
+      "ｷﾔﾂｼﾕ"
+    );
+  }
+
   #[test]
   fn test_white_space_preserve() {
     let input = "  a \t b\n";
@@ -752,4 +1493,19 @@ mod tests {
     // spaces and tabs collapsed to single space, line break preserved
     assert_eq!(out, "a \nb c");
   }
+
+  #[test]
+  fn test_quantize_glyph_origin_to_quarter_pixel() {
+    let transform = quantize_glyph_origin(Affine {
+      x: 10.3,
+      y: -4.6,
+      ..Affine::IDENTITY
+    });
+
+    assert_eq!(
+      transform.decompose_translation(),
+      Point { x: 10.25, y: -4.5 }
+    );
+    assert!(transform.only_translation());
+  }
 }
@@ -0,0 +1,88 @@
+#[cfg(feature = "rayon")]
+use std::{cell::RefCell, sync::Arc};
+
+#[cfg(feature = "rayon")]
+use rayon::ThreadPool;
+
+#[cfg(feature = "rayon")]
+thread_local! {
+  static CURRENT_THREAD_POOL: RefCell<Option<Arc<ThreadPool>>> = const { RefCell::new(None) };
+}
+
+/// Makes [`install`] use a thread pool on this thread, until the scope is dropped.
+///
+/// Rendering can't run inside `ThreadPool::install` itself, since the render tree isn't `Send`,
+/// so only the parallel sections are moved into the pool.
+pub(crate) struct ThreadPoolScope {
+  #[cfg(feature = "rayon")]
+  previous: Option<Arc<ThreadPool>>,
+}
+
+impl ThreadPoolScope {
+  /// Enters the thread pool of the global context, or the global rayon pool if it has none.
+  pub(crate) fn enter(global: &crate::GlobalContext) -> Self {
+    #[cfg(feature = "rayon")]
+    {
+      let previous =
+        CURRENT_THREAD_POOL.with(|current| current.replace(global.thread_pool.clone()));
+
+      Self { previous }
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+      let _ = global;
+
+      Self {}
+    }
+  }
+}
+
+#[cfg(feature = "rayon")]
+impl Drop for ThreadPoolScope {
+  fn drop(&mut self) {
+    let previous = self.previous.take();
+
+    CURRENT_THREAD_POOL.with(|current| current.replace(previous));
+  }
+}
+
+/// Runs parallel work in the thread pool of the current render, or the global rayon pool otherwise.
+pub(crate) fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+  #[cfg(feature = "rayon")]
+  if let Some(pool) = CURRENT_THREAD_POOL.with(|current| current.borrow().clone()) {
+    return pool.install(op);
+  }
+
+  op()
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+  use rayon::ThreadPoolBuilder;
+
+  use super::*;
+  use crate::GlobalContext;
+
+  #[test]
+  fn test_install_runs_in_entered_pool() {
+    let Ok(pool) = ThreadPoolBuilder::new().num_threads(2).build() else {
+      unreachable!()
+    };
+    let pool = Arc::new(pool);
+    let global = GlobalContext {
+      thread_pool: Some(pool.clone()),
+      ..Default::default()
+    };
+
+    assert_eq!(install(|| pool.current_thread_index()), None);
+
+    // Image resizing and animated WebP encoding run their parallel work through `install`.
+    let scope = ThreadPoolScope::enter(&global);
+    assert!(install(|| pool.current_thread_index()).is_some());
+    assert_eq!(install(rayon::current_num_threads), 2);
+    drop(scope);
+
+    assert_eq!(install(|| pool.current_thread_index()), None);
+  }
+}
@@ -1,7 +1,9 @@
 use std::{borrow::Cow, io::Write};
 
-use image::{ExtendedColorType, ImageEncoder, ImageFormat, RgbaImage, codecs::jpeg::JpegEncoder};
-use png::{ColorType, Compression, Filter};
+use image::{
+  ExtendedColorType, ImageEncoder, ImageError, ImageFormat, RgbaImage, codecs::jpeg::JpegEncoder,
+};
+use png::{BlendOp, ColorType, Compression, DisposeOp, Filter};
 use serde::Deserialize;
 
 use image_webp::WebPEncoder;
@@ -9,7 +11,9 @@ use image_webp::WebPEncoder;
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
-use crate::{Error::IoError, Result};
+#[cfg(feature = "rayon")]
+use crate::rendering::install;
+use crate::{Error::IoError, GlobalContext, Result, rendering::ThreadPoolScope};
 
 /// Output format for rendered images.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
@@ -17,6 +21,8 @@ use crate::{Error::IoError, Result};
 pub enum ImageOutputFormat {
   /// WebP image format, provides good compression and supports animation.
   /// It is useful for images in web contents.
+  ///
+  /// Frames are always encoded losslessly (VP8L), so `quality` has no effect.
   WebP,
 
   /// PNG image format, lossless and widely supported, and its the fastest format to encode.
@@ -47,6 +53,26 @@ impl From<ImageOutputFormat> for ImageFormat {
   }
 }
 
+/// How a frame is composited over the canvas left by the previous frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBlend {
+  /// The frame replaces the canvas, including its transparent pixels.
+  #[default]
+  Source,
+  /// The frame is alpha blended over the canvas, so transparent pixels keep the previous frame.
+  Over,
+}
+
+/// What happens to the canvas after a frame is shown, before the next frame is drawn.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FrameDisposal {
+  /// The frame stays on the canvas, so the next frame can be drawn as a diff over it.
+  #[default]
+  None,
+  /// The canvas is cleared to transparent.
+  Background,
+}
+
 /// Represents a single frame of an animated image.
 #[derive(Debug, Clone)]
 pub struct AnimationFrame {
@@ -55,12 +81,21 @@ pub struct AnimationFrame {
   /// The duration of the frame in milliseconds.
   /// Maximum value is 0xffffff (24-bit), overflow will be clamped.
   pub duration_ms: u32,
+  /// How the frame is composited over the previous one.
+  pub blend: FrameBlend,
+  /// What happens to the canvas after the frame is shown.
+  pub disposal: FrameDisposal,
 }
 
 impl AnimationFrame {
-  /// Creates a new animation frame.
+  /// Creates a new animation frame that fully replaces the previous one.
   pub fn new(image: RgbaImage, duration_ms: u32) -> Self {
-    Self { image, duration_ms }
+    Self {
+      image,
+      duration_ms,
+      blend: FrameBlend::default(),
+      disposal: FrameDisposal::default(),
+    }
   }
 }
 
@@ -85,21 +120,38 @@ fn has_any_alpha_pixel(image: &RgbaImage) -> bool {
 }
 
 /// Writes a single rendered image to `destination` using `format`.
+///
+/// `quality` applies to JPEG, and picks the compression level for PNG.
+/// WebP output is always lossless and decodes back to the exact same pixels.
+///
+/// `color_profile` is an ICC profile embedded as the PNG `iCCP` chunk, the JPEG `APP2` markers
+/// or the WebP `ICCP` chunk, like the one from [`srgb_color_profile`].
 pub fn write_image<T: Write>(
   image: &RgbaImage,
   destination: &mut T,
   format: ImageOutputFormat,
   quality: Option<u8>,
+  color_profile: Option<&[u8]>,
 ) -> Result<()> {
   match format {
     ImageOutputFormat::Jpeg => {
       let rgb = strip_alpha_channel(image);
 
-      let encoder = JpegEncoder::new_with_quality(destination, quality.unwrap_or(75));
+      let mut encoder = JpegEncoder::new_with_quality(destination, quality.unwrap_or(75));
+
+      if let Some(color_profile) = color_profile {
+        encoder
+          .set_icc_profile(color_profile.to_vec())
+          .map_err(ImageError::Unsupported)?;
+      }
+
       encoder.write_image(&rgb, image.width(), image.height(), ExtendedColorType::Rgb8)?;
     }
     ImageOutputFormat::Png => {
-      let mut encoder = png::Encoder::new(destination, image.width(), image.height());
+      let mut info = png::Info::with_size(image.width(), image.height());
+      info.icc_profile = color_profile.map(Cow::Borrowed);
+
+      let mut encoder = png::Encoder::with_info(destination, info)?;
 
       let has_alpha = has_any_alpha_pixel(image);
 
@@ -133,7 +185,11 @@ pub fn write_image<T: Write>(
       writer.finish()?;
     }
     ImageOutputFormat::WebP => {
-      let encoder = WebPEncoder::new(destination);
+      let mut encoder = WebPEncoder::new(destination);
+
+      if let Some(color_profile) = color_profile {
+        encoder.set_icc_profile(color_profile.to_vec());
+      }
 
       let has_alpha = has_any_alpha_pixel(image);
 
@@ -233,31 +289,38 @@ fn estimate_riff_size<'a, I: Iterator<Item = &'a [u8]>>(frames: I) -> Result<u32
 }
 
 /// Encode a sequence of RGBA frames into an animated WebP and write to `destination`.
+///
+/// Each frame is blended and disposed as set on its [`AnimationFrame`]. With the `rayon` feature,
+/// frames are encoded in parallel in the thread pool of `global`, or the global rayon pool if it
+/// has none.
 pub fn encode_animated_webp<W: Write>(
   frames: &[AnimationFrame],
   destination: &mut W,
-  blend: bool,
-  dispose: bool,
   loop_count: Option<u16>,
+  global: &GlobalContext,
 ) -> Result<()> {
   assert_ne!(frames.len(), 0);
 
+  let thread_pool_scope = ThreadPoolScope::enter(global);
+
   // encode frames losslessly and collect VP8L/VP8 payloads
   #[cfg(feature = "rayon")]
-  let frames_payloads: Vec<(&AnimationFrame, Vec<u8>)> = frames
-    .par_iter()
-    .map(|frame| {
-      let mut buf = Vec::new();
-      WebPEncoder::new(&mut buf).encode(
-        &frame.image,
-        frame.image.width(),
-        frame.image.height(),
-        image_webp::ColorType::Rgba8,
-      )?;
+  let frames_payloads: Vec<(&AnimationFrame, Vec<u8>)> = install(|| {
+    frames
+      .par_iter()
+      .map(|frame| {
+        let mut buf = Vec::new();
+        WebPEncoder::new(&mut buf).encode(
+          &frame.image,
+          frame.image.width(),
+          frame.image.height(),
+          image_webp::ColorType::Rgba8,
+        )?;
 
-      Ok((frame, buf))
-    })
-    .collect::<Result<Vec<(&AnimationFrame, Vec<u8>)>>>()?;
+        Ok((frame, buf))
+      })
+      .collect::<Result<Vec<(&AnimationFrame, Vec<u8>)>>>()
+  })?;
 
   #[cfg(not(feature = "rayon"))]
   let frames_payloads: Vec<(&AnimationFrame, Vec<u8>)> = frames
@@ -277,6 +340,8 @@ pub fn encode_animated_webp<W: Write>(
     })
     .collect::<Result<Vec<(&AnimationFrame, Vec<u8>)>>>()?;
 
+  drop(thread_pool_scope);
+
   let riff_size = estimate_riff_size(frames_payloads.iter().map(|(_, buf)| buf.as_slice()))?;
 
   // RIFF header
@@ -302,10 +367,12 @@ pub fn encode_animated_webp<W: Write>(
   destination.write_all(&[0u8; 4])?; // bgcolor (4 bytes)
   destination.write_all(&loop_count.unwrap_or(0).to_le_bytes())?;
 
-  let frame_flags = ((blend as u8) << 1) | (dispose as u8);
-
   // ANMF frames
   for (frame, vp8_data) in frames_payloads.into_iter() {
+    // Bit 1 set means "do not blend", bit 0 set means "dispose to background".
+    let frame_flags = (u8::from(frame.blend == FrameBlend::Source) << 1)
+      | u8::from(frame.disposal == FrameDisposal::Background);
+
     let w_bytes = (frame.image.width() - 1).to_le_bytes();
     let h_bytes = (frame.image.height() - 1).to_le_bytes();
 
@@ -345,6 +412,8 @@ pub fn encode_animated_webp<W: Write>(
 }
 
 /// Encode a sequence of RGBA frames into an animated PNG and write to `destination`.
+///
+/// Each frame is blended and disposed as set on its [`AnimationFrame`].
 pub fn encode_animated_png<W: Write>(
   frames: &[AnimationFrame],
   destination: &mut W,
@@ -374,6 +443,14 @@ pub fn encode_animated_png<W: Write>(
   let mut writer = encoder.write_header()?;
 
   for frame in frames {
+    writer.set_blend_op(match frame.blend {
+      FrameBlend::Source => BlendOp::Source,
+      FrameBlend::Over => BlendOp::Over,
+    })?;
+    writer.set_dispose_op(match frame.disposal {
+      FrameDisposal::None => DisposeOp::None,
+      FrameDisposal::Background => DisposeOp::Background,
+    })?;
     writer.write_image_data(frame.image.as_raw())?;
   }
 
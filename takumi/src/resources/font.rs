@@ -7,12 +7,16 @@ use std::{
   sync::Arc,
 };
 
+use cssparser::{ParseError, Parser, ParserInput};
 use parley::{
-  FontStyle, GenericFamily, GlyphRun, LayoutContext, TextStyle, TreeBuilder,
-  fontique::{Blob, Collection, CollectionOptions, FallbackKey, FontInfoOverride, Script},
+  FontFamily, FontStack, FontStyle, FontVariation, GenericFamily, GlyphRun, LayoutContext,
+  TextStyle, TreeBuilder,
+  fontique::{
+    Blob, Collection, CollectionOptions, FallbackKey, FamilyId, FontInfoOverride, Script,
+  },
 };
 use swash::{
-  FontRef,
+  FontDataRef, FontRef, Tag,
   scale::{ScaleContext, StrikeWith, image::Image, outline::Outline},
 };
 use thiserror::Error;
@@ -21,7 +25,11 @@ use zeno::{Angle as ZenoAngle, Transform as ZenoTransform};
 
 use crate::{
   Xxh3HashSet,
-  layout::inline::{InlineBrush, InlineLayout},
+  layout::{
+    inline::{InlineBrush, InlineLayout},
+    style::FontVariationSettings,
+  },
+  rendering::FontMetrics,
 };
 
 /// Represents a resolved glyph that can be either a bitmap image or an outline
@@ -53,6 +61,41 @@ pub enum FontError {
   /// Font index is invalid
   #[error("Font index is invalid")]
   InvalidFontIndex,
+  /// The `unicode-range` list couldn't be parsed
+  #[error("Invalid unicode-range")]
+  InvalidUnicodeRange,
+}
+
+/// An inclusive range of code points a font is used for, like `unicode-range` in `@font-face`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnicodeRange {
+  /// The first code point of the range.
+  pub start: u32,
+  /// The last code point of the range.
+  pub end: u32,
+}
+
+impl UnicodeRange {
+  /// Parses a comma separated `unicode-range` list, such as `U+0000-00FF, U+4E00-9FFF`.
+  pub fn parse_list(source: &str) -> Result<Vec<Self>, FontError> {
+    let mut input = ParserInput::new(source);
+    let mut parser = Parser::new(&mut input);
+
+    parser
+      .parse_comma_separated(|input| {
+        cssparser::UnicodeRange::parse(input)
+          .map(|range| UnicodeRange {
+            start: range.start,
+            end: range.end,
+          })
+          .map_err(ParseError::<()>::from)
+      })
+      .map_err(|_| FontError::InvalidUnicodeRange)
+  }
+
+  fn contains(self, character: char) -> bool {
+    (self.start..=self.end).contains(&(character as u32))
+  }
 }
 
 /// Supported font formats for loading and processing
@@ -121,6 +164,7 @@ pub(crate) struct FontCacheKey {
   width: Option<u32>,
   axes: Option<Box<[(u32, u32)]>>,
   generic_family: Option<GenericFamily>,
+  unicode_range: Box<[UnicodeRange]>,
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
@@ -140,24 +184,114 @@ impl From<FontStyle> for FontStyleHash {
   }
 }
 
+/// A named instance of a variable font, such as "SemiBold Condensed".
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedInstance {
+  /// The name of the instance.
+  pub name: String,
+  /// The axis coordinates of the instance, in the font's user space.
+  pub axes: Vec<(Tag, f32)>,
+}
+
+impl NamedInstance {
+  /// Returns the axis coordinates as `font-variation-settings`, which selects this instance when rendering.
+  pub fn to_font_variation_settings(&self) -> FontVariationSettings {
+    self
+      .axes
+      .iter()
+      .map(|&(tag, value)| FontVariation { tag, value })
+      .collect()
+  }
+}
+
+/// A font family loaded into a [`FontContext`], listed by [`FontContext::loaded_families`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadedFamily {
+  /// The family name, as matched by `font-family`.
+  pub family: String,
+  /// The distinct weights of the faces in the family, in ascending order.
+  pub weights: Vec<f32>,
+  /// The distinct styles of the faces in the family.
+  pub styles: Vec<FontStyle>,
+}
+
+/// Registers a loaded font into a collection, returning the names of the families it added.
+type RegisterFont = dyn Fn(&mut Collection) -> Vec<Box<str>> + Send + Sync;
+
+/// A font loaded through [`FontContext::load_and_store`], kept so the collection can be rebuilt without it.
+#[derive(Clone)]
+struct RegisteredFont {
+  cache_key: FontCacheKey,
+  data: Blob<u8>,
+  families: Vec<Box<str>>,
+  register: Arc<RegisterFont>,
+  unicode_range: Box<[UnicodeRange]>,
+}
+
+/// A snapshot of the fonts loaded into a [`FontContext`], created by [`FontContext::export_prepared`].
+///
+/// The snapshot shares the decoded font data, so importing it with [`FontContext::import_prepared`]
+/// skips reading and decompressing WOFF/WOFF2 sources again. It is cheap to clone and can be sent
+/// to other threads, which suits worker pools that each own a [`FontContext`].
+#[derive(Clone)]
+pub struct PreparedFonts {
+  fonts: Vec<RegisteredFont>,
+  fallback_family: Option<Box<str>>,
+}
+
 /// A context for managing fonts in the rendering system.
 #[derive(Clone)]
 pub struct FontContext {
   inner: parley::FontContext,
   cache: Xxh3HashSet<FontCacheKey>,
+  registered: Vec<RegisteredFont>,
+  fallback_family: Option<Box<str>>,
+  /// The `unicode-range` of families whose every face is restricted to one, keyed by lowercase name.
+  unicode_ranges: HashMap<Box<str>, Vec<UnicodeRange>>,
+}
+
+fn empty_parley_font_context() -> parley::FontContext {
+  parley::FontContext {
+    collection: Collection::new(CollectionOptions {
+      system_fonts: false,
+      shared: false,
+    }),
+    source_cache: Default::default(),
+  }
+}
+
+fn register_fonts(
+  collection: &mut Collection,
+  font_data: Blob<u8>,
+  info_override: Option<FontInfoOverride<'_>>,
+  generic_family: Option<GenericFamily>,
+) -> Vec<Box<str>> {
+  let fonts = collection.register_fonts(font_data, info_override);
+
+  fonts
+    .into_iter()
+    .filter_map(|(family, _)| {
+      if let Some(generic_family) = generic_family {
+        collection.append_generic_families(generic_family, once(family));
+      }
+
+      for (script, _) in Script::all_samples() {
+        collection.append_fallbacks(FallbackKey::new(*script, None), once(family));
+      }
+
+      collection.family_name(family).map(Into::into)
+    })
+    .collect()
 }
 
 impl Default for FontContext {
   fn default() -> Self {
     Self {
-      inner: parley::FontContext {
-        collection: Collection::new(CollectionOptions {
-          system_fonts: false,
-          shared: false,
-        }),
-        source_cache: Default::default(),
-      },
+      inner: empty_parley_font_context(),
       cache: Xxh3HashSet::default(),
+      registered: Vec::new(),
+      fallback_family: None,
+      unicode_ranges: HashMap::new(),
     }
   }
 }
@@ -270,12 +404,176 @@ impl FontContext {
     builder.build()
   }
 
+  /// Measures the `ch` and `ex` metrics of the font selected by the given style, relative to its font size.
+  pub(crate) fn measure_font_metrics(&self, style: TextStyle<'_, InlineBrush>) -> FontMetrics {
+    let font_size = style.font_size;
+    if font_size <= 0.0 {
+      return FontMetrics::default();
+    }
+
+    let (mut layout, _) = self.tree_builder(style, |builder| {
+      builder.push_text("0");
+    });
+    layout.break_all_lines(None);
+
+    let Some(run) = layout.lines().next().and_then(|line| line.runs().next()) else {
+      return FontMetrics::default();
+    };
+
+    let font = run.font();
+    let x_height = FontRef::from_index(font.data.as_ref(), font.index as usize)
+      .map(|font_ref| font_ref.metrics(run.normalized_coords()))
+      .filter(|metrics| metrics.units_per_em > 0 && metrics.x_height > 0.0)
+      .map(|metrics| metrics.x_height / metrics.units_per_em as f32);
+
+    FontMetrics {
+      ch: run.advance() / font_size,
+      ex: x_height.unwrap_or(FontMetrics::default().ex),
+    }
+  }
+
+  /// Returns whether the primary font selected by the given style provides the OpenType feature.
+  pub(crate) fn supports_feature(&self, style: TextStyle<'_, InlineBrush>, tag: Tag) -> bool {
+    let (mut layout, _) = self.tree_builder(style, |builder| {
+      builder.push_text("0");
+    });
+    layout.break_all_lines(None);
+
+    let Some(run) = layout.lines().next().and_then(|line| line.runs().next()) else {
+      return false;
+    };
+
+    let font = run.font();
+    FontRef::from_index(font.data.as_ref(), font.index as usize)
+      .is_some_and(|font_ref| font_ref.features().any(|feature| feature.tag() == tag))
+  }
+
+  /// Splits the text where the usable families change, pushing each part with its narrowed font stack.
+  ///
+  /// Families restricted by `unicode-range` are dropped from the stack for code points outside their ranges.
+  pub(crate) fn push_text_span(
+    &self,
+    builder: &mut TreeBuilder<'_, InlineBrush>,
+    text: &str,
+    style: TextStyle<'_, InlineBrush>,
+  ) {
+    for (segment, font_stack) in self.unicode_range_segments(text, &style.font_stack) {
+      let mut segment_style = style.clone();
+      if let Some(font_stack) = font_stack {
+        segment_style.font_stack = font_stack;
+      }
+
+      builder.push_style_span(segment_style);
+      builder.push_text(segment);
+      builder.pop_style_span();
+    }
+  }
+
+  /// Returns the text segments sharing the same usable families, with the font stack to use
+  /// or `None` when it's unchanged.
+  fn unicode_range_segments<'t>(
+    &self,
+    text: &'t str,
+    font_stack: &FontStack<'_>,
+  ) -> Vec<(&'t str, Option<FontStack<'static>>)> {
+    if self.unicode_ranges.is_empty() {
+      return vec![(text, None)];
+    }
+
+    let families: Vec<FontFamily<'_>> = match font_stack {
+      FontStack::Source(source) => FontFamily::parse_list(source).collect(),
+      FontStack::Single(family) => vec![family.clone()],
+      FontStack::List(families) => families.to_vec(),
+    };
+
+    // Only the first 64 families can be dropped, they're tracked as bits of a mask.
+    let family_ranges: Vec<Option<&[UnicodeRange]>> = families
+      .iter()
+      .take(u64::BITS as usize)
+      .map(|family| match family {
+        FontFamily::Named(name) => self
+          .unicode_ranges
+          .get(name.to_lowercase().as_str())
+          .map(Vec::as_slice),
+        FontFamily::Generic(_) => None,
+      })
+      .collect();
+
+    if family_ranges.iter().all(Option::is_none) {
+      return vec![(text, None)];
+    }
+
+    let excluded_mask = |character: char| {
+      family_ranges
+        .iter()
+        .enumerate()
+        .fold(0u64, |mask, (index, ranges)| match ranges {
+          Some(ranges) if !ranges.iter().any(|range| range.contains(character)) => {
+            mask | (1 << index)
+          }
+          _ => mask,
+        })
+    };
+
+    let narrowed_stack = |mask: u64| {
+      (mask != 0).then(|| {
+        FontStack::List(Cow::Owned(
+          families
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index >= u64::BITS as usize || mask & (1 << index) == 0)
+            .map(|(_, family)| match family {
+              FontFamily::Named(name) => FontFamily::Named(Cow::Owned(name.to_string())),
+              FontFamily::Generic(generic) => FontFamily::Generic(*generic),
+            })
+            .collect(),
+        ))
+      })
+    };
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut current_mask = None;
+
+    for (index, character) in text.char_indices() {
+      let mask = excluded_mask(character);
+
+      if let Some(current) = current_mask
+        && current != mask
+      {
+        segments.push((&text[start..index], narrowed_stack(current)));
+        start = index;
+      }
+
+      current_mask = Some(mask);
+    }
+
+    segments.push((&text[start..], narrowed_stack(current_mask.unwrap_or(0))));
+
+    segments
+  }
+
   /// Loads font into internal font db with caching
   pub fn load_and_store(
     &mut self,
     source: Cow<'_, [u8]>,
     info_override: Option<FontInfoOverride<'_>>,
     generic_family: Option<GenericFamily>,
+  ) -> Result<(), FontError> {
+    self.load_and_store_with_unicode_range(source, info_override, generic_family, &[])
+  }
+
+  /// Loads font into internal font db with caching, only using it for code points in `unicode_range`.
+  ///
+  /// Like `unicode-range` in `@font-face`, text outside the ranges skips the font's family and falls
+  /// through to the next family in the `font-family` list. An empty list doesn't restrict the font.
+  /// The ranges apply to the whole family, so a family with any unrestricted face isn't restricted.
+  pub fn load_and_store_with_unicode_range(
+    &mut self,
+    source: Cow<'_, [u8]>,
+    info_override: Option<FontInfoOverride<'_>>,
+    generic_family: Option<GenericFamily>,
+    unicode_range: &[UnicodeRange],
   ) -> Result<(), FontError> {
     let cache_key = FontCacheKey {
       data_hash: xxh3_64(&source),
@@ -296,6 +594,7 @@ impl FontContext {
           .collect()
       }),
       generic_family,
+      unicode_range: unicode_range.into(),
     };
 
     if self.cache.contains(&cache_key) {
@@ -304,29 +603,395 @@ impl FontContext {
 
     let font_data = Blob::new(Arc::new(load_font(source, None)?));
 
-    let fonts = self
-      .inner
-      .collection
-      .register_fonts(font_data, info_override);
+    // Keep an owned copy of the override, so the font can be registered again when the collection is rebuilt.
+    let family_name: Option<Box<str>> = info_override
+      .and_then(|info| info.family_name)
+      .map(Into::into);
+    let axes: Option<Box<[_]>> = info_override.and_then(|info| info.axes).map(Box::from);
+    let has_override = info_override.is_some();
+    let (style, weight, width) = info_override
+      .map(|info| (info.style, info.weight, info.width))
+      .unwrap_or_default();
+
+    let data = font_data.clone();
+    let register: Arc<RegisterFont> = Arc::new(move |collection: &mut Collection| {
+      let info_override = has_override.then(|| FontInfoOverride {
+        family_name: family_name.as_deref(),
+        style,
+        weight,
+        width,
+        axes: axes.as_deref(),
+      });
+
+      register_fonts(collection, font_data.clone(), info_override, generic_family)
+    });
+
+    let families = register(&mut self.inner.collection);
+
+    self.registered.push(RegisteredFont {
+      cache_key: cache_key.clone(),
+      data,
+      families,
+      register,
+      unicode_range: unicode_range.into(),
+    });
+    self.cache.insert(cache_key);
+    self.prioritize_fallback_family();
+    self.update_unicode_ranges();
 
-    for (family, _) in fonts {
-      if let Some(generic_family) = generic_family {
-        self
-          .inner
-          .collection
-          .append_generic_families(generic_family, once(family));
+    Ok(())
+  }
+
+  /// Exports the loaded fonts, so other contexts can be initialized with them without decoding the sources again.
+  pub fn export_prepared(&self) -> PreparedFonts {
+    PreparedFonts {
+      fonts: self.registered.clone(),
+      fallback_family: self.fallback_family.clone(),
+    }
+  }
+
+  /// Loads the fonts of a snapshot made by [`Self::export_prepared`], skipping fonts that are already loaded.
+  ///
+  /// The fallback family of the snapshot is used if this context doesn't have one set.
+  pub fn import_prepared(&mut self, prepared: &PreparedFonts) {
+    for font in &prepared.fonts {
+      if self.cache.contains(&font.cache_key) {
+        continue;
       }
 
-      for (script, _) in Script::all_samples() {
-        self
-          .inner
-          .collection
-          .append_fallbacks(FallbackKey::new(*script, None), once(family));
+      let families = (font.register)(&mut self.inner.collection);
+
+      self.registered.push(RegisteredFont {
+        families,
+        ..font.clone()
+      });
+      self.cache.insert(font.cache_key.clone());
+    }
+
+    if self.fallback_family.is_none() {
+      self.fallback_family = prepared.fallback_family.clone();
+    }
+
+    self.prioritize_fallback_family();
+    self.update_unicode_ranges();
+  }
+
+  /// Lists the named instances of the variable fonts loaded under the given family name.
+  ///
+  /// Pass [`NamedInstance::to_font_variation_settings`] as `font-variation-settings` to render with one.
+  pub fn named_instances(&self, family_name: &str) -> Vec<NamedInstance> {
+    self
+      .registered
+      .iter()
+      .filter(|font| {
+        font
+          .families
+          .iter()
+          .any(|family| family.eq_ignore_ascii_case(family_name))
+      })
+      .filter_map(|font| FontDataRef::new(font.data.as_ref()))
+      .flat_map(|data| data.fonts())
+      .flat_map(|font_ref| {
+        let tags: Vec<Tag> = font_ref
+          .variations()
+          .map(|variation| variation.tag())
+          .collect();
+
+        font_ref.instances().filter_map(move |instance| {
+          Some(NamedInstance {
+            name: instance.name(None)?.to_string(),
+            axes: tags.iter().copied().zip(instance.values()).collect(),
+          })
+        })
+      })
+      .collect()
+  }
+
+  /// Lists the loaded font families in load order, with the weights and styles of their faces.
+  ///
+  /// Variable fonts report the weight of their default instance, see [`Self::named_instances`] for the others.
+  pub fn loaded_families(&mut self) -> Vec<LoadedFamily> {
+    let mut names: Vec<&str> = Vec::new();
+    for family in self.registered.iter().flat_map(|font| &font.families) {
+      if !names.iter().any(|name| name.eq_ignore_ascii_case(family)) {
+        names.push(family);
       }
     }
 
-    self.cache.insert(cache_key);
+    let collection = &mut self.inner.collection;
 
-    Ok(())
+    names
+      .into_iter()
+      .filter_map(|name| {
+        let family = collection.family_by_name(name)?;
+
+        let mut weights: Vec<f32> = Vec::new();
+        let mut styles: Vec<FontStyle> = Vec::new();
+
+        for font in family.fonts() {
+          let weight = font.weight().value();
+          if !weights.contains(&weight) {
+            weights.push(weight);
+          }
+
+          if !styles.contains(&font.style()) {
+            styles.push(font.style());
+          }
+        }
+
+        weights.sort_by(f32::total_cmp);
+
+        Some(LoadedFamily {
+          family: family.name().to_string(),
+          weights,
+          styles,
+        })
+      })
+      .collect()
+  }
+
+  /// Sets the family tried first when the requested `font-family` isn't loaded or lacks a glyph.
+  ///
+  /// Without one, fallback goes through the loaded fonts in load order. Pass `None` to restore that.
+  /// The family may be set before its font is loaded.
+  pub fn set_fallback_family(&mut self, family_name: Option<&str>) {
+    self.fallback_family = family_name.map(Into::into);
+    self.rebuild_collection();
+  }
+
+  /// Moves the fallback family to the front of every script's fallback list.
+  fn prioritize_fallback_family(&mut self) {
+    let Some(family_name) = self.fallback_family.as_deref() else {
+      return;
+    };
+
+    let collection = &mut self.inner.collection;
+
+    let Some(family_id) = collection.family_id(family_name) else {
+      return;
+    };
+
+    for (script, _) in Script::all_samples() {
+      let families: Vec<FamilyId> = once(family_id)
+        .chain(
+          collection
+            .fallback_families(FallbackKey::new(*script, None))
+            .filter(|id| *id != family_id),
+        )
+        .collect();
+
+      collection.set_fallbacks(FallbackKey::new(*script, None), families.into_iter());
+    }
+  }
+
+  /// Removes the fonts registered under the given family name, so later renders no longer match it.
+  ///
+  /// Other faces loaded from the same source (e.g. a font collection) are removed along with it.
+  /// Returns `true` if any font was removed.
+  pub fn remove_family(&mut self, family_name: &str) -> bool {
+    let registered_count = self.registered.len();
+
+    self.registered.retain(|font| {
+      !font
+        .families
+        .iter()
+        .any(|family| family.eq_ignore_ascii_case(family_name))
+    });
+
+    if self.registered.len() == registered_count {
+      return false;
+    }
+
+    self.rebuild_collection();
+
+    true
+  }
+
+  /// Removes all loaded fonts.
+  pub fn clear(&mut self) {
+    self.registered.clear();
+    self.rebuild_collection();
+  }
+
+  /// Parley's collection can't unregister fonts, so it is rebuilt from the fonts that are still loaded.
+  fn rebuild_collection(&mut self) {
+    self.inner = empty_parley_font_context();
+    self.cache = self
+      .registered
+      .iter()
+      .map(|font| font.cache_key.clone())
+      .collect();
+
+    for font in &self.registered {
+      (font.register)(&mut self.inner.collection);
+    }
+
+    self.prioritize_fallback_family();
+    self.update_unicode_ranges();
+  }
+
+  /// Collects the `unicode-range` of each family, leaving out families with an unrestricted face.
+  fn update_unicode_ranges(&mut self) {
+    let unrestricted: HashSet<String> = self
+      .registered
+      .iter()
+      .filter(|font| font.unicode_range.is_empty())
+      .flat_map(|font| font.families.iter().map(|family| family.to_lowercase()))
+      .collect();
+
+    let mut unicode_ranges: HashMap<Box<str>, Vec<UnicodeRange>> = HashMap::new();
+
+    for font in self
+      .registered
+      .iter()
+      .filter(|font| !font.unicode_range.is_empty())
+    {
+      for family in &font.families {
+        let family = family.to_lowercase();
+        if unrestricted.contains(&family) {
+          continue;
+        }
+
+        unicode_ranges
+          .entry(family.into())
+          .or_default()
+          .extend(font.unicode_range.iter().copied());
+      }
+    }
+
+    self.unicode_ranges = unicode_ranges;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn load_range_font(
+    font_context: &mut FontContext,
+    data: &[u8],
+    family_name: &str,
+    unicode_range: &str,
+  ) {
+    let Ok(unicode_range) = UnicodeRange::parse_list(unicode_range) else {
+      unreachable!()
+    };
+
+    let Ok(()) = font_context.load_and_store_with_unicode_range(
+      Cow::Borrowed(data),
+      Some(FontInfoOverride {
+        family_name: Some(family_name),
+        ..Default::default()
+      }),
+      None,
+      &unicode_range,
+    ) else {
+      unreachable!()
+    };
+  }
+
+  #[test]
+  fn test_import_prepared_shares_font_data() {
+    let mut source = FontContext::default();
+    source.set_fallback_family(Some("Archivo"));
+    let Ok(()) = source.load_and_store(
+      Cow::Borrowed(include_bytes!(
+        "../../../assets/fonts/archivo/Archivo-VariableFont_wdth,wght.ttf"
+      )),
+      None,
+      None,
+    ) else {
+      unreachable!()
+    };
+
+    let prepared = source.export_prepared();
+
+    let mut target = FontContext::default();
+    target.import_prepared(&prepared);
+    // Importing again doesn't register the fonts twice.
+    target.import_prepared(&prepared);
+
+    assert_eq!(target.registered.len(), 1);
+    assert_eq!(
+      target.registered[0].data.id(),
+      source.registered[0].data.id()
+    );
+    assert_eq!(target.fallback_family.as_deref(), Some("Archivo"));
+    assert_eq!(
+      target
+        .loaded_families()
+        .into_iter()
+        .map(|family| family.family)
+        .collect::<Vec<_>>(),
+      ["Archivo"]
+    );
+  }
+
+  #[test]
+  fn test_parse_unicode_range_list() {
+    assert_eq!(
+      UnicodeRange::parse_list("U+0000-00FF, U+4E??").ok(),
+      Some(vec![
+        UnicodeRange {
+          start: 0x0,
+          end: 0xFF
+        },
+        UnicodeRange {
+          start: 0x4E00,
+          end: 0x4EFF
+        },
+      ])
+    );
+    assert!(UnicodeRange::parse_list("latin").is_err());
+  }
+
+  #[cfg(feature = "woff2")]
+  #[test]
+  fn test_unicode_range_selects_font_per_code_point() {
+    let mut font_context = FontContext::default();
+
+    // Noto Sans TC also covers Latin, so without its range it would render the whole text.
+    load_range_font(
+      &mut font_context,
+      include_bytes!("../../../assets/fonts/noto-sans/NotoSansTC-VariableFont_wght.woff2"),
+      "Range CJK",
+      "U+4E00-9FFF",
+    );
+    load_range_font(
+      &mut font_context,
+      include_bytes!("../../../assets/fonts/archivo/Archivo-VariableFont_wdth,wght.ttf"),
+      "Range Latin",
+      "U+0000-00FF",
+    );
+
+    let cjk_id = font_context.registered[0].data.id();
+    let latin_id = font_context.registered[1].data.id();
+
+    let style = TextStyle {
+      font_stack: FontStack::Source(Cow::Borrowed("'Range CJK', 'Range Latin'")),
+      ..TextStyle::default()
+    };
+
+    let (mut layout, text) = font_context.tree_builder(style.clone(), |builder| {
+      font_context.push_text_span(builder, "Hello 你好", style.clone());
+    });
+    layout.break_all_lines(None);
+
+    let mut run_count = 0;
+    for line in layout.lines() {
+      for run in line.runs() {
+        let run_text = &text[run.text_range()];
+        let expected_id = if run_text.is_ascii() {
+          latin_id
+        } else {
+          cjk_id
+        };
+
+        assert_eq!(run.font().data.id(), expected_id, "{run_text}");
+        run_count += 1;
+      }
+    }
+
+    assert!(run_count >= 2);
   }
 }
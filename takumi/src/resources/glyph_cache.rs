@@ -0,0 +1,251 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  hash::Hash,
+  sync::{Arc, Mutex},
+};
+
+use parley::GlyphRun;
+use xxhash_rust::xxh3::{Xxh3, Xxh3DefaultBuilder};
+use zeno::Placement;
+
+use crate::layout::inline::InlineBrush;
+
+/// The number of subpixel positions a glyph is rasterized at along each axis.
+pub(crate) const GLYPH_SUBPIXEL_STEPS: f32 = 4.0;
+
+/// The number of glyph masks kept by [`GlyphCache::default`].
+const DEFAULT_GLYPH_CACHE_CAPACITY: usize = 4096;
+
+/// Identifies a rasterized glyph mask.
+///
+/// The color isn't part of the key, since masks only hold coverage and are tinted when composited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct GlyphCacheKey {
+  /// The font, size, variation coordinates and synthesis of the glyph run, see [`glyph_run_cache_key`].
+  pub run: u64,
+  /// The glyph id in the font.
+  pub glyph_id: u32,
+  /// The horizontal subpixel offset, in steps of `1 / GLYPH_SUBPIXEL_STEPS` pixels.
+  pub subpixel_x: u8,
+  /// The vertical subpixel offset, in steps of `1 / GLYPH_SUBPIXEL_STEPS` pixels.
+  pub subpixel_y: u8,
+}
+
+/// A rasterized glyph coverage mask, placed relative to the pixel the glyph origin falls in.
+pub(crate) struct GlyphMask {
+  pub data: Vec<u8>,
+  pub placement: Placement,
+}
+
+#[derive(Default)]
+struct GlyphCacheState {
+  entries: HashMap<GlyphCacheKey, (Arc<GlyphMask>, u64), Xxh3DefaultBuilder>,
+  /// The keys ordered from least to most recently used.
+  recency: BTreeMap<u64, GlyphCacheKey>,
+  tick: u64,
+}
+
+/// A bounded least-recently-used cache of rasterized glyph masks, shared between renders.
+///
+/// Rendering the same text again, like the static parts of animation frames, reuses the masks
+/// instead of rasterizing the glyph outlines each time. Glyphs are rasterized at a quantized
+/// subpixel offset, so the output doesn't depend on whether a mask was cached.
+pub struct GlyphCache {
+  capacity: usize,
+  state: Mutex<GlyphCacheState>,
+}
+
+impl Default for GlyphCache {
+  fn default() -> Self {
+    Self::new(DEFAULT_GLYPH_CACHE_CAPACITY)
+  }
+}
+
+impl GlyphCache {
+  /// Creates a cache holding at most `capacity` glyph masks. A capacity of 0 disables caching.
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      state: Mutex::default(),
+    }
+  }
+
+  /// The maximum number of glyph masks kept.
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// The number of glyph masks currently cached.
+  pub fn len(&self) -> usize {
+    self.state.lock().map_or(0, |state| state.entries.len())
+  }
+
+  /// Whether no glyph masks are cached.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Removes every cached glyph mask.
+  pub fn clear(&self) {
+    if let Ok(mut state) = self.state.lock() {
+      *state = GlyphCacheState::default();
+    }
+  }
+
+  /// Returns the cached mask for `key`, rasterizing and caching it with `rasterize` on a miss.
+  pub(crate) fn get_or_insert_with(
+    &self,
+    key: GlyphCacheKey,
+    rasterize: impl FnOnce() -> GlyphMask,
+  ) -> Arc<GlyphMask> {
+    if self.capacity == 0 {
+      return Arc::new(rasterize());
+    }
+
+    if let Some(mask) = self.touch(key) {
+      return mask;
+    }
+
+    // The lock isn't held while rasterizing, so other renders aren't blocked by it.
+    let mask = Arc::new(rasterize());
+
+    if let Ok(mut state) = self.state.lock() {
+      state.tick += 1;
+      let tick = state.tick;
+
+      if let Some((_, previous_tick)) = state.entries.insert(key, (mask.clone(), tick)) {
+        state.recency.remove(&previous_tick);
+      }
+      state.recency.insert(tick, key);
+
+      while state.entries.len() > self.capacity {
+        let Some((_, evicted)) = state.recency.pop_first() else {
+          break;
+        };
+
+        state.entries.remove(&evicted);
+      }
+    }
+
+    mask
+  }
+
+  /// Looks up a mask and marks it as the most recently used.
+  fn touch(&self, key: GlyphCacheKey) -> Option<Arc<GlyphMask>> {
+    let mut state = self.state.lock().ok()?;
+
+    state.tick += 1;
+    let tick = state.tick;
+
+    let (mask, previous_tick) = state.entries.get_mut(&key)?;
+    let mask = mask.clone();
+    let previous_tick = std::mem::replace(previous_tick, tick);
+
+    state.recency.remove(&previous_tick);
+    state.recency.insert(tick, key);
+
+    Some(mask)
+  }
+}
+
+/// Hashes everything besides the glyph id that changes the outline of the glyphs in a run.
+pub(crate) fn glyph_run_cache_key(glyph_run: &GlyphRun<'_, InlineBrush>) -> u64 {
+  let run = glyph_run.run();
+  let font = run.font();
+  let synthesis = run.synthesis();
+  let brush = &glyph_run.style().brush;
+
+  let mut hasher = Xxh3::new();
+
+  font.data.id().hash(&mut hasher);
+  font.index.hash(&mut hasher);
+  run.font_size().to_bits().hash(&mut hasher);
+  run.normalized_coords().hash(&mut hasher);
+  synthesis.embolden().hash(&mut hasher);
+  synthesis.skew().map(f32::to_bits).hash(&mut hasher);
+  brush.font_synthesis.weight.is_allowed().hash(&mut hasher);
+  brush.font_synthesis.style.is_allowed().hash(&mut hasher);
+  run
+    .visual_clusters()
+    .any(|cluster| cluster.is_emoji())
+    .hash(&mut hasher);
+
+  hasher.digest()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn key(glyph_id: u32) -> GlyphCacheKey {
+    GlyphCacheKey {
+      run: 0,
+      glyph_id,
+      subpixel_x: 0,
+      subpixel_y: 0,
+    }
+  }
+
+  fn mask(width: u32) -> GlyphMask {
+    GlyphMask {
+      data: vec![255; width as usize],
+      placement: Placement {
+        left: 0,
+        top: 0,
+        width,
+        height: 1,
+      },
+    }
+  }
+
+  #[test]
+  fn test_glyph_cache_reuses_masks() {
+    let cache = GlyphCache::new(2);
+    let mut rasterized = 0;
+
+    for _ in 0..3 {
+      cache.get_or_insert_with(key(1), || {
+        rasterized += 1;
+        mask(1)
+      });
+    }
+
+    assert_eq!(rasterized, 1);
+    assert_eq!(cache.len(), 1);
+  }
+
+  #[test]
+  fn test_glyph_cache_evicts_least_recently_used() {
+    let cache = GlyphCache::new(2);
+
+    cache.get_or_insert_with(key(1), || mask(1));
+    cache.get_or_insert_with(key(2), || mask(2));
+    // Touching the first glyph makes the second one the least recently used.
+    cache.get_or_insert_with(key(1), || mask(1));
+    cache.get_or_insert_with(key(3), || mask(3));
+
+    assert_eq!(cache.len(), 2);
+
+    let mut rasterized = false;
+    cache.get_or_insert_with(key(1), || {
+      rasterized = true;
+      mask(1)
+    });
+    assert!(!rasterized);
+
+    cache.get_or_insert_with(key(2), || {
+      rasterized = true;
+      mask(2)
+    });
+    assert!(rasterized);
+  }
+
+  #[test]
+  fn test_glyph_cache_with_zero_capacity_stores_nothing() {
+    let cache = GlyphCache::new(0);
+
+    cache.get_or_insert_with(key(1), || mask(1));
+
+    assert!(cache.is_empty());
+  }
+}
@@ -3,10 +3,10 @@
 //! This module provides types and utilities for managing image resources,
 //! including loading states, error handling, and image processing operations.
 
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, io::Cursor, sync::Arc};
 
 use dashmap::DashMap;
-use image::RgbaImage;
+use image::{DynamicImage, ImageDecoder, ImageReader, RgbaImage, metadata::Orientation};
 
 use crate::{
   layout::style::ImageScalingAlgorithm,
@@ -30,6 +30,26 @@ pub enum ImageSource {
 /// Represents a persistent image store.
 pub type PersistentImageStore = DashMap<String, Arc<ImageSource>>;
 
+/// Resolves images on demand during rendering, for sources that are neither in the fetched
+/// resources nor in the persistent store.
+///
+/// Rendering is synchronous, so this suits native callers with blocking IO such as the filesystem
+/// or a blocking HTTP client. WASM builds can't block on network requests and should keep
+/// collecting fetch tasks up front and passing the results as fetched resources.
+pub trait ImageResolver: Send + Sync {
+  /// Resolves `src` into an image, returning [`ImageResourceError::Unknown`] for sources it doesn't handle.
+  fn resolve(&self, src: &str) -> ImageResult;
+}
+
+impl<F> ImageResolver for F
+where
+  F: Fn(&str) -> ImageResult + Send + Sync,
+{
+  fn resolve(&self, src: &str) -> ImageResult {
+    self(src)
+  }
+}
+
 impl From<RgbaImage> for ImageSource {
   fn from(bitmap: RgbaImage) -> Self {
     ImageSource::Bitmap(bitmap)
@@ -90,7 +110,8 @@ impl ImageSource {
 ///
 /// - When the `svg` feature is enabled and the bytes look like SVG XML, they
 ///   are parsed as an SVG using `resvg::usvg`.
-/// - Otherwise, the bytes are decoded as a raster image using the `image` crate.
+/// - Otherwise, the bytes are decoded as a raster image using the `image` crate, with its EXIF
+///   orientation applied so the image is upright.
 pub fn load_image_source_from_bytes(bytes: &[u8]) -> ImageResult {
   #[cfg(feature = "svg")]
   {
@@ -103,7 +124,18 @@ pub fn load_image_source_from_bytes(bytes: &[u8]) -> ImageResult {
     }
   }
 
-  let img = image::load_from_memory(bytes).map_err(ImageResourceError::DecodeError)?;
+  let mut decoder = ImageReader::new(Cursor::new(bytes))
+    .with_guessed_format()
+    .map_err(|error| ImageResourceError::DecodeError(error.into()))?
+    .into_decoder()?;
+
+  // Cameras store photos as captured and record how to turn them upright in the EXIF orientation.
+  // A malformed EXIF block shouldn't make the pixels unusable, so it is drawn as stored.
+  let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+
+  let mut img = DynamicImage::from_decoder(decoder)?;
+  img.apply_orientation(orientation);
+
   Ok(Arc::new(img.into_rgba8().into()))
 }
 
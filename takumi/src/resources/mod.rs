@@ -1,5 +1,7 @@
 /// Font loading and processing functionality
 pub mod font;
+/// Rasterized glyph mask caching
+pub mod glyph_cache;
 /// Image state and resource management
 pub mod image;
 /// Resource resolving tasks
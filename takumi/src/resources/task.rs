@@ -7,13 +7,30 @@ pub type FetchTask = Arc<str>;
 
 /// Collection of unique fetch tasks.
 #[derive(Default)]
-pub struct FetchTaskCollection(SmallVec<[FetchTask; 8]>);
+pub struct FetchTaskCollection {
+  tasks: SmallVec<[FetchTask; 8]>,
+  device_pixel_ratio: Option<f32>,
+}
 
 impl FetchTaskCollection {
+  /// Creates a collection for rendering at the device pixel ratio, so only the sources
+  /// picked for it are fetched instead of every pixel density.
+  pub fn with_device_pixel_ratio(device_pixel_ratio: f32) -> Self {
+    Self {
+      tasks: SmallVec::new(),
+      device_pixel_ratio: Some(device_pixel_ratio),
+    }
+  }
+
+  /// The device pixel ratio the resources are collected for, if known.
+  pub fn device_pixel_ratio(&self) -> Option<f32> {
+    self.device_pixel_ratio
+  }
+
   /// Insert a new task.
   pub fn insert(&mut self, task: FetchTask) {
-    if !self.0.contains(&task) {
-      self.0.push(task);
+    if !self.tasks.contains(&task) {
+      self.tasks.push(task);
     }
   }
 
@@ -28,6 +45,6 @@ impl FetchTaskCollection {
 
   /// Consumes the collection and returns the underlying `SmallVec`.
   pub fn into_inner(self) -> SmallVec<[FetchTask; 8]> {
-    self.0
+    self.tasks
   }
 }
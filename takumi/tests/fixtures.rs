@@ -2,12 +2,32 @@ mod test_utils;
 
 #[path = "fixtures/animation.rs"]
 pub mod animation;
+#[path = "fixtures/background_option.rs"]
+pub mod background_option;
 #[path = "fixtures/color_artifacts.rs"]
 pub mod color_artifacts;
+#[path = "fixtures/custom_filter.rs"]
+pub mod custom_filter;
+#[path = "fixtures/debug_highlight.rs"]
+pub mod debug_highlight;
+#[path = "fixtures/device_pixel_ratio.rs"]
+pub mod device_pixel_ratio;
+#[path = "fixtures/draw_hook.rs"]
+pub mod draw_hook;
+#[path = "fixtures/grid_auto_flow.rs"]
+pub mod grid_auto_flow;
+#[path = "fixtures/image_decode.rs"]
+pub mod image_decode;
 #[path = "fixtures/inline.rs"]
 pub mod inline;
 #[path = "fixtures/inline_vertical_align.rs"]
 pub mod inline_vertical_align;
+#[path = "fixtures/render_limits.rs"]
+pub mod render_limits;
+#[path = "fixtures/render_stats.rs"]
+pub mod render_stats;
+#[path = "fixtures/safe_area.rs"]
+pub mod safe_area;
 #[path = "fixtures/style_alignment.rs"]
 pub mod style_alignment;
 #[path = "fixtures/style_backdrop_filter.rs"]
@@ -22,6 +42,8 @@ pub mod style_clip_path;
 pub mod style_filter;
 #[path = "fixtures/style_layout.rs"]
 pub mod style_layout;
+#[path = "fixtures/style_list_style_type.rs"]
+pub mod style_list_style_type;
 #[path = "fixtures/style_mask_image.rs"]
 pub mod style_mask_image;
 #[path = "fixtures/style_mix_blend_mode.rs"]
@@ -52,5 +74,11 @@ pub mod style_transform;
 pub mod style_visuals;
 #[path = "fixtures/svg.rs"]
 pub mod svg;
+#[path = "fixtures/svg_export.rs"]
+pub mod svg_export;
 #[path = "fixtures/text.rs"]
 pub mod text;
+#[path = "fixtures/text_shadow.rs"]
+pub mod text_shadow;
+#[path = "fixtures/write.rs"]
+pub mod write;
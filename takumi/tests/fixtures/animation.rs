@@ -1,10 +1,14 @@
 use std::f32::consts::PI;
-use takumi::layout::{
-  node::{ContainerNode, NodeKind, TextNode},
-  style::{Length::*, *},
+
+use takumi::{
+  layout::{
+    node::{ContainerNode, NodeKind, TextNode},
+    style::{Length::*, *},
+  },
+  rendering::{AnimationBuilder, Easing, Keyframe, render_animation},
 };
 
-use crate::test_utils::{run_png_animation_test, run_webp_animation_test};
+use crate::test_utils::{CONTEXT, run_png_animation_test, run_webp_animation_test};
 
 fn create_bouncing_text_nodes() -> Vec<(NodeKind, u32)> {
   const FPS: u32 = 30;
@@ -23,6 +27,7 @@ fn create_bouncing_text_nodes() -> Vec<(NodeKind, u32)> {
 
       let node = ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -38,6 +43,7 @@ fn create_bouncing_text_nodes() -> Vec<(NodeKind, u32)> {
         children: Some(
           [ContainerNode {
             preset: None,
+            id: None,
             tw: None,
             style: Some(
               StyleBuilder::default()
@@ -48,7 +54,10 @@ fn create_bouncing_text_nodes() -> Vec<(NodeKind, u32)> {
             children: Some(
               [TextNode {
                 preset: None,
+                id: None,
                 tw: None,
+                font_size_fit: None,
+                first_letter_style: None,
                 style: Some(
                   StyleBuilder::default()
                     .font_size(Some(Px(56.0)))
@@ -80,8 +89,6 @@ fn animation_bouncing_text_webp() {
   run_webp_animation_test(
     create_bouncing_text_nodes(),
     "animation_bouncing_text.webp",
-    true,
-    false,
     None,
   );
 }
@@ -94,3 +101,62 @@ fn animation_bouncing_text_png() {
     None,
   );
 }
+
+fn opacity_keyframe(offset: f32, opacity: f32) -> Keyframe {
+  Keyframe::new(
+    offset,
+    Style {
+      opacity: CssValue::Value(PercentageNumber(opacity)),
+      ..Style::default()
+    },
+  )
+}
+
+#[test]
+fn test_render_animation_tweens_opacity() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .background_color(ColorInput::Value(Color([255, 0, 0, 255])))
+        .build()
+        .unwrap(),
+    ),
+    children: None,
+  }
+  .into();
+
+  let frames = render_animation(
+    AnimationBuilder::default()
+      .viewport((40, 40).into())
+      .global(&CONTEXT)
+      .node(node)
+      .keyframes(vec![opacity_keyframe(0.0, 0.0), opacity_keyframe(1.0, 1.0)])
+      .easing(Easing::Linear)
+      .frame_count(10)
+      .duration_ms(1000)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  assert_eq!(frames.len(), 10);
+  assert!(frames.iter().all(|frame| frame.duration_ms == 100));
+
+  let alpha_at = |frame: usize| frames[frame].image.get_pixel(20, 20).0[3];
+
+  assert_eq!(alpha_at(0), 0);
+  assert_eq!(alpha_at(9), 255);
+
+  // Frame 5 of 0..=9 is 5/9 of the way through the tween.
+  let expected = 255.0 * 5.0 / 9.0;
+  assert!(
+    (alpha_at(5) as f32 - expected).abs() <= 2.0,
+    "mid-frame alpha is {}, expected about {expected}",
+    alpha_at(5)
+  );
+}
@@ -0,0 +1,64 @@
+use image::{Rgba, RgbaImage};
+use serde_json::json;
+use takumi::Error;
+
+use crate::test_utils::render_json_with_options;
+
+const GREEN: Rgba<u8> = Rgba([0, 255, 0, 255]);
+
+fn red_square() -> serde_json::Value {
+  json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+    },
+    "children": [
+      {
+        "type": "container",
+        "style": {
+          "width": "20px",
+          "height": "20px",
+          "backgroundColor": "red",
+        },
+      },
+    ],
+  })
+}
+
+#[test]
+fn test_render_draws_node_tree_onto_supplied_background() {
+  let background = RgbaImage::from_pixel(60, 40, GREEN);
+
+  let image = render_json_with_options(red_square(), (60, 40), |options| {
+    options.background(Some(&background));
+  })
+  .unwrap();
+
+  assert_eq!(image.dimensions(), (60, 40));
+  assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+  assert_eq!(*image.get_pixel(19, 19), Rgba([255, 0, 0, 255]));
+
+  // Pixels the node tree doesn't cover keep the background.
+  assert_eq!(*image.get_pixel(20, 0), GREEN);
+  assert_eq!(*image.get_pixel(59, 39), GREEN);
+}
+
+#[test]
+fn test_render_rejects_background_of_different_size() {
+  let background = RgbaImage::from_pixel(50, 40, GREEN);
+
+  let result = render_json_with_options(red_square(), (60, 40), |options| {
+    options.background(Some(&background));
+  });
+
+  assert!(matches!(
+    result,
+    Err(Error::BackgroundSizeMismatch {
+      expected_width: 60,
+      expected_height: 40,
+      width: 50,
+      height: 40,
+    })
+  ));
+}
@@ -11,6 +11,7 @@ use crate::test_utils::run_fixture_test;
 fn test_color_artifacts() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -25,7 +26,9 @@ fn test_color_artifacts() {
     ),
     children: Some(
       [ImageNode {
+        sources: None,
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
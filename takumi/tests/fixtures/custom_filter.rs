@@ -0,0 +1,54 @@
+use image::RgbaImage;
+use serde_json::json;
+use takumi::GlobalContext;
+
+use crate::test_utils::render_json_with_options;
+
+fn grayscale(image: &mut RgbaImage) {
+  for pixel in image.pixels_mut() {
+    let [r, g, b, _] = pixel.0;
+    let luma = (r as f32 * 0.2126 + g as f32 * 0.7152 + b as f32 * 0.0722) as u8;
+    pixel.0[..3].fill(luma);
+  }
+}
+
+fn render_with_filter(global: &GlobalContext, filter: &str) -> RgbaImage {
+  render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "40px",
+        "height": "40px",
+        "backgroundColor": "rgb(200, 40, 40)",
+        "filter": filter,
+      }
+    }),
+    (40, 40),
+    |options| {
+      options.global(global);
+    },
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_registered_filter_desaturates_node() {
+  let mut global = GlobalContext::default();
+  global.register_filter("grayscale", grayscale);
+
+  let image = render_with_filter(&global, "url(#grayscale)");
+  let [r, g, b, a] = image.get_pixel(20, 20).0;
+
+  assert_eq!(a, 255);
+  assert_eq!((r, g), (g, b));
+  assert_eq!(r, 74);
+}
+
+#[test]
+fn test_unregistered_filter_is_skipped() {
+  let global = GlobalContext::default();
+
+  let image = render_with_filter(&global, "url(#missing) brightness(50%)");
+
+  assert_eq!(image.get_pixel(20, 20).0, [100, 20, 20, 255]);
+}
@@ -0,0 +1,91 @@
+use image::Rgba;
+use serde_json::json;
+
+use crate::test_utils::render_json_with_options;
+
+const HIGHLIGHT: Rgba<u8> = Rgba([255, 0, 255, 255]);
+
+#[test]
+fn test_debug_highlight_outlines_only_targeted_node() {
+  let image = render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100%",
+        "height": "100%",
+        "backgroundColor": "white",
+        "padding": "10px",
+        "columnGap": "10px",
+      },
+      "children": [
+        {
+          "type": "container",
+          "id": "card",
+          "style": { "width": "30px", "height": "30px" },
+        },
+        {
+          "type": "container",
+          "data-testid": "overflowing",
+          "style": { "width": "30px", "height": "30px" },
+        },
+      ],
+    }),
+    (100, 50),
+    |options| {
+      options.debug_highlight_ids(vec!["overflowing".to_string()]);
+    },
+  )
+  .unwrap();
+
+  // The second child spans x 50..80, y 10..40.
+  assert_eq!(*image.get_pixel(50, 25), HIGHLIGHT);
+  assert_eq!(*image.get_pixel(65, 10), HIGHLIGHT);
+  assert_eq!(*image.get_pixel(65, 25), Rgba([255, 255, 255, 255]));
+
+  // The first child, at x 10..40, isn't listed.
+  assert_eq!(*image.get_pixel(10, 25), Rgba([255, 255, 255, 255]));
+  assert_eq!(*image.get_pixel(25, 10), Rgba([255, 255, 255, 255]));
+}
+
+#[test]
+fn test_debug_highlight_draws_over_children_and_clip() {
+  let image = render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100%",
+        "height": "100%",
+        "backgroundColor": "white",
+        "padding": "10px",
+      },
+      "children": [
+        {
+          "type": "container",
+          "id": "clipped",
+          "style": {
+            "width": "30px",
+            "height": "30px",
+            "overflow": "hidden",
+            "opacity": 0.5,
+          },
+          "children": [
+            {
+              "type": "container",
+              "style": { "width": "100%", "height": "100%", "backgroundColor": "blue" },
+            },
+          ],
+        },
+      ],
+    }),
+    (100, 50),
+    |options| {
+      options.debug_highlight_ids(vec!["clipped".to_string()]);
+    },
+  )
+  .unwrap();
+
+  // The outline sits on top of the child, at full opacity.
+  assert_eq!(*image.get_pixel(10, 25), HIGHLIGHT);
+  assert_eq!(*image.get_pixel(25, 10), HIGHLIGHT);
+  assert_ne!(*image.get_pixel(25, 25), HIGHLIGHT);
+}
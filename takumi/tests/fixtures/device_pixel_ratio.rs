@@ -0,0 +1,179 @@
+use std::{collections::HashMap, sync::Arc};
+
+use image::{Rgba, RgbaImage};
+use serde_json::{from_value, json};
+use takumi::{
+  layout::{Viewport, node::NodeKind},
+  rendering::{RenderOptionsBuilder, render},
+  resources::image::ImageSource,
+};
+
+use crate::test_utils::CONTEXT;
+
+fn render_at(device_pixel_ratio: f32) -> RgbaImage {
+  // The font size is left unset on purpose, so the root font size is scaled by the device pixel ratio as well.
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "padding": "10px",
+      "backgroundColor": "white",
+    },
+    "children": [
+      {
+        "type": "text",
+        "text": "HI",
+        "style": {
+          "fontWeight": 700,
+          "letterSpacing": "12px",
+          "WebkitTextStroke": "2px red",
+          "textShadow": "6px 6px 0 blue",
+        }
+      }
+    ]
+  }))
+  .unwrap();
+
+  let viewport = Viewport {
+    device_pixel_ratio,
+    ..Viewport::new(
+      Some((120.0 * device_pixel_ratio) as u32),
+      Some((60.0 * device_pixel_ratio) as u32),
+    )
+  };
+
+  render(
+    RenderOptionsBuilder::default()
+      .viewport(viewport)
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+}
+
+/// Returns the pixel count and the bounding box of pixels matching the predicate.
+fn coverage(image: &RgbaImage, predicate: impl Fn(&Rgba<u8>) -> bool) -> (u32, [u32; 4]) {
+  let mut count = 0;
+  let mut bounds = [u32::MAX, u32::MAX, 0, 0];
+
+  for (x, y, pixel) in image.enumerate_pixels() {
+    if predicate(pixel) {
+      count += 1;
+      bounds = [
+        bounds[0].min(x),
+        bounds[1].min(y),
+        bounds[2].max(x),
+        bounds[3].max(y),
+      ];
+    }
+  }
+
+  (count, bounds)
+}
+
+fn is_red(pixel: &Rgba<u8>) -> bool {
+  pixel[0] > 200 && pixel[1] < 80 && pixel[2] < 80
+}
+
+fn is_blue(pixel: &Rgba<u8>) -> bool {
+  pixel[2] > 200 && pixel[0] < 80 && pixel[1] < 80
+}
+
+fn assert_scaled_twice(name: &str, low: (u32, [u32; 4]), high: (u32, [u32; 4])) {
+  assert!(low.0 > 0, "{name} is not drawn at 1x");
+
+  // Doubling every length quadruples the covered area, anti-aliasing aside.
+  let area_ratio = high.0 as f32 / low.0 as f32;
+  assert!(
+    (3.0..=5.0).contains(&area_ratio),
+    "{name} area ratio is {area_ratio}"
+  );
+
+  for (low_edge, high_edge) in low.1.into_iter().zip(high.1) {
+    let difference = (high_edge as f32 - low_edge as f32 * 2.0).abs();
+    assert!(
+      difference <= 3.0,
+      "{name} bounds {:?} at 2x are not twice {:?} at 1x",
+      high.1,
+      low.1
+    );
+  }
+}
+
+#[test]
+fn test_device_pixel_ratio_scales_text_stroke_and_shadow() {
+  let low = render_at(1.0);
+  let high = render_at(2.0);
+
+  assert_eq!(high.dimensions(), (low.width() * 2, low.height() * 2));
+
+  assert_scaled_twice(
+    "text stroke",
+    coverage(&low, is_red),
+    coverage(&high, is_red),
+  );
+  assert_scaled_twice(
+    "text shadow",
+    coverage(&low, is_blue),
+    coverage(&high, is_blue),
+  );
+}
+
+fn render_image_set_at(device_pixel_ratio: f32) -> RgbaImage {
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "backgroundImage": "image-set(url(red.png) 1x, url(blue.png) 2x)",
+      "backgroundRepeat": "no-repeat",
+    }
+  }))
+  .unwrap();
+
+  let solid = |size: u32, color: [u8; 4]| {
+    Arc::new(ImageSource::Bitmap(RgbaImage::from_pixel(
+      size,
+      size,
+      Rgba(color),
+    )))
+  };
+
+  let viewport = Viewport {
+    device_pixel_ratio,
+    ..Viewport::new(
+      Some((10.0 * device_pixel_ratio) as u32),
+      Some((10.0 * device_pixel_ratio) as u32),
+    )
+  };
+
+  render(
+    RenderOptionsBuilder::default()
+      .viewport(viewport)
+      .node(node)
+      .global(&CONTEXT)
+      .fetched_resources(HashMap::from([
+        (Arc::from("red.png"), solid(10, [255, 0, 0, 255])),
+        (Arc::from("blue.png"), solid(20, [0, 0, 255, 255])),
+      ]))
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_image_set_background_selects_asset_by_density() {
+  let standard = render_image_set_at(1.0);
+  let retina = render_image_set_at(2.0);
+
+  assert_eq!(coverage(&standard, is_red).0, 10 * 10);
+  assert_eq!(coverage(&standard, is_blue).0, 0);
+
+  // The 2x asset covers the box at its natural size, instead of the 1x asset being upscaled.
+  assert_eq!(coverage(&retina, is_blue).0, 20 * 20);
+  assert_eq!(coverage(&retina, is_red).0, 0);
+}
@@ -0,0 +1,76 @@
+use image::{Rgba, RgbaImage};
+use serde_json::json;
+use takumi::layout::style::Color;
+
+use crate::test_utils::render_json_with_options;
+
+#[test]
+fn test_on_draw_fills_corner_after_node_tree() {
+  let image = render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100%",
+        "height": "100%",
+        "backgroundColor": "white",
+      }
+    }),
+    (40, 30),
+    |options| {
+      options.on_draw(|canvas| {
+        let (width, height) = canvas.dimensions();
+
+        canvas.fill_color(
+          width as i32 - 10,
+          height as i32 - 10,
+          20,
+          20,
+          Color([255, 0, 0, 255]),
+        );
+      });
+    },
+  )
+  .unwrap();
+
+  assert_eq!(*image.get_pixel(39, 29), Rgba([255, 0, 0, 255]));
+  assert_eq!(*image.get_pixel(30, 20), Rgba([255, 0, 0, 255]));
+  assert_eq!(*image.get_pixel(29, 19), Rgba([255, 255, 255, 255]));
+  assert_eq!(*image.get_pixel(0, 0), Rgba([255, 255, 255, 255]));
+}
+
+#[test]
+fn test_on_draw_skips_areas_far_outside_canvas() {
+  let stamp = RgbaImage::from_pixel(4, 4, Rgba([0, 0, 255, 255]));
+
+  let image = render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100%",
+        "height": "100%",
+        "backgroundColor": "white",
+      }
+    }),
+    (40, 30),
+    |options| {
+      options.on_draw(move |canvas| {
+        canvas.fill_color(i32::MAX, 0, u32::MAX, 10, Color([255, 0, 0, 255]));
+        canvas.fill_color(
+          i32::MIN,
+          i32::MIN,
+          u32::MAX,
+          u32::MAX,
+          Color([0, 255, 0, 255]),
+        );
+        canvas.draw_image(&stamp, i32::MAX, i32::MAX);
+        canvas.draw_image(&stamp, -2, -2);
+      });
+    },
+  )
+  .unwrap();
+
+  // The second fill covers the whole canvas, and only a corner of the stamp is visible.
+  assert_eq!(*image.get_pixel(1, 1), Rgba([0, 0, 255, 255]));
+  assert_eq!(*image.get_pixel(2, 2), Rgba([0, 255, 0, 255]));
+  assert_eq!(*image.get_pixel(39, 29), Rgba([0, 255, 0, 255]));
+}
@@ -0,0 +1,79 @@
+use serde_json::{from_value, json};
+use takumi::{
+  layout::{Viewport, node::NodeKind},
+  rendering::{RenderOptionsBuilder, render_debug_layout},
+};
+
+use crate::test_utils::CONTEXT;
+
+/// Places two items spanning two tracks followed by two single-cell items in a 3×3 grid of
+/// 100px cells, returning the position of each item.
+fn place_items(grid_auto_flow: &str, span_property: &str) -> Vec<(f32, f32)> {
+  let item = |span: bool| {
+    if span {
+      json!({ "type": "container", "style": { (span_property): "span 2" } })
+    } else {
+      json!({ "type": "container" })
+    }
+  };
+
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "display": "grid",
+      "gridTemplateColumns": "repeat(3, 100px)",
+      "gridTemplateRows": "repeat(3, 100px)",
+      "gridAutoFlow": grid_auto_flow,
+    },
+    "children": [item(true), item(true), item(false), item(false)],
+  }))
+  .unwrap();
+
+  let layout = render_debug_layout(
+    RenderOptionsBuilder::default()
+      .viewport(Viewport::new(Some(300), Some(300)))
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  layout
+    .children
+    .iter()
+    .map(|child| (child.x, child.y))
+    .collect()
+}
+
+#[test]
+fn test_grid_auto_flow_row_leaves_holes() {
+  assert_eq!(
+    place_items("row", "gridColumn"),
+    [(0.0, 0.0), (0.0, 100.0), (200.0, 100.0), (0.0, 200.0)]
+  );
+}
+
+#[test]
+fn test_grid_auto_flow_row_dense_backfills_holes() {
+  assert_eq!(
+    place_items("row dense", "gridColumn"),
+    [(0.0, 0.0), (0.0, 100.0), (200.0, 0.0), (200.0, 100.0)]
+  );
+}
+
+#[test]
+fn test_grid_auto_flow_column_leaves_holes() {
+  assert_eq!(
+    place_items("column", "gridRow"),
+    [(0.0, 0.0), (100.0, 0.0), (100.0, 200.0), (200.0, 0.0)]
+  );
+}
+
+#[test]
+fn test_grid_auto_flow_column_dense_backfills_holes() {
+  assert_eq!(
+    place_items("column dense", "gridRow"),
+    [(0.0, 0.0), (100.0, 0.0), (0.0, 200.0), (100.0, 200.0)]
+  );
+}
@@ -0,0 +1,97 @@
+use image::{Rgb, RgbImage, RgbaImage, codecs::jpeg::JpegEncoder};
+use takumi::{
+  layout::style::ImageScalingAlgorithm, resources::image::load_image_source_from_bytes,
+};
+
+/// A non-square gradient, so both the axes and the dimensions of a wrong rotation show up.
+fn encode_jpeg() -> Vec<u8> {
+  let image = RgbImage::from_fn(48, 32, |x, y| Rgb([(x * 5) as u8, (y * 7) as u8, 128]));
+
+  let mut bytes = Vec::new();
+  JpegEncoder::new(&mut bytes).encode_image(&image).unwrap();
+
+  bytes
+}
+
+/// Inserts an APP1 segment with a big-endian TIFF header and a single-entry IFD holding the
+/// orientation tag right after the start of image marker.
+fn with_exif_orientation(jpeg: &[u8], orientation: u16) -> Vec<u8> {
+  let mut exif = b"Exif\0\0MM\0\x2a\0\0\0\x08\0\x01\x01\x12\0\x03\0\0\0\x01".to_vec();
+  exif.extend_from_slice(&orientation.to_be_bytes());
+  // Value padding and the offset of the next IFD.
+  exif.extend_from_slice(&[0; 6]);
+
+  let mut bytes = jpeg[..2].to_vec();
+  bytes.extend_from_slice(&[0xFF, 0xE1]);
+  bytes.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+  bytes.extend_from_slice(&exif);
+  bytes.extend_from_slice(&jpeg[2..]);
+
+  bytes
+}
+
+fn decode(bytes: &[u8]) -> RgbaImage {
+  let source = load_image_source_from_bytes(bytes).unwrap();
+  let (width, height) = source.size();
+
+  source
+    .render_to_rgba_image(width as u32, height as u32, ImageScalingAlgorithm::Auto)
+    .unwrap()
+    .into_owned()
+}
+
+#[test]
+fn test_decode_applies_exif_orientation() {
+  let jpeg = encode_jpeg();
+  let stored = decode(&jpeg);
+  let (width, height) = stored.dimensions();
+
+  for orientation in 1..=8 {
+    let upright = decode(&with_exif_orientation(&jpeg, orientation));
+
+    let expected_dimensions = if orientation >= 5 {
+      (height, width)
+    } else {
+      (width, height)
+    };
+    assert_eq!(
+      upright.dimensions(),
+      expected_dimensions,
+      "orientation {orientation}"
+    );
+
+    for (x, y, pixel) in upright.enumerate_pixels() {
+      // Where each upright pixel comes from in the image as it is stored.
+      let (source_x, source_y) = match orientation {
+        1 => (x, y),
+        2 => (width - 1 - x, y),
+        3 => (width - 1 - x, height - 1 - y),
+        4 => (x, height - 1 - y),
+        5 => (y, x),
+        6 => (y, height - 1 - x),
+        7 => (width - 1 - y, height - 1 - x),
+        _ => (width - 1 - y, x),
+      };
+
+      assert_eq!(
+        pixel,
+        stored.get_pixel(source_x, source_y),
+        "orientation {orientation} at ({x}, {y})"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_decode_ignores_malformed_exif() {
+  let jpeg = encode_jpeg();
+  let stored = decode(&jpeg);
+
+  // An APP1 segment claiming to be EXIF without a valid TIFF header.
+  let mut bytes = jpeg[..2].to_vec();
+  bytes.extend_from_slice(&[0xFF, 0xE1, 0x00, 0x0A]);
+  bytes.extend_from_slice(b"Exif\0\0XX");
+  bytes.extend_from_slice(&jpeg[2..]);
+
+  assert_eq!(decode(&bytes), stored);
+}
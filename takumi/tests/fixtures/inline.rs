@@ -47,7 +47,10 @@ fn text_inline() {
   let children = Box::from_iter(texts.iter().map(|(text, style)| {
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(style.clone()),
       text: text.to_string(),
     }
@@ -56,6 +59,7 @@ fn text_inline() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -82,7 +86,10 @@ fn inline_image() {
   let children = [
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -93,7 +100,9 @@ fn inline_image() {
     }
     .into(),
     ImageNode {
+      sources: None,
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -113,7 +122,10 @@ fn inline_image() {
     .into(),
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -127,6 +139,7 @@ fn inline_image() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -142,6 +155,7 @@ fn inline_image() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -168,7 +182,10 @@ fn inline_block_in_inline() {
   let children = vec![
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -180,6 +197,7 @@ fn inline_block_in_inline() {
     .into(),
     ContainerNode {
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -193,7 +211,10 @@ fn inline_block_in_inline() {
       children: Some(
         [TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .display(Display::Block)
@@ -209,7 +230,10 @@ fn inline_block_in_inline() {
     .into(),
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -223,6 +247,7 @@ fn inline_block_in_inline() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -280,7 +305,10 @@ fn inline_span_background_color() {
   let children = Box::from_iter(texts.iter().map(|(text, style)| {
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(style.clone()),
       text: text.to_string(),
     }
@@ -289,6 +317,7 @@ fn inline_span_background_color() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -313,6 +342,7 @@ fn inline_atomic_containers() {
   let atomic = |display, color, label: &str| {
     ContainerNode {
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -327,7 +357,10 @@ fn inline_atomic_containers() {
       children: Some(
         [TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: None,
           text: label.to_string(),
         }
@@ -340,6 +373,7 @@ fn inline_atomic_containers() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -355,6 +389,7 @@ fn inline_atomic_containers() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -369,7 +404,10 @@ fn inline_atomic_containers() {
           [
             TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .display(Display::Inline)
@@ -386,7 +424,10 @@ fn inline_atomic_containers() {
             ),
             TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .display(Display::Inline)
@@ -399,7 +440,10 @@ fn inline_atomic_containers() {
             atomic(Display::InlineFlex, Color([0, 255, 0, 100]), "inline-flex"),
             TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .display(Display::Inline)
@@ -426,7 +470,10 @@ fn inline_nested_flex_block() {
   let children = [
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -438,6 +485,7 @@ fn inline_nested_flex_block() {
     .into(),
     ContainerNode {
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -453,7 +501,10 @@ fn inline_nested_flex_block() {
         [
           TextNode {
             preset: None,
+            id: None,
             tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
             style: Some(
               StyleBuilder::default()
                 .display(Display::Inline)
@@ -465,6 +516,7 @@ fn inline_nested_flex_block() {
           .into(),
           ContainerNode {
             preset: None,
+            id: None,
             tw: None,
             style: Some(
               StyleBuilder::default()
@@ -478,7 +530,10 @@ fn inline_nested_flex_block() {
             children: Some(
               [TextNode {
                 preset: None,
+                id: None,
                 tw: None,
+                font_size_fit: None,
+                first_letter_style: None,
                 style: None,
                 text: "Inner".to_string(),
               }
@@ -489,7 +544,10 @@ fn inline_nested_flex_block() {
           .into(),
           TextNode {
             preset: None,
+            id: None,
             tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
             style: Some(
               StyleBuilder::default()
                 .display(Display::Inline)
@@ -506,7 +564,10 @@ fn inline_nested_flex_block() {
     .into(),
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -520,6 +581,7 @@ fn inline_nested_flex_block() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -715,3 +777,45 @@ fn inline_text_decorations() {
   let node = from_value(json_data).expect("Failed to parse JSON fixture");
   run_fixture_test(node, "inline_text_decorations");
 }
+
+#[test]
+fn inline_font_variant_position_sub() {
+  let json_data = json!({
+    "type": "container",
+    "style": {
+      "display": "block",
+      "width": "100%",
+      "height": "100%",
+      "backgroundColor": "white",
+      "padding": "40px",
+      "fontSize": "96px",
+    },
+    "children": [
+      {
+        "type": "text",
+        "text": "H",
+        "style": {
+          "display": "inline",
+        }
+      },
+      {
+        "type": "text",
+        "text": "2",
+        "style": {
+          "display": "inline",
+          "fontVariantPosition": "sub",
+        }
+      },
+      {
+        "type": "text",
+        "text": "O",
+        "style": {
+          "display": "inline",
+        }
+      }
+    ]
+  });
+
+  let node = from_value(json_data).expect("Failed to parse JSON fixture");
+  run_fixture_test(node, "inline_font_variant_position_sub");
+}
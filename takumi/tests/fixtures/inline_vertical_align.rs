@@ -10,6 +10,7 @@ fn inline_vertical_align_types() {
   let row = |label: &str, align: VerticalAlign, color: Color| {
     ContainerNode {
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -28,7 +29,10 @@ fn inline_vertical_align_types() {
         [
           TextNode {
             preset: None,
+            id: None,
             tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
             style: Some(
               StyleBuilder::default()
                 .display(Display::Inline)
@@ -43,6 +47,7 @@ fn inline_vertical_align_types() {
           .into(),
           ContainerNode {
             preset: None,
+            id: None,
             tw: None,
             style: Some(
               StyleBuilder::default()
@@ -59,7 +64,10 @@ fn inline_vertical_align_types() {
           .into(),
           TextNode {
             preset: None,
+            id: None,
             tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
             style: Some(
               StyleBuilder::default()
                 .display(Display::Inline)
@@ -100,6 +108,7 @@ fn inline_vertical_align_types() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -123,7 +132,10 @@ fn inline_vertical_align_multiline() {
   let children = [
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -137,6 +149,7 @@ fn inline_vertical_align_multiline() {
     .into(),
     ContainerNode {
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -153,7 +166,10 @@ fn inline_vertical_align_multiline() {
     .into(),
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -166,6 +182,7 @@ fn inline_vertical_align_multiline() {
     .into(),
     ContainerNode {
       preset: None,
+      id: None,
       tw: None,
       style: Some(
         StyleBuilder::default()
@@ -182,7 +199,10 @@ fn inline_vertical_align_multiline() {
     .into(),
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .display(Display::Inline)
@@ -197,6 +217,7 @@ fn inline_vertical_align_multiline() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -214,3 +235,57 @@ fn inline_vertical_align_multiline() {
 
   run_fixture_test(container.into(), "inline_vertical_align_multiline");
 }
+
+#[test]
+fn inline_vertical_align_sub_super_text() {
+  let text = |text: &str, align: VerticalAlign| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .display(Display::Inline)
+          .vertical_align(align)
+          .build()
+          .unwrap(),
+      ),
+      text: text.to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Block)
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .font_size(Some(Px(48.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        text("H", VerticalAlign::Baseline),
+        text("2", VerticalAlign::Sub),
+        text("O is water", VerticalAlign::Baseline),
+        text("1", VerticalAlign::Super),
+        text(", E = mc", VerticalAlign::Baseline),
+        text("2", VerticalAlign::Super),
+        text(" and x", VerticalAlign::Baseline),
+        text("-8px", VerticalAlign::Length(Px(-8.0))),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "inline_vertical_align_sub_super_text");
+}
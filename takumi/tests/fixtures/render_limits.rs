@@ -0,0 +1,145 @@
+use serde_json::{from_value, json};
+use takumi::{
+  Error,
+  layout::{Viewport, node::NodeKind},
+  rendering::{
+    AnimationBuilder, RenderOptions, RenderOptionsBuilder, measure_layout, render_animation,
+    render_to_svg,
+  },
+};
+
+use crate::test_utils::{CONTEXT, render_json_with_options};
+
+fn tall_node() -> serde_json::Value {
+  json!({
+    "type": "container",
+    "style": {
+      "width": "100px",
+      "height": "5000px",
+      "backgroundColor": "white",
+    }
+  })
+}
+
+#[test]
+fn test_max_height_rejects_auto_sized_canvas() {
+  let result = render_json_with_options(tall_node(), Viewport::new(Some(100), None), |options| {
+    options.max_height(Some(1000));
+  });
+
+  assert!(matches!(
+    result,
+    Err(Error::CanvasTooLarge {
+      width: 100,
+      height: 5000
+    })
+  ));
+}
+
+#[test]
+fn test_max_area_rejects_large_canvas() {
+  let result = render_json_with_options(tall_node(), Viewport::new(Some(100), None), |options| {
+    options.max_area(Some(100 * 1000));
+  });
+
+  assert!(matches!(result, Err(Error::CanvasTooLarge { .. })));
+}
+
+#[test]
+fn test_limits_allow_canvas_within_bounds() {
+  let image = render_json_with_options(tall_node(), Viewport::new(Some(100), None), |options| {
+    options
+      .max_width(Some(100))
+      .max_height(Some(5000))
+      .max_area(Some(100 * 5000));
+  })
+  .unwrap();
+
+  assert_eq!(image.dimensions(), (100, 5000));
+}
+
+fn text_node(length: usize) -> serde_json::Value {
+  json!({
+    "type": "container",
+    "children": [
+      {
+        "type": "text",
+        "text": "a".repeat(length),
+      }
+    ]
+  })
+}
+
+fn render_text(length: usize, max_text_length: usize) -> takumi::Result<image::RgbaImage> {
+  render_json_with_options(text_node(length), (100, 100), |options| {
+    options.max_text_length(Some(max_text_length));
+  })
+}
+
+#[test]
+fn test_max_text_length_rejects_long_text() {
+  let Err(error) = render_text(1001, 1000) else {
+    panic!("text over the limit should fail to render");
+  };
+
+  assert!(matches!(
+    error,
+    Error::TextTooLong {
+      length: 1001,
+      max_length: 1000
+    }
+  ));
+  assert_eq!(
+    error.to_string(),
+    "Text too long: 1001 characters exceeds the configured limit of 1000"
+  );
+}
+
+#[test]
+fn test_max_text_length_allows_text_at_limit() {
+  assert!(render_text(1000, 1000).is_ok());
+}
+
+fn text_options(length: usize, max_text_length: usize) -> RenderOptions<'static, NodeKind> {
+  RenderOptionsBuilder::default()
+    .viewport(Viewport::new(Some(100), Some(100)))
+    .node(from_value(text_node(length)).unwrap())
+    .global(&CONTEXT)
+    .max_text_length(Some(max_text_length))
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn test_max_text_length_applies_to_measure_and_svg() {
+  assert!(matches!(
+    measure_layout(text_options(1001, 1000)),
+    Err(Error::TextTooLong { .. })
+  ));
+  assert!(matches!(
+    render_to_svg(text_options(1001, 1000)),
+    Err(Error::TextTooLong { .. })
+  ));
+
+  assert!(measure_layout(text_options(1000, 1000)).is_ok());
+  assert!(render_to_svg(text_options(1000, 1000)).is_ok());
+}
+
+#[test]
+fn test_max_text_length_applies_to_animation() {
+  let animation = AnimationBuilder::default()
+    .viewport(Viewport::new(Some(100), Some(100)))
+    .global(&CONTEXT)
+    .node(from_value::<NodeKind>(text_node(1001)).unwrap())
+    .keyframes(Vec::new())
+    .frame_count(2)
+    .duration_ms(100)
+    .max_text_length(Some(1000))
+    .build()
+    .unwrap();
+
+  assert!(matches!(
+    render_animation(animation),
+    Err(Error::TextTooLong { .. })
+  ));
+}
@@ -0,0 +1,113 @@
+use std::{
+  collections::HashMap,
+  sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+  },
+  time::Duration,
+};
+
+use image::RgbaImage;
+use serde_json::{from_value, json};
+use takumi::{
+  GlobalContext,
+  layout::{Viewport, node::NodeKind},
+  rendering::{RenderOptionsBuilder, render_with_stats},
+  resources::image::ImageSource,
+};
+
+/// A clock that advances by one millisecond on every read.
+fn ticking_clock() -> Duration {
+  static TICKS: AtomicU64 = AtomicU64::new(0);
+
+  Duration::from_millis(TICKS.fetch_add(1, Ordering::Relaxed))
+}
+
+#[test]
+fn test_render_with_stats_reports_counters() {
+  let context = GlobalContext::default();
+
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+    },
+    "children": [
+      {
+        "type": "container",
+        "style": {
+          "width": "20px",
+          "height": "20px",
+          "backgroundColor": "red",
+          "transform": "rotate(45deg)",
+        },
+      },
+      {
+        "type": "image",
+        "src": "https://example.com/logo.png",
+        "style": { "width": "10px", "height": "10px" },
+      },
+      {
+        "type": "image",
+        "src": "https://example.com/logo.png",
+        "style": { "width": "20px", "height": "20px" },
+      },
+    ],
+  }))
+  .unwrap();
+
+  let fetched_resources = HashMap::from([(
+    Arc::from("https://example.com/logo.png"),
+    Arc::new(ImageSource::Bitmap(RgbaImage::new(4, 4))),
+  )]);
+
+  let (image, stats) = render_with_stats(
+    RenderOptionsBuilder::default()
+      .viewport(Viewport::new(Some(100), Some(50)))
+      .node(node)
+      .global(&context)
+      .fetched_resources(fetched_resources)
+      .clock(ticking_clock)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  assert_eq!(image.dimensions(), (100, 50));
+  assert_eq!(stats.node_count, 4);
+  assert_eq!(stats.resolved_image_count, 1);
+  // The rotated child is drawn on an isolated layer on top of the base image.
+  assert_eq!(stats.peak_canvas_bytes, 100 * 50 * 4 * 2);
+  assert_eq!(stats.layout_time, Duration::from_millis(1));
+  assert_eq!(stats.draw_time, Duration::from_millis(1));
+}
+
+#[test]
+fn test_render_with_stats_without_isolated_layers() {
+  let context = GlobalContext::default();
+
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "backgroundColor": "blue",
+    },
+  }))
+  .unwrap();
+
+  let (_, stats) = render_with_stats(
+    RenderOptionsBuilder::default()
+      .viewport(Viewport::new(Some(40), Some(30)))
+      .node(node)
+      .global(&context)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  assert_eq!(stats.node_count, 1);
+  assert_eq!(stats.resolved_image_count, 0);
+  assert_eq!(stats.peak_canvas_bytes, 40 * 30 * 4);
+}
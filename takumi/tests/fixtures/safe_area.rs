@@ -0,0 +1,137 @@
+use image::{Rgba, RgbaImage};
+use serde_json::json;
+use takumi::{layout::Viewport, taffy::Rect};
+
+use crate::test_utils::render_json_with_options;
+
+const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+
+fn filled_root(width: &str, height: &str) -> serde_json::Value {
+  json!({
+    "type": "container",
+    "style": {
+      "width": width,
+      "height": height,
+      "backgroundColor": "red",
+    }
+  })
+}
+
+fn inset() -> Rect<f32> {
+  Rect {
+    left: 20.0,
+    right: 10.0,
+    top: 30.0,
+    bottom: 5.0,
+  }
+}
+
+#[test]
+fn test_safe_area_inset_shifts_root_and_keeps_canvas_background() {
+  let background = RgbaImage::from_pixel(200, 100, BLUE);
+
+  let image = render_json_with_options(filled_root("100%", "100%"), (200, 100), |options| {
+    options
+      .background(Some(&background))
+      .safe_area_inset(inset());
+  })
+  .unwrap();
+
+  assert_eq!(image.dimensions(), (200, 100));
+
+  // The root fills the inner rect only.
+  assert_eq!(*image.get_pixel(20, 30), RED);
+  assert_eq!(*image.get_pixel(189, 94), RED);
+
+  // The margin keeps the canvas background on every side.
+  assert_eq!(*image.get_pixel(19, 50), BLUE);
+  assert_eq!(*image.get_pixel(190, 50), BLUE);
+  assert_eq!(*image.get_pixel(100, 29), BLUE);
+  assert_eq!(*image.get_pixel(100, 95), BLUE);
+}
+
+#[test]
+fn test_safe_area_inset_grows_auto_sized_canvas() {
+  let image = render_json_with_options(
+    filled_root("100px", "50px"),
+    Viewport::new(None, None),
+    |options| {
+      options.safe_area_inset(inset());
+    },
+  )
+  .unwrap();
+
+  assert_eq!(image.dimensions(), (130, 85));
+  assert_eq!(*image.get_pixel(20, 30), RED);
+  assert_eq!(image.get_pixel(10, 10).0[3], 0);
+}
+
+fn render_with_fixed_corner(ancestor_style: serde_json::Value) -> RgbaImage {
+  render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "50%",
+        "height": "50%",
+        "border": "5px solid red",
+      },
+      "children": [
+        {
+          "type": "container",
+          "style": ancestor_style,
+          "children": [
+            {
+              "type": "container",
+              "style": {
+                "position": "fixed",
+                "right": "0",
+                "bottom": "0",
+                "width": "10px",
+                "height": "10px",
+                "backgroundColor": "blue",
+              },
+            },
+          ],
+        },
+      ],
+    }),
+    (200, 100),
+    |options| {
+      options.safe_area_inset(inset());
+    },
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_fixed_node_anchors_to_viewport_outside_root_and_inset() {
+  let image = render_with_fixed_corner(json!({ "width": "20px", "height": "20px" }));
+
+  // The root ends at (125, 80), but the fixed node sits in the corner of the whole image.
+  assert_eq!(*image.get_pixel(199, 99), BLUE);
+  assert_eq!(*image.get_pixel(190, 90), BLUE);
+  assert_eq!(image.get_pixel(189, 89).0[3], 0);
+}
+
+#[test]
+fn test_fixed_node_keeps_ancestor_opacity() {
+  let image = render_with_fixed_corner(json!({ "opacity": 0.5 }));
+
+  let pixel = image.get_pixel(199, 99);
+  assert!(pixel.0[3].abs_diff(128) <= 1, "{pixel:?}");
+}
+
+#[test]
+fn test_transformed_ancestor_contains_fixed_node() {
+  let image = render_with_fixed_corner(json!({
+    "width": "40px",
+    "height": "40px",
+    "transform": "translate(0px, 0px)",
+  }));
+
+  // The ancestor starts inside the inset and the root border, at (25, 35).
+  assert_eq!(*image.get_pixel(60, 70), BLUE);
+  assert_eq!(*image.get_pixel(64, 74), BLUE);
+  assert_eq!(image.get_pixel(199, 99).0[3], 0);
+}
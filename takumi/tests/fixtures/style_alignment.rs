@@ -1,3 +1,4 @@
+use serde_json::{from_value, json};
 use takumi::layout::{
   node::ContainerNode,
   style::{
@@ -13,6 +14,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_align_items() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -28,6 +30,7 @@ fn test_style_align_items() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -42,6 +45,7 @@ fn test_style_align_items() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -56,6 +60,7 @@ fn test_style_align_items() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -80,6 +85,7 @@ fn test_style_align_items() {
 fn test_style_justify_content() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -95,6 +101,7 @@ fn test_style_justify_content() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -109,6 +116,7 @@ fn test_style_justify_content() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -123,6 +131,7 @@ fn test_style_justify_content() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -142,3 +151,96 @@ fn test_style_justify_content() {
 
   run_fixture_test(container.into(), "style_justify_content");
 }
+
+#[test]
+fn test_style_flex_direction_row_reverse() {
+  // The first child should end up on the right, packed toward the reversed main-start edge.
+  let container = json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "display": "flex",
+      "flexDirection": "row-reverse",
+      "justifyContent": "flex-start",
+      "alignItems": "center",
+      "gap": 20,
+      "padding": 40,
+      "backgroundColor": "#ffffff"
+    },
+    "children": [
+      {
+        "type": "container",
+        "style": { "width": 120, "height": 120, "backgroundColor": "#ef4444" }
+      },
+      {
+        "type": "container",
+        "style": { "width": 120, "height": 120, "backgroundColor": "#22c55e" }
+      },
+      {
+        "type": "container",
+        "style": { "width": 120, "height": 120, "backgroundColor": "#3b82f6" }
+      },
+      {
+        "type": "container",
+        "style": {
+          "position": "absolute",
+          "left": 0,
+          "top": 0,
+          "width": 40,
+          "height": 40,
+          "backgroundColor": "#111827"
+        }
+      }
+    ]
+  });
+
+  run_fixture_test(
+    from_value(container).unwrap(),
+    "style_flex_direction_row_reverse",
+  );
+}
+
+#[test]
+fn test_style_grid_align_items_baseline() {
+  // Items of different font sizes should share the first text baseline across the row.
+  let container = json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "display": "grid",
+      "gridTemplateColumns": "1fr 1fr 1fr",
+      "alignItems": "baseline",
+      "columnGap": 20,
+      "padding": 40,
+      "backgroundColor": "#ffffff"
+    },
+    "children": [
+      {
+        "type": "text",
+        "text": "Small",
+        "style": { "fontSize": 24, "backgroundColor": "#fecaca" }
+      },
+      {
+        "type": "text",
+        "text": "Large",
+        "style": { "fontSize": 72, "backgroundColor": "#bbf7d0" }
+      },
+      {
+        "type": "text",
+        "text": "Padded",
+        "style": {
+          "fontSize": 40,
+          "paddingTop": 30,
+          "backgroundColor": "#bfdbfe"
+        }
+      }
+    ]
+  });
+
+  run_fixture_test(
+    from_value(container).unwrap(),
+    "style_grid_align_items_baseline",
+  );
+}
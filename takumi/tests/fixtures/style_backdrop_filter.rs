@@ -9,6 +9,7 @@ use crate::test_utils::run_fixture_test;
 fn create_backdrop_card(filter: &str, label_font_size_px: f32) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -27,7 +28,10 @@ fn create_backdrop_card(filter: &str, label_font_size_px: f32) -> NodeKind {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: None,
         text: filter.to_string(),
       }
@@ -65,6 +69,7 @@ fn test_style_backdrop_filter() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -97,6 +102,7 @@ fn test_style_backdrop_filter() {
 fn test_style_backdrop_filter_frosted_glass() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -115,6 +121,7 @@ fn test_style_backdrop_filter_frosted_glass() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -134,7 +141,10 @@ fn test_style_backdrop_filter_frosted_glass() {
           [
             TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .font_size(Some(Px(48.0)))
@@ -148,7 +158,10 @@ fn test_style_backdrop_filter_frosted_glass() {
             .into(),
             TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .font_size(Some(Px(24.0)))
@@ -1,9 +1,11 @@
+use image::RgbaImage;
+use serde_json::json;
 use takumi::layout::{
   node::{ContainerNode, NodeKind, TextNode},
   style::{Length::*, *},
 };
 
-use crate::test_utils::run_fixture_test;
+use crate::test_utils::{render_json, run_fixture_test};
 
 fn create_container_with_background_clip(
   background_clip: BackgroundClip,
@@ -13,6 +15,7 @@ fn create_container_with_background_clip(
 ) -> ContainerNode<NodeKind> {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -27,6 +30,7 @@ fn create_container_with_background_clip(
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -95,6 +99,7 @@ fn test_style_background_clip_text_gradient() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -110,7 +115,10 @@ fn test_style_background_clip_text_gradient() {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .background_image(Some(gradient_images))
@@ -139,6 +147,7 @@ fn test_style_background_clip_text_radial_gradient() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -155,7 +164,10 @@ fn test_style_background_clip_text_radial_gradient() {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .background_image(Some(gradient_images))
@@ -179,6 +191,7 @@ fn test_style_background_clip_text_radial_gradient() {
 fn test_style_background_clip_border_area() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -193,6 +206,7 @@ fn test_style_background_clip_border_area() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -225,6 +239,7 @@ fn test_style_background_clip_with_gradient_background() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -239,6 +254,7 @@ fn test_style_background_clip_with_gradient_background() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -270,6 +286,7 @@ fn test_style_background_clip_text_multiline() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -285,7 +302,10 @@ fn test_style_background_clip_text_multiline() {
     children: Some([
       TextNode {
     preset: None,
+    id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .background_image(Some(gradient_images))
@@ -309,6 +329,7 @@ fn test_style_background_clip_text_multiline() {
 fn test_style_background_clip_comparison() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -327,6 +348,7 @@ fn test_style_background_clip_comparison() {
         // Border Box
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -344,7 +366,10 @@ fn test_style_background_clip_comparison() {
           children: Some(
             [TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .font_size(Some(Px(20.0)))
@@ -362,6 +387,7 @@ fn test_style_background_clip_comparison() {
         // Padding Box
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -379,7 +405,10 @@ fn test_style_background_clip_comparison() {
           children: Some(
             [TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .font_size(Some(Px(20.0)))
@@ -397,6 +426,7 @@ fn test_style_background_clip_comparison() {
         // Content Box
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -414,7 +444,10 @@ fn test_style_background_clip_comparison() {
           children: Some(
             [TextNode {
               preset: None,
+              id: None,
               tw: None,
+              font_size_fit: None,
+              first_letter_style: None,
               style: Some(
                 StyleBuilder::default()
                   .font_size(Some(Px(20.0)))
@@ -436,3 +469,49 @@ fn test_style_background_clip_comparison() {
 
   run_fixture_test(container.into(), "style_background_clip_comparison");
 }
+
+fn render_gradient_text(background_clip: &str) -> RgbaImage {
+  render_json(
+    json!({
+      "type": "text",
+      "text": "MMMM",
+      "style": {
+        "width": "400px",
+        "height": "120px",
+        "fontSize": "96px",
+        "color": "transparent",
+        "backgroundImage": "linear-gradient(to right, red, yellow, lime, cyan, blue, magenta)",
+        "backgroundSize": "200% 100%",
+        "backgroundPosition": "-37.3px 0",
+        "backgroundClip": background_clip,
+      }
+    }),
+    (400, 120),
+  )
+}
+
+#[test]
+fn test_background_clip_text_samples_gradient_in_box_space() {
+  let clipped = render_gradient_text("text");
+  let background = render_gradient_text("border-box");
+
+  let mut covered_pixels = 0;
+
+  for (x, y, pixel) in clipped.enumerate_pixels() {
+    if pixel.0[3] != 255 {
+      continue;
+    }
+
+    covered_pixels += 1;
+
+    let expected = background.get_pixel(x, y);
+    for channel in 0..3 {
+      assert!(
+        pixel.0[channel].abs_diff(expected.0[channel]) <= 2,
+        "glyph pixel at ({x}, {y}) is {pixel:?}, the gradient there is {expected:?}"
+      );
+    }
+  }
+
+  assert!(covered_pixels > 0);
+}
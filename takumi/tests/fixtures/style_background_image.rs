@@ -8,6 +8,7 @@ use crate::test_utils::run_fixture_test;
 fn create_container(background_images: BackgroundImages) -> ContainerNode<NodeKind> {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -29,6 +30,7 @@ fn create_container_with(
 ) -> ContainerNode<NodeKind> {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -210,6 +212,22 @@ fn test_background_position_percentage_with_no_repeat() {
   run_fixture_test(container.into(), "style_background_position_percent_25_75");
 }
 
+#[test]
+fn test_background_position_edge_offsets() {
+  let images = BackgroundImages::from_str(
+    "linear-gradient(135deg, rgba(0,0,0,0.6), rgba(0,0,0,0.2)), linear-gradient(to bottom, white, white)",
+  )
+  .unwrap();
+  let container = create_container_with(
+    images,
+    Some(BackgroundSizes::from_str("160px 48px, 100% 100%").unwrap()),
+    Some(BackgroundPositions::from_str("right 20px bottom 10px, 0 0").unwrap()),
+    Some(BackgroundRepeats::from_str("no-repeat").unwrap()),
+  );
+
+  run_fixture_test(container.into(), "style_background_position_edge_offsets");
+}
+
 #[test]
 fn test_background_size_percentage_with_repeat() {
   let images =
@@ -302,6 +320,43 @@ fn test_background_size_contain() {
   run_fixture_test(container.into(), "style_background_size_contain");
 }
 
+// The contained image leaves space on both sides of the landscape box, which is split evenly
+// by the default `background-position`.
+#[test]
+fn test_background_size_contain_default_position() {
+  let images = BackgroundImages::from_str("url(assets/images/yeecord.png)").unwrap();
+  let mut container = create_container_with(
+    images,
+    Some(BackgroundSizes::from_str("contain").unwrap()),
+    None,
+    Some(BackgroundRepeats::from_str("no-repeat").unwrap()),
+  );
+
+  container.style.as_mut().unwrap().background_color =
+    ColorInput::Value(Color([226, 232, 240, 255])).into();
+
+  run_fixture_test(
+    container.into(),
+    "style_background_size_contain_default_position",
+  );
+}
+
+#[test]
+fn test_background_size_contain_right() {
+  let images = BackgroundImages::from_str("url(assets/images/yeecord.png)").unwrap();
+  let mut container = create_container_with(
+    images,
+    Some(BackgroundSizes::from_str("contain").unwrap()),
+    Some(BackgroundPositions::from_str("right").unwrap()),
+    Some(BackgroundRepeats::from_str("no-repeat").unwrap()),
+  );
+
+  container.style.as_mut().unwrap().background_color =
+    ColorInput::Value(Color([226, 232, 240, 255])).into();
+
+  run_fixture_test(container.into(), "style_background_size_contain_right");
+}
+
 #[test]
 fn test_background_size_cover() {
   let images = BackgroundImages::from_str("url(assets/images/yeecord.png)").unwrap();
@@ -314,3 +369,45 @@ fn test_background_size_cover() {
 
   run_fixture_test(container.into(), "style_background_size_cover");
 }
+
+fn create_container_with_origin(background_origin: BackgroundOrigin) -> ContainerNode<NodeKind> {
+  ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .border_width(Some(Sides([Px(10.0); 4])))
+        .border_style(Some(BorderStyle::Solid))
+        .border_color(Some(ColorInput::Value(Color([0, 0, 0, 255]))))
+        .background_image(Some(
+          BackgroundImages::from_str("linear-gradient(135deg, rgb(255, 0, 0), rgb(0, 0, 255))")
+            .unwrap(),
+        ))
+        .background_size(Some(BackgroundSizes::from_str("120px 120px").unwrap()))
+        .background_position(Some(BackgroundPositions::from_str("bottom right").unwrap()))
+        .background_repeat(Some(BackgroundRepeats::from_str("no-repeat").unwrap()))
+        .background_origin(Some(background_origin))
+        .build()
+        .unwrap(),
+    ),
+    children: None,
+  }
+}
+
+#[test]
+fn test_background_origin_border_box() {
+  let container = create_container_with_origin(BackgroundOrigin::BorderBox);
+
+  run_fixture_test(container.into(), "style_background_origin_border_box");
+}
+
+#[test]
+fn test_background_origin_content_box() {
+  let container = create_container_with_origin(BackgroundOrigin::ContentBox);
+
+  run_fixture_test(container.into(), "style_background_origin_content_box");
+}
@@ -1,9 +1,11 @@
+use image::RgbaImage;
+use serde_json::json;
 use takumi::layout::{
-  node::{ContainerNode, TextNode},
+  node::{ContainerNode, ImageNode, TextNode},
   style::{Length::*, *},
 };
 
-use crate::test_utils::run_fixture_test;
+use crate::test_utils::{render_json, run_fixture_test};
 
 #[test]
 fn clip_path_text_stroke_filled() {
@@ -11,6 +13,7 @@ fn clip_path_text_stroke_filled() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -31,7 +34,10 @@ fn clip_path_text_stroke_filled() {
       [
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .position(Position::Absolute)
@@ -48,7 +54,10 @@ fn clip_path_text_stroke_filled() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .position(Position::Absolute)
@@ -78,6 +87,7 @@ fn clip_path_text_stroke_filled() {
 fn clip_path_triangle_vercel() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -96,6 +106,7 @@ fn clip_path_triangle_vercel() {
         // Triangle with clip-path
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -124,6 +135,7 @@ fn clip_path_triangle_vercel() {
 fn clip_path_triangle_gradient() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -142,6 +154,7 @@ fn clip_path_triangle_gradient() {
         // Triangle with gradient background and clip-path
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -175,6 +188,7 @@ fn clip_path_triangle_gradient() {
 fn clip_path_circle() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -193,6 +207,7 @@ fn clip_path_circle() {
         // Circle with clip-path
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -219,6 +234,7 @@ fn clip_path_circle() {
 fn clip_path_inset_rounded() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -237,6 +253,7 @@ fn clip_path_inset_rounded() {
         // Inset with border radius and clip-path
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -266,6 +283,7 @@ fn clip_path_inset_round_clips_children() {
   // Outer wrapper (white background, defines canvas)
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -280,6 +298,7 @@ fn clip_path_inset_round_clips_children() {
         // Inner container with clip-path: inset(0px round 50px)
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -298,6 +317,7 @@ fn clip_path_inset_round_clips_children() {
               // Full-bleed red child — should be clipped to rounded rect
               ContainerNode {
                 preset: None,
+                id: None,
                 tw: None,
                 style: Some(
                   StyleBuilder::default()
@@ -325,3 +345,170 @@ fn clip_path_inset_round_clips_children() {
 
   run_fixture_test(container.into(), "clip_path_inset_round_clips_children");
 }
+
+#[test]
+fn clip_path_shape_chevron_image() {
+  let image = ImageNode {
+    sources: None,
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .object_fit(ObjectFit::Cover)
+        .clip_path(Some(
+          BasicShape::from_str(
+            "shape(from 0% 0%, line to 75% 0%, line to 100% 50%, line to 75% 100%, hline to 0%, line to 25% 50%, close)",
+          )
+          .unwrap(),
+        ))
+        .build()
+        .unwrap(),
+    ),
+    width: None,
+    height: None,
+    src: "assets/images/yeecord.png".into(),
+  };
+
+  run_fixture_test(image.into(), "clip_path_shape_chevron_image");
+}
+
+const PENTAGRAM_POLYGON: &str =
+  "polygon(50% 0%, 79.39% 90.45%, 2.45% 34.55%, 97.55% 34.55%, 20.61% 90.45%)";
+
+fn create_pentagram_image(clip_rule: FillRule) -> ImageNode {
+  ImageNode {
+    sources: None,
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .object_fit(ObjectFit::Cover)
+        .clip_path(Some(BasicShape::from_str(PENTAGRAM_POLYGON).unwrap()))
+        .clip_rule(clip_rule)
+        .build()
+        .unwrap(),
+    ),
+    width: None,
+    height: None,
+    src: "assets/images/yeecord.png".into(),
+  }
+}
+
+#[test]
+fn clip_path_pentagram_nonzero() {
+  run_fixture_test(
+    create_pentagram_image(FillRule::NonZero).into(),
+    "clip_path_pentagram_nonzero",
+  );
+}
+
+#[test]
+fn clip_path_pentagram_evenodd() {
+  run_fixture_test(
+    create_pentagram_image(FillRule::EvenOdd).into(),
+    "clip_path_pentagram_evenodd",
+  );
+}
+
+fn render_pentagram(clip_rule: &str) -> RgbaImage {
+  render_json(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100px",
+        "height": "100px",
+        "backgroundColor": "red",
+        "clipPath": "polygon(50% 0%, 79.39% 90.45%, 2.45% 34.55%, 97.55% 34.55%, 20.61% 90.45%)",
+        "clipRule": clip_rule,
+      }
+    }),
+    (100, 100),
+  )
+}
+
+#[test]
+fn test_pentagram_center_filled_with_nonzero() {
+  let image = render_pentagram("nonzero");
+
+  assert_eq!(image.get_pixel(50, 50).0[3], 255);
+  assert_eq!(image.get_pixel(50, 15).0[3], 255);
+}
+
+#[test]
+fn test_pentagram_center_empty_with_evenodd() {
+  let image = render_pentagram("evenodd");
+
+  assert_eq!(image.get_pixel(50, 50).0[3], 0);
+  assert_eq!(image.get_pixel(50, 15).0[3], 255);
+}
+
+fn assert_clipped_to_content_box(clip_path: &str) {
+  let image = render_json(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100px",
+        "height": "100px",
+        "padding": "20px",
+        "backgroundColor": "red",
+        "clipPath": clip_path,
+      }
+    }),
+    (100, 100),
+  );
+
+  // The padding area is clipped away, leaving only the content box.
+  assert_eq!(image.get_pixel(10, 10).0[3], 0);
+  assert_eq!(image.get_pixel(10, 50).0[3], 0);
+  assert_eq!(image.get_pixel(90, 50).0[3], 0);
+  assert_eq!(image.get_pixel(50, 90).0[3], 0);
+  assert_eq!(image.get_pixel(21, 21).0[3], 255);
+  assert_eq!(image.get_pixel(50, 50).0[3], 255);
+  assert_eq!(image.get_pixel(78, 78).0[3], 255);
+}
+
+#[test]
+fn test_inset_content_box_removes_padding() {
+  assert_clipped_to_content_box("inset(0) content-box");
+}
+
+#[test]
+fn test_content_box_alone_removes_padding() {
+  assert_clipped_to_content_box("content-box");
+}
+
+#[test]
+fn test_border_box_alone_follows_border_radius() {
+  let image = render_json(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100px",
+        "height": "100px",
+        "borderRadius": "50px",
+        "clipPath": "border-box",
+      },
+      "children": [{
+        "type": "container",
+        "style": {
+          "width": "100%",
+          "height": "100%",
+          "backgroundColor": "red",
+        }
+      }]
+    }),
+    (100, 100),
+  );
+
+  // The child has square corners, so only the clip path can round them.
+  assert_eq!(image.get_pixel(2, 2).0[3], 0);
+  assert_eq!(image.get_pixel(97, 97).0[3], 0);
+  assert_eq!(image.get_pixel(50, 50).0[3], 255);
+  assert_eq!(image.get_pixel(50, 2).0[3], 255);
+}
@@ -15,6 +15,7 @@ fn create_filter_test_container(
 ) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -43,6 +44,7 @@ fn create_filter_test_container(
 fn create_filter_card(filter: &str, image_size_px: f32, label_font_size_px: f32) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -56,7 +58,9 @@ fn create_filter_card(filter: &str, image_size_px: f32, label_font_size_px: f32)
     children: Some(
       [
         ImageNode {
+          sources: None,
           preset: None,
+          id: None,
           tw: None,
           src: "assets/images/yeecord.png".into(),
           style: Some(
@@ -73,7 +77,10 @@ fn create_filter_card(filter: &str, image_size_px: f32, label_font_size_px: f32)
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: None,
           text: filter.to_string(),
         }
@@ -144,3 +151,72 @@ fn test_style_filter_sepia() {
   let container = create_filter_test_container(&sepia_values, 16.0, 150.0, 24.0);
   run_fixture_test(container, "style_filter_sepia");
 }
+
+#[test]
+fn test_style_filter_tint() {
+  let tint_values = [
+    "grayscale(100%)",
+    "grayscale(100%) tint(#e11d48)",
+    "grayscale(100%) tint(rgb(37 99 235))",
+    "grayscale(100%) brightness(150%) tint(#e11d48)",
+  ];
+
+  let container = create_filter_test_container(&tint_values, 16.0, 150.0, 16.0);
+  run_fixture_test(container, "style_filter_tint");
+}
+
+#[test]
+fn test_style_filter_grayscale_contrast_chain_on_photo() {
+  let filters = [
+    "grayscale(0.5)",
+    "grayscale(0.5) contrast(1.2)",
+    "contrast(1.2) grayscale(0.5)",
+  ];
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .gap(SpacePair::from_single(Px(8.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      filters
+        .iter()
+        .map(|filter| -> NodeKind {
+          ImageNode {
+            sources: None,
+            preset: None,
+            id: None,
+            tw: None,
+            src: "assets/images/luma-cover-0dfbf65d-0f58-4941-947c-d84a5b131dc0.jpeg".into(),
+            style: Some(
+              StyleBuilder::default()
+                .width(Percentage(100.0))
+                .flex_grow(Some(FlexGrow(1.0)))
+                .object_fit(ObjectFit::Cover)
+                .filter(Filters::from_str(filter).unwrap())
+                .build()
+                .unwrap(),
+            ),
+            width: None,
+            height: None,
+          }
+          .into()
+        })
+        .collect(),
+    ),
+  };
+
+  run_fixture_test(
+    container.into(),
+    "style_filter_grayscale_contrast_chain_on_photo",
+  );
+}
@@ -1,5 +1,5 @@
 use takumi::layout::{
-  node::ContainerNode,
+  node::{ContainerNode, NodeKind},
   style::{
     Length::{Percentage, Px},
     *,
@@ -12,6 +12,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_flex_basis() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -27,6 +28,7 @@ fn test_style_flex_basis() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -41,6 +43,7 @@ fn test_style_flex_basis() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -55,6 +58,7 @@ fn test_style_flex_basis() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -79,6 +83,7 @@ fn test_style_flex_basis() {
 fn test_style_flex_direction() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -94,6 +99,7 @@ fn test_style_flex_direction() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -108,6 +114,7 @@ fn test_style_flex_direction() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -122,6 +129,7 @@ fn test_style_flex_direction() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -146,6 +154,7 @@ fn test_style_flex_direction() {
 fn test_style_gap() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -162,6 +171,7 @@ fn test_style_gap() {
         // First child
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -177,6 +187,7 @@ fn test_style_gap() {
         // Second child
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -192,6 +203,7 @@ fn test_style_gap() {
         // Third child
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -216,6 +228,7 @@ fn test_style_gap() {
 fn test_style_grid_template_columns() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -234,6 +247,7 @@ fn test_style_grid_template_columns() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -246,6 +260,7 @@ fn test_style_grid_template_columns() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -268,6 +283,7 @@ fn test_style_grid_template_columns() {
 fn test_style_grid_template_rows() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -286,6 +302,7 @@ fn test_style_grid_template_rows() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -298,6 +315,7 @@ fn test_style_grid_template_rows() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -315,3 +333,119 @@ fn test_style_grid_template_rows() {
 
   run_fixture_test(container.into(), "style_grid_template_rows");
 }
+
+#[test]
+fn test_style_grid_column_rule() {
+  let cell = |color: Color| -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .height(Px(80.0))
+          .background_color(ColorInput::Value(color))
+          .build()
+          .unwrap(),
+      ),
+      children: None,
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .display(Display::Grid)
+        .grid_template_columns(Some(vec![
+          GridTemplateComponent::Single(
+            GridTrackSize::Fixed(GridLength::Fr(1.0))
+          );
+          3
+        ]))
+        .gap(SpacePair::from_single(Px(24.0)))
+        .column_rule_width(Some(Px(2.0)))
+        .column_rule_style(Some(BorderStyle::Solid))
+        .column_rule_color(Some(ColorInput::Value(Color([128, 128, 128, 255]))))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        cell(Color([255, 99, 71, 255])),
+        cell(Color([60, 179, 113, 255])),
+        cell(Color([65, 105, 225, 255])),
+        cell(Color([238, 130, 238, 255])),
+        cell(Color([255, 165, 0, 255])),
+        cell(Color([106, 90, 205, 255])),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_grid_column_rule");
+}
+
+#[test]
+fn test_style_grid_auto_flow_dense() {
+  let cell = |color: Color, span: bool| -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .height(Px(120.0))
+          .grid_column(span.then(|| GridLine::start(GridPlacement::span(2))))
+          .background_color(ColorInput::Value(color))
+          .build()
+          .unwrap(),
+      ),
+      children: None,
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .display(Display::Grid)
+        .grid_template_columns(Some(vec![
+          GridTemplateComponent::Single(
+            GridTrackSize::Fixed(GridLength::Fr(1.0))
+          );
+          3
+        ]))
+        .grid_auto_flow(Some(GridAutoFlow::row().dense()))
+        .gap(SpacePair::from_single(Px(24.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    // The single cells backfill the hole left next to the first spanning cell.
+    children: Some(
+      [
+        cell(Color([255, 99, 71, 255]), true),
+        cell(Color([60, 179, 113, 255]), true),
+        cell(Color([65, 105, 225, 255]), false),
+        cell(Color([238, 130, 238, 255]), false),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_grid_auto_flow_dense");
+}
@@ -0,0 +1,99 @@
+use takumi::layout::{
+  node::{ContainerNode, NodeKind, TextNode},
+  style::{Length::*, *},
+};
+
+use crate::test_utils::run_fixture_test;
+
+fn list_item(text: &str) -> NodeKind {
+  TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: None,
+    text: text.to_string(),
+  }
+  .into()
+}
+
+fn list(list_style_type: ListStyleType, children: Vec<NodeKind>) -> NodeKind {
+  ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .list_style_type(list_style_type)
+        .padding_left(Some(Px(48.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(children.into()),
+  }
+  .into()
+}
+
+#[test]
+fn test_style_list_style_type() {
+  let nested_item = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        list_item("Nested list"),
+        list(
+          ListStyleType::Decimal,
+          vec![list_item("Counts from one"), list_item("Again")],
+        ),
+      ]
+      .into(),
+    ),
+  }
+  .into();
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .gap(SpacePair::from_single(Px(24.0)))
+        .padding(Sides([Px(32.0); 4]))
+        .font_size(Some(Px(32.0)))
+        .background_color(ColorInput::Value(Color([255, 255, 255, 255])))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        list(
+          ListStyleType::Decimal,
+          vec![list_item("First"), list_item("Second"), list_item("Third")],
+        ),
+        list(
+          ListStyleType::Disc,
+          vec![list_item("Apples"), nested_item, list_item("Pears")],
+        ),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_list_style_type");
+}
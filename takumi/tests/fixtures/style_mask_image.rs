@@ -1,5 +1,5 @@
 use takumi::layout::{
-  node::{ContainerNode, ImageNode, NodeKind},
+  node::{ContainerNode, ImageNode, NodeKind, TextNode},
   style::{Length::*, *},
 };
 
@@ -11,6 +11,7 @@ fn create_container_with_mask(
 ) -> ContainerNode<NodeKind> {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -90,6 +91,7 @@ fn test_style_mask_image_with_background_image() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -113,6 +115,7 @@ fn test_style_mask_image_on_image_node() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -127,6 +130,7 @@ fn test_style_mask_image_on_image_node() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -139,7 +143,9 @@ fn test_style_mask_image_on_image_node() {
         children: Some(
           vec![
             ImageNode {
+              sources: None,
               preset: None,
+              id: None,
               tw: None,
               style: Some(
                 StyleBuilder::default()
@@ -188,3 +194,59 @@ fn test_style_mask_image_corner_fade() {
 
   run_fixture_test(container.into(), "style_mask_image_corner_fade");
 }
+
+#[test]
+fn test_style_mask_image_on_text() {
+  let fade = "linear-gradient(to right, black, transparent)";
+
+  let headline = |builder: &mut StyleBuilder| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        builder
+          .font_size(Some(Px(96.0)))
+          .font_weight(FontWeight::from(700.0))
+          .color(ColorInput::Value(Color([17, 24, 39, 255])))
+          .build()
+          .unwrap(),
+      ),
+      text: "Fading Headline".to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .justify_content(JustifyContent::Center)
+        .align_items(AlignItems::Center)
+        .gap(SpacePair::from_single(Px(40.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        // The glyph coverage is multiplied by the alpha of the `mask-image` gradient.
+        headline(
+          StyleBuilder::default().mask_image(Some(BackgroundImages::from_str(fade).unwrap())),
+        ),
+        // The `mask` shorthand behaves the same way.
+        headline(StyleBuilder::default().mask(Backgrounds::from_str(fade).unwrap())),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_mask_image_on_text");
+}
@@ -10,6 +10,7 @@ use std::sync::Arc;
 fn create_blend_card(mode: BlendMode, label_font_size_px: f32) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -24,7 +25,9 @@ fn create_blend_card(mode: BlendMode, label_font_size_px: f32) -> NodeKind {
     children: Some(
       [
         ImageNode {
+          sources: None,
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -41,7 +44,10 @@ fn create_blend_card(mode: BlendMode, label_font_size_px: f32) -> NodeKind {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .font_size(Some(Px(label_font_size_px)))
@@ -85,6 +91,7 @@ fn test_style_mix_blend_mode() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -112,6 +119,7 @@ fn test_style_mix_blend_mode() {
 fn test_style_mlx_blend_mode_isolation() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -127,6 +135,7 @@ fn test_style_mlx_blend_mode_isolation() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -138,7 +147,9 @@ fn test_style_mlx_blend_mode_isolation() {
           ),
           children: Some(
             [ImageNode {
+              sources: None,
               preset: None,
+              id: None,
               tw: None,
               style: Some(
                 StyleBuilder::default()
@@ -157,6 +168,7 @@ fn test_style_mlx_blend_mode_isolation() {
         .into(),
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -168,7 +180,9 @@ fn test_style_mlx_blend_mode_isolation() {
           ),
           children: Some(
             [ImageNode {
+              sources: None,
               preset: None,
+              id: None,
               tw: None,
               style: Some(
                 StyleBuilder::default()
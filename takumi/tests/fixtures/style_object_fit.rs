@@ -1,6 +1,16 @@
-use takumi::layout::{
-  node::ImageNode,
-  style::{Length::Percentage, ObjectFit, StyleBuilder},
+use std::sync::Arc;
+
+use image::{Rgba, RgbaImage};
+use serde_json::{from_value, json};
+use takumi::{
+  GlobalContext,
+  layout::{
+    Viewport,
+    node::{ImageNode, NodeKind},
+    style::{Length::Percentage, ObjectFit, StyleBuilder},
+  },
+  rendering::{RenderOptionsBuilder, render},
+  resources::image::ImageSource,
 };
 
 use crate::test_utils::run_fixture_test;
@@ -8,7 +18,9 @@ use crate::test_utils::run_fixture_test;
 #[test]
 fn test_style_object_fit_contain() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -29,7 +41,9 @@ fn test_style_object_fit_contain() {
 #[test]
 fn test_style_object_fit_cover() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -50,7 +64,9 @@ fn test_style_object_fit_cover() {
 #[test]
 fn test_style_object_fit_fill() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -71,7 +87,9 @@ fn test_style_object_fit_fill() {
 #[test]
 fn test_style_object_fit_none() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -92,7 +110,9 @@ fn test_style_object_fit_none() {
 #[test]
 fn test_style_object_fit_scale_down() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -109,3 +129,64 @@ fn test_style_object_fit_scale_down() {
 
   run_fixture_test(image.into(), "style_object_fit_scale_down");
 }
+
+const INSIDE: Rgba<u8> = Rgba([0, 255, 0, 255]);
+const OUTSIDE: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// Renders a 1000px image with `object-fit: none` into a 200px box.
+///
+/// Only the 200px square of the image starting at `window` is painted green.
+fn render_cropped(object_position: &str, window: (u32, u32)) -> RgbaImage {
+  let source = RgbaImage::from_fn(1000, 1000, |x, y| {
+    if (window.0..window.0 + 200).contains(&x) && (window.1..window.1 + 200).contains(&y) {
+      INSIDE
+    } else {
+      OUTSIDE
+    }
+  });
+
+  let mut context = GlobalContext::default();
+  context.persistent_image_store.insert(
+    "large.png".to_string(),
+    Arc::new(ImageSource::Bitmap(source)),
+  );
+
+  let node: NodeKind = from_value(json!({
+    "type": "image",
+    "src": "large.png",
+    "style": {
+      "width": "200px",
+      "height": "200px",
+      "objectFit": "none",
+      "objectPosition": object_position,
+    },
+  }))
+  .unwrap();
+
+  render(
+    RenderOptionsBuilder::default()
+      .viewport(Viewport::new(Some(200), Some(200)))
+      .node(node)
+      .global(&context)
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_object_fit_none_crops_to_object_position() {
+  for (object_position, window) in [
+    ("bottom right", (800, 800)),
+    ("top left", (0, 0)),
+    ("center", (400, 400)),
+    ("-300px -100px", (300, 100)),
+  ] {
+    let image = render_cropped(object_position, window);
+
+    assert!(
+      image.pixels().all(|pixel| *pixel == INSIDE),
+      "expected only the crop window for `{object_position}`"
+    );
+  }
+}
@@ -1,8 +1,9 @@
 use takumi::layout::{
   node::ImageNode,
   style::{
-    BackgroundPosition, Length::Percentage, ObjectFit, PositionComponent, PositionKeywordX,
-    PositionKeywordY, SpacePair, StyleBuilder,
+    BackgroundPosition,
+    Length::{Percentage, Px},
+    ObjectFit, PositionComponent, PositionKeywordX, PositionKeywordY, SpacePair, StyleBuilder,
   },
 };
 
@@ -11,7 +12,9 @@ use crate::test_utils::run_fixture_test;
 #[test]
 fn test_style_object_position_contain_center() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -35,7 +38,9 @@ fn test_style_object_position_contain_center() {
 #[test]
 fn test_style_object_position_contain_top_left() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -60,7 +65,9 @@ fn test_style_object_position_contain_top_left() {
 #[test]
 fn test_style_object_position_contain_bottom_right() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -85,7 +92,9 @@ fn test_style_object_position_contain_bottom_right() {
 #[test]
 fn test_style_object_position_cover_center() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -110,7 +119,9 @@ fn test_style_object_position_cover_center() {
 #[test]
 fn test_style_object_position_cover_top_left() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -135,7 +146,9 @@ fn test_style_object_position_cover_top_left() {
 #[test]
 fn test_style_object_position_none_center() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -160,7 +173,9 @@ fn test_style_object_position_none_center() {
 #[test]
 fn test_style_object_position_none_top_left() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -185,7 +200,9 @@ fn test_style_object_position_none_top_left() {
 #[test]
 fn test_style_object_position_percentage_25_75() {
   let image = ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -206,3 +223,30 @@ fn test_style_object_position_percentage_25_75() {
 
   run_fixture_test(image.into(), "style_object_position_percentage_25_75");
 }
+
+#[test]
+fn test_style_object_position_none_bottom_right_crop() {
+  let image = ImageNode {
+    sources: None,
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(200.0))
+        .height(Px(200.0))
+        .object_fit(ObjectFit::None)
+        .object_position(BackgroundPosition(SpacePair::from_pair(
+          PositionComponent::KeywordX(PositionKeywordX::Right),
+          PositionComponent::KeywordY(PositionKeywordY::Bottom),
+        )))
+        .build()
+        .unwrap(),
+    ),
+    width: None,
+    height: None,
+    src: "assets/images/luma-cover-0dfbf65d-0f58-4941-947c-d84a5b131dc0.jpeg".into(),
+  };
+
+  run_fixture_test(image.into(), "style_object_position_none_bottom_right_crop");
+}
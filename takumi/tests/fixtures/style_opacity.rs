@@ -1,13 +1,19 @@
-use takumi::layout::{
-  node::{ContainerNode, ImageNode, NodeKind, TextNode},
-  style::{PercentageNumber, *},
+use image::RgbaImage;
+use serde_json::json;
+use takumi::{
+  layout::{
+    node::{ContainerNode, ImageNode, NodeKind, TextNode},
+    style::{PercentageNumber, *},
+  },
+  rendering::AlphaMode,
 };
 
-use crate::test_utils::run_fixture_test;
+use crate::test_utils::{render_json, render_json_with_options, run_fixture_test};
 
 fn create_test_container(opacity: f32) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -26,7 +32,10 @@ fn create_test_container(opacity: f32) -> NodeKind {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: None,
         text: opacity.to_string(),
       }
@@ -41,6 +50,7 @@ fn create_test_container(opacity: f32) -> NodeKind {
 fn test_style_opacity() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -71,6 +81,7 @@ fn test_style_opacity() {
 fn test_style_opacity_image_with_text() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -88,6 +99,7 @@ fn test_style_opacity_image_with_text() {
       [
         ContainerNode {
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -99,7 +111,9 @@ fn test_style_opacity_image_with_text() {
           ),
           children: Some(
             [ImageNode {
+              sources: None,
               preset: None,
+              id: None,
               tw: None,
               style: Some(
                 StyleBuilder::default()
@@ -119,7 +133,10 @@ fn test_style_opacity_image_with_text() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .font_size(Some(Length::Rem(3.0)))
@@ -139,3 +156,150 @@ fn test_style_opacity_image_with_text() {
 
   run_fixture_test(container.into(), "style_opacity_image_with_text");
 }
+
+#[test]
+fn test_style_opacity_overlapping_children() {
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Length::Percentage(100.0))
+        .height(Length::Percentage(100.0))
+        .background_color(ColorInput::Value(Color([255, 255, 255, 255])))
+        .opacity(PercentageNumber(0.5))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        ContainerNode {
+          preset: None,
+          id: None,
+          tw: None,
+          style: Some(
+            StyleBuilder::default()
+              .position(Position::Absolute)
+              .top(Some(Length::Rem(5.0)))
+              .left(Some(Length::Rem(10.0)))
+              .width(Length::Rem(16.0))
+              .height(Length::Rem(16.0))
+              .border_radius(BorderRadius(Sides(
+                [SpacePair::from_single(Length::Percentage(50.0)); 4],
+              )))
+              .background_color(ColorInput::Value(Color([0, 0, 255, 255])))
+              .build()
+              .unwrap(),
+          ),
+          children: None,
+        }
+        .into(),
+        ContainerNode {
+          preset: None,
+          id: None,
+          tw: None,
+          style: Some(
+            StyleBuilder::default()
+              .position(Position::Absolute)
+              .top(Some(Length::Rem(5.0)))
+              .left(Some(Length::Rem(20.0)))
+              .width(Length::Rem(16.0))
+              .height(Length::Rem(16.0))
+              .border_radius(BorderRadius(Sides(
+                [SpacePair::from_single(Length::Percentage(50.0)); 4],
+              )))
+              .background_color(ColorInput::Value(Color([0, 0, 255, 255])))
+              .build()
+              .unwrap(),
+          ),
+          children: None,
+        }
+        .into(),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_opacity_overlapping_children");
+}
+
+fn circle(left: u32) -> serde_json::Value {
+  json!({
+    "type": "container",
+    "style": {
+      "position": "absolute",
+      "top": "10px",
+      "left": format!("{left}px"),
+      "width": "80px",
+      "height": "80px",
+      "borderRadius": "50%",
+      "backgroundColor": "blue",
+    }
+  })
+}
+
+fn render_overlapping_circles() -> RgbaImage {
+  render_json(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "200px",
+        "height": "100px",
+        "opacity": 0.5,
+      },
+      "children": [circle(20), circle(90)],
+    }),
+    (200, 100),
+  )
+}
+
+#[test]
+fn test_opacity_composites_subtree_as_a_group() {
+  let image = render_overlapping_circles();
+
+  let single = image.get_pixel(50, 50).0;
+  let overlap = image.get_pixel(105, 50).0;
+
+  assert!((i32::from(single[3]) - 128).abs() <= 1);
+  assert_eq!(overlap, single);
+}
+
+fn render_translucent_pixel(alpha_mode: AlphaMode) -> [u8; 4] {
+  let image = render_json_with_options(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "100%",
+        "height": "100%",
+        "backgroundColor": "rgba(200, 100, 50, 0.5)",
+      },
+    }),
+    (4, 4),
+    |options| {
+      options.alpha_mode(alpha_mode);
+    },
+  )
+  .unwrap();
+
+  image.get_pixel(2, 2).0
+}
+
+#[test]
+fn test_premultiplied_alpha_mode_scales_color_by_alpha() {
+  let straight = render_translucent_pixel(AlphaMode::Straight);
+  let premultiplied = render_translucent_pixel(AlphaMode::Premultiplied);
+
+  assert_eq!(straight[3], 128);
+  assert_eq!(premultiplied[3], straight[3]);
+
+  for channel in 0..3 {
+    let expected = u32::from(straight[channel]) * u32::from(straight[3]) / 255;
+
+    assert!(
+      u32::from(premultiplied[channel]).abs_diff(expected) <= 1,
+      "channel {channel} is {}, expected {expected}",
+      premultiplied[channel]
+    );
+  }
+}
@@ -1,13 +1,16 @@
+use image::RgbaImage;
+use serde_json::json;
 use takumi::layout::{
   node::{ContainerNode, ImageNode, NodeKind, TextNode},
   style::{Length::*, *},
 };
 
-use crate::test_utils::run_fixture_test;
+use crate::test_utils::{render_json, run_fixture_test};
 
 fn create_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -22,6 +25,7 @@ fn create_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -37,7 +41,9 @@ fn create_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
         ),
         children: Some(
           [ImageNode {
+            sources: None,
             preset: None,
+            id: None,
             tw: None,
             style: Some(
               StyleBuilder::default()
@@ -67,6 +73,7 @@ fn create_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
 fn create_text_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -80,6 +87,7 @@ fn create_text_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
     ),
     children: Some([ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -96,7 +104,10 @@ fn create_text_overflow_fixture(overflows: SpacePair<Overflow>) -> NodeKind {
         children: Some([
           TextNode {
             preset: None,
+            id: None,
             tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
             style: Some(
               StyleBuilder::default()
               .font_size(Some(Rem(4.0)))
@@ -172,3 +183,332 @@ fn test_text_overflow_mixed_axes() {
 
   run_fixture_test(container, "style_overflow_hidden_visible_text");
 }
+
+#[test]
+fn test_overflow_scroll_rounded_with_scrollbar_gutter() {
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .background_color(ColorInput::Value(Color::white()))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .display(Display::Block)
+            .width(Px(200.0))
+            .height(Px(200.0))
+            .border_width(Some(Sides([Px(4.0); 4])))
+            .border_style(Some(BorderStyle::Solid))
+            .border_color(Some(Color([255, 0, 0, 255]).into()))
+            .border_radius(BorderRadius(Sides([SpacePair::from_single(Px(40.0)); 4])))
+            .background_color(ColorInput::Value(Color([230, 230, 230, 255])))
+            .overflow(SpacePair::from_single(Overflow::Scroll))
+            .scrollbar_gutter(ScrollbarGutter::Stable)
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [ImageNode {
+            sources: None,
+            preset: None,
+            id: None,
+            tw: None,
+            style: Some(
+              StyleBuilder::default()
+                .width(Px(300.0))
+                .height(Px(300.0))
+                .build()
+                .unwrap(),
+            ),
+            width: None,
+            height: None,
+            src: "assets/images/yeecord.png".into(),
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  run_fixture_test(container, "style_overflow_scroll_rounded_scrollbar_gutter");
+}
+
+fn create_translated_child_fixture(
+  overflows: SpacePair<Overflow>,
+  clip_margin: Length<false>,
+) -> NodeKind {
+  ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .background_color(ColorInput::Value(Color::white()))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .display(Display::Block)
+            .width(Px(200.0))
+            .height(Px(200.0))
+            .border_width(Some(Sides([Px(4.0); 4])))
+            .border_style(Some(BorderStyle::Solid))
+            .border_color(Some(Color([255, 0, 0, 255]).into()))
+            .overflow(overflows)
+            .overflow_clip_margin(clip_margin)
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [ContainerNode {
+            preset: None,
+            id: None,
+            tw: None,
+            style: Some(
+              StyleBuilder::default()
+                .width(Px(120.0))
+                .height(Px(120.0))
+                .translate(Some(SpacePair::from_pair(Px(-60.0), Px(-60.0))))
+                .background_color(ColorInput::Value(Color([0, 128, 255, 255])))
+                .build()
+                .unwrap(),
+            ),
+            children: None,
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into()
+}
+
+#[test]
+fn test_overflow_visible_translated_child() {
+  let container =
+    create_translated_child_fixture(SpacePair::from_single(Overflow::Visible), Px(0.0));
+
+  run_fixture_test(container, "style_overflow_visible_translated_child");
+}
+
+#[test]
+fn test_overflow_clip_x_translated_child() {
+  let container = create_translated_child_fixture(
+    SpacePair::from_pair(Overflow::Clip, Overflow::Visible),
+    Px(0.0),
+  );
+
+  run_fixture_test(container, "style_overflow_clip_x_translated_child");
+}
+
+#[test]
+fn test_overflow_clip_margin() {
+  let container = create_translated_child_fixture(SpacePair::from_single(Overflow::Clip), Px(20.0));
+
+  run_fixture_test(container, "style_overflow_clip_margin");
+}
+
+#[test]
+fn test_overflow_hidden_rotated_rounded_image() {
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .background_color(ColorInput::Value(Color::white()))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .display(Display::Block)
+            .width(Px(240.0))
+            .height(Px(240.0))
+            .border_radius(BorderRadius::from_str("32px").unwrap())
+            .rotate(Some(Angle::new(15.0)))
+            .overflow(SpacePair::from_single(Overflow::Hidden))
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [ImageNode {
+            sources: None,
+            preset: None,
+            id: None,
+            tw: None,
+            style: Some(
+              StyleBuilder::default()
+                .width(Px(300.0))
+                .height(Px(300.0))
+                .build()
+                .unwrap(),
+            ),
+            width: None,
+            height: None,
+            src: "assets/images/yeecord.png".into(),
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(
+    container.into(),
+    "style_overflow_hidden_rotated_rounded_image",
+  );
+}
+
+#[test]
+fn test_text_overflow_ellipsis_on_shrunk_flex_item() {
+  // Only the second label truncates, the first one keeps its content width.
+  let label = |text: &str, color: Color, text_overflow: TextOverflow| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .padding(Sides([Px(16.0); 4]))
+          .background_color(ColorInput::Value(color))
+          .white_space(WhiteSpace::no_wrap())
+          .text_overflow(text_overflow)
+          .build()
+          .unwrap(),
+      ),
+      text: text.to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .background_color(ColorInput::Value(Color::white()))
+        .align_items(AlignItems::Center)
+        .font_size(Some(Px(48.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        label("Status", Color([191, 219, 254, 255]), TextOverflow::Clip),
+        label(
+          "A very long label that cannot fit in the remaining width of the row",
+          Color([254, 215, 170, 255]),
+          TextOverflow::Ellipsis,
+        ),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(
+    container.into(),
+    "style_text_overflow_ellipsis_shrunk_flex_item",
+  );
+}
+
+fn render_rotated_clipped_card() -> RgbaImage {
+  render_json(
+    json!({
+      "type": "container",
+      "style": {
+        "width": "200px",
+        "height": "200px",
+        "backgroundColor": "white",
+        "alignItems": "center",
+        "justifyContent": "center",
+      },
+      "children": [{
+        "type": "container",
+        "style": {
+          "width": "120px",
+          "height": "120px",
+          "borderRadius": "24px",
+          "rotate": "30deg",
+          "overflow": "hidden",
+        },
+        "children": [{
+          "type": "container",
+          "style": {
+            "position": "absolute",
+            "top": "-40px",
+            "left": "-40px",
+            "width": "200px",
+            "height": "200px",
+            "backgroundColor": "black",
+          }
+        }],
+      }],
+    }),
+    (200, 200),
+  )
+}
+
+#[test]
+fn test_rotated_overflow_clip_is_antialiased() {
+  let image = render_rotated_clipped_card();
+
+  // The child overflows on every side, so all edges of the card come from the overflow clip.
+  let partial = (90..110)
+    .flat_map(|y| (0..100).map(move |x| (x, y)))
+    .filter(|&(x, y)| (16..240).contains(&image.get_pixel(x, y).0[0]))
+    .count();
+
+  assert!(
+    partial >= 10,
+    "expected antialiased clip edges, got {partial}"
+  );
+}
@@ -13,6 +13,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_padding() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -26,6 +27,7 @@ fn test_style_padding() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
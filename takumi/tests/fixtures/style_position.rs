@@ -13,6 +13,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_position() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -25,6 +26,7 @@ fn test_style_position() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -45,3 +47,62 @@ fn test_style_position() {
 
   run_fixture_test(container.into(), "style_position");
 }
+
+#[test]
+fn test_style_position_fixed_badge() {
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .background_color(ColorInput::Value(Color([0, 0, 255, 255])))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .width(Px(400.0))
+            .height(Px(200.0))
+            .position(Position::Relative)
+            .background_color(ColorInput::Value(Color([255, 255, 255, 255])))
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [ContainerNode {
+            preset: None,
+            id: None,
+            tw: None,
+            style: Some(
+              StyleBuilder::default()
+                .width(Px(120.0))
+                .height(Px(40.0))
+                .position(Position::Fixed) // Anchored to the viewport, not the white box
+                .right(Some(Px(16.0)))
+                .bottom(Some(Px(16.0)))
+                .background_color(ColorInput::Value(Color([255, 0, 0, 255])))
+                .build()
+                .unwrap(),
+            ),
+            children: None,
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_position_fixed_badge");
+}
@@ -13,6 +13,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_width() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -32,6 +33,7 @@ fn test_style_width() {
 fn test_style_height() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -51,6 +53,7 @@ fn test_style_height() {
 fn test_style_min_width() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -71,6 +74,7 @@ fn test_style_min_width() {
 fn test_style_min_height() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -91,6 +95,7 @@ fn test_style_min_height() {
 fn test_style_max_width() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -111,6 +116,7 @@ fn test_style_max_width() {
 fn test_style_max_height() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -13,6 +13,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_margin() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -25,6 +26,7 @@ fn test_style_margin() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -49,6 +51,7 @@ fn test_style_margin() {
 fn test_style_padding() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -62,6 +65,7 @@ fn test_style_padding() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
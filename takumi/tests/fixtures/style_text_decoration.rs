@@ -9,7 +9,10 @@ use crate::test_utils::run_fixture_test;
 fn test_style_text_decoration() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .width(Percentage(100.0))
@@ -36,7 +39,10 @@ fn text_decoration_skip_ink_parapsychologists() {
   let make_line = |label: &str, skip_ink: TextDecorationSkipInk| {
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .width(Percentage(100.0))
@@ -59,6 +65,7 @@ fn text_decoration_skip_ink_parapsychologists() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -85,3 +92,112 @@ fn text_decoration_skip_ink_parapsychologists() {
     "text_decoration_skip_ink_parapsychologists",
   );
 }
+
+#[test]
+fn text_underline_position_under() {
+  let make_line = |label: &str, position: TextUnderlinePosition| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(Percentage(100.0))
+          .text_align(TextAlign::Center)
+          .font_size(Some(Px(96.0)))
+          .text_decoration(TextDecoration {
+            line: TextDecorationLines::UNDERLINE,
+            style: None,
+            color: Some(ColorInput::Value(Color([255, 0, 0, 255]))),
+            thickness: None,
+          })
+          .text_decoration_skip_ink(TextDecorationSkipInk::None)
+          .text_underline_position(position)
+          .build()
+          .unwrap(),
+      ),
+      text: format!("{label}: typography"),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .row_gap(Some(Px(28.0)))
+        .padding_top(Some(Px(40.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        make_line("auto", TextUnderlinePosition::Auto),
+        make_line("under", TextUnderlinePosition::Under),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_underline_position_under");
+}
+
+#[test]
+fn text_emphasis_japanese() {
+  let make_line = |emphasis: &str| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_family(FontFamily::from_str("Noto Sans TC").ok())
+          .font_size(Some(Px(64.0)))
+          .line_height(LineHeight::Unitless(2.0))
+          .text_emphasis(TextEmphasis::from_str(emphasis).unwrap())
+          .build()
+          .unwrap(),
+      ),
+      text: "これは重要です".to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .align_items(AlignItems::Center)
+        .padding_top(Some(Px(40.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        make_line("filled dot"),
+        make_line("open circle red"),
+        make_line("filled double-circle"),
+        make_line("open triangle #3b82f6"),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_emphasis_japanese");
+}
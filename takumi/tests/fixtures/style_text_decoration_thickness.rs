@@ -10,7 +10,10 @@ fn test_style_text_decoration_thickness() {
   let make_line = |label: &str, thickness: TextDecorationThickness| {
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .width(Percentage(100.0))
@@ -32,6 +35,7 @@ fn test_style_text_decoration_thickness() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -14,6 +14,7 @@ const ROTATED_ANGLES: &[f32] = &[0.0, 45.0, 90.0, 135.0, 180.0, 225.0, 270.0, 31
 fn test_rotate_image() {
   let image = ContainerNode {
     preset: None,
+    id: None,
     style: Some(
       StyleBuilder::default()
         .width(Percentage(100.0))
@@ -27,7 +28,9 @@ fn test_rotate_image() {
     tw: None,
     children: Some(
       [ImageNode {
+        sources: None,
         preset: None,
+        id: None,
         style: Some(
           StyleBuilder::default()
             .rotate(Some(Angle::new(90.0)))
@@ -51,6 +54,7 @@ fn test_rotate_image() {
 fn test_rotate() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -65,6 +69,7 @@ fn test_rotate() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         style: Some(
           StyleBuilder::default()
             .width(Rem(16.0))
@@ -89,6 +94,7 @@ fn test_rotate() {
 fn test_style_transform_origin_center() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -110,6 +116,7 @@ fn test_style_transform_origin_center() {
 fn test_style_transform_origin_top_left() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -143,7 +150,9 @@ fn test_style_transform_origin_top_left() {
 
 fn create_rotated_container(angle: f32, transform_origin: BackgroundPosition) -> ImageNode {
   ImageNode {
+    sources: None,
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -172,6 +181,7 @@ fn create_rotated_container(angle: f32, transform_origin: BackgroundPosition) ->
 fn test_style_transform_translate_and_scale() {
   let mut container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -188,6 +198,7 @@ fn test_style_transform_translate_and_scale() {
 
   let position = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -200,8 +211,11 @@ fn test_style_transform_translate_and_scale() {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         text: "200px x 100px".to_string(),
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: None,
       }
       .into()]
@@ -211,6 +225,7 @@ fn test_style_transform_translate_and_scale() {
 
   let translated = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -225,7 +240,9 @@ fn test_style_transform_translate_and_scale() {
     ),
     children: Some(
       [ImageNode {
+        sources: None,
         preset: None,
+        id: None,
         tw: None,
         src: "assets/images/yeecord.png".into(),
         style: Some(
@@ -245,6 +262,7 @@ fn test_style_transform_translate_and_scale() {
 
   let scaled = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -261,8 +279,11 @@ fn test_style_transform_translate_and_scale() {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         text: "100px x 100px, scale(2.0, 2.0)".to_string(),
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: None,
       }
       .into()]
@@ -272,6 +293,7 @@ fn test_style_transform_translate_and_scale() {
 
   let rotated = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -289,8 +311,11 @@ fn test_style_transform_translate_and_scale() {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         text: "200px x 200px, rotate(45deg)".to_string(),
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: None,
       }
       .into()]
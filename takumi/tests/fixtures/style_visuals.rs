@@ -1,6 +1,6 @@
 use serde_json::{from_value, json};
 use takumi::layout::{
-  node::{ContainerNode, ImageNode, TextNode},
+  node::{ContainerNode, ImageNode, NodeKind, TextNode},
   style::{Length::*, *},
 };
 
@@ -10,6 +10,7 @@ use crate::test_utils::run_fixture_test;
 fn test_style_background_color() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -29,6 +30,7 @@ fn test_style_background_color() {
 fn test_style_border_radius() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -49,6 +51,7 @@ fn test_style_border_radius() {
 fn test_style_border_radius_per_corner() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -73,6 +76,7 @@ fn test_style_border_radius_per_corner() {
 fn test_style_border_width() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -95,6 +99,7 @@ fn test_style_border_width() {
 fn test_style_border_width_with_radius() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -108,6 +113,7 @@ fn test_style_border_width_with_radius() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -134,6 +140,7 @@ fn test_style_border_width_with_radius() {
 fn test_style_box_shadow() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -146,6 +153,7 @@ fn test_style_box_shadow() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -180,6 +188,7 @@ fn test_style_box_shadow() {
 fn test_style_box_shadow_inset() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -192,6 +201,7 @@ fn test_style_box_shadow_inset() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -223,10 +233,120 @@ fn test_style_box_shadow_inset() {
   run_fixture_test(container.into(), "style_box_shadow_inset");
 }
 
+#[test]
+fn test_style_box_shadow_inset_with_border() {
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .justify_content(JustifyContent::Center)
+        .align_items(AlignItems::Center)
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .width(Px(240.0))
+            .height(Px(160.0))
+            .background_color(ColorInput::Value(Color([255, 255, 200, 255])))
+            .border_width(Some(Sides([Px(20.0); 4])))
+            .border_style(Some(BorderStyle::Solid))
+            .border_color(Some(ColorInput::Value(Color([255, 140, 0, 160]))))
+            .border_radius(BorderRadius(Sides([SpacePair::from_single(Px(48.0)); 4])))
+            .box_shadow(Some(
+              [BoxShadow {
+                color: ColorInput::Value(Color([0, 0, 0, 200])),
+                offset_x: Px(0.0),
+                offset_y: Px(0.0),
+                blur_radius: Px(12.0),
+                spread_radius: Px(10.0),
+                inset: true,
+              }]
+              .into(),
+            ))
+            .build()
+            .unwrap(),
+        ),
+        children: None,
+      }
+      .into()]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "style_box_shadow_inset_with_border");
+}
+
+#[test]
+fn test_style_box_shadow_inset_circle() {
+  let circle = |spread_radius: f32| {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(Px(240.0))
+          .height(Px(240.0))
+          .background_color(ColorInput::Value(Color::white()))
+          .border_radius(BorderRadius(Sides(
+            [SpacePair::from_single(Percentage(50.0)); 4],
+          )))
+          .box_shadow(Some(
+            [BoxShadow {
+              color: ColorInput::Value(Color([0, 0, 0, 200])),
+              offset_x: Px(0.0),
+              offset_y: Px(0.0),
+              blur_radius: Px(60.0),
+              spread_radius: Px(spread_radius),
+              inset: true,
+            }]
+            .into(),
+          ))
+          .build()
+          .unwrap(),
+      ),
+      children: None,
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .justify_content(JustifyContent::Center)
+        .align_items(AlignItems::Center)
+        .gap(SpacePair::from_single(Px(48.0)))
+        .background_color(ColorInput::Value(Color([59, 130, 246, 255])))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([circle(24.0), circle(-24.0)].into()),
+  };
+
+  run_fixture_test(container.into(), "style_box_shadow_inset_circle");
+}
+
 #[test]
 fn test_style_position() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -239,6 +359,7 @@ fn test_style_position() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -264,6 +385,7 @@ fn test_style_position() {
 fn test_style_border_radius_circle() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -287,6 +409,7 @@ fn test_style_border_radius_circle() {
 fn test_style_border_radius_width_offset() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -300,6 +423,7 @@ fn test_style_border_radius_width_offset() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -316,7 +440,10 @@ fn test_style_border_radius_width_offset() {
         children: Some(
           [TextNode {
             preset: None,
+            id: None,
             tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
             text: "The newest blog post".to_string(),
             style: Some(
               StyleBuilder::default()
@@ -345,6 +472,7 @@ fn test_style_border_radius_width_offset() {
 fn test_style_border_radius_circle_avatar() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -359,6 +487,7 @@ fn test_style_border_radius_circle_avatar() {
     children: Some(
       [ContainerNode {
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -375,7 +504,9 @@ fn test_style_border_radius_circle_avatar() {
         ),
         children: Some(
           [ImageNode {
+            sources: None,
             preset: None,
+            id: None,
             tw: None,
             style: Some(
               StyleBuilder::default()
@@ -420,6 +551,7 @@ fn test_style_border_width_on_image_node() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -455,6 +587,7 @@ fn test_style_outline() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -471,3 +604,130 @@ fn test_style_outline() {
 
   run_fixture_test(container.into(), "style_outline");
 }
+
+#[test]
+fn test_style_border_image_rounded_frame() {
+  let frame = |border_image_repeat: &str, border_image_slice: &str| {
+    json!({
+      "type": "container",
+      "style": {
+        "width": "320px",
+        "height": "160px",
+        "borderWidth": "28px",
+        "borderStyle": "solid",
+        "borderColor": "red",
+        "borderImageSource": "url(assets/images/rounded-frame.png)",
+        "borderImageSlice": border_image_slice,
+        "borderImageRepeat": border_image_repeat,
+      }
+    })
+  };
+
+  let container: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "display": "flex",
+      "flexWrap": "wrap",
+      "alignItems": "center",
+      "justifyContent": "center",
+      "gap": "32px",
+      "backgroundColor": "white",
+    },
+    "children": [
+      frame("stretch", "30 fill"),
+      frame("round", "30"),
+      frame("repeat space", "30 fill"),
+    ],
+  }))
+  .unwrap();
+
+  run_fixture_test(container, "style_border_image_rounded_frame");
+}
+
+#[test]
+fn test_style_image_rendering_checkerboard() {
+  // The 32px checkerboard is scaled 2.5x, so each 1px cell covers 2.5px.
+  let checkerboard = |image_rendering: &str| {
+    json!({
+      "type": "image",
+      "src": "assets/images/checkerboard.png",
+      "style": {
+        "width": "80px",
+        "height": "80px",
+        "imageRendering": image_rendering,
+      }
+    })
+  };
+
+  let container: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "display": "flex",
+      "alignItems": "center",
+      "justifyContent": "center",
+      "gap": "32px",
+      "backgroundColor": "white",
+    },
+    "children": [
+      checkerboard("auto"),
+      checkerboard("smooth"),
+      checkerboard("pixelated"),
+      checkerboard("crisp-edges"),
+    ],
+  }))
+  .unwrap();
+
+  run_fixture_test(container, "style_image_rendering_checkerboard");
+}
+
+#[test]
+fn test_style_border_collapse_grid() {
+  let cell = |border_width: u32, text: &str| {
+    json!({
+      "type": "text",
+      "text": text,
+      "style": {
+        "padding": 16,
+        "fontSize": 32,
+        "borderWidth": border_width,
+        "borderStyle": "solid",
+        "borderColor": "#0f172a",
+      }
+    })
+  };
+
+  // The wider border of the last cell wins over the ones it shares with its neighbours.
+  let grid: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "display": "grid",
+      "gridTemplateColumns": "repeat(2, 240px)",
+      "borderCollapse": "collapse",
+    },
+    "children": [cell(4, "A1"), cell(4, "B1"), cell(4, "A2"), cell(10, "B2")],
+  }))
+  .unwrap();
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([grid].into()),
+  };
+
+  run_fixture_test(container.into(), "style_border_collapse_grid");
+}
@@ -8,6 +8,7 @@ use crate::test_utils::run_fixture_test;
 fn create_luma_logo_container() -> ContainerNode<NodeKind> {
   ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -24,7 +25,9 @@ fn create_luma_logo_container() -> ContainerNode<NodeKind> {
     ),
     children: Some(
       [NodeKind::Image(ImageNode {
+        sources: None,
         preset: None,
+        id: None,
         tw: None,
         style: Some(
           StyleBuilder::default()
@@ -0,0 +1,88 @@
+use std::{fs::read, path::Path};
+
+use serde_json::{from_value, json};
+use takumi::{
+  GlobalContext,
+  layout::{Viewport, node::NodeKind},
+  rendering::{RenderOptionsBuilder, render_to_svg},
+};
+
+fn svg_of(node: NodeKind) -> String {
+  let mut context = GlobalContext::default();
+
+  let font_data =
+    read(Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets/fonts/geist/Geist[wght].woff2"))
+      .unwrap();
+
+  context
+    .font_context
+    .load_and_store(font_data.into(), None, None)
+    .unwrap();
+
+  render_to_svg(
+    RenderOptionsBuilder::default()
+      .viewport(Viewport::new(Some(200), Some(100)))
+      .node(node)
+      .global(&context)
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_render_to_svg_emits_rects_and_text() {
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "backgroundColor": "red",
+      "border": "2px solid blue",
+      "padding": "10px",
+    },
+    "children": [
+      {
+        "type": "text",
+        "text": "Hello & <world>",
+        "style": { "fontSize": "24px", "color": "white" },
+      },
+    ],
+  }))
+  .unwrap();
+
+  let svg = svg_of(node);
+
+  assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="100""#));
+  assert!(svg.ends_with("</svg>"));
+
+  // Background color and the border ring.
+  assert!(svg.contains(r#"<rect x="0" y="0" width="200" height="100" fill="rgb(255,0,0)"/>"#));
+  assert!(svg.contains(r#"fill-rule="evenodd" fill="rgb(0,0,255)""#));
+
+  assert!(svg.contains("<text "));
+  assert!(svg.contains(r#"font-size="24""#));
+  assert!(svg.contains(r#"fill="rgb(255,255,255)""#));
+  assert!(svg.contains("Hello &amp; &lt;world&gt;"));
+}
+
+#[test]
+fn test_render_to_svg_emits_linear_gradient_defs() {
+  let node: NodeKind = from_value(json!({
+    "type": "container",
+    "style": {
+      "width": "100%",
+      "height": "100%",
+      "backgroundImage": "linear-gradient(to right, red, blue)",
+    },
+  }))
+  .unwrap();
+
+  let svg = svg_of(node);
+
+  assert!(svg.contains(r#"<linearGradient id="gradient-1" gradientUnits="userSpaceOnUse""#));
+  assert!(svg.contains(r#"<stop offset="0" stop-color="rgb(255,0,0)"/>"#));
+  assert!(svg.contains(r#"<stop offset="1" stop-color="rgb(0,0,255)"/>"#));
+  assert!(svg.contains(r#"<rect x="0" y="0" width="200" height="100" fill="url(#gradient-1)"/>"#));
+  assert!(!svg.contains("<image"));
+}
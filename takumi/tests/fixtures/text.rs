@@ -1,18 +1,21 @@
 use parley::FontVariation;
 use swash::tag_from_bytes;
 use takumi::layout::{
-  node::{ContainerNode, TextNode},
+  node::{ContainerNode, FontSizeFit, FontSizeFitMode, NodeKind, TextNode},
   style::{Length::*, *},
 };
 
-use crate::test_utils::run_fixture_test;
+use crate::test_utils::{run_fixture_test, run_fixture_test_with_options};
 
 // Basic text render with defaults
 #[test]
 fn text_basic() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -29,7 +32,10 @@ fn text_basic() {
 fn text_typography_regular_24px() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -52,7 +58,10 @@ fn text_typography_variable_width() {
     .map(|width| {
       TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .font_variation_settings(Some(
@@ -76,6 +85,7 @@ fn text_typography_variable_width() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -101,7 +111,10 @@ fn text_typography_variable_weight() {
     .map(|weight| {
       TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .font_size(Some(Px(48.0)))
@@ -117,6 +130,7 @@ fn text_typography_variable_weight() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -137,7 +151,10 @@ fn text_typography_variable_weight() {
 fn text_typography_medium_weight_500() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -156,7 +173,10 @@ fn text_typography_medium_weight_500() {
 fn text_typography_line_height_40px() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -175,7 +195,10 @@ fn text_typography_line_height_40px() {
 fn text_typography_letter_spacing_2px() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -194,7 +217,10 @@ fn text_typography_letter_spacing_2px() {
 fn text_align_start() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -214,7 +240,10 @@ fn text_align_start() {
 fn text_align_center() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -234,7 +263,10 @@ fn text_align_center() {
 fn text_align_right() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -256,7 +288,10 @@ fn text_ellipsis_line_clamp_2() {
 
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .width(Percentage(100.0))
@@ -273,10 +308,37 @@ fn text_ellipsis_line_clamp_2() {
   run_fixture_test(text.into(), "text_ellipsis_line_clamp_2");
 }
 
+// Without `text-overflow`, the clamped text is cut after the last line without an ellipsis
+#[test]
+fn text_line_clamp_2_clip() {
+  let long_text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat.";
+
+  let text = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+        .font_size(Some(Px(48.0)))
+        .line_clamp(Some(2.into()))
+        .build()
+        .unwrap(),
+    ),
+    text: long_text.to_string(),
+  };
+
+  run_fixture_test(text.into(), "text_line_clamp_2_clip");
+}
+
 #[test]
 fn text_transform_all() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -290,7 +352,10 @@ fn text_transform_all() {
       [
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .width(Percentage(100.0))
@@ -304,7 +369,10 @@ fn text_transform_all() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .width(Percentage(100.0))
@@ -318,7 +386,10 @@ fn text_transform_all() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .width(Percentage(100.0))
@@ -332,7 +403,10 @@ fn text_transform_all() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .width(Percentage(100.0))
@@ -361,6 +435,7 @@ fn text_mask_image_gradient_and_emoji() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -376,7 +451,10 @@ fn text_mask_image_gradient_and_emoji() {
     children: Some(
       [TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .background_image(Some(gradient_images))
@@ -402,7 +480,10 @@ fn text_mask_image_gradient_and_emoji() {
 fn text_stroke_black_red() {
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -428,7 +509,10 @@ fn text_stroke_background_clip() {
 
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_image(Some(gradient_images))
@@ -446,6 +530,7 @@ fn text_stroke_background_clip() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -476,7 +561,10 @@ fn text_shadow() {
 
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -503,7 +591,10 @@ fn text_shadow_no_blur_radius() {
 
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
@@ -518,12 +609,59 @@ fn text_shadow_no_blur_radius() {
   run_fixture_test(text.into(), "text_shadow_no_blur_radius");
 }
 
+#[test]
+fn text_shadow_layered_neon() {
+  // #ffffff 0 0 4px, #ff00de 0 0 12px, #00d9ff 0 0 28px
+  let shadows = [
+    TextShadow {
+      offset_x: Px(0.0),
+      offset_y: Px(0.0),
+      blur_radius: Px(4.0),
+      color: ColorInput::Value(Color([255, 255, 255, 255])),
+    },
+    TextShadow {
+      offset_x: Px(0.0),
+      offset_y: Px(0.0),
+      blur_radius: Px(12.0),
+      color: ColorInput::Value(Color([255, 0, 222, 255])),
+    },
+    TextShadow {
+      offset_x: Px(0.0),
+      offset_y: Px(0.0),
+      blur_radius: Px(28.0),
+      color: ColorInput::Value(Color([0, 217, 255, 255])),
+    },
+  ];
+
+  let text = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: Some(
+      StyleBuilder::default()
+        .background_color(ColorInput::Value(Color([12, 10, 24, 255])))
+        .color(ColorInput::Value(Color([255, 240, 252, 255])))
+        .font_size(Some(Px(96.0)))
+        .padding(Sides([Px(48.0); 4]))
+        .text_shadow(Some(shadows.into()))
+        .build()
+        .unwrap(),
+    ),
+    text: "Neon".to_string(),
+  };
+
+  run_fixture_test(text.into(), "text_shadow_layered_neon");
+}
+
 #[test]
 fn text_wrap_nowrap() {
   let long_text = "This is a very long piece of text that should demonstrate text wrapping behavior when it exceeds the container width. The quick brown fox jumps over the lazy dog.";
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -543,7 +681,10 @@ fn text_wrap_nowrap() {
         // Wrap text
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .text_wrap_mode(Some(TextWrapMode::Wrap))
@@ -555,7 +696,10 @@ fn text_wrap_nowrap() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .text_wrap_mode(Some(TextWrapMode::NoWrap))
@@ -577,6 +721,7 @@ fn text_wrap_nowrap() {
 fn text_whitespace_collapse() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -595,7 +740,10 @@ fn text_whitespace_collapse() {
       [
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .white_space_collapse(Some(WhiteSpaceCollapse::Collapse))
@@ -607,7 +755,10 @@ fn text_whitespace_collapse() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .white_space_collapse(Some(WhiteSpaceCollapse::Preserve))
@@ -619,7 +770,10 @@ fn text_whitespace_collapse() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .white_space_collapse(Some(WhiteSpaceCollapse::PreserveSpaces))
@@ -631,7 +785,10 @@ fn text_whitespace_collapse() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .white_space_collapse(Some(WhiteSpaceCollapse::PreserveBreaks))
@@ -654,6 +811,7 @@ fn text_whitespace_collapse() {
 fn text_ellipsis_text_nowrap() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -668,7 +826,10 @@ fn text_ellipsis_text_nowrap() {
     children: Some([
       TextNode {
     preset: None,
+    id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .text_overflow(TextOverflow::Ellipsis)
@@ -690,10 +851,53 @@ fn text_ellipsis_text_nowrap() {
   run_fixture_test(container.into(), "text_ellipsis_text_nowrap");
 }
 
+#[test]
+fn text_ellipsis_middle_url() {
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+        .font_size(Some(Px(36.0)))
+        .padding(Sides([Px(20.0); 4]))
+        .width(Percentage(100.0))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([
+      TextNode {
+        preset: None,
+        id: None,
+        tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
+        style: Some(
+          StyleBuilder::default()
+            .text_overflow(TextOverflow::EllipsisMiddle)
+            .text_wrap_mode(Some(TextWrapMode::NoWrap))
+            .border_width(Some(Sides([Px(1.0); 4])))
+            .border_style(Some(BorderStyle::Solid))
+            .border_color(Some(ColorInput::Value(Color([255, 0, 0, 255]))))
+            .width(Percentage(100.0))
+            .build()
+            .unwrap(),
+        ),
+        text: "https://example.com/assets/images/2024/very/deeply/nested/directory/structure/final-image.png".to_string(),
+      }
+      .into(),
+    ].into()),
+  };
+
+  run_fixture_test(container.into(), "text_ellipsis_middle_url");
+}
+
 #[test]
 fn text_wrap_style_all() {
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -713,7 +917,10 @@ fn text_wrap_style_all() {
         // Auto (default) - standard line breaking
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .text_wrap_style(Some(TextWrapStyle::Auto))
@@ -726,7 +933,10 @@ fn text_wrap_style_all() {
         // Balance - evenly distributes text across lines
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .text_wrap_style(Some(TextWrapStyle::Balance))
@@ -739,7 +949,10 @@ fn text_wrap_style_all() {
         // Pretty - avoids orphans on the last line (text ends with short word "it")
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .text_wrap_style(Some(TextWrapStyle::Pretty))
@@ -766,7 +979,10 @@ fn text_super_bold_stroke_background_clip() {
 
   let text = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .background_image(Some(gradient_images))
@@ -785,6 +1001,7 @@ fn text_super_bold_stroke_background_clip() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -831,7 +1048,10 @@ fn text_font_stretch() {
     .map(|(label, stretch)| {
       TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .font_size(Some(Px(36.0)))
@@ -847,6 +1067,7 @@ fn text_font_stretch() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -872,7 +1093,10 @@ fn text_font_synthesis_weight_auto_none() {
     .map(|(label, synthesis_weight)| {
       TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .font_size(Some(Px(72.0)))
@@ -890,6 +1114,7 @@ fn text_font_synthesis_weight_auto_none() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -914,7 +1139,10 @@ fn text_font_synthesis_style_auto_none() {
     .map(|(label, synthesis_style)| {
       TextNode {
         preset: None,
+        id: None,
         tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
         style: Some(
           StyleBuilder::default()
             .font_size(Some(Px(72.0)))
@@ -932,6 +1160,7 @@ fn text_font_synthesis_style_auto_none() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -971,7 +1200,10 @@ fn text_font_synthesis_weight_emoji() {
   .map(|(label, synthesis)| {
     TextNode {
       preset: None,
+      id: None,
       tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
       style: Some(
         StyleBuilder::default()
           .font_size(Some(Px(72.0)))
@@ -990,6 +1222,7 @@ fn text_font_synthesis_weight_emoji() {
 
   let container = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -1013,7 +1246,10 @@ fn text_chinese_ellipsis() {
 
   let node = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .width(Percentage(100.0))
@@ -1038,7 +1274,10 @@ fn text_devanagari_noto_sans() {
 
   let node = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .width(Percentage(100.0))
@@ -1055,3 +1294,802 @@ fn text_devanagari_noto_sans() {
 
   run_fixture_test(node.into(), "text_devanagari_noto_sans");
 }
+
+#[test]
+fn text_font_size_fit() {
+  let titles = [
+    "Short title",
+    "A considerably longer headline that would overflow the box at its maximum font size",
+  ];
+
+  let children = Box::from_iter(titles.iter().map(|title| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: Some(FontSizeFit {
+        min: 12.0,
+        max: 64.0,
+        mode: FontSizeFitMode::Box,
+      }),
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(Px(400.0))
+          .height(Px(120.0))
+          .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+          .build()
+          .unwrap(),
+      ),
+      text: title.to_string(),
+    }
+    .into()
+  }));
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .gap(SpacePair::from_single(Px(20.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(children),
+  };
+
+  run_fixture_test(container.into(), "text_font_size_fit");
+}
+
+#[test]
+fn text_word_break_keep_all_korean() {
+  let text = "한국어 문장은 단어 사이에서만 줄바꿈되어야 자연스럽게 읽힙니다";
+
+  let children = Box::from_iter([WordBreak::Normal, WordBreak::KeepAll].map(|word_break| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(Px(360.0))
+          .padding(Sides([Px(12.0); 4]))
+          .border_width(Some(Sides([Px(2.0); 4])))
+          .border_style(Some(BorderStyle::Solid))
+          .border_color(Some(ColorInput::Value(Color([0, 0, 0, 255]))))
+          .word_break(word_break)
+          .build()
+          .unwrap(),
+      ),
+      text: text.to_string(),
+    }
+    .into()
+  }));
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .display(Display::Flex)
+        .gap(SpacePair::from_single(Px(40.0)))
+        .padding(Sides([Px(20.0); 4]))
+        .background_color(ColorInput::Value(Color::white()))
+        .font_family(FontFamily::from_str("Noto Sans TC").ok())
+        .font_size(Some(Px(40.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(children),
+  };
+
+  run_fixture_test(container.into(), "text_word_break_keep_all_korean");
+}
+
+#[test]
+fn text_first_letter_drop_cap() {
+  let paragraphs = [
+    "Once upon a time, in a render pipeline far away, every card wanted a drop cap.",
+    "\u{201c}Quoted openings keep their punctuation together with the first letter.\u{201d}",
+    "\u{1f680} Emoji first characters are styled as a whole grapheme too.",
+  ];
+
+  let children = Box::from_iter(paragraphs.iter().map(|paragraph| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Em(3.0)))
+          .font_weight(FontWeight::from(700.0))
+          .line_height(LineHeight::Unitless(1.0))
+          .color(ColorInput::Value(Color([190, 18, 60, 255])))
+          .build()
+          .unwrap(),
+      ),
+      style: Some(
+        StyleBuilder::default()
+          .width(Px(520.0))
+          .font_size(Some(Px(24.0)))
+          .build()
+          .unwrap(),
+      ),
+      text: paragraph.to_string(),
+    }
+    .into()
+  }));
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .gap(SpacePair::from_single(Px(32.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(children),
+  };
+
+  run_fixture_test(container.into(), "text_first_letter_drop_cap");
+}
+
+#[test]
+fn text_column_count_three() {
+  let text = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .column_count(ColumnCount::Count(3))
+        .column_gap(Some(Px(40.0)))
+        .font_size(Some(Px(22.0)))
+        .line_height(LineHeight::Unitless(1.5))
+        .text_align(TextAlign::Justify)
+        .background_color(ColorInput::Value(Color([250, 248, 240, 255])))
+        .build()
+        .unwrap(),
+    ),
+    text: "Takumi renders a tree of nodes into an image without a browser. Layout is computed with \
+      taffy, text is shaped and broken into lines by parley, and every glyph is rasterized on the \
+      CPU. Newspaper layouts often split a long article into several narrow columns so each line \
+      stays comfortable to read. The text is first laid out at the width of a single column, then \
+      its lines are spread across the columns so they end up about the same height. When the lines \
+      don't divide evenly, the last column is simply a little shorter than the others, just like in \
+      a printed paper."
+      .to_string(),
+  };
+
+  run_fixture_test(text.into(), "text_column_count_three");
+}
+
+#[test]
+fn text_transform_uppercase_turkish_lang() {
+  let line = |lang: &str| -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .lang(Some(Lang::from_str(lang).unwrap()))
+          .build()
+          .unwrap(),
+      ),
+      children: Some(
+        [TextNode {
+          preset: None,
+          id: None,
+          tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
+          style: Some(
+            StyleBuilder::default()
+              .font_size(Some(Px(48.0)))
+              .text_transform(TextTransform::Uppercase)
+              .build()
+              .unwrap(),
+          ),
+          text: format!("{lang}: istanbul, iyi, ılık"),
+        }
+        .into()]
+        .into(),
+      ),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .justify_content(JustifyContent::Center)
+        .padding(Sides([Px(40.0); 4]))
+        .gap(SpacePair::from_single(Px(24.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([line("en"), line("tr")].into()),
+  };
+
+  run_fixture_test(container.into(), "text_transform_uppercase_turkish_lang");
+}
+
+#[test]
+fn text_transform_full_width() {
+  let line = |text: &str, text_transform: TextTransform| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Px(48.0)))
+          .font_family(FontFamily::from_str("Noto Sans TC").ok())
+          .text_transform(text_transform)
+          .build()
+          .unwrap(),
+      ),
+      text: text.to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .justify_content(JustifyContent::Center)
+        .padding(Sides([Px(40.0); 4]))
+        .gap(SpacePair::from_single(Px(24.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        line("Takumi 123", TextTransform::None),
+        line("Takumi 123", TextTransform::FullWidth),
+        // Only the ASCII characters change, the CJK ones are already full-width.
+        line("匠 Takumi 123", TextTransform::FullWidth),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_transform_full_width");
+}
+
+#[test]
+fn text_white_space_pre_line_vs_pre_wrap() {
+  let text = "    Leading spaces and a long line that has to wrap inside the box\n  second    line\n\n    after a blank line";
+
+  let column = |white_space: WhiteSpace, label: &str| -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .display(Display::Flex)
+          .flex_direction(FlexDirection::Column)
+          .flex_grow(Some(FlexGrow(1.0)))
+          .flex_basis(Some(Px(0.0)))
+          .gap(SpacePair::from_single(Px(12.0)))
+          .build()
+          .unwrap(),
+      ),
+      children: Some(
+        [
+          TextNode {
+            preset: None,
+            id: None,
+            tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
+            style: Some(
+              StyleBuilder::default()
+                .font_size(Some(Px(20.0)))
+                .color(ColorInput::Value(Color([120, 120, 120, 255])))
+                .build()
+                .unwrap(),
+            ),
+            text: label.to_string(),
+          }
+          .into(),
+          TextNode {
+            preset: None,
+            id: None,
+            tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
+            style: Some(
+              StyleBuilder::default()
+                .white_space(white_space)
+                .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+                .build()
+                .unwrap(),
+            ),
+            text: text.to_string(),
+          }
+          .into(),
+        ]
+        .into(),
+      ),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .background_color(ColorInput::Value(Color::white()))
+        .display(Display::Flex)
+        .font_size(Some(Px(32.0)))
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .gap(SpacePair::from_single(Px(40.0)))
+        .padding(Sides([Px(40.0); 4]))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        column(WhiteSpace::pre_line(), "pre-line"),
+        column(WhiteSpace::pre_wrap(), "pre-wrap"),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_white_space_pre_line_vs_pre_wrap");
+}
+
+#[test]
+fn text_autospace_cjk_latin() {
+  let line = |text_autospace: TextAutospace| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Px(48.0)))
+          .font_family(FontFamily::from_str("Noto Sans TC").ok())
+          .text_autospace(text_autospace)
+          .build()
+          .unwrap(),
+      ),
+      text: "Takumiは最高のライブラリ123です".to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .justify_content(JustifyContent::Center)
+        .padding(Sides([Px(40.0); 4]))
+        .gap(SpacePair::from_single(Px(24.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        line(TextAutospace::NoAutospace),
+        line(TextAutospace::Normal),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_autospace_cjk_latin");
+}
+
+#[test]
+fn text_generated_content_close_button() {
+  let close_button = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(96.0))
+        .height(Px(96.0))
+        .border_radius(BorderRadius::from_str("50%").unwrap())
+        .background_color(ColorInput::Value(Color([30, 41, 59, 255])))
+        .color(ColorInput::Value(Color::white()))
+        .font_size(Some(Px(64.0)))
+        .line_height(LineHeight::from_str("96px").unwrap())
+        .text_align(TextAlign::Center)
+        .content(Content::from_str("\"×\"").unwrap())
+        .build()
+        .unwrap(),
+    ),
+    // Replaced by the generated content.
+    children: Some(
+      [TextNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: None,
+        text: "Close".to_string(),
+        font_size_fit: None,
+        first_letter_style: None,
+      }
+      .into()]
+      .into(),
+    ),
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([close_button.into()].into()),
+  };
+
+  run_fixture_test(container.into(), "text_generated_content_close_button");
+}
+
+#[test]
+fn text_generated_content_counters() {
+  let step = || -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .padding(Sides([Px(16.0), Px(32.0), Px(16.0), Px(32.0)]))
+          .background_color(ColorInput::Value(Color([30, 41, 59, 255])))
+          .color(ColorInput::Value(Color::white()))
+          .font_size(Some(Px(48.0)))
+          .counter_increment(CounterIncrement::from_str("step").unwrap())
+          .content(Content::from_str("\"Step \" counter(step)").unwrap())
+          .build()
+          .unwrap(),
+      ),
+      children: None,
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .gap(SpacePair::from_pair(Px(24.0), Px(24.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .counter_reset(CounterReset::from_str("step").unwrap())
+        .build()
+        .unwrap(),
+    ),
+    children: Some([step(), step(), step()].into()),
+  };
+
+  run_fixture_test(container.into(), "text_generated_content_counters");
+}
+
+#[test]
+fn text_font_variant_emoji_heart() {
+  let heart = |variant: FontVariantEmoji| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Px(120.0)))
+          .color(ColorInput::Value(Color([37, 99, 235, 255])))
+          .font_variant_emoji(variant)
+          .build()
+          .unwrap(),
+      ),
+      text: "❤".to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .gap(SpacePair::from_pair(Px(96.0), Px(96.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    // The text presentation follows `color`, the emoji presentation keeps its own colors.
+    children: Some(
+      [
+        heart(FontVariantEmoji::Text),
+        heart(FontVariantEmoji::Emoji),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_font_variant_emoji_heart");
+}
+
+fn small_text_at_fractional_offsets() -> NodeKind {
+  let line = |offset: f32| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Px(12.0)))
+          .margin_left(Some(Px(offset)))
+          .build()
+          .unwrap(),
+      ),
+      text: format!("Offset by {offset}px: The quick brown fox jumps over the lazy dog 0123456789"),
+    }
+    .into()
+  };
+
+  ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .padding(Sides([Px(16.3); 4]))
+        .row_gap(Some(Px(5.4)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([0.0, 0.25, 0.5, 0.75].map(line).into()),
+  }
+  .into()
+}
+
+#[test]
+fn text_snap_to_pixel_off() {
+  run_fixture_test(small_text_at_fractional_offsets(), "text_snap_to_pixel_off");
+}
+
+#[test]
+fn text_snap_to_pixel_on() {
+  run_fixture_test_with_options(
+    small_text_at_fractional_offsets(),
+    "text_snap_to_pixel_on",
+    |options| {
+      options.snap_text_to_pixel(true);
+    },
+  );
+}
+
+#[test]
+fn text_font_variant_ligatures() {
+  let line = |font_family: &str, text: &str, ligatures: FontVariantLigatures| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_family(FontFamily::from_str(font_family).ok())
+          .font_variant_ligatures(ligatures)
+          .build()
+          .unwrap(),
+      ),
+      text: text.to_string(),
+    }
+    .into()
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .justify_content(JustifyContent::Center)
+        .padding(Sides([Px(48.0); 4]))
+        .gap(SpacePair::from_single(Px(24.0)))
+        .font_size(Some(Px(72.0)))
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        line("Geist", "fi office flow", FontVariantLigatures::default()),
+        line("Geist", "fi office flow", FontVariantLigatures::NONE),
+        line("Geist Mono", "a -> b != c", FontVariantLigatures::default()),
+        line("Geist Mono", "a -> b != c", FontVariantLigatures::NONE),
+      ]
+      .into(),
+    ),
+  };
+
+  run_fixture_test(container.into(), "text_font_variant_ligatures");
+}
+
+#[test]
+fn text_tab_size_code_block() {
+  let code = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: Some(
+      StyleBuilder::default()
+        .font_family(FontFamily::from_str("Geist Mono").ok())
+        .font_size(Some(Px(32.0)))
+        .white_space(WhiteSpace::pre())
+        .tab_size(TabSize::Spaces(4.0))
+        .padding(Sides([Px(32.0); 4]))
+        .background_color(ColorInput::Value(Color([245, 245, 245, 255])))
+        .build()
+        .unwrap(),
+    ),
+    text: "fn main() {\n\tfor i in 0..3 {\n\t\tprintln!(\"{i}\");\n\t}\n}\n\nname\tvalue\tnote\nid\t42\tanswer\nfoo\tbar\tbaz"
+      .to_string(),
+  };
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .align_items(AlignItems::Center)
+        .justify_content(JustifyContent::Center)
+        .background_color(ColorInput::Value(Color::white()))
+        .build()
+        .unwrap(),
+    ),
+    children: Some([code.into()].into()),
+  };
+
+  run_fixture_test(container.into(), "text_tab_size_code_block");
+}
+
+#[test]
+fn text_direction_rtl() {
+  let nodes = [
+    ("auto", None),
+    ("ltr", Some(Direction::Ltr)),
+    ("rtl", Some(Direction::Rtl)),
+  ]
+  .iter()
+  .map(|(label, direction)| {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Px(48.0)))
+          .font_family(FontFamily::from_str("Scheherazade New Test").ok())
+          .direction(*direction)
+          .padding(Sides([Px(16.0); 4]))
+          .background_color(ColorInput::Value(Color::white()))
+          .build()
+          .unwrap(),
+      ),
+      // The label goes last, so the paragraph still starts with a strong RTL character.
+      text: format!("السعر 250 ريال للطلب رقم 42 ({label})"),
+    }
+    .into()
+  })
+  .collect::<Vec<_>>();
+
+  let container = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .background_color(ColorInput::Value(Color([240, 240, 240, 255])))
+        .width(Percentage(100.0))
+        .flex_direction(FlexDirection::Column)
+        .row_gap(Some(Px(20.0)))
+        .padding(Sides([Px(20.0); 4]))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(nodes.into()),
+  };
+
+  run_fixture_test(container.into(), "text_direction_rtl");
+}
@@ -0,0 +1,35 @@
+use serde_json::json;
+
+use crate::test_utils::render_json;
+
+#[test]
+fn test_first_text_shadow_is_painted_on_top() {
+  // Both shadows cover the same pixels, so only the topmost one should be visible.
+  let image = render_json(
+    json!({
+      "type": "text",
+      "text": "HHHH",
+      "style": {
+        "width": "320px",
+        "height": "160px",
+        "backgroundColor": "white",
+        "color": "white",
+        "fontSize": "64px",
+        "textShadow": "0 48px rgb(255, 0, 0), 0 48px rgb(0, 0, 255)",
+      },
+    }),
+    (320, 160),
+  );
+
+  let red = image
+    .pixels()
+    .filter(|pixel| pixel.0[0] > pixel.0[2].saturating_add(128))
+    .count();
+  let blue = image
+    .pixels()
+    .filter(|pixel| pixel.0[2] > pixel.0[0].saturating_add(8))
+    .count();
+
+  assert!(red > 0, "expected the first shadow to be visible");
+  assert_eq!(blue, 0, "expected the last shadow to be covered");
+}
@@ -0,0 +1,193 @@
+use std::io::Cursor;
+
+use image::{
+  AnimationDecoder, Frame, ImageDecoder, ImageFormat, Rgba, RgbaImage,
+  codecs::{jpeg::JpegDecoder, png::PngDecoder, webp::WebPDecoder},
+  load_from_memory_with_format,
+};
+use takumi::{
+  GlobalContext,
+  rendering::{
+    AnimationFrame, FrameBlend, ImageOutputFormat, encode_animated_png, encode_animated_webp,
+    srgb_color_profile, write_image,
+  },
+};
+
+fn create_test_image(alpha: u8) -> RgbaImage {
+  RgbaImage::from_fn(64, 32, |x, y| {
+    Rgba([(x * 4) as u8, (y * 8) as u8, ((x + y) * 3) as u8, alpha])
+  })
+}
+
+fn assert_webp_round_trip(image: &RgbaImage) {
+  let mut buffer = Vec::new();
+  write_image(image, &mut buffer, ImageOutputFormat::WebP, None, None).unwrap();
+
+  let decoded = load_from_memory_with_format(&buffer, ImageFormat::WebP)
+    .unwrap()
+    .into_rgba8();
+
+  assert_eq!(decoded.dimensions(), image.dimensions());
+  assert_eq!(decoded.as_raw(), image.as_raw());
+}
+
+#[test]
+fn test_write_webp_is_lossless_for_opaque_image() {
+  assert_webp_round_trip(&create_test_image(u8::MAX));
+}
+
+#[test]
+fn test_write_webp_is_lossless_for_transparent_image() {
+  assert_webp_round_trip(&create_test_image(128));
+}
+
+#[test]
+fn test_write_webp_ignores_quality() {
+  let image = create_test_image(u8::MAX);
+
+  let mut default_quality = Vec::new();
+  write_image(
+    &image,
+    &mut default_quality,
+    ImageOutputFormat::WebP,
+    None,
+    None,
+  )
+  .unwrap();
+
+  let mut low_quality = Vec::new();
+  write_image(
+    &image,
+    &mut low_quality,
+    ImageOutputFormat::WebP,
+    Some(10),
+    None,
+  )
+  .unwrap();
+
+  assert_eq!(default_quality, low_quality);
+}
+
+fn write_with_srgb_profile(format: ImageOutputFormat) -> Vec<u8> {
+  let mut buffer = Vec::new();
+  write_image(
+    &create_test_image(u8::MAX),
+    &mut buffer,
+    format,
+    None,
+    Some(&srgb_color_profile()),
+  )
+  .unwrap();
+
+  buffer
+}
+
+#[test]
+fn test_write_png_embeds_color_profile() {
+  let buffer = write_with_srgb_profile(ImageOutputFormat::Png);
+
+  // The profile is stored compressed in an `iCCP` chunk before the image data.
+  assert!(buffer.windows(4).any(|chunk_type| chunk_type == b"iCCP"));
+
+  let mut decoder = PngDecoder::new(Cursor::new(buffer)).unwrap();
+  assert_eq!(decoder.icc_profile().unwrap(), Some(srgb_color_profile()));
+}
+
+#[test]
+fn test_write_jpeg_embeds_color_profile() {
+  let buffer = write_with_srgb_profile(ImageOutputFormat::Jpeg);
+
+  let mut decoder = JpegDecoder::new(Cursor::new(buffer)).unwrap();
+  assert_eq!(decoder.icc_profile().unwrap(), Some(srgb_color_profile()));
+}
+
+#[test]
+fn test_write_png_without_color_profile_is_untagged() {
+  let mut buffer = Vec::new();
+  write_image(
+    &create_test_image(u8::MAX),
+    &mut buffer,
+    ImageOutputFormat::Png,
+    None,
+    None,
+  )
+  .unwrap();
+
+  assert!(!buffer.windows(4).any(|chunk_type| chunk_type == b"iCCP"));
+}
+
+const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+/// A static red background, then a frame that only holds a changed blue square.
+fn create_diff_frames(blend: FrameBlend) -> Vec<AnimationFrame> {
+  let background = RgbaImage::from_pixel(16, 16, RED);
+  let diff = RgbaImage::from_fn(16, 16, |x, y| {
+    if (4..8).contains(&x) && (4..8).contains(&y) {
+      BLUE
+    } else {
+      TRANSPARENT
+    }
+  });
+
+  let mut diff_frame = AnimationFrame::new(diff, 100);
+  diff_frame.blend = blend;
+
+  vec![AnimationFrame::new(background, 100), diff_frame]
+}
+
+fn assert_diff_frame_composited(frames: Vec<Frame>, blend: FrameBlend) {
+  assert_eq!(frames.len(), 2);
+
+  let composited = frames[1].buffer();
+
+  assert_eq!(*composited.get_pixel(5, 5), BLUE);
+  assert_eq!(
+    *composited.get_pixel(12, 12),
+    match blend {
+      FrameBlend::Over => RED,
+      FrameBlend::Source => TRANSPARENT,
+    }
+  );
+}
+
+#[test]
+fn test_animated_webp_blends_diff_frame_over_previous() {
+  for blend in [FrameBlend::Over, FrameBlend::Source] {
+    let mut buffer = Vec::new();
+    encode_animated_webp(
+      &create_diff_frames(blend),
+      &mut buffer,
+      None,
+      &GlobalContext::default(),
+    )
+    .unwrap();
+
+    let frames = WebPDecoder::new(Cursor::new(buffer))
+      .unwrap()
+      .into_frames()
+      .collect_frames()
+      .unwrap();
+
+    assert_diff_frame_composited(frames, blend);
+  }
+}
+
+#[test]
+fn test_animated_png_blends_diff_frame_over_previous() {
+  for blend in [FrameBlend::Over, FrameBlend::Source] {
+    let mut buffer = Vec::new();
+    encode_animated_png(&create_diff_frames(blend), &mut buffer, None).unwrap();
+
+    let frames = PngDecoder::new(Cursor::new(buffer))
+      .unwrap()
+      .apng()
+      .unwrap()
+      .into_frames()
+      .collect_frames()
+      .unwrap();
+
+    assert_diff_frame_composited(frames, blend);
+  }
+}
@@ -4,7 +4,18 @@ use std::{
   path::{Path, PathBuf},
 };
 
-use takumi::{GlobalContext, resources::font::FontError};
+use parley::{FontStyle, FontVariation};
+use swash::{Tag, tag_from_bytes};
+use takumi::{
+  GlobalContext,
+  layout::{
+    Viewport,
+    node::{NodeKind, TextNode},
+    style::{FontFamily, FromCss, StyleBuilder},
+  },
+  rendering::{RenderOptionsBuilder, measure_layout},
+  resources::font::FontError,
+};
 
 fn font_path(path: &str) -> PathBuf {
   Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -13,6 +24,49 @@ fn font_path(path: &str) -> PathBuf {
     .to_path_buf()
 }
 
+fn load_font(context: &mut GlobalContext, path: &str) {
+  let mut font_data = Vec::new();
+  File::open(font_path(path))
+    .unwrap()
+    .read_to_end(&mut font_data)
+    .unwrap();
+
+  context
+    .font_context
+    .load_and_store(font_data.into(), None, None)
+    .unwrap();
+}
+
+fn measure_text_width(context: &GlobalContext, family: &str) -> f32 {
+  let node: NodeKind = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: Some(
+      StyleBuilder::default()
+        .font_family(FontFamily::from_str(family).ok())
+        .build()
+        .unwrap(),
+    ),
+    text: "Takumi".to_string(),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(Viewport::new(Some(1200), Some(630)))
+      .node(node)
+      .global(context)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  result.runs.iter().map(|run| run.width).sum()
+}
+
 #[test]
 fn test_ttf_font_loading() {
   let mut context = GlobalContext::default();
@@ -84,3 +138,140 @@ fn test_too_short_data() {
     .load_and_store(short_data.into(), None, None);
   assert!(matches!(result, Err(FontError::UnsupportedFormat)));
 }
+
+#[test]
+fn test_clear_unloads_fonts() {
+  let mut context = GlobalContext::default();
+  load_font(&mut context, "geist/Geist[wght].woff2");
+
+  assert!(measure_text_width(&context, "Geist") > 0.0);
+
+  context.font_context.clear();
+
+  assert_eq!(measure_text_width(&context, "Geist"), 0.0);
+  assert!(context.font_context.collection.family_id("Geist").is_none());
+}
+
+#[test]
+fn test_remove_family_keeps_other_fonts() {
+  let mut context = GlobalContext::default();
+  load_font(&mut context, "geist/Geist[wght].woff2");
+  load_font(&mut context, "archivo/Archivo-VariableFont_wdth,wght.ttf");
+
+  assert!(!context.font_context.remove_family("Missing"));
+  assert!(context.font_context.remove_family("archivo"));
+
+  assert!(
+    context
+      .font_context
+      .collection
+      .family_id("Archivo")
+      .is_none()
+  );
+  assert!(context.font_context.collection.family_id("Geist").is_some());
+  assert!(measure_text_width(&context, "Geist") > 0.0);
+
+  // The family can be loaded again after being removed.
+  load_font(&mut context, "archivo/Archivo-VariableFont_wdth,wght.ttf");
+  assert!(
+    context
+      .font_context
+      .collection
+      .family_id("Archivo")
+      .is_some()
+  );
+}
+
+#[test]
+fn test_fallback_family_is_used_for_unloaded_family() {
+  let mut context = GlobalContext::default();
+  load_font(&mut context, "geist/Geist[wght].woff2");
+  load_font(&mut context, "archivo/Archivo-VariableFont_wdth,wght.ttf");
+
+  let geist_width = measure_text_width(&context, "Geist");
+  let archivo_width = measure_text_width(&context, "Archivo");
+  assert_ne!(geist_width, archivo_width);
+
+  // Without a fallback family, the first loaded font is used.
+  assert_eq!(measure_text_width(&context, "Missing"), geist_width);
+
+  context.set_fallback_family(Some("Archivo"));
+  assert_eq!(measure_text_width(&context, "Missing"), archivo_width);
+
+  context.set_fallback_family(None);
+  assert_eq!(measure_text_width(&context, "Missing"), geist_width);
+}
+
+fn find_axis(axes: &[(Tag, f32)], tag: &[u8; 4]) -> Option<f32> {
+  axes
+    .iter()
+    .find(|(axis, _)| *axis == tag_from_bytes(tag))
+    .map(|(_, value)| *value)
+}
+
+#[test]
+fn test_named_instances_of_variable_woff2_font() {
+  let mut context = GlobalContext::default();
+  load_font(&mut context, "geist/Geist[wght].woff2");
+
+  let instances = context.font_context.named_instances("geist");
+
+  assert!(!instances.is_empty());
+  assert!(
+    instances
+      .iter()
+      .all(|instance| find_axis(&instance.axes, b"wght").is_some())
+  );
+
+  let bold = instances
+    .iter()
+    .find(|instance| instance.name == "Bold")
+    .unwrap();
+  assert_eq!(find_axis(&bold.axes, b"wght"), Some(700.0));
+  assert_eq!(
+    bold.to_font_variation_settings().as_ref(),
+    [FontVariation {
+      tag: tag_from_bytes(b"wght"),
+      value: 700.0,
+    }]
+  );
+}
+
+#[test]
+fn test_named_instances_list_every_axis() {
+  let mut context = GlobalContext::default();
+  load_font(&mut context, "archivo/Archivo-VariableFont_wdth,wght.ttf");
+
+  let instances = context.font_context.named_instances("Archivo");
+
+  assert!(!instances.is_empty());
+  for instance in &instances {
+    assert!(find_axis(&instance.axes, b"wdth").is_some(), "{instance:?}");
+    assert!(find_axis(&instance.axes, b"wght").is_some(), "{instance:?}");
+  }
+
+  assert!(context.font_context.named_instances("Unknown").is_empty());
+}
+
+#[test]
+fn test_loaded_families_lists_fonts_in_load_order() {
+  let mut context = GlobalContext::default();
+  assert!(context.font_context.loaded_families().is_empty());
+
+  load_font(&mut context, "geist/Geist[wght].woff2");
+  load_font(&mut context, "archivo/Archivo-VariableFont_wdth,wght.ttf");
+
+  let families = context.font_context.loaded_families();
+
+  assert_eq!(
+    families
+      .iter()
+      .map(|family| family.family.as_str())
+      .collect::<Vec<_>>(),
+    ["Geist", "Archivo"]
+  );
+  for family in &families {
+    assert!(!family.weights.is_empty(), "{family:?}");
+    assert_eq!(family.styles, [FontStyle::Normal], "{family:?}");
+  }
+}
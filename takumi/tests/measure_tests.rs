@@ -1,11 +1,20 @@
 mod test_utils;
 
 use takumi::{
+  GlobalContext,
   layout::{
-    node::{ContainerNode, ImageNode, NodeKind, TextNode},
-    style::{Affine, Color, ColorInput, Display, Length::*, StyleBuilder},
+    node::{ContainerNode, FontSizeFit, FontSizeFitMode, ImageNode, NodeKind, TextNode},
+    style::{
+      Affine, AlignItems, AspectRatio, Color, ColorInput, Display, FlexDirection, FlexGrow,
+      FontFamily, FromCss, Gap, GridLength, GridTemplateComponent, GridTrackSize, JustifyContent,
+      Length, Length::*, LineHeight, OverflowWrap, Position, Sides, StyleBuilder, StylePreset,
+      TextAlign, TextOverflow, TextWrapMode, VerticalAlign,
+    },
+  },
+  rendering::{
+    MeasuredNode, MeasuredTextRun, RenderOptionsBuilder, measure_layout, render,
+    render_debug_layout,
   },
-  rendering::{MeasuredNode, MeasuredTextRun, RenderOptionsBuilder, measure_layout},
 };
 use test_utils::{CONTEXT, create_test_viewport};
 
@@ -13,6 +22,7 @@ use test_utils::{CONTEXT, create_test_viewport};
 fn test_measure_simple_container() {
   let node: NodeKind = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -52,7 +62,10 @@ fn test_measure_simple_container() {
 fn test_measure_text_node() {
   let node: NodeKind = TextNode {
     preset: None,
+    id: None,
     tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
     style: Some(
       StyleBuilder::default()
         .width(Px(300.0))
@@ -86,10 +99,162 @@ fn test_measure_text_node() {
   )
 }
 
+/// Measures a block holding "H" followed by an inline "2" with the given `vertical-align`.
+fn measure_vertical_align_line(vertical_align: VerticalAlign) -> MeasuredNode {
+  let text = |text: &str, vertical_align: VerticalAlign| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .display(Display::Inline)
+          .vertical_align(vertical_align)
+          .build()
+          .unwrap(),
+      ),
+      text: text.to_string(),
+    }
+    .into()
+  };
+
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Block)
+        .width(Px(300.0))
+        .font_size(Some(Px(20.0)))
+        .line_height(LineHeight::Length(Px(20.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        text("H", VerticalAlign::Baseline),
+        text("2", vertical_align),
+      ]
+      .into(),
+    ),
+  }
+  .into();
+
+  measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_measure_vertical_align_shift_grows_line_box() {
+  let baseline = measure_vertical_align_line(VerticalAlign::Baseline);
+  let raised = measure_vertical_align_line(VerticalAlign::Length(Px(10.0)));
+
+  assert_eq!(baseline.height, 20.0);
+  assert!(
+    raised.height >= baseline.height + 10.0,
+    "raised={}, baseline={}",
+    raised.height,
+    baseline.height
+  );
+}
+
+#[test]
+fn test_render_middle_ellipsis_narrower_than_ellipsis() {
+  let node: NodeKind = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(1.0))
+        .font_size(Some(Px(20.0)))
+        .text_overflow(TextOverflow::EllipsisMiddle)
+        .text_wrap_mode(Some(TextWrapMode::NoWrap))
+        .build()
+        .unwrap(),
+    ),
+    text: "https://example.com/image.png".to_string(),
+  }
+  .into();
+
+  // Measuring returns before the ellipsis is applied, so the text has to be drawn.
+  let result = render(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  );
+
+  assert!(result.is_ok());
+}
+
+/// Measures "Hello World" in a 300px wide box, fitting its font size to a single line.
+fn measure_fitted_text(min: f32, max: f32) -> MeasuredNode {
+  let node: NodeKind = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: Some(FontSizeFit {
+      min,
+      max,
+      mode: FontSizeFitMode::SingleLine,
+    }),
+    first_letter_style: None,
+    style: Some(StyleBuilder::default().width(Px(300.0)).build().unwrap()),
+    text: "Hello World".to_string(),
+  }
+  .into();
+
+  measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_measure_font_size_fit() {
+  // At 20px the text fits easily, so the largest size is kept (26px line, as above).
+  let kept = measure_fitted_text(10.0, 20.0);
+  assert_eq!((kept.width, kept.height), (300.0, 26.0));
+
+  // Growing is capped by the single line, so the size lands between both bounds.
+  let fitted = measure_fitted_text(10.0, 200.0);
+  assert_eq!(fitted.width, 300.0);
+  assert!(
+    fitted.height > 26.0 && fitted.height < 260.0,
+    "{}",
+    fitted.height
+  );
+
+  // The minimum wins even when the text doesn't fit on one line at it, so it wraps.
+  let wrapped = measure_fitted_text(100.0, 200.0);
+  assert!(wrapped.height > 200.0, "{}", wrapped.height);
+}
+
 #[test]
 fn test_measure_inline_layout() {
   let node: NodeKind = ContainerNode {
     preset: None,
+    id: None,
     tw: None,
     style: Some(
       StyleBuilder::default()
@@ -104,7 +269,10 @@ fn test_measure_inline_layout() {
       vec![
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .display(Display::Inline)
@@ -115,7 +283,9 @@ fn test_measure_inline_layout() {
         }
         .into(),
         ImageNode {
+          sources: None,
           preset: None,
+          id: None,
           tw: None,
           style: Some(
             StyleBuilder::default()
@@ -131,7 +301,10 @@ fn test_measure_inline_layout() {
         .into(),
         TextNode {
           preset: None,
+          id: None,
           tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
           style: Some(
             StyleBuilder::default()
               .display(Display::Inline)
@@ -196,3 +369,1034 @@ fn test_measure_inline_layout() {
     }
   )
 }
+
+#[test]
+fn test_measure_fixed_position_anchors_to_viewport() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .padding(Sides([Px(40.0); 4]))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .width(Px(400.0))
+            .height(Px(200.0))
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [ContainerNode {
+            preset: None,
+            id: None,
+            tw: None,
+            style: Some(
+              StyleBuilder::default()
+                .width(Px(120.0))
+                .height(Px(40.0))
+                .position(Position::Fixed)
+                .right(Some(Px(16.0)))
+                .bottom(Some(Px(16.0)))
+                .build()
+                .unwrap(),
+            ),
+            children: None,
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  // The fixed node is hoisted out of its parent into a viewport box appended to the root.
+  assert_eq!(result.children.len(), 2);
+  assert!(result.children[0].children.is_empty());
+  assert_eq!(result.children[1].width, 1200.0);
+  assert_eq!(result.children[1].height, 630.0);
+
+  let fixed = &result.children[1].children[0];
+  assert_eq!(fixed.width, 120.0);
+  assert_eq!(fixed.height, 40.0);
+  assert_eq!(
+    fixed.transform,
+    Affine::translation(1200.0 - 16.0 - 120.0, 630.0 - 16.0 - 40.0).to_cols_array()
+  );
+}
+
+#[test]
+fn test_measure_aspect_ratio_respects_min_height() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Grid)
+        .align_items(AlignItems::Start)
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .aspect_ratio(AspectRatio::Ratio(3.0))
+            .width(Px(300.0))
+            .min_height(Px(150.0))
+            .build()
+            .unwrap(),
+        ),
+        children: None,
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  // The ratio would give 100px, but the min-height floor wins without widening the item.
+  let item = &result.children[0];
+  assert_eq!(item.width, 300.0);
+  assert_eq!(item.height, 150.0);
+}
+
+#[test]
+fn test_measure_aspect_ratio_min_height_lets_flex_item_shrink() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Flex)
+        .align_items(AlignItems::Start)
+        .width(Px(200.0))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .aspect_ratio(AspectRatio::Ratio(3.0))
+            .width(Px(300.0))
+            .min_height(Px(150.0))
+            .build()
+            .unwrap(),
+        ),
+        children: None,
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  // The item shrinks to the container while the min-height floor still applies.
+  let item = &result.children[0];
+  assert_eq!(item.width, 200.0);
+  assert_eq!(item.height, 150.0);
+}
+
+#[test]
+fn test_measure_aspect_ratio_respects_max_height() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Grid)
+        .align_items(AlignItems::Start)
+        .width(Percentage(100.0))
+        .height(Percentage(100.0))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .aspect_ratio(AspectRatio::Ratio(3.0))
+            .width(Px(300.0))
+            .max_height(Px(50.0))
+            .build()
+            .unwrap(),
+        ),
+        children: None,
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  // The ratio would give 100px, but the max-height cap wins without narrowing the item.
+  let item = &result.children[0];
+  assert_eq!(item.width, 300.0);
+  assert_eq!(item.height, 50.0);
+}
+
+#[test]
+fn test_measure_aspect_ratio_max_height_lets_flex_item_grow() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Flex)
+        .align_items(AlignItems::Start)
+        .width(Px(600.0))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .aspect_ratio(AspectRatio::Ratio(3.0))
+            .width(Px(300.0))
+            .max_height(Px(50.0))
+            .flex_grow(Some(FlexGrow(1.0)))
+            .build()
+            .unwrap(),
+        ),
+        children: None,
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  // The item grows to the container while the max-height cap still applies.
+  let item = &result.children[0];
+  assert_eq!(item.width, 600.0);
+  assert_eq!(item.height, 50.0);
+}
+
+#[test]
+fn test_measure_ch_unit_matches_zero_advance() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .align_items(AlignItems::Start)
+        .font_family(FontFamily::from_str("Geist Mono").ok())
+        .font_size(Some(Px(20.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        ContainerNode {
+          preset: None,
+          id: None,
+          tw: None,
+          style: Some(
+            StyleBuilder::default()
+              .width(Ch(10.0))
+              .height(Ex(2.0))
+              .build()
+              .unwrap(),
+          ),
+          children: None,
+        }
+        .into(),
+        TextNode {
+          preset: None,
+          id: None,
+          tw: None,
+          font_size_fit: None,
+          first_letter_style: None,
+          style: None,
+          text: "0000000000".to_string(),
+        }
+        .into(),
+      ]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  let sized_by_ch = &result.children[0];
+  let zeros = &result.children[1];
+
+  assert!((sized_by_ch.width - zeros.width).abs() < 1.0);
+  // The x-height of a Latin font is always a fraction of the font size.
+  assert!(sized_by_ch.height > 0.0 && sized_by_ch.height < 40.0);
+}
+
+#[test]
+fn test_render_debug_layout() {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(200.0))
+        .height(Px(100.0))
+        .padding(Sides([Px(10.0); 4]))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .width(Px(50.0))
+            .height(Px(50.0))
+            .margin(Sides([Px(5.0); 4]))
+            .build()
+            .unwrap(),
+        ),
+        children: None,
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = render_debug_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  assert_eq!(result.node_type, Some("container"));
+  assert_eq!((result.width, result.height), (200.0, 100.0));
+  assert_eq!(result.padding, [10.0; 4]);
+  assert_eq!(result.style.font_size, 16.0);
+
+  let child = &result.children[0];
+  assert_eq!(child.node_type, Some("container"));
+  assert_eq!((child.x, child.y), (15.0, 15.0));
+  assert_eq!((child.width, child.height), (50.0, 50.0));
+  assert_eq!(child.margin, [5.0; 4]);
+  assert!(child.children.is_empty());
+}
+
+#[test]
+fn test_debug_layout_row_reverse_packs_from_the_right() {
+  let child = |width: f32| -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(Px(width))
+          .height(Px(50.0))
+          .build()
+          .unwrap(),
+      ),
+      children: None,
+    }
+    .into()
+  };
+
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(600.0))
+        .height(Px(100.0))
+        .flex_direction(FlexDirection::RowReverse)
+        .justify_content(JustifyContent::FlexStart)
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [
+        child(100.0),
+        child(150.0),
+        child(200.0),
+        ContainerNode {
+          preset: None,
+          id: None,
+          tw: None,
+          style: Some(
+            StyleBuilder::default()
+              .position(Position::Absolute)
+              .left(Some(Px(0.0)))
+              .top(Some(Px(0.0)))
+              .width(Px(30.0))
+              .height(Px(30.0))
+              .build()
+              .unwrap(),
+          ),
+          children: None,
+        }
+        .into(),
+      ]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = render_debug_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  let xs = result
+    .children
+    .iter()
+    .map(|child| child.x)
+    .collect::<Vec<_>>();
+
+  // Items are placed right-to-left in source order, while physical insets are not mirrored.
+  assert_eq!(xs, [500.0, 350.0, 150.0, 0.0]);
+}
+
+#[test]
+fn test_debug_layout_grid_baseline_ignores_padding_below_text() {
+  let item = |padding_bottom: f32| -> NodeKind {
+    TextNode {
+      preset: None,
+      id: None,
+      tw: None,
+      font_size_fit: None,
+      first_letter_style: None,
+      style: Some(
+        StyleBuilder::default()
+          .font_size(Some(Px(20.0)))
+          .padding_bottom(Some(Px(padding_bottom)))
+          .build()
+          .unwrap(),
+      ),
+      text: "Baseline".to_string(),
+    }
+    .into()
+  };
+
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(400.0))
+        .display(Display::Grid)
+        .grid_template_columns(Some(vec![
+          GridTemplateComponent::Single(GridTrackSize::Fixed(GridLength::Unit(Px(200.0)))),
+          GridTemplateComponent::Single(GridTrackSize::Fixed(GridLength::Unit(Px(200.0)))),
+        ]))
+        .align_items(AlignItems::Baseline)
+        .build()
+        .unwrap(),
+    ),
+    children: Some([item(100.0), item(0.0)].into()),
+  }
+  .into();
+
+  let result = render_debug_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  // With the same font size the first baselines line up, so the padding below
+  // the first item's text must not push the second item down.
+  assert_eq!(result.children[0].y, 0.0);
+  assert_eq!(result.children[1].y, 0.0);
+}
+
+#[test]
+fn test_named_preset_is_overridden_by_inline_style() {
+  let mut global = GlobalContext::default();
+  global.register_preset(
+    "heading",
+    StyleBuilder::default()
+      .font_size(Some(Px(48.0)))
+      .build()
+      .unwrap(),
+  );
+
+  let container = |style: Option<_>, children: Option<Box<[NodeKind]>>| -> NodeKind {
+    ContainerNode {
+      preset: Some(StylePreset::Named("heading".to_string())),
+      id: None,
+      tw: None,
+      style,
+      children,
+    }
+    .into()
+  };
+
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: None,
+    children: Some(
+      [
+        container(
+          None,
+          Some(
+            [ContainerNode {
+              preset: None,
+              id: None,
+              tw: None,
+              style: None,
+              children: None,
+            }
+            .into()]
+            .into(),
+          ),
+        ),
+        container(
+          Some(
+            StyleBuilder::default()
+              .font_size(Some(Px(20.0)))
+              .build()
+              .unwrap(),
+          ),
+          None,
+        ),
+      ]
+      .into(),
+    ),
+  }
+  .into();
+
+  let result = render_debug_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&global)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  let preset_only = &result.children[0];
+  assert_eq!(preset_only.style.font_size, 48.0);
+  assert_eq!(preset_only.children[0].style.font_size, 48.0);
+
+  let overridden = &result.children[1];
+  assert_eq!(overridden.style.font_size, 20.0);
+}
+
+fn measure_paragraph_runs(text_align: TextAlign) -> Vec<MeasuredTextRun> {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(300.0))
+        .font_size(Some(Px(20.0)))
+        .display(Display::Block)
+        .text_align(text_align)
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [TextNode {
+        preset: None,
+        id: None,
+        tw: None,
+        font_size_fit: None,
+        first_letter_style: None,
+        style: Some(
+          StyleBuilder::default()
+            .display(Display::Inline)
+            .build()
+            .unwrap(),
+        ),
+        text: "The quick brown fox jumps over the lazy dog while five boxing wizards jump quickly. The end."
+          .to_string(),
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap()
+  .runs
+}
+
+#[test]
+fn test_measure_justify_spreads_words_except_last_line() {
+  let left = measure_paragraph_runs(TextAlign::Left);
+  let justified = measure_paragraph_runs(TextAlign::Justify);
+
+  assert!(justified.len() >= 3);
+  assert_eq!(
+    left.iter().map(|run| &run.text).collect::<Vec<_>>(),
+    justified.iter().map(|run| &run.text).collect::<Vec<_>>()
+  );
+
+  let (left_last, left_lines) = left.split_last().unwrap();
+  let (justified_last, justified_lines) = justified.split_last().unwrap();
+
+  // Interior lines start at the left edge and only grow by the space added between words.
+  for (left_run, justified_run) in left_lines.iter().zip(justified_lines) {
+    assert_eq!(justified_run.x, 0.0);
+    assert!(justified_run.width >= left_run.width);
+  }
+  assert!(
+    left_lines
+      .iter()
+      .zip(justified_lines)
+      .any(|(left_run, justified_run)| justified_run.width > left_run.width)
+  );
+
+  // The last line keeps its natural width at the start of the line.
+  assert_eq!(justified_last.x, 0.0);
+  assert_eq!(justified_last.width, left_last.width);
+}
+
+fn measure_intrinsic_container(width: Length) -> MeasuredNode {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(600.0))
+        .display(Display::Block)
+        .font_size(Some(Px(20.0)))
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .width(width)
+            .display(Display::Block)
+            .padding(Sides([Px(8.0); 4]))
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [TextNode {
+            preset: None,
+            id: None,
+            tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
+            style: None,
+            text: "Hug this label".to_string(),
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let mut result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  result.children.remove(0)
+}
+
+fn collect_runs(node: &MeasuredNode) -> Vec<&MeasuredTextRun> {
+  node
+    .runs
+    .iter()
+    .chain(node.children.iter().flat_map(collect_runs))
+    .collect()
+}
+
+#[test]
+fn test_measure_max_content_width_hugs_text() {
+  let container = measure_intrinsic_container(MaxContent);
+  let runs = collect_runs(&container);
+
+  assert_eq!(runs.len(), 1);
+  assert_eq!(runs[0].text, "Hug this label");
+  assert!(
+    (container.width - 16.0 - runs[0].width).abs() <= 1.0,
+    "container is {} wide for a {} wide run",
+    container.width,
+    runs[0].width
+  );
+}
+
+#[test]
+fn test_measure_min_content_width_breaks_every_word() {
+  let max_content = measure_intrinsic_container(MaxContent);
+  let min_content = measure_intrinsic_container(MinContent);
+  let fit_content = measure_intrinsic_container(FitContent);
+
+  assert_eq!(collect_runs(&min_content).len(), 3);
+  assert!(min_content.width < max_content.width);
+
+  // With plenty of room, fit-content resolves to max-content.
+  assert_eq!(fit_content.width, max_content.width);
+}
+
+#[test]
+fn test_measure_deeply_nested_fit_content() {
+  // Measuring each level must reuse the intrinsic sizes of the levels below, or the work doubles per level.
+  let mut node: NodeKind = TextNode {
+    preset: None,
+    id: None,
+    tw: None,
+    font_size_fit: None,
+    first_letter_style: None,
+    style: None,
+    text: "Deep".to_string(),
+  }
+  .into();
+
+  for _ in 0..32 {
+    node = ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(FitContent)
+          .display(Display::Block)
+          .build()
+          .unwrap(),
+      ),
+      children: Some([node].into()),
+    }
+    .into();
+  }
+
+  let result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  let mut level = &result;
+  while let Some(child) = level.children.first() {
+    assert_eq!(child.width, result.width);
+    level = child;
+  }
+
+  assert!(result.width > 0.0 && result.width < 1200.0);
+}
+
+fn measure_min_content_with_overflow_wrap(overflow_wrap: &str) -> MeasuredNode {
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(
+      StyleBuilder::default()
+        .width(Px(600.0))
+        .display(Display::Block)
+        .font_size(Some(Px(20.0)))
+        .overflow_wrap(OverflowWrap::from_str(overflow_wrap).unwrap())
+        .build()
+        .unwrap(),
+    ),
+    children: Some(
+      [ContainerNode {
+        preset: None,
+        id: None,
+        tw: None,
+        style: Some(
+          StyleBuilder::default()
+            .width(MinContent)
+            .display(Display::Block)
+            .build()
+            .unwrap(),
+        ),
+        children: Some(
+          [TextNode {
+            preset: None,
+            id: None,
+            tw: None,
+            font_size_fit: None,
+            first_letter_style: None,
+            style: None,
+            text: "see https://example.com/a/very/long/unbreakable/path".to_string(),
+          }
+          .into()]
+          .into(),
+        ),
+      }
+      .into()]
+      .into(),
+    ),
+  }
+  .into();
+
+  let mut result = measure_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  result.children.remove(0)
+}
+
+#[test]
+fn test_measure_min_content_overflow_wrap_anywhere_shrinks_below_longest_word() {
+  let break_word = measure_min_content_with_overflow_wrap("break-word");
+  let anywhere = measure_min_content_with_overflow_wrap("anywhere");
+
+  // `break-word` keeps the URL whole for min-content, so the box is as wide as the URL.
+  let break_word_runs = collect_runs(&break_word);
+  let url_width = break_word_runs
+    .iter()
+    .map(|run| run.width)
+    .fold(0.0, f32::max);
+
+  assert!(url_width > 0.0);
+  assert!(
+    break_word.width >= url_width - 1.0,
+    "break-word box is {} wide for a {} wide URL",
+    break_word.width,
+    url_width
+  );
+
+  // `anywhere` lets the URL break between any characters, so the box shrinks much further.
+  assert!(
+    anywhere.width < break_word.width / 2.0,
+    "anywhere box is {} wide, break-word box is {} wide",
+    anywhere.width,
+    break_word.width
+  );
+}
+
+fn gap_item_offsets(mut style: StyleBuilder) -> Vec<(f32, f32)> {
+  let child = || -> NodeKind {
+    ContainerNode {
+      preset: None,
+      id: None,
+      tw: None,
+      style: Some(
+        StyleBuilder::default()
+          .width(Px(50.0))
+          .height(Px(50.0))
+          .build()
+          .unwrap(),
+      ),
+      children: None,
+    }
+    .into()
+  };
+
+  let node: NodeKind = ContainerNode {
+    preset: None,
+    id: None,
+    tw: None,
+    style: Some(style.width(Px(500.0)).height(Px(200.0)).build().unwrap()),
+    children: Some([child(), child(), child()].into()),
+  }
+  .into();
+
+  let result = render_debug_layout(
+    RenderOptionsBuilder::default()
+      .viewport(create_test_viewport())
+      .node(node)
+      .global(&CONTEXT)
+      .build()
+      .unwrap(),
+  )
+  .unwrap();
+
+  result
+    .children
+    .iter()
+    .map(|child| (child.x, child.y))
+    .collect()
+}
+
+#[test]
+fn test_flex_row_percentage_gap_resolves_against_width() {
+  let mut style = StyleBuilder::default();
+  style
+    .flex_direction(FlexDirection::Row)
+    .gap(Gap::from_single(Percentage(10.0)));
+
+  // 10% of the 500px row width.
+  assert_eq!(
+    gap_item_offsets(style),
+    [(0.0, 0.0), (100.0, 0.0), (200.0, 0.0)]
+  );
+}
+
+#[test]
+fn test_flex_column_percentage_gap_resolves_against_height() {
+  let mut style = StyleBuilder::default();
+  style
+    .flex_direction(FlexDirection::Column)
+    .gap(Gap::from_single(Percentage(10.0)));
+
+  // 10% of the 200px column height.
+  assert_eq!(
+    gap_item_offsets(style),
+    [(0.0, 0.0), (0.0, 70.0), (0.0, 140.0)]
+  );
+}
+
+#[test]
+fn test_flex_gap_longhands_apply_to_their_axis() {
+  let mut row = StyleBuilder::default();
+  row
+    .flex_direction(FlexDirection::Row)
+    .column_gap(Some(Px(30.0)))
+    .row_gap(Some(Percentage(50.0)));
+
+  assert_eq!(
+    gap_item_offsets(row),
+    [(0.0, 0.0), (80.0, 0.0), (160.0, 0.0)]
+  );
+
+  let mut column = StyleBuilder::default();
+  column
+    .flex_direction(FlexDirection::Column)
+    .column_gap(Some(Percentage(50.0)))
+    .row_gap(Some(Px(30.0)));
+
+  assert_eq!(
+    gap_item_offsets(column),
+    [(0.0, 0.0), (0.0, 80.0), (0.0, 160.0)]
+  );
+}
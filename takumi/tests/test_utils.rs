@@ -8,6 +8,7 @@ use std::{
 use image::{RgbaImage, load_from_memory};
 use parley::{GenericFamily, fontique::FontInfoOverride};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde_json::from_value;
 use takumi::{
   GlobalContext,
   layout::{Viewport, node::NodeKind},
@@ -85,6 +86,36 @@ fn create_test_context() -> GlobalContext {
     )),
   );
 
+  let mut rounded_frame_image_data = Vec::new();
+  File::open(assets_path("images/rounded-frame.png"))
+    .unwrap()
+    .read_to_end(&mut rounded_frame_image_data)
+    .unwrap();
+
+  context.persistent_image_store.insert(
+    "assets/images/rounded-frame.png".to_string(),
+    Arc::new(ImageSource::Bitmap(
+      load_from_memory(&rounded_frame_image_data)
+        .unwrap()
+        .into_rgba8(),
+    )),
+  );
+
+  let mut checkerboard_image_data = Vec::new();
+  File::open(assets_path("images/checkerboard.png"))
+    .unwrap()
+    .read_to_end(&mut checkerboard_image_data)
+    .unwrap();
+
+  context.persistent_image_store.insert(
+    "assets/images/checkerboard.png".to_string(),
+    Arc::new(ImageSource::Bitmap(
+      load_from_memory(&checkerboard_image_data)
+        .unwrap()
+        .into_rgba8(),
+    )),
+  );
+
   let mut luma_cover_image_data = Vec::new();
   File::open(assets_path(
     "images/luma-cover-0dfbf65d-0f58-4941-947c-d84a5b131dc0.jpeg",
@@ -130,6 +161,7 @@ fn create_test_context() -> GlobalContext {
   context
 }
 
+#[allow(dead_code)]
 pub fn create_test_viewport() -> Viewport {
   (1200, 630).into()
 }
@@ -138,17 +170,23 @@ pub static CONTEXT: LazyLock<GlobalContext> = LazyLock::new(create_test_context)
 
 #[allow(dead_code)]
 pub fn run_fixture_test(node: NodeKind, fixture_name: &str) {
+  run_fixture_test_with_options(node, fixture_name, |_| {});
+}
+
+/// Like [`run_fixture_test`], letting the test set extra render options.
+#[allow(dead_code)]
+pub fn run_fixture_test_with_options(
+  node: NodeKind,
+  fixture_name: &str,
+  configure: impl FnOnce(&mut RenderOptionsBuilder<'static, NodeKind>),
+) {
   let viewport = create_test_viewport();
 
-  let image = render(
-    RenderOptionsBuilder::default()
-      .viewport(viewport)
-      .node(node)
-      .global(&CONTEXT)
-      .build()
-      .unwrap(),
-  )
-  .unwrap();
+  let mut options = RenderOptionsBuilder::default();
+  options.viewport(viewport).node(node).global(&CONTEXT);
+  configure(&mut options);
+
+  let image = render(options.build().unwrap()).unwrap();
 
   save_image(
     &image,
@@ -157,20 +195,43 @@ pub fn run_fixture_test(node: NodeKind, fixture_name: &str) {
   );
 }
 
+/// Deserializes `node` from JSON and renders it with the shared test context.
+#[allow(dead_code)]
+pub fn render_json(node: serde_json::Value, viewport: impl Into<Viewport>) -> RgbaImage {
+  render_json_with_options(node, viewport, |_| {}).unwrap()
+}
+
+/// Like [`render_json`], letting the test set extra render options and see the render error.
+#[allow(dead_code)]
+pub fn render_json_with_options<'g>(
+  node: serde_json::Value,
+  viewport: impl Into<Viewport>,
+  configure: impl FnOnce(&mut RenderOptionsBuilder<'g, NodeKind>),
+) -> takumi::Result<RgbaImage> {
+  let node: NodeKind = from_value(node).unwrap();
+
+  let mut options = RenderOptionsBuilder::default();
+  options
+    .viewport(viewport.into())
+    .node(node)
+    .global(&CONTEXT);
+  configure(&mut options);
+
+  render(options.build().unwrap())
+}
+
 fn save_image<P: AsRef<Path>>(image: &RgbaImage, path: P, format: ImageOutputFormat) {
   let path = path.as_ref();
 
   let mut file = File::create(path).unwrap();
 
-  write_image(image, &mut file, format, None).unwrap();
+  write_image(image, &mut file, format, None, None).unwrap();
 }
 
 #[allow(dead_code)]
 pub fn run_webp_animation_test(
   nodes: Vec<(NodeKind, u32)>,
   fixture_name: &str,
-  blend: bool,
-  dispose: bool,
   loop_count: Option<u16>,
 ) {
   assert_ne!(nodes.len(), 0);
@@ -197,7 +258,7 @@ pub fn run_webp_animation_test(
 
   let fixture_path = format!("tests/fixtures-generated/{}", fixture_name);
   let mut out = File::create(fixture_path).unwrap();
-  encode_animated_webp(&frames, &mut out, blend, dispose, loop_count).unwrap();
+  encode_animated_webp(&frames, &mut out, loop_count, &CONTEXT).unwrap();
 }
 
 #[allow(dead_code)]